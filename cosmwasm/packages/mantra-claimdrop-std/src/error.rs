@@ -0,0 +1,309 @@
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal, HexBinary, OverflowError, StdError, Timestamp, Uint128,
+};
+use cw_migrate_error_derive::cw_migrate_invalid_version_error;
+use cw_ownable::OwnershipError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+/// The errors of the contract. Besides its message, every error has a stable code and name, see
+/// [ContractError::code] and [ContractError::name], so clients don't need to parse the messages.
+#[cw_migrate_invalid_version_error]
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{0}")]
+    OwnershipError(#[from] OwnershipError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("{0}")]
+    ConversionOverflowError(#[from] ConversionOverflowError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Invalid distribution percentage, expected: {expected}, actual: {actual}")]
+    InvalidDistributionPercentage { expected: Decimal, actual: Decimal },
+
+    #[error("Invalid distribution percentage, cannot be zero")]
+    ZeroDistributionPercentage,
+
+    #[error("Invalid campaign parameter: {param} - {reason}")]
+    InvalidCampaignParam { param: String, reason: String },
+
+    #[error("Claim amount exceeds the maximum claimable amount")]
+    ExceededMaxClaimAmount,
+
+    #[error("Campaign error: {reason}")]
+    CampaignError { reason: String },
+
+    #[error("There's not an active campaign")]
+    NoActiveCampaign,
+
+    #[error("There's already an existing campaign")]
+    CampaignAlreadyExists,
+
+    #[error("Campaign has been closed")]
+    CampaignClosed,
+
+    #[error("Campaign has not started, it starts at {starts_at}")]
+    CampaignNotStarted { starts_at: Timestamp },
+
+    #[error("Campaign has already started at {started_at}")]
+    CampaignAlreadyStarted { started_at: Timestamp },
+
+    #[error("Insufficient funds, required: {required}, available: {available}")]
+    InsufficientFunds {
+        required: Uint128,
+        available: Uint128,
+    },
+
+    #[error("Campaign is not fully funded yet, required: {required}, available: {available}")]
+    CampaignNotFullyFunded {
+        required: Uint128,
+        available: Uint128,
+    },
+
+    #[error("Distribution slot {slot} is not funded yet, requested: {requested}, available: {available}")]
+    SlotNotFunded {
+        slot: usize,
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error("The campaign would be overfunded, required: {required}, funded: {funded}")]
+    CampaignOverfunded { required: Uint128, funded: Uint128 },
+
+    #[error("Voucher has expired at {expired_at}")]
+    VoucherExpired { expired_at: Timestamp },
+
+    #[error("Voucher has already been registered")]
+    VoucherAlreadyRegistered,
+
+    #[error("Invalid distribution times, start time: {start_time}, end time: {end_time}")]
+    InvalidDistributionTimes { start_time: u64, end_time: u64 },
+
+    #[error("Invalid start distribution time, start time: {start_time}, campaign start time: {campaign_start_time}. The start time needs to be in the future.")]
+    InvalidStartDistributionTime {
+        start_time: u64,
+        campaign_start_time: u64,
+    },
+
+    #[error("Invalid end distribution time, end time: {end_time}, campaign_end_time: {campaign_end_time}. The distribution end time needs to be less or equal to the campaign's end time.")]
+    InvalidEndDistributionTime {
+        end_time: u64,
+        campaign_end_time: u64,
+    },
+
+    #[error("There's nothing to claim for the given address")]
+    NothingToClaim,
+
+    #[error("No allocation found for address: {address}")]
+    NoAllocationFound { address: String },
+
+    #[error("The current address already has an allocation: {address}")]
+    AllocationAlreadyExists { address: String },
+
+    #[error("Address is blacklisted")]
+    AddressBlacklisted,
+
+    #[error("Address is not allowlisted")]
+    AddressNotAllowlisted,
+
+    #[error("Address is frozen")]
+    AddressFrozen,
+
+    #[error("The claims of the address are on hold until {until}")]
+    AddressOnHold { until: u64 },
+
+    #[error("The address {address} didn't pass the eligibility check")]
+    AddressNotEligible { address: String },
+
+    #[error("The account of {address} already exists, a vesting account can't be created for it")]
+    ReceiverAccountExists { address: String },
+
+    #[error("No group found with name: {name}")]
+    GroupNotFound { name: String },
+
+    #[error("The address {address} is not a member of the group {group}")]
+    NotGroupMember { group: String, address: String },
+
+    #[error("Claim cooldown is active, the next claim is allowed at {next_claim_at}")]
+    ClaimCooldownActive { next_claim_at: u64 },
+
+    #[error("Claims are paused")]
+    ClaimsPaused,
+
+    #[error("The contract is paused: {scope}")]
+    ContractPaused { scope: String },
+
+    #[error("IBC channel is not allowed: {channel_id}")]
+    IbcChannelNotAllowed { channel_id: String },
+
+    #[error("Invalid IBC admin channel: {reason}")]
+    InvalidIbcAdminChannel { reason: String },
+
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    #[error("Invalid nonce: expected {expected}, got {actual}")]
+    InvalidNonce { expected: u64, actual: u64 },
+
+    #[error("Invalid claim amount: {reason}")]
+    InvalidClaimAmount { reason: String },
+
+    #[error("The claim window expired at {expired_at}")]
+    ClaimWindowExpired { expired_at: Timestamp },
+
+    #[error("The terms {terms_hash} must be accepted before claiming")]
+    TermsNotAccepted { terms_hash: HexBinary },
+
+    #[error("Invalid denom {denom}: {reason}")]
+    InvalidDenom { denom: String, reason: String },
+
+    #[error("Invalid input: {reason}")]
+    InvalidInput { reason: String },
+
+    #[error("Batch size limit exceeded: {actual}, maximum allowed: {max}")]
+    BatchSizeLimitExceeded { actual: usize, max: usize },
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Invariant violation: {invariant}")]
+    InvariantViolation { invariant: String },
+}
+
+impl ContractError {
+    /// Returns the stable numeric code of the error. The codes are never reused nor changed, new
+    /// errors get new codes.
+    pub fn code(&self) -> u16 {
+        match self {
+            ContractError::Std(_) => 1,
+            ContractError::SemVer(_) => 2,
+            ContractError::OwnershipError(_) => 3,
+            ContractError::OverflowError(_) => 4,
+            ContractError::ConversionOverflowError(_) => 5,
+            ContractError::PaymentError(_) => 6,
+            ContractError::MigrateInvalidVersion { .. } => 7,
+            ContractError::InvalidDistributionPercentage { .. } => 100,
+            ContractError::ZeroDistributionPercentage => 101,
+            ContractError::InvalidCampaignParam { .. } => 102,
+            ContractError::InvalidDistributionTimes { .. } => 103,
+            ContractError::InvalidStartDistributionTime { .. } => 104,
+            ContractError::InvalidEndDistributionTime { .. } => 105,
+            ContractError::CampaignError { .. } => 200,
+            ContractError::NoActiveCampaign => 201,
+            ContractError::CampaignAlreadyExists => 202,
+            ContractError::CampaignClosed => 203,
+            ContractError::CampaignNotStarted { .. } => 204,
+            ContractError::CampaignAlreadyStarted { .. } => 205,
+            ContractError::InsufficientFunds { .. } => 206,
+            ContractError::CampaignNotFullyFunded { .. } => 207,
+            ContractError::SlotNotFunded { .. } => 208,
+            ContractError::CampaignOverfunded { .. } => 209,
+            ContractError::ContractPaused { .. } => 210,
+            ContractError::ExceededMaxClaimAmount => 300,
+            ContractError::NothingToClaim => 301,
+            ContractError::ClaimCooldownActive { .. } => 302,
+            ContractError::ClaimsPaused => 303,
+            ContractError::InvalidClaimAmount { .. } => 304,
+            ContractError::ClaimWindowExpired { .. } => 305,
+            ContractError::TermsNotAccepted { .. } => 306,
+            ContractError::NoAllocationFound { .. } => 400,
+            ContractError::AllocationAlreadyExists { .. } => 401,
+            ContractError::AddressBlacklisted => 402,
+            ContractError::AddressNotAllowlisted => 403,
+            ContractError::GroupNotFound { .. } => 404,
+            ContractError::NotGroupMember { .. } => 405,
+            ContractError::AddressFrozen => 406,
+            ContractError::AddressOnHold { .. } => 407,
+            ContractError::AddressNotEligible { .. } => 408,
+            ContractError::ReceiverAccountExists { .. } => 409,
+            ContractError::IbcChannelNotAllowed { .. } => 500,
+            ContractError::InvalidIbcAdminChannel { .. } => 501,
+            ContractError::InvalidSignature => 600,
+            ContractError::InvalidNonce { .. } => 601,
+            ContractError::VoucherExpired { .. } => 602,
+            ContractError::VoucherAlreadyRegistered => 603,
+            ContractError::InvalidDenom { .. } => 700,
+            ContractError::InvalidInput { .. } => 701,
+            ContractError::BatchSizeLimitExceeded { .. } => 702,
+            ContractError::Unauthorized => 800,
+            ContractError::InvariantViolation { .. } => 900,
+        }
+    }
+
+    /// Returns the stable name of the error, in snake case
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractError::Std(_) => "std",
+            ContractError::SemVer(_) => "semver",
+            ContractError::OwnershipError(_) => "ownership_error",
+            ContractError::OverflowError(_) => "overflow_error",
+            ContractError::ConversionOverflowError(_) => "conversion_overflow_error",
+            ContractError::PaymentError(_) => "payment_error",
+            ContractError::MigrateInvalidVersion { .. } => "migrate_invalid_version",
+            ContractError::InvalidDistributionPercentage { .. } => {
+                "invalid_distribution_percentage"
+            }
+            ContractError::ZeroDistributionPercentage => "zero_distribution_percentage",
+            ContractError::InvalidCampaignParam { .. } => "invalid_campaign_param",
+            ContractError::InvalidDistributionTimes { .. } => "invalid_distribution_times",
+            ContractError::InvalidStartDistributionTime { .. } => "invalid_start_distribution_time",
+            ContractError::InvalidEndDistributionTime { .. } => "invalid_end_distribution_time",
+            ContractError::CampaignError { .. } => "campaign_error",
+            ContractError::NoActiveCampaign => "no_active_campaign",
+            ContractError::CampaignAlreadyExists => "campaign_already_exists",
+            ContractError::CampaignClosed => "campaign_closed",
+            ContractError::CampaignNotStarted { .. } => "campaign_not_started",
+            ContractError::CampaignAlreadyStarted { .. } => "campaign_already_started",
+            ContractError::InsufficientFunds { .. } => "insufficient_funds",
+            ContractError::CampaignNotFullyFunded { .. } => "campaign_not_fully_funded",
+            ContractError::SlotNotFunded { .. } => "slot_not_funded",
+            ContractError::CampaignOverfunded { .. } => "campaign_overfunded",
+            ContractError::ExceededMaxClaimAmount => "exceeded_max_claim_amount",
+            ContractError::NothingToClaim => "nothing_to_claim",
+            ContractError::ClaimCooldownActive { .. } => "claim_cooldown_active",
+            ContractError::ClaimsPaused => "claims_paused",
+            ContractError::ContractPaused { .. } => "contract_paused",
+            ContractError::InvalidClaimAmount { .. } => "invalid_claim_amount",
+            ContractError::ClaimWindowExpired { .. } => "claim_window_expired",
+            ContractError::TermsNotAccepted { .. } => "terms_not_accepted",
+            ContractError::NoAllocationFound { .. } => "no_allocation_found",
+            ContractError::AllocationAlreadyExists { .. } => "allocation_already_exists",
+            ContractError::AddressBlacklisted => "address_blacklisted",
+            ContractError::AddressNotAllowlisted => "address_not_allowlisted",
+            ContractError::GroupNotFound { .. } => "group_not_found",
+            ContractError::NotGroupMember { .. } => "not_group_member",
+            ContractError::AddressFrozen => "address_frozen",
+            ContractError::AddressOnHold { .. } => "address_on_hold",
+            ContractError::AddressNotEligible { .. } => "address_not_eligible",
+            ContractError::ReceiverAccountExists { .. } => "receiver_account_exists",
+            ContractError::IbcChannelNotAllowed { .. } => "ibc_channel_not_allowed",
+            ContractError::InvalidIbcAdminChannel { .. } => "invalid_ibc_admin_channel",
+            ContractError::InvalidSignature => "invalid_signature",
+            ContractError::InvalidNonce { .. } => "invalid_nonce",
+            ContractError::VoucherExpired { .. } => "voucher_expired",
+            ContractError::VoucherAlreadyRegistered => "voucher_already_registered",
+            ContractError::InvalidDenom { .. } => "invalid_denom",
+            ContractError::InvalidInput { .. } => "invalid_input",
+            ContractError::BatchSizeLimitExceeded { .. } => "batch_size_limit_exceeded",
+            ContractError::Unauthorized => "unauthorized",
+            ContractError::InvariantViolation { .. } => "invariant_violation",
+        }
+    }
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,80 @@
+//! The raw state export, letting indexers and migration tooling sync the full state of the
+//! contract page by page. The entries mirror the storage layout, so the format only changes when
+//! the storage does.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+use crate::msg::{BlacklistEntry, DistributionSlot};
+
+/// A section of the state that can be exported.
+#[cw_serde]
+pub enum StateSection {
+    /// The allocations, keyed by address
+    Allocations,
+    /// The claims made, keyed by address
+    Claims,
+    /// The blacklisted addresses
+    Blacklist,
+    /// The authorized wallets
+    AuthorizedWallets,
+}
+
+/// A raw entry of the exported state.
+#[cw_serde]
+pub enum StateEntry {
+    /// The allocation of an address
+    Allocation {
+        /// The address of the allocation
+        address: String,
+        /// The amount allocated to the address
+        amount: Uint128,
+        /// Whether the address has claimed any of its allocation
+        claimed: bool,
+        /// The wallet that added the allocation
+        added_by: String,
+        /// The time when the allocation was added
+        added_at: Timestamp,
+        /// The address the claims of the allocation are locked to pay out to, if any
+        locked_receiver: Option<String>,
+    },
+    /// The claims made by an address
+    Claims {
+        /// The address that claimed
+        address: String,
+        /// The claims per distribution slot, sorted by slot
+        claims: Vec<RawClaim>,
+    },
+    /// A blacklisted address
+    Blacklisted {
+        /// The blacklisted address
+        address: String,
+        /// The details of the blacklisting
+        entry: BlacklistEntry,
+    },
+    /// An authorized wallet
+    AuthorizedWallet {
+        /// The authorized address
+        address: String,
+    },
+}
+
+/// The tokens claimed by an address from a distribution slot, as stored.
+#[cw_serde]
+pub struct RawClaim {
+    /// The distribution slot, i.e. the index of the distribution type on the campaign
+    pub slot: DistributionSlot,
+    /// The total amount claimed from the slot
+    pub amount: Uint128,
+    /// The timestamp of the last claim from the slot, in seconds
+    pub claimed_at: u64,
+}
+
+/// Response to the ExportState query.
+#[cw_serde]
+pub struct ExportStateResponse {
+    /// The entries of the section, sorted by address
+    pub entries: Vec<StateEntry>,
+    /// The address to start the next page after, or None if the section has been exported
+    pub next_start_after: Option<String>,
+}
@@ -0,0 +1,3758 @@
+use std::fmt::{Display, Formatter};
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, Binary, BlockInfo, Coin, CosmosMsg, Decimal, Decimal256,
+    HexBinary, Int128, StdResult, Timestamp, Uint128, WasmMsg,
+};
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+
+use crate::error::ContractError;
+use crate::export::{ExportStateResponse, StateSection};
+
+/// Maximum length for campaign name
+const MAX_NAME_LENGTH: usize = 200;
+/// Maximum length for campaign description
+const MAX_DESCRIPTION_LENGTH: usize = 2000;
+/// Maximum length for campaign type
+const MAX_TYPE_LENGTH: usize = 200;
+/// Maximum length for campaign metadata uri
+const MAX_METADATA_URI_LENGTH: usize = 500;
+/// Maximum length for the allocations and terms hashes, in bytes
+const MAX_HASH_LENGTH: usize = 64;
+/// Default maximum number of distribution types per campaign, if not set in the [Limits]
+pub const DEFAULT_MAX_DISTRIBUTION_SLOTS: u32 = 2;
+/// Maximum number of distribution types per campaign the [Limits] can allow, e.g. 48 monthly
+/// unlocks. The slots fully claimed by an address are tracked in a 64-bit mask.
+pub const MAX_DISTRIBUTION_SLOTS: u32 = 64;
+/// Maximum number of raffle winners
+pub const MAX_RAFFLE_WINNERS: u32 = 100;
+/// The version of the IBC channels administering the campaign
+pub const IBC_ADMIN_VERSION: &str = "claimdrop-admin-1";
+/// The placeholder replaced with the receiver address in the payout message template
+pub const PAYOUT_RECEIVER_PLACEHOLDER: &str = "{receiver}";
+/// The placeholder replaced with the receiver address in the eligibility query template
+pub const ELIGIBILITY_ADDRESS_PLACEHOLDER: &str = "{address}";
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Owner of the contract. If not set, it is the sender of the Instantiate message.
+    pub owner: Option<String>,
+    /// Optinal action in case the contract is instantiated via the claimdrop factory
+    pub action: Option<CampaignAction>,
+    /// The limits of the contract. If not set, the contract defaults are used.
+    pub limits: Option<Limits>,
+}
+
+#[cw_ownable_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Manages campaigns based on the action, defined by [CampaignAction].
+    ManageCampaign { action: CampaignAction },
+    /// Claims rewards from a campaign
+    Claim {
+        /// The receiver address of the claimed rewards. If not set, the sender of the message will be the receiver.
+        /// This is useful for allowing a contract to do the claim operation on behalf of a user.
+        receiver: Option<String>,
+        /// The amount to claim. If not set, all available tokens will be claimed.
+        amount: Option<Uint128>,
+        /// The share of the claimable tokens to claim, within (0, 1]. Alternative to `amount`, it
+        /// is converted to an amount against the tokens claimable at the time of the claim.
+        percentage: Option<Decimal>,
+        /// The distribution slots to claim from. If not set, the tokens are claimed from all the
+        /// slots, draining the lump sums first, then the linear vestings.
+        slots: Option<Vec<DistributionSlot>>,
+        /// Whether to exit the linear vestings into a continuous vesting account. If set, the
+        /// unvested tokens are locked in a new vesting account for the receiver, vesting until the
+        /// end of the linear vestings, and the vested tokens are sent as usual. The vesting module
+        /// can only create the vesting account over a fresh address, so the receiver account must
+        /// not exist yet: a receiver that ever signed a transaction or received tokens can't use
+        /// this option, which makes it practical only for an authorized wallet claiming on behalf
+        /// of a never used receiver. The whole unvested amount is claimed at once, so it can only
+        /// be used once per receiver.
+        into_vesting_account: Option<bool>,
+        /// The address on a remote chain to deliver the rewards to via IBC, instead of sending
+        /// them to the receiver. Only the receiver itself can set it. If the transfer times out or
+        /// fails, the refunded rewards are credited back to the receiver, who can withdraw them
+        /// with [ExecuteMsg::WithdrawUnlocked].
+        ibc_receiver: Option<IbcReceiver>,
+        /// The tier to lock the claimed tokens for. If set, the claim is boosted by the multiplier
+        /// of the tier, and the tokens are locked in the contract until they can be withdrawn with
+        /// [ExecuteMsg::WithdrawUnlocked]. Only the receiver itself can set it.
+        lock_option: Option<LockTier>,
+    },
+    /// Withdraws the tokens of the sender's locks that have unlocked.
+    WithdrawUnlocked {},
+    /// Forfeits the allocations whose claim window expired, sending what's left unclaimed to the
+    /// community pool of the campaign. Anyone can forfeit expired allocations.
+    ForfeitExpiredAllocations {
+        /// The addresses whose allocations expired
+        addresses: Vec<String>,
+    },
+    /// Accepts the terms and conditions of the campaign on behalf of the sender, who can't claim
+    /// before accepting them when the campaign has terms.
+    AcceptTerms {
+        /// The hash of the terms accepted, which must match [Campaign::terms_hash]
+        terms_hash: HexBinary,
+    },
+    /// Claims the rewards of the sender vested up to the given time, which can't be in the future.
+    /// The integrations settling on fixed intervals claim deterministic amounts this way,
+    /// regardless of when their claim is executed.
+    ClaimUpTo {
+        /// The time up to which the vested rewards are claimed
+        timestamp: Timestamp,
+    },
+    /// Tops up the campaign with the reward denom sent along, recording the deposit in the funding
+    /// history. Anyone can top up the campaign, as long as it is funded in advance and not closed.
+    TopUpCampaign {
+        /// Whether to accept funding the campaign beyond what it still owes. Defaults to false.
+        allow_overfund: Option<bool>,
+    },
+    /// Creates a group sharing a pool of the campaign rewards, vested following its own schedule.
+    /// The members of the group claim the pool pro-rata to their weights. The allocations and the
+    /// group pools together can't exceed the campaign total reward. Only the owner can create
+    /// groups, before the campaign starts.
+    CreateGroup {
+        /// The name of the group
+        name: String,
+        /// The vesting schedule of the pool, made of linear, curve or lump sum distributions
+        schedule: Vec<DistributionType>,
+        /// The size of the pool, in the reward denom. It's paid out of the campaign total reward.
+        total: Uint128,
+    },
+    /// Adds members to a group, or updates their weights, and removes members from it. Only the
+    /// owner can manage the members, before the campaign starts.
+    UpdateGroupMembers {
+        /// The name of the group
+        name: String,
+        /// The members to add or update, with their weights
+        add: Vec<(String, Uint128)>,
+        /// The members to remove
+        remove: Vec<String>,
+    },
+    /// Claims the share of the sender in a group pool that has vested so far. The campaign terms
+    /// and claim cooldown apply, but not the claim window, so the group pools are never forfeited.
+    ClaimGroup {
+        /// The name of the group
+        name: String,
+    },
+    /// Sets the budget of the campaign, the amount of the reward denom it can pay out of the
+    /// contract balance (owner only). Once set, the claims can't pay out more than the budget and
+    /// closing the campaign only refunds what's left of it, leaving the rest of the balance alone.
+    SetBudget {
+        /// The budget, which can't be lower than the amount already paid out of it
+        amount: Uint128,
+    },
+    /// Claims rewards on behalf of the receiver, who signed a [ClaimSignaturePayload] off-chain
+    /// wrapped in an ADR-36 sign doc. Anyone can relay the claim, and the rewards are sent to the
+    /// receiver, allowing to claim without holding gas tokens.
+    ClaimWithSignature {
+        /// The address claiming the rewards, which signed the payload
+        receiver: String,
+        /// The amount to claim. If not set, all available tokens will be claimed.
+        amount: Option<Uint128>,
+        /// The nonce of the receiver, which must match the one returned by [QueryMsg::ClaimNonce]
+        nonce: u64,
+        /// The compressed secp256k1 public key of the receiver
+        pubkey: Binary,
+        /// The secp256k1 signature of the ADR-36 sign doc
+        signature: Binary,
+    },
+    /// Claims rewards on behalf of the receiver, which granted the sender an authz authorization to
+    /// execute contracts on its behalf, i.e. a custodian. The grant is verified with the authz
+    /// module, and the rewards are sent to the receiver. Either a `GenericAuthorization` of
+    /// `MsgExecuteContract` or a `ContractExecutionAuthorization` for this contract allowing the
+    /// `claim_authz` message, without a calls limit, is accepted.
+    ClaimAuthz {
+        /// The address claiming the rewards, which granted the authorization
+        receiver: String,
+        /// The amount to claim. If not set, all available tokens will be claimed.
+        amount: Option<Uint128>,
+    },
+    /// Claims all the available rewards of a batch of receivers in a single message. Only the
+    /// owner and the authorized wallets can claim in batches. The receivers with nothing to claim
+    /// are skipped, while if any other claim fails, the whole batch fails.
+    ClaimBatch {
+        /// The addresses to claim the rewards of
+        receivers: Vec<String>,
+    },
+    /// Registers the allocation of an [AllocationVoucher] signed off-chain by the owner, wrapped in
+    /// an ADR-36 sign doc. Anyone can submit a voucher, the allocation is added for the address of
+    /// the voucher. This can only be done before the campaign has started, same as
+    /// [ExecuteMsg::AddAllocations]. Each voucher can only be registered once, even if its
+    /// allocation was removed since.
+    RegisterAllocation {
+        /// The voucher signed by the owner
+        voucher: AllocationVoucher,
+        /// The secp256k1 signature of the ADR-36 sign doc, verified against [Config::voucher_pubkey]
+        signature: Binary,
+    },
+    /// Adds a batch of addresses and their allocations. This can only be done before the campaign has started.
+    AddAllocations {
+        /// Vector of (address, amount) pairs
+        allocations: Vec<(String, Uint128)>,
+        /// Whether to skip the addresses that already have an allocation, or appear twice in the
+        /// batch, instead of failing the whole batch. Useful to resume an interrupted bulk
+        /// upload. Defaults to false.
+        skip_duplicates: Option<bool>,
+        /// The tag of the cohort the addresses belong to, if any, to query the allocations and
+        /// the stats per cohort
+        tag: Option<String>,
+        /// The identifier of the batch. Re-submitting a batch already processed is a no-op, so
+        /// the uploads can be retried safely.
+        batch_id: Option<String>,
+    },
+    /// Sets a batch of allocations according to the given mode, so the allocations uploaded by
+    /// mistake can be fixed in place. This can only be done before the campaign has started.
+    SetAllocations {
+        /// Vector of (address, amount) pairs
+        entries: Vec<(String, Uint128)>,
+        /// How the entries are applied to the existing allocations, defined by [AllocationMode]
+        mode: AllocationMode,
+        /// The tag of the cohort the addresses belong to. The existing allocations keep their tag
+        /// if not set.
+        tag: Option<String>,
+    },
+    /// Applies a diff to the allocations, so a corrected snapshot can be deployed without
+    /// re-uploading it. The whole diff is validated before being applied, and fails if an address
+    /// appears more than once. This can only be done before the campaign has started.
+    ApplyAllocationDelta {
+        /// The (address, amount) pairs of the new allocations, failing if an address already has
+        /// one
+        additions: Vec<(String, Uint128)>,
+        /// The addresses whose allocation is removed, failing if an address has none
+        removals: Vec<String>,
+        /// The (address, change) pairs of the existing allocations to increase or decrease,
+        /// failing if an address has none or if its allocation would drop to zero or below
+        adjustments: Vec<(String, Int128)>,
+    },
+    /// Imports a page of allocations from a previous claimdrop contract, skipping the addresses that
+    /// already claimed on it. This can only be done before the campaign has started.
+    ImportAllocations {
+        /// The claimdrop contract to import the allocations from
+        source_contract: String,
+        /// The address to start importing after. Used for paginating the import.
+        start_after: Option<String>,
+        /// The maximum number of allocations to import. If not set, the maximum value is used.
+        limit: Option<u16>,
+        /// Whether to import the allocations of the addresses that already claimed on the source
+        /// contract, i.e. when cloning a recurring campaign. Defaults to false.
+        include_claimed: Option<bool>,
+    },
+    /// Snapshots the delegators of the given validators from the staking module, adding an
+    /// allocation proportional to their stake for each of them. This can only be done by the owner,
+    /// before the campaign has started.
+    SnapshotDelegators {
+        /// The operator addresses of the validators whose delegators are snapshotted
+        validator_addrs: Vec<String>,
+        /// The minimum amount staked across the validators for a delegator to get an allocation
+        min_stake: Uint128,
+        /// The amount of reward allocated per staked token
+        ratio: Decimal,
+    },
+    /// Starts the vesting of the sender, when the campaign vests from the activation. The
+    /// distribution schedules of the sender are offset by the time elapsed since the campaign
+    /// start, and nothing can be claimed before the activation.
+    ActivateVesting {},
+    /// Transfers the allocation and claims of the sender to another address it controls, unless
+    /// the campaign disabled the allocation transfers. Neither address can be blacklisted.
+    TransferAllocation {
+        /// The address to transfer the allocation to
+        new_address: String,
+    },
+    /// Binds the allocation of a foreign, i.e. Ethereum, address to the sender, moving its
+    /// allocation and claims to the sender's address. The sender proves the control of the foreign
+    /// key by signing a [LinkAddressPayload] with EIP-191 (`personal_sign`). Neither address can be
+    /// blacklisted.
+    LinkAddress {
+        /// The 0x-prefixed foreign address holding the allocation
+        foreign_address: String,
+        /// The 65 bytes `r || s || v` secp256k1 signature of the EIP-191 message
+        signature: HexBinary,
+    },
+    /// Closes the campaign once its grace period after the end time has passed, refunding the
+    /// remaining funds to the owner same as [CampaignAction::CloseCampaign]. Anyone can execute it.
+    FinalizeCampaign {},
+    /// Requests the replacement of the sender's address by a new address it controls, to be approved
+    /// by an admin with [ExecuteMsg::ReplaceAddress]. If the new address is not set, the pending
+    /// request is withdrawn.
+    RequestAddressReplacement {
+        /// The address to replace the sender with
+        new_address: Option<String>,
+    },
+    /// Replaces an address in the allocation list. When [Config::replacements_require_request] is
+    /// set, the old address must have requested the replacement with
+    /// [ExecuteMsg::RequestAddressReplacement].
+    ReplaceAddress {
+        /// The old address to replace
+        old_address: String,
+        /// The new address to use
+        new_address: String,
+    },
+    /// Replaces a batch of addresses in the allocation list. If any of the pairs is invalid, the
+    /// whole batch fails.
+    ReplaceAddresses {
+        /// Vector of (old_address, new_address) pairs
+        pairs: Vec<(String, String)>,
+    },
+    /// Removes an address in the allocation list. This can only be done before the campaign has started.
+    RemoveAddress {
+        /// The address to remove
+        address: String,
+    },
+    /// Blacklists or unblacklists an address. This can be done at any time.
+    BlacklistAddress {
+        /// The address to blacklist/unblacklist
+        address: String,
+        /// Whether to blacklist or unblacklist
+        blacklist: bool,
+        /// The reason for blacklisting the address, recorded for auditing purposes
+        reason: Option<String>,
+    },
+    /// Blacklists or unblacklists a batch of addresses, same as [ExecuteMsg::BlacklistAddress].
+    /// The owner and the pending owner are skipped instead of failing the whole batch.
+    ManageBlacklist {
+        /// Vector of addresses to blacklist/unblacklist
+        addresses: Vec<String>,
+        /// Whether to blacklist or unblacklist the addresses
+        blacklist: bool,
+    },
+    /// Freezes an address under investigation. Same as the blacklisted addresses, the frozen ones
+    /// can't claim, but their allocations are left out of [ExecuteMsg::RedistributeForfeited]
+    /// until the freeze is resolved with [ExecuteMsg::ResolveFrozen].
+    FreezeAddress {
+        /// The address to freeze
+        address: String,
+        /// The reason for freezing the address, recorded for auditing purposes
+        reason: Option<String>,
+    },
+    /// Resolves the freeze of an address, either forfeiting its allocation by blacklisting it, or
+    /// releasing it so it can claim again.
+    ResolveFrozen {
+        /// The frozen address
+        address: String,
+        /// The resolution of the freeze
+        action: FrozenResolution,
+    },
+    /// Holds the claims of an address until the given time, e.g. for a compliance check. Unlike
+    /// the blacklist, the hold is temporary and the allocation isn't forfeited, the address can
+    /// claim again once the hold expires.
+    HoldAddress {
+        /// The address to hold
+        address: String,
+        /// The time until which the claims are held, in seconds. If not set, the hold is lifted.
+        until: Option<u64>,
+    },
+    /// Adds or removes addresses from the allowlist. Only allowlisted addresses can claim when the
+    /// campaign is in [ClaimMode::AllowlistOnly] mode. This can be done at any time.
+    ManageAllowlist {
+        /// Vector of addresses to add to/remove from the allowlist
+        addresses: Vec<String>,
+        /// Whether to add or remove the addresses from the allowlist
+        allowlist: bool,
+    },
+    /// Locks the payouts of an allocation to a pre-approved address, so claims for it can only pay
+    /// out there, even when claimed by an authorized wallet. Useful for custodial flows where an
+    /// exchange claims on behalf of its users. Only the owner can lock receivers.
+    SetLockedReceiver {
+        /// The address of the allocation
+        address: String,
+        /// The address the claims of the allocation pay out to. If not set, the lock is removed.
+        locked_receiver: Option<String>,
+    },
+    /// Registers or unregisters contracts to be notified with a [ClaimHookMsg] whenever a claim
+    /// succeeds. Only the owner can manage the claim hooks.
+    ManageClaimHooks {
+        /// Vector of contract addresses to register/unregister
+        addresses: Vec<String>,
+        /// Whether to register or unregister the addresses
+        registered: bool,
+    },
+    /// Manages authorized wallets that can perform admin actions. Only the owner can manage authorized wallets.
+    /// When the config sets an authorized wallet delay, the wallets added are pending until the delay
+    /// has elapsed and they are activated with [ExecuteMsg::ActivateAuthorizedWallets]. The removals
+    /// are immediate, and cancel the pending additions.
+    ManageAuthorizedWallets {
+        /// Vector of addresses to authorize/unauthorize
+        addresses: Vec<String>,
+        /// Whether to authorize or unauthorize the addresses
+        authorized: bool,
+    },
+    /// Activates the pending authorized wallets whose delay has elapsed. Anyone can activate them.
+    ActivateAuthorizedWallets {
+        /// The pending authorized wallets to activate
+        addresses: Vec<String>,
+    },
+    /// Grants roles to a wallet, allowing it to perform the admin actions of the roles. Only the
+    /// owner can grant roles.
+    GrantRoles {
+        /// The address to grant the roles to
+        address: String,
+        /// The roles to grant, defined by [Role]
+        roles: Vec<Role>,
+    },
+    /// Revokes roles from a wallet. Only the owner can revoke roles.
+    RevokeRoles {
+        /// The address to revoke the roles from
+        address: String,
+        /// The roles to revoke, defined by [Role]
+        roles: Vec<Role>,
+    },
+    /// Sweep non-reward tokens from the contract (owner only)
+    /// This allows retrieving any tokens accidentally sent to the contract
+    /// that are not the campaign's reward denom. Sweeps taking the amount of the denom swept
+    /// since its last approved sweep above the sweep threshold are held pending until an
+    /// authorized wallet approves them, and can't be requested while another one is pending
+    /// approval.
+    Sweep {
+        /// The denomination of the token to sweep
+        denom: String,
+        /// Optional amount to sweep. If not provided, sweeps entire balance
+        amount: Option<Uint128>,
+    },
+    /// Approves the pending sweep, performing it (owner or authorized wallet other than the one
+    /// that requested the sweep). Must happen within the sweep approval window.
+    ApproveSweep {},
+    /// Deletes the allocations, claims and blacklist entries of a closed campaign in bounded
+    /// batches, to reclaim the chain storage (owner only). Must be called repeatedly until the
+    /// `done` attribute of the `claimdrop/prune_state` event is true.
+    PruneState {
+        /// The maximum number of entries to delete. If not set, the maximum value is used.
+        limit: Option<u16>,
+    },
+    /// Redistributes the unclaimed allocations of the blacklisted addresses to the other
+    /// addresses, proportionally to their allocations (owner only). The allocations are processed
+    /// in batches, first reclaiming the blacklisted allocations, then increasing the others. Must
+    /// be called repeatedly until the `done` attribute of the `claimdrop/redistribute` event is
+    /// true.
+    RedistributeForfeited {
+        /// The maximum number of allocations to process. If not set, the maximum value is used.
+        limit: Option<u16>,
+    },
+    /// Updates the contract configuration (owner only)
+    UpdateConfig {
+        /// The circuit breaker pausing the claims when too many tokens are claimed within a time
+        /// window. If not set, the circuit breaker is disabled.
+        circuit_breaker: Option<CircuitBreaker>,
+        /// The limits of the contract. If not set, the limits are left unchanged.
+        limits: Option<Limits>,
+        /// Whether the address replacements require a request from the old address. If not set,
+        /// the setting is left unchanged.
+        replacements_require_request: Option<bool>,
+        /// The compressed secp256k1 public key of the owner, verifying the allocation vouchers. If
+        /// not set, the key is left unchanged, and an empty key disables the vouchers.
+        voucher_pubkey: Option<Binary>,
+        /// The foreign bech32 prefixes accepted in the addresses, replacing the current ones. If
+        /// not set, the prefixes are left unchanged.
+        allowed_prefixes: Option<Vec<String>>,
+        /// The delay, in seconds, before the authorized wallets added take effect. Raising it
+        /// applies immediately, while lowering it only applies once the current delay has elapsed.
+        /// If not set, the delay is left unchanged.
+        authorized_wallet_delay: Option<u64>,
+        /// The amount above which the sweeps require a second approval. If not set, the threshold
+        /// is left unchanged. The owner can only lower it on its own, raising or disabling it
+        /// requires a proposal.
+        sweep_threshold: Option<Uint128>,
+        /// The campaign registry notified on the lifecycle events of the campaign. If not set, the
+        /// registry is left unchanged, and an empty address removes it.
+        registry: Option<String>,
+    },
+    /// Resumes the claims after the circuit breaker has been tripped (owner only). It doesn't
+    /// lift the pause set with [ExecuteMsg::SetPause].
+    ResumeClaims {},
+    /// Pauses the given scope of the contract, i.e. for maintenance, or lifts the pause if no
+    /// scope is set (owner only). The pause is independent of the circuit breaker, so lifting it
+    /// doesn't resume the claims paused by a tripped circuit breaker, see
+    /// [ExecuteMsg::ResumeClaims].
+    SetPause {
+        /// The scope of the contract to pause, defined by [PauseScope]
+        scope: Option<PauseScope>,
+    },
+    /// Adds or removes the IBC channels the rewards can be delivered over (owner only)
+    ManageIbcChannels {
+        /// Vector of channel ids to allow/disallow
+        channel_ids: Vec<String>,
+        /// Whether to allow or disallow the channels
+        allowed: bool,
+    },
+    /// Sets the counterparty allowed to administer the campaign over IBC, or disables the IBC
+    /// administration if not set (owner only). Changing it doesn't close the current channel.
+    UpdateIbcAdmin {
+        /// The counterparty allowed to open the IBC admin channel, defined by [IbcAdmin]
+        ibc_admin: Option<IbcAdmin>,
+    },
+    /// Submits the seed used to draw the raffle winners, i.e. a drand beacon relayed by an oracle
+    /// (owner or authorized wallet). Can only be submitted once the raffle draw time has passed.
+    SubmitRaffleSeed {
+        /// The random seed
+        seed: HexBinary,
+    },
+    /// Draws the raffle winners from the allocation holders, weighted by their allocations, and
+    /// credits the prizes to the winners' allocations. Anyone can draw the raffle once the seed has
+    /// been submitted.
+    DrawRaffle {},
+    /// Proposes an irreversible action, to be approved by the owner (owner or authorized wallet).
+    /// The owner can also close the campaign and sweep directly, as it's the one approving.
+    Propose {
+        /// The action to perform once approved
+        action: ProposalAction,
+    },
+    /// Approves a proposal, performing its action (owner only). The owner can't approve the sweep
+    /// proposals it made.
+    Approve {
+        /// The id of the proposal to approve
+        proposal_id: u64,
+    },
+}
+
+/// The irreversible actions that authorized wallets can only perform through a proposal approved
+/// by the owner.
+#[cw_serde]
+pub enum ProposalAction {
+    /// Closes the campaign, refunding the remaining funds to the owner
+    CloseCampaign {},
+    /// Sweeps non-reward tokens to the owner
+    Sweep {
+        /// The denomination of the token to sweep
+        denom: String,
+        /// Optional amount to sweep. If not provided, sweeps entire balance
+        amount: Option<Uint128>,
+    },
+    /// Raises or disables the sweep threshold, which the owner can't do alone
+    SetSweepThreshold {
+        /// The amount above which the sweeps require a second approval. Zero disables it.
+        threshold: Uint128,
+    },
+}
+
+#[cw_ownable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(CampaignResponse)]
+    /// Get the airdrop campaign
+    Campaign {},
+    #[returns(CampaignStatusResponse)]
+    /// Get an overview of the campaign progress, i.e. allocations, claims and funding.
+    CampaignStatus {},
+    #[returns(CampaignPhaseResponse)]
+    /// Get the phase of the campaign
+    CampaignPhase {},
+    #[returns(IsLiveResponse)]
+    /// Get whether the campaign accepts claims, i.e. it has started, isn't closed and holds the
+    /// rewards left to claim if it requires full funding
+    IsLive {},
+    #[returns(RewardsResponse)]
+    /// Get the rewards for a specific campaign and receiver address.
+    Rewards {
+        /// The address to get the rewards for.
+        receiver: String,
+    },
+    #[returns(BatchRewardsResponse)]
+    /// Get the rewards for a batch of addresses, up to 100 at once. The addresses without an
+    /// allocation are returned with empty rewards.
+    BatchRewards {
+        /// The addresses to get the rewards for.
+        addresses: Vec<String>,
+    },
+    #[returns(SimulateClaimResponse)]
+    /// Simulates a claim of the whole available amount by an address, detailing it per distribution
+    /// slot along with the rounding dust attributed to each slot.
+    SimulateClaim {
+        /// The address to simulate the claim for.
+        address: String,
+    },
+    #[returns(ClaimedResponse)]
+    /// Get the total amount of tokens claimed on the campaign.
+    Claimed {
+        /// If provided, it will return the tokens claimed by the specified address.
+        address: Option<String>,
+        /// The address to start querying from. Used for paginating results.
+        start_from: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(ClaimedBySlotResponse)]
+    /// Get the tokens claimed per distribution slot, i.e. the vesting history of the addresses.
+    ClaimedBySlot {
+        /// If provided, it will return the claims of the specified address only.
+        address: Option<String>,
+        /// The address to start querying after. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(ClaimHistoryResponse)]
+    /// Get the history of the individual claims performed by an address.
+    ClaimHistory {
+        /// The address to get the claim history for.
+        address: String,
+        /// The sequence number to start querying after. Used for paginating results.
+        start_after: Option<u64>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(ClaimVolumeResponse)]
+    /// Get the amount claimed per day within the given range of days, inclusive. Days are counted
+    /// since the unix epoch, i.e. the block time in seconds divided by 86400.
+    ClaimVolume {
+        /// The first day of the range
+        from_day: u64,
+        /// The last day of the range
+        to_day: u64,
+    },
+    #[returns(SlotSolvencyResponse)]
+    /// Get the funding obligations of each distribution slot against the funds received
+    SlotSolvency {},
+    #[returns(CampaignStats)]
+    /// Get the statistics of the claims made on the campaign
+    CampaignStats {},
+    #[returns(PauseStatusResponse)]
+    /// Get which scopes of the contract are paused, i.e. to show a maintenance banner
+    PauseStatus {},
+    #[returns(LiabilitiesResponse)]
+    /// Get the liabilities of the campaign against the balance of the contract, for the treasury
+    /// reconciliation. The amount claimable right now is summed over a page of allocations.
+    Liabilities {
+        /// The address to start summing the claimable amounts from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of allocations to sum. If not set, the default value is used. Used
+        /// for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(AllocationsResponse)]
+    /// Get the allocation for an address, or a page of allocations sorted by address. A page scans
+    /// at most [ContractLimits::max_allocations_page_size] allocations, so a filtered page can hold
+    /// fewer items than the limit even though more allocations remain, in which case
+    /// [AllocationsResponse::next_start_after] is set to resume the scan from.
+    Allocations {
+        /// The address to get the allocation for, if provided
+        address: Option<String>,
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+        /// Only return the allocations of at least this amount. The filters are left out when unset,
+        /// so the query stays compatible with the previous versions imported from.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_amount: Option<Uint128>,
+        /// Only return the allocations of the addresses that have, or haven't, claimed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        has_claimed: Option<bool>,
+    },
+    #[returns(AllocationsResponse)]
+    /// Get the largest allocations, sorted by amount in descending order
+    TopAllocations {
+        /// The maximum number of items to return. If not set, the default value is used.
+        limit: Option<u16>,
+    },
+    #[returns(AllocationsResponse)]
+    /// Get the allocations of the addresses that haven't claimed yet
+    UnclaimedAllocations {
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(AllocationsResponse)]
+    /// Get the allocations added by the given wallet, sorted by address
+    AllocationsByUploader {
+        /// The wallet that added the allocations
+        uploader: String,
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(AllocationsResponse)]
+    /// Get the allocations tagged with the given cohort tag, sorted by address
+    AllocationsByTag {
+        /// The tag of the cohort
+        tag: String,
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(TagStatsResponse)]
+    /// Get the allocation and claim statistics of a cohort tag
+    TagStats {
+        /// The tag of the cohort
+        tag: String,
+    },
+    #[returns(BlacklistResponse)]
+    /// Check if an address is blacklisted
+    IsBlacklisted {
+        /// The address to check
+        address: String,
+    },
+    #[returns(BlacklistEntryResponse)]
+    /// Get who blacklisted an address, when and why
+    BlacklistEntry {
+        /// The address to check
+        address: String,
+    },
+    #[returns(FrozenEntryResponse)]
+    /// Get who froze an address, when and why
+    FrozenEntry {
+        /// The address to check
+        address: String,
+    },
+    #[returns(HoldResponse)]
+    /// Get the hold on the claims of an address, if any and not expired yet
+    Hold {
+        /// The address to check
+        address: String,
+    },
+    #[returns(PendingSweepResponse)]
+    /// Get the sweep waiting for a second approval, if any
+    PendingSweep {},
+    #[returns(ClaimNonceResponse)]
+    /// Get the nonce the next signed claim of an address must use
+    ClaimNonce {
+        /// The address to check
+        address: String,
+    },
+    #[returns(AddressReplacementRequestResponse)]
+    /// Get the pending replacement request of an address
+    AddressReplacementRequest {
+        /// The address to check
+        address: String,
+    },
+    #[returns(VestingActivationResponse)]
+    /// Get the time an address activated its vesting
+    VestingActivation {
+        /// The address to check
+        address: String,
+    },
+    #[returns(AllowlistResponse)]
+    /// Check if an address is allowlisted
+    IsAllowlisted {
+        /// The address to check
+        address: String,
+    },
+    #[returns(LockedReceiverResponse)]
+    /// Get the address the claims of an allocation are locked to pay out to, if any
+    LockedReceiver {
+        /// The address of the allocation
+        address: String,
+    },
+    #[returns(LocksResponse)]
+    /// Get the locks of an address, sorted by unlock time
+    Locks {
+        /// The address owning the locks
+        address: String,
+    },
+    #[returns(BudgetResponse)]
+    /// Get the budget of the campaign
+    Budget {},
+    #[returns(GroupResponse)]
+    /// Get a group sharing a pool of the campaign rewards
+    Group {
+        /// The name of the group
+        name: String,
+    },
+    #[returns(GroupMemberResponse)]
+    /// Get the share of a member in a group pool
+    GroupMember {
+        /// The name of the group
+        name: String,
+        /// The address of the member
+        address: String,
+    },
+    #[returns(FundingHistoryResponse)]
+    /// Get the deposits made with [ExecuteMsg::TopUpCampaign]
+    FundingHistory {
+        /// The sequence number to start querying after. Used for paginating results.
+        start_after: Option<u64>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(FundersResponse)]
+    /// Get the total contributed by each funder with [ExecuteMsg::TopUpCampaign], sorted by address
+    Funders {
+        /// The address to start querying after. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. If not set, the default value is used. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(TermsAcceptanceResponse)]
+    /// Get whether an address accepted the current terms of the campaign
+    TermsAcceptance {
+        /// The address accepting the terms
+        address: String,
+    },
+    #[returns(ClaimWindowResponse)]
+    /// Get the claim window of an address
+    ClaimWindow {
+        /// The address of the allocation
+        address: String,
+    },
+    #[returns(ExportStateResponse)]
+    /// Export a page of the raw state of a section, sorted by address. Meant for indexers and
+    /// migration tooling syncing the full state.
+    ExportState {
+        /// The section of the state to export
+        section: StateSection,
+        /// The address to start exporting after
+        start_after: Option<String>,
+        /// The maximum number of entries to return
+        limit: Option<u16>,
+    },
+    #[returns(ClaimHooksResponse)]
+    /// Get the contracts registered as claim hooks
+    ClaimHooks {},
+    #[returns(ConfigResponse)]
+    /// Get the contract configuration
+    Config {},
+    #[returns(ContractInfoResponse)]
+    /// Get the contract name and version, the features it supports and the limits in effect, so
+    /// tooling can adapt to the deployed version
+    ContractInfo {},
+    #[returns(CountsResponse)]
+    /// Get the number of allocations, claimants and blacklisted addresses
+    Counts {},
+    #[returns(RaffleResponse)]
+    /// Get the raffle seed and winners
+    Raffle {},
+    #[returns(ProposalsResponse)]
+    /// Get the proposals, by ascending id
+    Proposals {
+        /// The proposal id to start querying after. Used for paginating results.
+        start_after: Option<u64>,
+        /// The maximum number of items to return. Used for paginating results.
+        limit: Option<u16>,
+    },
+    #[returns(AuthorizedResponse)]
+    /// Check if an address is authorized (owner or authorized wallet)
+    IsAuthorized {
+        /// The address to check
+        address: String,
+    },
+    #[returns(AuthorizedWalletsResponse)]
+    /// Get authorized wallets with pagination
+    AuthorizedWallets {
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. Used for paginating results.
+        limit: Option<u32>,
+    },
+    #[returns(PendingAuthorizedWalletsResponse)]
+    /// Get the authorized wallets waiting for their delay to elapse, with pagination
+    PendingAuthorizedWallets {
+        /// The address to start querying from. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. Used for paginating results.
+        limit: Option<u32>,
+    },
+    #[returns(RolesResponse)]
+    /// Get the roles of an address. The owner and the authorized wallets have all the roles.
+    Roles {
+        /// The address to get the roles for
+        address: String,
+    },
+    #[returns(RoleHoldersResponse)]
+    /// Get the wallets granted a role with pagination. The owner and the authorized wallets are
+    /// not listed.
+    RoleHolders {
+        /// The role to get the holders for
+        role: Role,
+        /// The address to start querying after. Used for paginating results.
+        start_after: Option<String>,
+        /// The maximum number of items to return. Used for paginating results.
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+/// The message sent to the registered claim hooks whenever a claim succeeds.
+#[cw_serde]
+pub struct ClaimHookMsg {
+    /// The address that received the claimed tokens
+    pub receiver: String,
+    /// The amount of tokens claimed
+    pub amount: Coin,
+    /// The name of the campaign the tokens were claimed from
+    pub campaign: String,
+}
+
+impl ClaimHookMsg {
+    /// Creates a [WasmMsg::Execute] message for the given hook contract, wrapping the message in
+    /// [ClaimHookExecuteMsg]
+    pub fn into_cosmos_msg(self, contract_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&ClaimHookExecuteMsg::ClaimHook(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The execute message hook contracts must implement to be notified about claims.
+#[cw_serde]
+pub enum ClaimHookExecuteMsg {
+    /// Notifies the hook contract about a successful claim
+    ClaimHook(ClaimHookMsg),
+}
+
+/// The update sent to the campaign registry on the lifecycle events of the campaign, so the
+/// registry stays in sync without indexers.
+#[cw_serde]
+pub enum RegistryUpdateMsg {
+    /// The campaign was created
+    CampaignCreated {
+        /// The name of the campaign
+        name: String,
+        /// The type of the campaign
+        ty: String,
+        /// The total reward of the campaign
+        total_reward: Coin,
+        /// The start time of the campaign
+        start_time: Timestamp,
+        /// The end time of the campaign
+        end_time: Timestamp,
+    },
+    /// The campaign was closed by the owner, a proposal, the IBC admin or the governance
+    CampaignClosed {
+        /// The name of the campaign
+        name: String,
+        /// The amount claimed from the campaign
+        claimed: Coin,
+        /// The remaining funds refunded to the owner
+        refunded: Coin,
+    },
+    /// The campaign was finalized once its grace period had passed
+    CampaignFinalized {
+        /// The name of the campaign
+        name: String,
+        /// The amount claimed from the campaign
+        claimed: Coin,
+        /// The remaining funds refunded to the owner
+        refunded: Coin,
+        /// The address that finalized the campaign
+        finalized_by: String,
+    },
+}
+
+impl RegistryUpdateMsg {
+    /// Creates a [WasmMsg::Execute] message for the given registry contract, wrapping the message
+    /// in [RegistryExecuteMsg]
+    pub fn into_cosmos_msg(self, contract_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&RegistryExecuteMsg::RegistryUpdate(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The execute message the campaign registry must implement to be notified about the campaigns.
+#[cw_serde]
+pub enum RegistryExecuteMsg {
+    /// Notifies the registry about a lifecycle event of a campaign
+    RegistryUpdate(RegistryUpdateMsg),
+}
+
+/// Privileged messages that can only be dispatched by the chain, i.e. via governance proposals.
+#[cw_serde]
+pub enum SudoMsg {
+    /// Closes the campaign, refunding the remaining reward tokens to the owner.
+    ForceCloseCampaign {},
+    /// Blacklists an address, bypassing the owner and authorized wallets.
+    ForceBlacklist {
+        /// The address to blacklist
+        address: String,
+        /// The reason for blacklisting the address, recorded for auditing purposes
+        reason: Option<String>,
+    },
+    /// Transfers the ownership of the contract to a new owner, cancelling any pending transfer.
+    TransferOwnership {
+        /// The new owner of the contract
+        new_owner: String,
+    },
+}
+
+pub type CampaignResponse = Campaign;
+
+/// Response to the CampaignStatus query.
+#[cw_serde]
+pub struct CampaignStatusResponse {
+    /// The campaign
+    pub campaign: Campaign,
+    /// The status of the campaign
+    pub status: CampaignStatus,
+    /// The total amount of tokens allocated to the addresses
+    pub total_allocated: Coin,
+    /// The total amount of tokens claimed on the campaign
+    pub total_claimed: Coin,
+    /// The number of allocation entries
+    pub allocations_count: u64,
+    /// The number of addresses that have claimed
+    pub claimants_count: u64,
+    /// The contract balance of the reward denom
+    pub balance: Coin,
+}
+
+/// The status of a campaign.
+#[cw_serde]
+pub enum CampaignStatus {
+    /// The campaign hasn't started yet
+    NotStarted,
+    /// The campaign is active, i.e. it has started and hasn't ended nor been closed
+    Active,
+    /// The campaign has ended
+    Ended,
+    /// The campaign has been closed by the owner
+    Closed,
+}
+
+/// The phases of a campaign, which go from [CampaignPhase::Upcoming] to [CampaignPhase::Closed].
+#[cw_serde]
+pub enum CampaignPhase {
+    /// The campaign has been created, but doesn't accept allocations yet. Only campaigns with
+    /// manual phases go through this phase.
+    Upcoming,
+    /// The campaign accepts allocations, and can still be updated
+    AllocationUpload,
+    /// The campaign can be claimed from, following its distribution schedule
+    Active,
+    /// The campaign has reached its end time. The remaining allocations can still be claimed.
+    Ended,
+    /// The campaign has been closed by the owner
+    Closed,
+}
+
+impl Display for CampaignPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CampaignPhase::Upcoming => write!(f, "upcoming"),
+            CampaignPhase::AllocationUpload => write!(f, "allocation_upload"),
+            CampaignPhase::Active => write!(f, "active"),
+            CampaignPhase::Ended => write!(f, "ended"),
+            CampaignPhase::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+/// Response to the CampaignPhase query.
+#[cw_serde]
+pub struct CampaignPhaseResponse {
+    /// The phase of the campaign
+    pub phase: CampaignPhase,
+}
+
+/// Response to the IsLive query.
+#[cw_serde]
+pub struct IsLiveResponse {
+    /// Whether the campaign accepts claims
+    pub is_live: bool,
+}
+
+/// Response to the Rewards query.
+#[cw_serde]
+pub struct RewardsResponse {
+    /// The total allocation of the address.
+    #[serde(default)]
+    pub total_allocation: Vec<Coin>,
+    /// The tokens that have been claimed by the address.
+    pub claimed: Vec<Coin>,
+    /// The total amount of tokens that is pending to be claimed by the address.
+    pub pending: Vec<Coin>,
+    /// The tokens that are available to be claimed by the address.
+    pub available_to_claim: Vec<Coin>,
+    /// The pending tokens that are still locked, i.e. not yet available to be claimed.
+    #[serde(default)]
+    pub locked: Vec<Coin>,
+    /// The tokens accrued per second by the linear vesting distributions in progress
+    #[serde(default)]
+    pub accrual_rate: Decimal256,
+    /// The upcoming times at which tokens unlock at once, i.e. the lump sums and the end of the
+    /// cliffs, sorted
+    #[serde(default)]
+    pub next_unlocks: Vec<Timestamp>,
+}
+
+/// Response to the BatchRewards query.
+#[cw_serde]
+pub struct BatchRewardsResponse {
+    /// The rewards of each address, in the order they were queried
+    pub rewards: Vec<AddressRewards>,
+}
+
+/// The rewards of an address, as returned by the BatchRewards query.
+#[cw_serde]
+pub struct AddressRewards {
+    /// The address, as queried
+    pub address: String,
+    /// The rewards of the address
+    pub rewards: RewardsResponse,
+}
+
+/// Response to the SimulateClaim query.
+#[cw_serde]
+pub struct SimulateClaimResponse {
+    /// The address the claim is simulated for
+    pub address: String,
+    /// The total amount that would be claimed
+    pub claimable: Coin,
+    /// The details of the claim per distribution slot, sorted by slot
+    pub slots: Vec<SlotClaimSimulation>,
+}
+
+/// The simulated claim of an address from a distribution slot.
+#[cw_serde]
+pub struct SlotClaimSimulation {
+    /// The distribution slot, i.e. the index of the distribution type on the campaign
+    pub slot: DistributionSlot,
+    /// The allocation of the address on the slot, including the rounding dust attributed to it
+    pub allocation: Coin,
+    /// The rounding dust attributed to the slot, paid on its final claim
+    pub rounding_remainder: Uint128,
+    /// The amount already claimed from the slot
+    pub claimed: Coin,
+    /// The amount that would be claimed from the slot
+    pub claimable: Coin,
+}
+
+/// Response to the SlotSolvency query.
+#[cw_serde]
+pub struct SlotSolvencyResponse {
+    /// The funding of each distribution slot, sorted by slot
+    pub slots: Vec<SlotSolvency>,
+    /// The balance of the reward denom held by the contract
+    pub balance: Coin,
+    /// The funds received by the campaign, i.e. the balance plus the amount already paid out
+    pub funds_received: Coin,
+}
+
+/// The funding of a distribution slot.
+#[cw_serde]
+pub struct SlotSolvency {
+    /// The distribution slot, i.e. the index of the distribution type on the campaign
+    pub slot: DistributionSlot,
+    /// The amount of the total reward the slot has to pay out, including its reserved pool
+    pub obligation: Uint128,
+    /// The pro-rata share of the funds received covering the slot
+    pub funded: Uint128,
+    /// The amount already paid out from the slot
+    pub claimed: Uint128,
+}
+
+/// Response to the Claimed query.
+#[cw_serde]
+pub struct ClaimedResponse {
+    /// Contains a vector with a tuple with (address, coin) that have been claimed
+    pub claimed: Vec<(String, Coin)>,
+}
+
+/// The distribution slot is the index of the [DistributionType] on the campaign.
+pub type DistributionSlot = usize;
+
+/// Response to the ClaimedBySlot query.
+#[cw_serde]
+pub struct ClaimedBySlotResponse {
+    /// The claims per distribution slot, per address
+    pub claims: Vec<AddressClaims>,
+}
+
+/// The claims made by an address, per distribution slot.
+#[cw_serde]
+pub struct AddressClaims {
+    /// The address that claimed
+    pub address: String,
+    /// The claims per distribution slot, sorted by slot
+    pub slots: Vec<SlotClaim>,
+}
+
+/// The tokens claimed by an address from a distribution slot.
+#[cw_serde]
+pub struct SlotClaim {
+    /// The distribution slot, i.e. the index of the distribution type on the campaign
+    pub slot: DistributionSlot,
+    /// The distribution type of the slot
+    pub distribution_type: DistributionType,
+    /// The total amount claimed from the slot
+    pub claimed: Coin,
+    /// The timestamp of the last claim from the slot, in seconds
+    pub last_claimed_at: u64,
+}
+
+/// Response to the ClaimVolume query.
+#[cw_serde]
+pub struct ClaimVolumeResponse {
+    /// The amount claimed per day, sorted by day. Days without claims are omitted.
+    pub volumes: Vec<(u64, Coin)>,
+    /// The total amount claimed within the range
+    pub total: Coin,
+}
+
+/// The statistics of the claims made on the campaign, updated on every claim. Unlike the claimants
+/// counter, they are kept when the claims are pruned.
+#[cw_serde]
+#[derive(Default)]
+pub struct CampaignStats {
+    /// The number of claims made
+    pub claims: u64,
+    /// The number of unique addresses that have claimed
+    pub claimants: u64,
+    /// The largest amount paid out by a single claim
+    pub largest_claim: Uint128,
+    /// The time of the first claim
+    pub first_claim_at: Option<Timestamp>,
+    /// The time of the last claim
+    pub last_claim_at: Option<Timestamp>,
+}
+
+/// The statistics of the allocations sharing a cohort tag.
+#[cw_serde]
+#[derive(Default)]
+pub struct TagStats {
+    /// The number of allocations with the tag
+    pub allocations: u64,
+    /// The total amount allocated to the addresses with the tag
+    pub total_allocated: Uint128,
+    /// The number of addresses with the tag that have claimed
+    pub claimants: u64,
+    /// The total amount claimed by the addresses with the tag
+    pub total_claimed: Uint128,
+}
+
+/// Response to the TagStats query.
+#[cw_serde]
+pub struct TagStatsResponse {
+    /// The tag of the cohort
+    pub tag: String,
+    /// The statistics of the cohort, zeroed if no allocation has the tag
+    pub stats: TagStats,
+}
+
+/// Response to the ClaimHistory query.
+#[cw_serde]
+pub struct ClaimHistoryResponse {
+    /// The claim receipts, sorted by sequence number
+    pub history: Vec<ClaimReceipt>,
+}
+
+/// A receipt of the tokens claimed from a distribution slot in a single claim.
+#[cw_serde]
+pub struct ClaimReceipt {
+    /// The sequence number of the receipt for the address
+    pub sequence: u64,
+    /// The distribution slot the tokens were claimed from
+    pub slot: DistributionSlot,
+    /// The amount claimed
+    pub amount: Coin,
+    /// The timestamp of the claim, in seconds
+    pub timestamp: u64,
+}
+
+/// Response to the Allocation query.
+#[cw_serde]
+pub struct AllocationsResponse {
+    /// A vector with a tuple with (address, coin) that have been allocated.
+    pub allocations: Vec<(String, Coin)>,
+    /// The total number of allocations, regardless of the filters. Only set by the Allocations
+    /// query.
+    #[serde(default)]
+    pub total_count: Option<u64>,
+    /// The address to resume the Allocations query from, if the scan stopped before the end of
+    /// the allocations. Only set by the Allocations query.
+    #[serde(default)]
+    pub next_start_after: Option<String>,
+}
+
+/// Response to the Blacklist query.
+#[cw_serde]
+pub struct BlacklistResponse {
+    /// Whether the address is blacklisted
+    pub is_blacklisted: bool,
+}
+
+/// The audit metadata of a blacklisted address. For the addresses blacklisted before v3.2.0, the
+/// metadata is unknown, so `blacklisted_by` is empty and `blacklisted_at` is zero.
+#[cw_serde]
+pub struct BlacklistEntry {
+    /// The address that blacklisted the address, or `governance` if done via sudo
+    pub blacklisted_by: String,
+    /// The time at which the address was blacklisted, in seconds
+    pub blacklisted_at: u64,
+    /// The reason for blacklisting the address
+    pub reason: Option<String>,
+}
+
+/// How a batch of allocations is applied to the existing allocations.
+#[cw_serde]
+pub enum AllocationMode {
+    /// Only creates new allocations, failing if an address already has one
+    CreateOnly,
+    /// Creates the new allocations and overwrites the amount of the existing ones
+    UpsertBeforeStart,
+    /// Creates the new allocations and adds to the amount of the existing ones
+    IncrementBeforeStart,
+}
+
+/// The resolution of the freeze of an address.
+#[cw_serde]
+pub enum FrozenResolution {
+    /// Blacklists the address, so its unclaimed allocation is redistributed by
+    /// [ExecuteMsg::RedistributeForfeited]
+    Forfeit,
+    /// Lifts the freeze, so the address can claim again
+    Release,
+}
+
+/// The audit metadata of a frozen address.
+#[cw_serde]
+pub struct FrozenEntry {
+    /// The address that froze the address
+    pub frozen_by: String,
+    /// The time at which the address was frozen, in seconds
+    pub frozen_at: u64,
+    /// The reason for freezing the address
+    pub reason: Option<String>,
+}
+
+/// Response to the FrozenEntry query.
+#[cw_serde]
+pub struct FrozenEntryResponse {
+    /// The address
+    pub address: String,
+    /// The freeze metadata, if the address is frozen
+    pub entry: Option<FrozenEntry>,
+}
+
+/// A temporary hold on the claims of an address.
+#[cw_serde]
+pub struct HoldEntry {
+    /// The address that held the address
+    pub held_by: String,
+    /// The time at which the address was held, in seconds
+    pub held_at: u64,
+    /// The time until which the claims are held, in seconds
+    pub until: u64,
+}
+
+/// Response to the Hold query.
+#[cw_serde]
+pub struct HoldResponse {
+    /// The address
+    pub address: String,
+    /// The hold on the claims of the address, if any and not expired yet
+    pub hold: Option<HoldEntry>,
+}
+
+/// Response to the BlacklistEntry query.
+#[cw_serde]
+pub struct BlacklistEntryResponse {
+    /// The address
+    pub address: String,
+    /// The blacklist metadata, if the address is blacklisted
+    pub entry: Option<BlacklistEntry>,
+}
+
+/// Response to the ClaimNonce query.
+#[cw_serde]
+pub struct ClaimNonceResponse {
+    /// The address
+    pub address: String,
+    /// The nonce the next signed claim must use
+    pub nonce: u64,
+}
+
+/// The payload a receiver signs to claim with [ExecuteMsg::ClaimWithSignature]. It is serialized
+/// as JSON and set as the data of an ADR-36 sign doc, signed by the receiver.
+#[cw_serde]
+pub struct ClaimSignaturePayload {
+    /// The chain id, preventing replays across chains
+    pub chain_id: String,
+    /// The claimdrop contract address, preventing replays across contracts
+    pub contract: String,
+    /// The amount to claim, or all the available tokens if not set
+    pub amount: Option<Uint128>,
+    /// The nonce of the receiver, preventing replays of the same claim
+    pub nonce: u64,
+}
+
+/// An allocation the owner grants off-chain, registered on-chain by anyone with
+/// [ExecuteMsg::RegisterAllocation]. It is serialized as JSON and set as the data of an ADR-36 sign
+/// doc, signed by the owner.
+#[cw_serde]
+pub struct AllocationVoucher {
+    /// The address the allocation is granted to
+    pub address: String,
+    /// The amount allocated
+    pub amount: Uint128,
+    /// The claimdrop contract running the campaign, preventing replays across campaigns
+    pub campaign_id: String,
+    /// The time after which the voucher can't be registered anymore
+    pub expiry: Timestamp,
+}
+
+/// The payload a foreign address signs to be linked with [ExecuteMsg::LinkAddress]. It is
+/// serialized as JSON and signed as an EIP-191 personal message by the foreign key.
+#[cw_serde]
+pub struct LinkAddressPayload {
+    /// The chain id, preventing replays across chains
+    pub chain_id: String,
+    /// The claimdrop contract address, preventing replays across contracts
+    pub contract: String,
+    /// The MANTRA address the foreign address is linked to
+    pub address: String,
+}
+
+/// Response to the AddressReplacementRequest query.
+#[cw_serde]
+pub struct AddressReplacementRequestResponse {
+    /// The address that requested the replacement
+    pub address: String,
+    /// The address requested to replace it, if there is a pending request
+    pub new_address: Option<String>,
+}
+
+/// Response to the VestingActivation query.
+#[cw_serde]
+pub struct VestingActivationResponse {
+    /// The address checked
+    pub address: String,
+    /// The time the address activated its vesting, if it did
+    pub activated_at: Option<Timestamp>,
+}
+
+/// Response to the IsAllowlisted query.
+#[cw_serde]
+pub struct AllowlistResponse {
+    /// Whether the address is allowlisted
+    pub is_allowlisted: bool,
+}
+
+/// Response to the Group query.
+#[cw_serde]
+pub struct GroupResponse {
+    /// The name of the group
+    pub name: String,
+    /// The vesting schedule of the pool
+    pub schedule: Vec<DistributionType>,
+    /// The size of the pool
+    pub total: Coin,
+    /// The sum of the weights of the members
+    pub total_weight: Uint128,
+    /// The number of members
+    pub members: u32,
+    /// The amount claimed from the pool so far
+    pub claimed: Coin,
+}
+
+/// Response to the GroupMember query.
+#[cw_serde]
+pub struct GroupMemberResponse {
+    /// The name of the group
+    pub group: String,
+    /// The address of the member
+    pub address: String,
+    /// The weight of the member
+    pub weight: Uint128,
+    /// The share of the member in the pool, pro-rata to its weight
+    pub share: Coin,
+    /// The amount claimed by the member so far
+    pub claimed: Coin,
+    /// The amount the member can claim now
+    pub claimable: Coin,
+}
+
+/// Response to the FundingHistory query.
+#[cw_serde]
+pub struct FundingHistoryResponse {
+    /// The deposits, sorted by sequence number
+    pub deposits: Vec<Deposit>,
+}
+
+/// Response to the Funders query.
+#[cw_serde]
+pub struct FundersResponse {
+    /// The funders and the total they contributed, sorted by address
+    pub funders: Vec<(String, Coin)>,
+}
+
+/// A deposit topping up the campaign.
+#[cw_serde]
+pub struct Deposit {
+    /// The sequence number of the deposit
+    pub sequence: u64,
+    /// The address that made the deposit
+    pub funder: String,
+    /// The tokens deposited
+    pub amount: Coin,
+    /// The time of the deposit
+    pub deposited_at: Timestamp,
+}
+
+/// Response to the Budget query.
+#[cw_serde]
+pub struct BudgetResponse {
+    /// The budget of the campaign, if set
+    pub amount: Option<Uint128>,
+    /// The amount paid out of the budget since it was set
+    pub used: Uint128,
+    /// What's left of the budget, if set
+    pub remaining: Option<Uint128>,
+}
+
+/// Response to the ClaimWindow query.
+#[cw_serde]
+pub struct ClaimWindowResponse {
+    /// The time at which the claim window of the address expires, if the campaign has claim
+    /// windows and the allocation of the address has become claimable
+    pub expires_at: Option<Timestamp>,
+    /// The amount forfeited to the community pool, if the allocation was forfeited
+    pub forfeited: Option<Uint128>,
+}
+
+/// Response to the Locks query.
+#[cw_serde]
+pub struct LocksResponse {
+    /// The locks of the address, sorted by unlock time
+    pub locks: Vec<Lock>,
+}
+
+/// Tokens claimed with a [LockTier], locked in the contract until the unlock time.
+#[cw_serde]
+pub struct Lock {
+    /// The locked tokens, including the boost
+    pub amount: Coin,
+    /// The time after which the tokens can be withdrawn
+    pub unlocks_at: Timestamp,
+}
+
+/// Response to the TermsAcceptance query.
+#[cw_serde]
+pub struct TermsAcceptanceResponse {
+    /// The hash of the current terms of the campaign, if it has terms
+    pub terms_hash: Option<HexBinary>,
+    /// The hash of the terms last accepted by the address, if any
+    pub accepted_hash: Option<HexBinary>,
+    /// The time at which the address last accepted terms, if any
+    pub accepted_at: Option<Timestamp>,
+    /// Whether the address can claim as far as the terms are concerned, i.e. the campaign has no
+    /// terms or the address accepted the current ones
+    pub accepted: bool,
+}
+
+/// Response to the LockedReceiver query.
+#[cw_serde]
+pub struct LockedReceiverResponse {
+    /// The address the claims of the allocation pay out to, if locked
+    pub locked_receiver: Option<String>,
+}
+
+/// The contract configuration.
+#[cw_serde]
+#[derive(Default)]
+pub struct Config {
+    /// The circuit breaker pausing the claims when too many tokens are claimed within a time window
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// Whether the circuit breaker was tripped, pausing the claims until the owner resumes them
+    /// with [ExecuteMsg::ResumeClaims]. It's independent of [Config::pause]: the claims are paused
+    /// while either is set, and each is only lifted by its own message.
+    pub paused: bool,
+    /// The scope of the contract paused by the owner, if any, defined by [PauseScope]. Lifting it
+    /// doesn't resume the claims paused by the circuit breaker, see [Config::paused].
+    #[serde(default)]
+    pub pause: Option<PauseScope>,
+    /// The IBC channels the rewards can be delivered over
+    #[serde(default)]
+    pub allowed_ibc_channels: Vec<String>,
+    /// The limits of the contract, defined by [Limits]
+    #[serde(default)]
+    pub limits: Limits,
+    /// The counterparty allowed to administer the campaign over IBC, defined by [IbcAdmin]
+    pub ibc_admin: Option<IbcAdmin>,
+    /// Whether replacing an address requires the old address to request the replacement first,
+    /// so the admins can't redirect an allocation on their own
+    #[serde(default)]
+    pub replacements_require_request: bool,
+    /// The compressed secp256k1 public key of the owner, verifying the allocation vouchers. The
+    /// vouchers are disabled if not set.
+    #[serde(default)]
+    pub voucher_pubkey: Option<Binary>,
+    /// The foreign bech32 prefixes accepted in the addresses, e.g. `osmo` or `cosmos`. The
+    /// addresses with these prefixes are canonicalized to the local prefix, so they map to the
+    /// same key as the local address with the same bytes.
+    #[serde(default)]
+    pub allowed_prefixes: Vec<String>,
+    /// The delay, in seconds, before the authorized wallets added take effect
+    #[serde(default)]
+    pub authorized_wallet_delay: u64,
+    /// A lower authorized wallet delay, applying once the current delay has elapsed
+    #[serde(default)]
+    pub scheduled_authorized_wallet_delay: Option<ScheduledDelay>,
+    /// The amount of a denom that can be swept between two approved sweeps, above which the
+    /// sweeps require a second approval. Disabled if not set.
+    #[serde(default)]
+    pub sweep_threshold: Option<Uint128>,
+    /// The campaign registry notified with a [RegistryUpdateMsg] when the campaign is created,
+    /// closed or finalized
+    #[serde(default)]
+    pub registry: Option<String>,
+}
+
+impl Config {
+    /// Returns the authorized wallet delay in effect at the given time, i.e. the scheduled delay
+    /// if it applies already, or the current one otherwise.
+    pub fn authorized_wallet_delay(&self, current_time: &Timestamp) -> u64 {
+        match &self.scheduled_authorized_wallet_delay {
+            Some(scheduled) if scheduled.effective_at <= *current_time => scheduled.delay,
+            _ => self.authorized_wallet_delay,
+        }
+    }
+}
+
+/// A delay change scheduled for later.
+#[cw_serde]
+pub struct ScheduledDelay {
+    /// The new delay, in seconds
+    pub delay: u64,
+    /// The time from which the new delay applies
+    pub effective_at: Timestamp,
+}
+
+/// Maximum delay before the authorized wallets added take effect, in seconds
+pub const MAX_AUTHORIZED_WALLET_DELAY: u64 = 30 * 86_400;
+
+/// The time window within which a pending sweep must be approved, in seconds
+pub const SWEEP_APPROVAL_WINDOW: u64 = 86_400;
+
+/// The counterparty allowed to administer the campaign over IBC, i.e. the interchain account
+/// controller of a partner DAO on another chain.
+#[cw_serde]
+pub struct IbcAdmin {
+    /// The connection the admin channel must be opened on
+    pub connection_id: String,
+    /// The port of the counterparty sending the admin packets
+    pub counterparty_port_id: String,
+}
+
+/// The admin packets accepted over the IBC admin channel. The packets are executed with the
+/// authority of the owner.
+#[cw_serde]
+pub enum IbcAdminPacket {
+    /// Adds a batch of addresses and their allocations, same as [ExecuteMsg::AddAllocations]
+    AddAllocations {
+        /// Vector of (address, amount) pairs
+        allocations: Vec<(String, Uint128)>,
+    },
+    /// Blacklists or unblacklists an address, same as [ExecuteMsg::BlacklistAddress]
+    BlacklistAddress {
+        /// The address to blacklist/unblacklist
+        address: String,
+        /// Whether to blacklist or unblacklist
+        blacklist: bool,
+        /// The reason for blacklisting the address, recorded for auditing purposes
+        reason: Option<String>,
+    },
+    /// Closes the campaign, refunding the remaining funds to the owner
+    CloseCampaign {},
+}
+
+/// The limits of the contract, tunable per deployment. The limits that are not set fall back to
+/// the contract defaults.
+#[cw_serde]
+#[derive(Default)]
+pub struct Limits {
+    /// The maximum number of allocations that can be added in a single batch
+    pub max_allocation_batch_size: Option<u32>,
+    /// The maximum number of addresses that can be added to/removed from the allowlist in a single
+    /// batch
+    pub max_allowlist_batch_size: Option<u32>,
+    /// The maximum number of distribution types a campaign can have
+    pub max_distribution_slots: Option<u32>,
+    /// The minimum duration of a campaign, in seconds
+    pub min_campaign_duration: Option<u64>,
+    /// The denoms the campaigns can be rewarded in. If empty, any denom is allowed.
+    #[serde(default)]
+    pub allowed_reward_denoms: Vec<String>,
+    /// Whether the reward denom of the campaigns must have a supply on chain, guarding against
+    /// typo'd denoms. Campaigns minting on claim are exempt, as their denom has no supply yet.
+    #[serde(default)]
+    pub require_existing_reward_denom: bool,
+}
+
+impl Limits {
+    /// Validates the limits
+    pub fn validate(&self) -> Result<(), ContractError> {
+        for (param, limit) in [
+            ("max_allocation_batch_size", self.max_allocation_batch_size),
+            ("max_allowlist_batch_size", self.max_allowlist_batch_size),
+            ("max_distribution_slots", self.max_distribution_slots),
+        ] {
+            ensure!(
+                limit != Some(0),
+                ContractError::InvalidInput {
+                    reason: format!("{param} cannot be zero")
+                }
+            );
+        }
+
+        ensure!(
+            self.max_distribution_slots
+                .is_none_or(|max| max <= MAX_DISTRIBUTION_SLOTS),
+            ContractError::InvalidInput {
+                reason: format!("max_distribution_slots cannot exceed {MAX_DISTRIBUTION_SLOTS}")
+            }
+        );
+
+        ensure!(
+            self.allowed_reward_denoms
+                .iter()
+                .all(|denom| !denom.is_empty()),
+            ContractError::InvalidInput {
+                reason: "allowed_reward_denoms cannot contain empty denoms".to_string()
+            }
+        );
+
+        Ok(())
+    }
+}
+
+/// The remote chain address to deliver the claimed rewards to, via an ICS-20 transfer.
+#[cw_serde]
+pub struct IbcReceiver {
+    /// The channel to send the rewards over. Must be allowed by the owner.
+    pub channel_id: String,
+    /// The address on the remote chain to deliver the rewards to
+    pub to_address: String,
+    /// The number of seconds after which the transfer times out, counted from the claim
+    pub timeout_seconds: u64,
+}
+
+/// The scope of the contract paused by the owner.
+#[cw_serde]
+pub enum PauseScope {
+    /// The claims are paused
+    Claims,
+    /// The allocations can't be added, updated, replaced, removed nor redistributed
+    AllocationManagement,
+    /// Every message is rejected, but lifting the pause and managing the ownership
+    All,
+}
+
+impl PauseScope {
+    /// Returns whether pausing this scope pauses the given one
+    pub fn covers(&self, scope: &PauseScope) -> bool {
+        self == &PauseScope::All || self == scope
+    }
+
+    /// Returns the name of the scope
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PauseScope::Claims => "claims",
+            PauseScope::AllocationManagement => "allocation_management",
+            PauseScope::All => "all",
+        }
+    }
+}
+
+/// Response to the PauseStatus query.
+#[cw_serde]
+pub struct PauseStatusResponse {
+    /// The scope of the contract paused by the owner, if any
+    pub scope: Option<PauseScope>,
+    /// Whether the claims are paused, by the owner or by the circuit breaker
+    pub claims_paused: bool,
+    /// Whether the allocation management is paused by the owner
+    pub allocation_management_paused: bool,
+    /// Whether the circuit breaker paused the claims
+    pub circuit_breaker_tripped: bool,
+}
+
+/// Response to the Liabilities query.
+#[cw_serde]
+pub struct LiabilitiesResponse {
+    /// The total amount allocated to the addresses
+    pub total_allocated: Uint128,
+    /// The total amount claimed from the campaign
+    pub total_claimed: Uint128,
+    /// The amount allocated and not claimed yet, zero once the campaign is closed
+    pub outstanding: Uint128,
+    /// The balance of the reward denom held by the contract
+    pub balance: Coin,
+    /// The balance held on top of the outstanding amount
+    pub surplus: Uint128,
+    /// The outstanding amount not covered by the balance
+    pub shortfall: Uint128,
+    /// The amount claimable right now by the addresses of the page
+    pub claimable: Uint128,
+    /// The address to query the next page after, if there are more allocations to sum
+    pub next_start_after: Option<String>,
+}
+
+/// Pauses the claims when the cumulative claimed amount within a time window exceeds a threshold,
+/// as protection against a suspicious drain of the campaign.
+#[cw_serde]
+pub struct CircuitBreaker {
+    /// The maximum amount that can be claimed within the window
+    pub max_claim_amount: Uint128,
+    /// The time window the claimed amounts are accumulated in
+    pub window: CircuitBreakerWindow,
+}
+
+/// The time window used by the [CircuitBreaker].
+#[cw_serde]
+pub enum CircuitBreakerWindow {
+    /// The claims are accumulated per block
+    Block,
+    /// The claims are accumulated per hour
+    Hour,
+}
+
+impl CircuitBreakerWindow {
+    /// Returns the identifier of the window the given block belongs to
+    pub fn current(&self, block: &BlockInfo) -> u64 {
+        match self {
+            CircuitBreakerWindow::Block => block.height,
+            CircuitBreakerWindow::Hour => block.time.seconds() / 3_600,
+        }
+    }
+}
+
+impl CircuitBreaker {
+    /// Validates the circuit breaker parameters
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            !self.max_claim_amount.is_zero(),
+            ContractError::InvalidInput {
+                reason: "circuit breaker max_claim_amount cannot be zero".to_string()
+            }
+        );
+
+        Ok(())
+    }
+}
+
+/// Response to the Config query.
+pub type ConfigResponse = Config;
+
+/// Response to the ContractInfo query.
+#[cw_serde]
+pub struct ContractInfoResponse {
+    /// The contract name, as stored by cw2
+    pub contract: String,
+    /// The contract version, as stored by cw2
+    pub version: String,
+    /// The features supported by the contract, e.g. `mint_on_claim` or `groups`. The features
+    /// missing from the list, e.g. cw20 rewards or merkle allocations, aren't supported.
+    pub features: Vec<String>,
+    /// The limits in effect, defined by [ContractLimits]
+    pub limits: ContractLimits,
+}
+
+/// The limits in effect, i.e. the configured [Limits] or their defaults, and the fixed ones.
+#[cw_serde]
+pub struct ContractLimits {
+    /// The maximum number of allocations that can be added in a single batch
+    pub max_allocation_batch_size: u32,
+    /// The maximum number of addresses that can be added to/removed from the allowlist in a single
+    /// batch
+    pub max_allowlist_batch_size: u32,
+    /// The maximum number of distribution types a campaign can have
+    pub max_distribution_slots: u32,
+    /// The maximum number of receivers that can be claimed for in a single batch
+    pub max_claim_batch_size: u32,
+    /// The maximum number of authorized wallets that can be managed in a single batch
+    pub max_authorized_wallets_batch_size: u32,
+    /// The maximum number of addresses that can be blacklisted/unblacklisted in a single batch
+    pub max_blacklist_batch_size: u32,
+    /// The maximum number of groups sharing a pool of the campaign rewards
+    pub max_groups: u32,
+    /// The maximum number of members that can be added to/removed from a group in a single batch
+    pub max_group_members_batch_size: u32,
+    /// The maximum number of addresses in a BatchRewards query
+    pub max_batch_rewards_addresses: u32,
+    /// The maximum number of items returned by the paginated queries
+    pub max_query_limit: u32,
+    /// The maximum number of allocations scanned by a page of the Allocations query
+    pub max_allocations_page_size: u32,
+}
+
+/// Response to the Counts query.
+#[cw_serde]
+pub struct CountsResponse {
+    /// The number of addresses with an allocation
+    pub allocations: u64,
+    /// The number of addresses that have claimed
+    pub claimants: u64,
+    /// The number of blacklisted addresses
+    pub blacklisted: u64,
+}
+
+/// Response to the Raffle query.
+#[cw_serde]
+pub struct RaffleResponse {
+    /// The seed the winners are drawn with, once submitted
+    pub seed: Option<HexBinary>,
+    /// Whether the raffle has been drawn
+    pub drawn: bool,
+    /// The winners and the prizes credited to their allocations. An address can win more than once.
+    pub winners: Vec<(String, Coin)>,
+}
+
+/// A proposal to perform an irreversible action.
+#[cw_serde]
+pub struct Proposal {
+    /// The id of the proposal
+    pub id: u64,
+    /// The action to perform once approved
+    pub action: ProposalAction,
+    /// The address that made the proposal
+    pub proposer: String,
+    /// The time at which the proposal was made, in seconds
+    pub proposed_at: u64,
+    /// The time at which the owner approved the proposal, in seconds
+    pub approved_at: Option<u64>,
+}
+
+/// Response to the Proposals query.
+#[cw_serde]
+pub struct ProposalsResponse {
+    /// The proposals
+    pub proposals: Vec<Proposal>,
+}
+
+/// Response to the ClaimHooks query.
+#[cw_serde]
+pub struct ClaimHooksResponse {
+    /// The contracts notified whenever a claim succeeds
+    pub hooks: Vec<String>,
+}
+
+/// Response to the IsAuthorized query.
+#[cw_serde]
+pub struct AuthorizedResponse {
+    /// Whether the address is authorized (owner or authorized wallet)
+    pub is_authorized: bool,
+}
+
+/// Response to the AuthorizedWallets query.
+#[cw_serde]
+pub struct AuthorizedWalletsResponse {
+    /// List of authorized wallet addresses
+    pub wallets: Vec<String>,
+}
+
+/// Response to the PendingAuthorizedWallets query.
+#[cw_serde]
+pub struct PendingAuthorizedWalletsResponse {
+    /// The pending authorized wallets
+    pub wallets: Vec<PendingAuthorizedWallet>,
+}
+
+/// An authorized wallet waiting for its delay to elapse.
+#[cw_serde]
+pub struct PendingAuthorizedWallet {
+    /// The address of the wallet
+    pub address: String,
+    /// The time from which the wallet can be activated
+    pub activates_at: Timestamp,
+}
+
+/// A sweep above the sweep threshold, waiting for a second approval.
+#[cw_serde]
+pub struct PendingSweep {
+    /// The swept token
+    pub amount: Coin,
+    /// The address that requested the sweep
+    pub requested_by: String,
+    /// The time at which the sweep was requested
+    pub requested_at: Timestamp,
+    /// The time after which the sweep can no longer be approved
+    pub expires_at: Timestamp,
+}
+
+/// Response to the PendingSweep query.
+#[cw_serde]
+pub struct PendingSweepResponse {
+    /// The sweep waiting for a second approval, if any
+    pub pending_sweep: Option<PendingSweep>,
+}
+
+/// Response to the Roles query.
+#[cw_serde]
+pub struct RolesResponse {
+    /// The address the roles belong to
+    pub address: String,
+    /// The roles of the address
+    pub roles: Vec<Role>,
+}
+
+/// Response to the RoleHolders query.
+#[cw_serde]
+pub struct RoleHoldersResponse {
+    /// The role
+    pub role: Role,
+    /// The wallets granted the role
+    pub holders: Vec<String>,
+}
+
+/// The roles that can be granted to a wallet, each allowing a subset of the admin actions. The
+/// authorized wallets have all the roles.
+#[cw_serde]
+#[derive(Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum Role {
+    /// Manages the allocations, i.e. adds, imports, replaces and removes them
+    AllocationManager,
+    /// Manages the blacklist and the allowlist
+    BlacklistManager,
+    /// Manages the campaign, i.e. creates and updates it, submits the raffle seed and proposes
+    /// the irreversible actions
+    CampaignManager,
+}
+
+impl Role {
+    /// All the roles
+    pub const ALL: [Role; 3] = [
+        Role::AllocationManager,
+        Role::BlacklistManager,
+        Role::CampaignManager,
+    ];
+
+    /// Returns the name of the role, as serialized
+    pub fn name(&self) -> &'static str {
+        match self {
+            Role::AllocationManager => "allocation_manager",
+            Role::BlacklistManager => "blacklist_manager",
+            Role::CampaignManager => "campaign_manager",
+        }
+    }
+}
+
+/// The campaign action that can be executed with the [ExecuteMsg::ManageCampaign] message.
+#[cw_serde]
+pub enum CampaignAction {
+    /// Creates a new campaign
+    CreateCampaign {
+        /// The parameters to create a campaign with
+        params: Box<CampaignParams>,
+    },
+    /// Closes the campaign
+    CloseCampaign {},
+    /// Updates the campaign, replacing its parameters. This can only be done before the campaign
+    /// has started. The reward denom can't be updated.
+    UpdateCampaign {
+        /// The new parameters of the campaign
+        params: Box<CampaignParams>,
+    },
+    /// Updates the metadata uri of the campaign. This can only be done before the campaign has
+    /// started. The allocations hash can't be updated.
+    UpdateMetadataUri {
+        /// The new metadata uri. If not set, the metadata uri is removed.
+        metadata_uri: Option<String>,
+    },
+    /// Updates the name, description and type of the campaign, i.e. to fix a typo after launch.
+    /// This can be done any time before the campaign is closed, as it doesn't touch the economic
+    /// parameters. The fields not set are left unchanged.
+    UpdateMetadata {
+        /// The new name of the campaign
+        name: Option<String>,
+        /// The new description of the campaign
+        description: Option<String>,
+        /// The new type of the campaign
+        #[serde(rename = "type")]
+        ty: Option<String>,
+    },
+    /// Creates a new campaign copying the configuration of a finished campaign on another claimdrop
+    /// contract, i.e. for recurring reward drops. The distribution schedule is shifted to the new
+    /// start time. The allocations can be copied with [ExecuteMsg::ImportAllocations].
+    CloneCampaign {
+        /// The claimdrop contract running the campaign to clone, which must have ended
+        source_contract: String,
+        /// The start time of the new campaign
+        start_time: Timestamp,
+        /// The parameters replacing the ones of the cloned campaign, defined by [CloneOverrides]
+        overrides: Box<CloneOverrides>,
+    },
+    /// Advances a campaign with manual phases to its next phase, i.e. from
+    /// [CampaignPhase::Upcoming] to [CampaignPhase::AllocationUpload], and from there to
+    /// [CampaignPhase::Active]. The campaign ends at its end time, and is closed with
+    /// [CampaignAction::CloseCampaign].
+    AdvancePhase {},
+}
+
+/// The parameters of a cloned campaign replacing the ones of the source campaign. The parameters
+/// not set are copied.
+#[cw_serde]
+#[derive(Default)]
+pub struct CloneOverrides {
+    /// The campaign name
+    pub name: Option<String>,
+    /// The campaign description
+    pub description: Option<String>,
+    /// The total amount of the reward asset allocated to the campaign
+    pub total_reward: Option<Coin>,
+    /// The uri of the off-chain campaign metadata
+    pub metadata_uri: Option<String>,
+    /// The hash of the published allocations
+    pub allocations_hash: Option<HexBinary>,
+}
+
+/// Represents a campaign.
+#[cw_serde]
+#[derive(Default)]
+pub struct Campaign {
+    /// The campaign name
+    pub name: String,
+    /// The campaign description
+    pub description: String,
+    /// Campaign type. Value used by front ends.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The total amount of the reward asset that is intended to be allocated to the campaign
+    pub total_reward: Coin,
+    /// The amount of the reward asset that has been claimed
+    pub claimed: Coin,
+    /// The ways the reward is distributed, which are defined by the [DistributionType].
+    /// The sum of the percentages must be 100.
+    pub distribution_type: Vec<DistributionType>,
+    /// The campaign start time
+    pub start_time: Timestamp,
+    /// The campaign end time
+    pub end_time: Timestamp,
+    /// The timestamp at which the campaign was closed, in seconds
+    pub closed: Option<u64>,
+    /// The claim mode of the campaign, defined by [ClaimMode]
+    #[serde(default)]
+    pub claim_mode: ClaimMode,
+    /// The minimum time between two claims of the same address, in seconds
+    pub claim_cooldown_seconds: Option<u64>,
+    /// The duration of the campaign cliff, in seconds, counted from the campaign start time
+    pub cliff_duration: Option<u64>,
+    /// Where the rewards come from when claiming, defined by [RewardSource]
+    #[serde(default)]
+    pub reward_source: RewardSource,
+    /// The uri of the off-chain campaign metadata, i.e. the published allocations
+    pub metadata_uri: Option<String>,
+    /// The hash of the published allocations, anchoring the off-chain content on-chain
+    pub allocations_hash: Option<HexBinary>,
+    /// The fee charged on the claims, defined by [ClaimFee]
+    pub claim_fee: Option<ClaimFee>,
+    /// The total amount of fees charged on the claims, in the reward denom
+    #[serde(default)]
+    pub fees_collected: Uint128,
+    /// Whether the users are prevented from transferring their allocations to another address
+    #[serde(default)]
+    pub allocation_transfers_disabled: bool,
+    /// The time after the end time, in seconds, after which anyone can finalize the campaign
+    pub grace_period: Option<u64>,
+    /// Where the claimed tokens are paid out, defined by [PayoutTarget]
+    #[serde(default)]
+    pub payout_target: PayoutTarget,
+    /// The minimum amount of a claim, unless it exhausts the allocation
+    pub min_claim_amount: Option<Uint128>,
+    /// The phase the campaign was advanced to, if its phases are advanced manually. See
+    /// [Campaign::phase].
+    pub manual_phase: Option<CampaignPhase>,
+    /// The fee allowance granted to each address when its allocation is added, defined by
+    /// [FeeGrant]
+    pub fee_grant: Option<FeeGrant>,
+    /// The maximum allocation of a single address
+    pub max_allocation_per_address: Option<Uint128>,
+    /// The maximum amount of a single claim
+    pub max_single_claim: Option<Uint128>,
+    /// Whether the vesting of each address starts when it activates it, instead of at the start
+    /// times of the distributions
+    #[serde(default)]
+    pub vesting_from_activation: bool,
+    /// Whether the claims are refused until the contract holds the rewards left to claim
+    #[serde(default)]
+    pub require_full_funding: bool,
+    /// The boosts granted to the claims locked in the contract, defined by [LockBoost]
+    #[serde(default)]
+    pub lock_boost: Option<LockBoost>,
+    /// The time each address has to claim its allocation once it first becomes claimable
+    #[serde(default)]
+    pub claim_window_seconds: Option<u64>,
+    /// The address receiving the allocations left unclaimed once their claim window expired
+    #[serde(default)]
+    pub community_pool: Option<String>,
+    /// The hash of the terms and conditions the addresses must accept before claiming
+    #[serde(default)]
+    pub terms_hash: Option<HexBinary>,
+    /// When the vesting of each allocation starts accruing, defined by [AccrualFrom]
+    #[serde(default)]
+    pub accrual_from: AccrualFrom,
+    /// Which distribution slots the partial claims drain first, defined by [ClaimPriority]
+    #[serde(default)]
+    pub claim_priority: ClaimPriority,
+    /// Who the funds left when closing the campaign are refunded to, defined by [RefundPolicy]
+    #[serde(default)]
+    pub refund_policy: RefundPolicy,
+    /// The eligibility criterion the receivers must pass to claim, defined by [Eligibility]
+    #[serde(default)]
+    pub eligibility: Option<Eligibility>,
+}
+
+impl Display for Campaign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Campaign {{ name: {}, description: {}, type: {}, total_reward: {}, claimed: {}, distribution_type: {:?}, start_time: {}, end_time: {}, closed: {:?}, claim_mode: {:?}, claim_cooldown_seconds: {:?}, cliff_duration: {:?}, reward_source: {:?}, metadata_uri: {:?}, allocations_hash: {:?}, claim_fee: {:?}, fees_collected: {}, allocation_transfers_disabled: {}, grace_period: {:?}, payout_target: {:?}, min_claim_amount: {:?}, manual_phase: {:?}, fee_grant: {:?}, max_allocation_per_address: {:?}, max_single_claim: {:?}, vesting_from_activation: {}, require_full_funding: {}, lock_boost: {:?}, claim_window_seconds: {:?}, community_pool: {:?}, terms_hash: {:?}, accrual_from: {:?}, claim_priority: {:?}, refund_policy: {:?}, eligibility: {:?} }}",
+            self.name,
+            self.description,
+            self.ty,
+            self.total_reward,
+            self.claimed,
+            self.distribution_type,
+            self.start_time,
+            self.end_time,
+            self.closed,
+            self.claim_mode,
+            self.claim_cooldown_seconds,
+            self.cliff_duration,
+            self.reward_source,
+            self.metadata_uri,
+            self.allocations_hash,
+            self.claim_fee,
+            self.fees_collected,
+            self.allocation_transfers_disabled,
+            self.grace_period,
+            self.payout_target,
+            self.min_claim_amount,
+            self.manual_phase,
+            self.fee_grant,
+            self.max_allocation_per_address,
+            self.max_single_claim,
+            self.vesting_from_activation,
+            self.require_full_funding,
+            self.lock_boost,
+            self.claim_window_seconds,
+            self.community_pool,
+            self.terms_hash,
+            self.accrual_from,
+            self.claim_priority,
+            self.refund_policy,
+            self.eligibility
+        )
+    }
+}
+
+impl Campaign {
+    /// Creates a new campaign from the given parameters
+    pub fn from_params(params: CampaignParams) -> Self {
+        let reward_denom = params.total_reward.denom.clone();
+
+        Campaign {
+            name: params.name,
+            description: params.description,
+            ty: params.ty,
+            total_reward: params.total_reward,
+            claimed: Coin {
+                denom: reward_denom,
+                amount: Uint128::zero(),
+            },
+            distribution_type: params.distribution_type,
+            start_time: params.start_time,
+            end_time: params.end_time,
+            closed: None,
+            claim_mode: params.claim_mode,
+            claim_cooldown_seconds: params.claim_cooldown_seconds,
+            cliff_duration: params.cliff_duration,
+            reward_source: params.reward_source,
+            metadata_uri: params.metadata_uri,
+            allocations_hash: params.allocations_hash,
+            claim_fee: params.claim_fee,
+            fees_collected: Uint128::zero(),
+            allocation_transfers_disabled: params.allocation_transfers_disabled,
+            grace_period: params.grace_period,
+            payout_target: params.payout_target,
+            min_claim_amount: params.min_claim_amount,
+            manual_phase: params.manual_phases.then_some(CampaignPhase::Upcoming),
+            fee_grant: params.fee_grant,
+            max_allocation_per_address: params.max_allocation_per_address,
+            max_single_claim: params.max_single_claim,
+            vesting_from_activation: params.vesting_from_activation,
+            require_full_funding: params.require_full_funding,
+            lock_boost: params.lock_boost,
+            claim_window_seconds: params.claim_window_seconds,
+            community_pool: params.community_pool,
+            terms_hash: params.terms_hash,
+            accrual_from: params.accrual_from,
+            claim_priority: params.claim_priority,
+            refund_policy: params.refund_policy,
+            eligibility: params.eligibility,
+        }
+    }
+
+    /// Checks if the campaign has started
+    pub fn has_started(&self, current_time: &Timestamp) -> bool {
+        current_time >= &self.start_time
+    }
+
+    /// Returns the time at which the allocations first become claimable, i.e. once the campaign
+    /// cliff has passed and the first distribution has started and passed its own cliff.
+    pub fn first_unlock_time(&self) -> Timestamp {
+        let first_distribution_unlock = self
+            .distribution_type
+            .iter()
+            .filter_map(|distribution| distribution.unlock_time(&self.start_time))
+            .min()
+            .unwrap_or(self.start_time);
+
+        first_distribution_unlock.max(
+            self.start_time
+                .plus_seconds(self.cliff_duration.unwrap_or_default()),
+        )
+    }
+
+    /// Checks if the campaign has ended
+    pub fn has_ended(&self, current_time: &Timestamp) -> bool {
+        current_time >= &self.end_time
+    }
+
+    /// Checks if the campaign cliff has passed. Campaigns without a cliff are considered to have
+    /// passed it.
+    pub fn has_cliff_passed(&self, current_time: &Timestamp) -> bool {
+        current_time
+            >= &self
+                .start_time
+                .plus_seconds(self.cliff_duration.unwrap_or_default())
+    }
+
+    /// Returns the phase of the campaign at the given time. Campaigns with manual phases are in the
+    /// phase they were advanced to, until they end at the end time. Otherwise, the campaign accepts
+    /// allocations until the start time, and the [CampaignPhase::Upcoming] phase is skipped.
+    pub fn phase(&self, current_time: &Timestamp) -> CampaignPhase {
+        if self.closed.is_some() {
+            return CampaignPhase::Closed;
+        }
+
+        match &self.manual_phase {
+            Some(CampaignPhase::Active) if self.has_ended(current_time) => CampaignPhase::Ended,
+            Some(phase) => phase.clone(),
+            None if !self.has_started(current_time) => CampaignPhase::AllocationUpload,
+            None if self.has_ended(current_time) => CampaignPhase::Ended,
+            None => CampaignPhase::Active,
+        }
+    }
+
+    /// Ensures the campaign is in one of the given phases at the given time. Fails with
+    /// [ContractError::CampaignClosed] if the campaign has been closed, with
+    /// [ContractError::CampaignNotStarted] or [ContractError::CampaignAlreadyStarted] if it hasn't
+    /// started or has already started when it shouldn't have, and with the given reason otherwise.
+    pub fn ensure_phase(
+        &self,
+        current_time: &Timestamp,
+        phases: &[CampaignPhase],
+        reason: &str,
+    ) -> Result<(), ContractError> {
+        let phase = self.phase(current_time);
+        if phases.contains(&phase) {
+            return Ok(());
+        }
+
+        let started =
+            |phase: &CampaignPhase| matches!(phase, CampaignPhase::Active | CampaignPhase::Ended);
+
+        Err(match phase {
+            CampaignPhase::Closed => ContractError::CampaignClosed,
+            phase if !started(&phase) && phases.iter().all(started) => {
+                ContractError::CampaignNotStarted {
+                    starts_at: self.start_time,
+                }
+            }
+            phase if started(&phase) && !phases.iter().any(started) => {
+                ContractError::CampaignAlreadyStarted {
+                    started_at: self.start_time,
+                }
+            }
+            _ => ContractError::CampaignError {
+                reason: reason.to_string(),
+            },
+        })
+    }
+
+    /// Returns the campaign with its vesting schedules offset by the given number of seconds, i.e.
+    /// the schedules of an address that activated its vesting after the campaign start. The early
+    /// bird bonus, the raffle and the campaign cliff aren't offset.
+    pub fn with_vesting_offset(&self, offset_seconds: u64) -> Campaign {
+        let mut campaign = self.clone();
+
+        for distribution in campaign.distribution_type.iter_mut() {
+            match distribution {
+                DistributionType::LinearVesting {
+                    start_time,
+                    end_time,
+                    ..
+                }
+                | DistributionType::CurveVesting {
+                    start_time,
+                    end_time,
+                    ..
+                } => {
+                    *start_time = start_time.plus_seconds(offset_seconds);
+                    *end_time = end_time.plus_seconds(offset_seconds);
+                }
+                DistributionType::LumpSum { start_time, .. } => {
+                    *start_time = start_time.plus_seconds(offset_seconds);
+                }
+                DistributionType::EarlyBirdBonus { .. } | DistributionType::Raffle { .. } => {}
+            }
+        }
+
+        campaign
+    }
+
+    /// Returns the campaign with its vesting distributions accruing from the given time, i.e. the
+    /// schedules of an address added after they started. The distributions start at the given time
+    /// and their share is reduced by the time elapsed, so the address forfeits what vested before.
+    pub fn with_accrual_start(&self, accrual_start: Timestamp) -> Campaign {
+        let mut campaign = self.clone();
+
+        for distribution in campaign.distribution_type.iter_mut() {
+            if let DistributionType::LinearVesting {
+                percentage,
+                start_time,
+                end_time,
+                ..
+            }
+            | DistributionType::CurveVesting {
+                percentage,
+                start_time,
+                end_time,
+                ..
+            } = distribution
+            {
+                if accrual_start <= *start_time {
+                    continue;
+                }
+
+                let remaining = end_time.seconds().saturating_sub(accrual_start.seconds());
+                let duration = end_time.seconds().saturating_sub(start_time.seconds());
+                *percentage *= Decimal::from_ratio(remaining, duration.max(1));
+                // the distribution keeps a non-zero duration, as the vesting math requires it
+                *start_time = accrual_start.min(end_time.minus_seconds(1));
+            }
+        }
+
+        campaign
+    }
+
+    /// Returns the parameters the campaign was created with, with its whole schedule shifted by the
+    /// given number of seconds, including the deadlines of the bonus and the raffle.
+    pub fn to_shifted_params(&self, offset_seconds: u64) -> CampaignParams {
+        CampaignParams {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            ty: self.ty.clone(),
+            total_reward: self.total_reward.clone(),
+            distribution_type: self
+                .distribution_type
+                .iter()
+                .map(|distribution| distribution.shifted(offset_seconds))
+                .collect(),
+            start_time: self.start_time.plus_seconds(offset_seconds),
+            end_time: self.end_time.plus_seconds(offset_seconds),
+            claim_mode: self.claim_mode.clone(),
+            claim_cooldown_seconds: self.claim_cooldown_seconds,
+            cliff_duration: self.cliff_duration,
+            reward_source: self.reward_source.clone(),
+            metadata_uri: self.metadata_uri.clone(),
+            allocations_hash: self.allocations_hash.clone(),
+            claim_fee: self.claim_fee.clone(),
+            allocation_transfers_disabled: self.allocation_transfers_disabled,
+            grace_period: self.grace_period,
+            payout_target: self.payout_target.clone(),
+            min_claim_amount: self.min_claim_amount,
+            manual_phases: self.manual_phase.is_some(),
+            fee_grant: self.fee_grant.clone().map(|fee_grant| FeeGrant {
+                expiration: fee_grant
+                    .expiration
+                    .map(|expiration| expiration.plus_seconds(offset_seconds)),
+                ..fee_grant
+            }),
+            max_allocation_per_address: self.max_allocation_per_address,
+            max_single_claim: self.max_single_claim,
+            vesting_from_activation: self.vesting_from_activation,
+            require_full_funding: self.require_full_funding,
+            lock_boost: self.lock_boost.clone(),
+            claim_window_seconds: self.claim_window_seconds,
+            community_pool: self.community_pool.clone(),
+            terms_hash: self.terms_hash.clone(),
+            accrual_from: self.accrual_from.clone(),
+            claim_priority: self.claim_priority.clone(),
+            refund_policy: self.refund_policy.clone(),
+            eligibility: self.eligibility.clone(),
+        }
+    }
+
+    /// Returns the status of the campaign at the given time
+    pub fn status(&self, current_time: &Timestamp) -> CampaignStatus {
+        if self.closed.is_some() {
+            CampaignStatus::Closed
+        } else if !self.has_started(current_time) {
+            CampaignStatus::NotStarted
+        } else if self.has_ended(current_time) {
+            CampaignStatus::Ended
+        } else {
+            CampaignStatus::Active
+        }
+    }
+}
+
+/// Represents the parameters to create a campaign with.
+#[cw_serde]
+#[derive(Default)]
+pub struct CampaignParams {
+    /// The campaign name
+    pub name: String,
+    /// The campaign description
+    pub description: String,
+    /// Campaign type. Value used by front ends.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The total amount of the reward asset that is intended to be allocated to the campaign
+    pub total_reward: Coin,
+    /// The ways the reward is distributed, which are defined by the [DistributionType].
+    /// The sum of the percentages must be 100.
+    pub distribution_type: Vec<DistributionType>,
+    /// The campaign start time
+    pub start_time: Timestamp,
+    /// The campaign end time
+    pub end_time: Timestamp,
+    /// The claim mode of the campaign, defined by [ClaimMode]. Defaults to [ClaimMode::Open].
+    #[serde(default)]
+    pub claim_mode: ClaimMode,
+    /// The minimum time between two claims of the same address, in seconds. If not set, there's
+    /// no cooldown between claims.
+    pub claim_cooldown_seconds: Option<u64>,
+    /// The duration of the campaign cliff, in seconds, counted from the campaign start time. No
+    /// distribution can be claimed until the cliff has passed.
+    pub cliff_duration: Option<u64>,
+    /// Where the rewards come from when claiming, defined by [RewardSource]. Defaults to
+    /// [RewardSource::PreFunded].
+    #[serde(default)]
+    pub reward_source: RewardSource,
+    /// The uri of the off-chain campaign metadata, i.e. the published allocations CSV or an IPFS
+    /// link. It can be updated before the campaign starts.
+    pub metadata_uri: Option<String>,
+    /// The hash of the published allocations, anchoring the off-chain content on-chain. It can't
+    /// be updated once the campaign is created.
+    pub allocations_hash: Option<HexBinary>,
+    /// The fee charged on the claims, defined by [ClaimFee]. If not set, no fee is charged.
+    pub claim_fee: Option<ClaimFee>,
+    /// Whether to prevent the users from transferring their allocations to another address with
+    /// [ExecuteMsg::TransferAllocation]. Defaults to false.
+    #[serde(default)]
+    pub allocation_transfers_disabled: bool,
+    /// The time after the campaign end time, in seconds, after which anyone can close the campaign
+    /// with [ExecuteMsg::FinalizeCampaign]. If not set, only the owner can close the campaign.
+    pub grace_period: Option<u64>,
+    /// Where the claimed tokens are paid out, defined by [PayoutTarget]. Defaults to
+    /// [PayoutTarget::Wallet].
+    #[serde(default)]
+    pub payout_target: PayoutTarget,
+    /// The minimum amount of a claim. Smaller claims are rejected, unless they exhaust the
+    /// allocation. If not set, there's no minimum.
+    pub min_claim_amount: Option<Uint128>,
+    /// Whether the campaign phases are advanced manually with [CampaignAction::AdvancePhase],
+    /// instead of following the campaign start time. Defaults to false.
+    #[serde(default)]
+    pub manual_phases: bool,
+    /// The fee allowance granted to each address when its allocation is added, so new wallets can
+    /// pay the fees to claim. If not set, no allowance is granted.
+    pub fee_grant: Option<FeeGrant>,
+    /// The maximum allocation of a single address, guarding the uploads against typos. If not set,
+    /// there's no maximum.
+    pub max_allocation_per_address: Option<Uint128>,
+    /// The maximum amount of a single claim. If not set, there's no maximum.
+    pub max_single_claim: Option<Uint128>,
+    /// Whether the vesting of each address starts when it calls [ExecuteMsg::ActivateVesting],
+    /// offsetting its distribution schedules by the time elapsed since the campaign start. The
+    /// early bird bonus and raffle aren't offset. Defaults to false.
+    #[serde(default)]
+    pub vesting_from_activation: bool,
+    /// Whether the claims are refused until the contract holds the rewards left to claim, i.e.
+    /// the total reward minus the amount already claimed, so an underfunded campaign doesn't go
+    /// live. Only applies to prefunded campaigns. Defaults to false.
+    #[serde(default)]
+    pub require_full_funding: bool,
+    /// The boosts granted to the claims locked in the contract for a [LockTier], defined by
+    /// [LockBoost]. If not set, the claims can't be locked.
+    #[serde(default)]
+    pub lock_boost: Option<LockBoost>,
+    /// The time each address has to claim its allocation once it first becomes claimable. Once the
+    /// window expires, the unclaimed allocation can be forfeited to the `community_pool`. If not
+    /// set, the allocations don't expire.
+    #[serde(default)]
+    pub claim_window_seconds: Option<u64>,
+    /// The address receiving the forfeited allocations, required along with the
+    /// `claim_window_seconds`
+    #[serde(default)]
+    pub community_pool: Option<String>,
+    /// The hash of the terms and conditions of the campaign, e.g. the SHA-256 of the document.
+    /// If set, the addresses must accept the terms with this hash before claiming.
+    #[serde(default)]
+    pub terms_hash: Option<HexBinary>,
+    /// When the vesting of each allocation starts accruing, defined by [AccrualFrom]. Defaults to
+    /// the campaign start.
+    #[serde(default)]
+    pub accrual_from: AccrualFrom,
+    /// Which distribution slots the partial claims drain first, defined by [ClaimPriority].
+    /// Defaults to the lump sums first.
+    #[serde(default)]
+    pub claim_priority: ClaimPriority,
+    /// Who the funds left when closing the campaign are refunded to, defined by [RefundPolicy].
+    /// Defaults to the owner.
+    #[serde(default)]
+    pub refund_policy: RefundPolicy,
+    /// If set, the eligibility criterion the receivers must pass to claim, i.e. an anti-sybil
+    /// oracle.
+    #[serde(default)]
+    pub eligibility: Option<Eligibility>,
+}
+
+/// The boosts granted to the claims locked in the contract. A claim locked for a tier is boosted
+/// by its multiplier, the boosts being paid from a pool of `pool_percentage` of the total reward,
+/// reserved on top of the allocations, until it's depleted.
+#[cw_serde]
+pub struct LockBoost {
+    /// The percentage of the total reward paid out as boosts
+    pub pool_percentage: Decimal,
+    /// The multiplier of each tier offered, at least 1. A multiplier of 1.2 boosts the claim by 20%.
+    pub tiers: Vec<(LockTier, Decimal)>,
+}
+
+impl LockBoost {
+    /// Returns the multiplier of the given tier, if offered
+    pub fn multiplier(&self, tier: &LockTier) -> Option<Decimal> {
+        self.tiers
+            .iter()
+            .find(|(offered, _)| offered == tier)
+            .map(|(_, multiplier)| *multiplier)
+    }
+}
+
+/// The duration of a lock chosen at claim time.
+#[cw_serde]
+pub enum LockTier {
+    /// Locked for 3 months, i.e. 90 days
+    ThreeMonths,
+    /// Locked for 6 months, i.e. 180 days
+    SixMonths,
+    /// Locked for 12 months, i.e. 365 days
+    TwelveMonths,
+}
+
+impl LockTier {
+    /// Returns the duration of the lock, in seconds
+    pub fn duration(&self) -> u64 {
+        const DAY: u64 = 86_400;
+        match self {
+            LockTier::ThreeMonths => 90 * DAY,
+            LockTier::SixMonths => 180 * DAY,
+            LockTier::TwelveMonths => 365 * DAY,
+        }
+    }
+}
+
+/// A fee charged on every claim and sent to a treasury address.
+#[cw_serde]
+pub struct ClaimFee {
+    /// The share of the claimed tokens paid to the recipient
+    pub percentage: Decimal,
+    /// The address receiving the fees
+    pub recipient: String,
+}
+
+/// A fee allowance granted by the contract to the addresses receiving an allocation, through the
+/// feegrant module. The allowance is paid from the contract balance, so the owner must fund the
+/// contract with the fee denom.
+#[cw_serde]
+pub struct FeeGrant {
+    /// The maximum amount of fees each address can spend
+    pub spend_limit: Coin,
+    /// The time at which the allowance expires. If not set, the allowance doesn't expire.
+    pub expiration: Option<Timestamp>,
+}
+
+/// Defines when the vesting of an allocation starts accruing.
+#[cw_serde]
+#[derive(Default)]
+pub enum AccrualFrom {
+    /// The vesting distributions accrue from their start times, whenever the allocation was added
+    #[default]
+    CampaignStart,
+    /// The vesting distributions accrue from the time the allocation was added, so the addresses
+    /// added late forfeit what vested before, getting a proportionally smaller share. The lump
+    /// sums, early bird bonus and raffle aren't time weighted.
+    AllocationTime,
+}
+
+/// Defines which distribution slots a partial claim drains first, when claiming less than what's
+/// claimable. The full claims take everything claimable, whatever the priority.
+#[cw_serde]
+#[derive(Default)]
+pub enum ClaimPriority {
+    /// The lump sums, early bird bonus and raffle are drained first, then the vesting slots, each
+    /// in ascending slot order
+    #[default]
+    LumpSumFirst,
+    /// The vesting slots are drained first, then the lump sums, early bird bonus and raffle, each
+    /// in ascending slot order
+    VestingFirst,
+    /// The slots are drained in ascending slot order, whatever their type
+    SlotOrder,
+}
+
+/// Defines who the funds left when closing the campaign are refunded to.
+#[cw_serde]
+#[derive(Default)]
+pub enum RefundPolicy {
+    /// Everything is refunded to the owner
+    #[default]
+    Owner,
+    /// The funds are refunded to the funders that topped up the campaign, pro-rata to their
+    /// contributions. The funds exceeding the contributions, i.e. sent with a plain bank transfer,
+    /// and the rounding dust are refunded to the owner first.
+    ProRata,
+}
+
+/// Defines who is allowed to claim from a campaign.
+#[cw_serde]
+#[derive(Default)]
+pub enum ClaimMode {
+    /// Any address with an allocation can claim
+    #[default]
+    Open,
+    /// Only allowlisted addresses with an allocation can claim, i.e. for KYC'd campaigns
+    AllowlistOnly,
+}
+
+/// Defines where the rewards of a campaign come from.
+#[cw_serde]
+#[derive(Default)]
+pub enum RewardSource {
+    /// The contract must be topped up with the rewards before they can be claimed
+    #[default]
+    PreFunded,
+    /// The rewards are minted on every claim. Requires the reward denom to be a tokenfactory denom
+    /// administered by the contract, i.e. `factory/{contract_address}/{subdenom}`.
+    MintOnClaim,
+}
+
+/// Defines where the claimed tokens are paid out.
+#[cw_serde]
+#[derive(Default)]
+pub enum PayoutTarget {
+    /// The claimed tokens are sent to the receiver
+    #[default]
+    Wallet,
+    /// The claimed tokens are sent along with an execute message to a contract, i.e. a staking
+    /// vault locking them on behalf of the receiver
+    Contract {
+        /// The address of the contract receiving the claimed tokens
+        addr: String,
+        /// The JSON execute message sent to the contract. Every occurrence of
+        /// [PAYOUT_RECEIVER_PLACEHOLDER] is replaced with the address of the receiver.
+        msg_template: String,
+    },
+}
+
+/// Defines the eligibility criterion the receivers must pass to claim.
+#[cw_serde]
+pub enum Eligibility {
+    /// The receiver must pass the check of an oracle contract, i.e. an anti-sybil or
+    /// proof-of-personhood registry, queried on every claim
+    OracleCheck {
+        /// The address of the oracle contract
+        contract: String,
+        /// The JSON query sent to the oracle, which must respond with a boolean. Every occurrence
+        /// of [ELIGIBILITY_ADDRESS_PLACEHOLDER] is replaced with the address of the receiver.
+        query_template: String,
+    },
+}
+
+impl Eligibility {
+    /// Builds the query sent to the oracle for the given receiver
+    pub fn query_msg(query_template: &str, address: &str) -> Binary {
+        Binary::from(
+            query_template
+                .replace(ELIGIBILITY_ADDRESS_PLACEHOLDER, address)
+                .into_bytes(),
+        )
+    }
+}
+
+impl PayoutTarget {
+    /// Builds the execute message sent to the payout contract on behalf of the receiver
+    pub fn payout_msg(msg_template: &str, receiver: &str) -> Binary {
+        Binary::from(
+            msg_template
+                .replace(PAYOUT_RECEIVER_PLACEHOLDER, receiver)
+                .into_bytes(),
+        )
+    }
+}
+
+impl CampaignParams {
+    /// Validates the campaign name and description
+    pub fn validate_campaign_name_description(&self) -> Result<(), ContractError> {
+        validate_name(&self.name)?;
+        validate_description(&self.description)
+    }
+
+    /// Validates the campaign metadata uri, allocations hash and terms hash
+    pub fn validate_campaign_metadata(&self) -> Result<(), ContractError> {
+        if let Some(metadata_uri) = &self.metadata_uri {
+            validate_metadata_uri(metadata_uri)?;
+        }
+
+        for (param, hash) in [
+            ("allocations_hash", &self.allocations_hash),
+            ("terms_hash", &self.terms_hash),
+        ] {
+            let Some(hash) = hash else {
+                continue;
+            };
+
+            ensure!(
+                !hash.is_empty(),
+                ContractError::InvalidCampaignParam {
+                    param: param.to_string(),
+                    reason: "cannot be empty".to_string(),
+                }
+            );
+
+            ensure!(
+                hash.len() <= MAX_HASH_LENGTH,
+                ContractError::InvalidCampaignParam {
+                    param: param.to_string(),
+                    reason: format!("cannot be longer than {} bytes", MAX_HASH_LENGTH),
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the campaign type
+    pub fn validate_campaign_type(&self) -> Result<(), ContractError> {
+        validate_type(&self.ty)
+    }
+
+    /// Validates the start and end times of a campaign
+    pub fn validate_campaign_times(&self, current_time: Timestamp) -> Result<(), ContractError> {
+        validate_timestamp("start_time", &self.start_time)?;
+        validate_timestamp("end_time", &self.end_time)?;
+
+        for dist in self.distribution_type.iter() {
+            match dist {
+                DistributionType::LinearVesting {
+                    start_time,
+                    end_time,
+                    ..
+                }
+                | DistributionType::CurveVesting {
+                    start_time,
+                    end_time,
+                    ..
+                } => {
+                    validate_timestamp("distribution_type.start_time", start_time)?;
+                    validate_timestamp("distribution_type.end_time", end_time)?;
+                }
+                DistributionType::LumpSum { start_time, .. } => {
+                    validate_timestamp("distribution_type.start_time", start_time)?;
+                }
+                DistributionType::EarlyBirdBonus { bonus_deadline, .. } => {
+                    validate_timestamp("distribution_type.bonus_deadline", bonus_deadline)?;
+                }
+                DistributionType::Raffle { draw_time, .. } => {
+                    validate_timestamp("distribution_type.draw_time", draw_time)?;
+                }
+            }
+        }
+
+        ensure!(
+            self.start_time < self.end_time,
+            ContractError::InvalidCampaignParam {
+                param: "start_time".to_string(),
+                reason: "cannot be greater or equal than end_time".to_string(),
+            }
+        );
+        ensure!(
+            self.start_time >= current_time,
+            ContractError::InvalidCampaignParam {
+                param: "start_time".to_string(),
+                reason: "cannot be less than the current time".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Ensures the distribution type parameters are correct
+    pub fn validate_campaign_distribution(
+        &self,
+        max_distribution_slots: u32,
+    ) -> Result<(), ContractError> {
+        let mut total_percentage = Decimal::zero();
+
+        ensure!(
+            !self.distribution_type.is_empty()
+                && self.distribution_type.len() <= max_distribution_slots as usize,
+            ContractError::InvalidCampaignParam {
+                param: "distribution_type".to_string(),
+                reason: format!(
+                    "invalid number of distribution types, should be at least 1, maximum {}",
+                    max_distribution_slots
+                ),
+            }
+        );
+
+        ensure!(
+            self.distribution_type
+                .iter()
+                .filter(|dist| matches!(dist, DistributionType::EarlyBirdBonus { .. }))
+                .count()
+                <= 1,
+            ContractError::InvalidCampaignParam {
+                param: "distribution_type".to_string(),
+                reason: "there can be at most one early bird bonus distribution".to_string(),
+            }
+        );
+
+        ensure!(
+            self.distribution_type
+                .iter()
+                .filter(|dist| matches!(dist, DistributionType::Raffle { .. }))
+                .count()
+                <= 1,
+            ContractError::InvalidCampaignParam {
+                param: "distribution_type".to_string(),
+                reason: "there can be at most one raffle distribution".to_string(),
+            }
+        );
+
+        for dist in self.distribution_type.iter() {
+            let (percentage, start_time, end_time, cliff_duration) = match dist {
+                DistributionType::LinearVesting {
+                    percentage,
+                    start_time,
+                    end_time,
+                    cliff_duration,
+                    cliff_behavior,
+                } => {
+                    ensure!(
+                        cliff_behavior.is_none() || cliff_duration.is_some(),
+                        ContractError::InvalidCampaignParam {
+                            param: "cliff_behavior".to_string(),
+                            reason: "cannot be set without a cliff duration".to_string(),
+                        }
+                    );
+
+                    (percentage, start_time, Some(end_time), cliff_duration)
+                }
+                DistributionType::CurveVesting {
+                    percentage,
+                    start_time,
+                    end_time,
+                    curve,
+                } => {
+                    curve.validate(end_time.seconds().saturating_sub(start_time.seconds()))?;
+
+                    (percentage, start_time, Some(end_time), &None)
+                }
+                DistributionType::LumpSum {
+                    percentage,
+                    start_time,
+                    cliff_duration,
+                } => (percentage, start_time, None, cliff_duration),
+                DistributionType::EarlyBirdBonus {
+                    base_percentage,
+                    bonus_percentage,
+                    bonus_deadline,
+                } => {
+                    ensure!(
+                        !bonus_percentage.is_zero() && *bonus_percentage <= Decimal::one(),
+                        ContractError::InvalidCampaignParam {
+                            param: "bonus_percentage".to_string(),
+                            reason: "must be greater than zero and at most 100%".to_string(),
+                        }
+                    );
+
+                    ensure!(
+                        *bonus_deadline > self.start_time && *bonus_deadline <= self.end_time,
+                        ContractError::InvalidCampaignParam {
+                            param: "bonus_deadline".to_string(),
+                            reason: "must be within the campaign times".to_string(),
+                        }
+                    );
+
+                    (base_percentage, &self.start_time, None, &None)
+                }
+                // the raffle prize pool is reserved on top of the allocations, so it doesn't
+                // count towards the distribution percentages
+                DistributionType::Raffle {
+                    prize_pool_percentage,
+                    draw_time,
+                    winners,
+                } => {
+                    ensure!(
+                        !prize_pool_percentage.is_zero()
+                            && *prize_pool_percentage <= Decimal::one(),
+                        ContractError::InvalidCampaignParam {
+                            param: "prize_pool_percentage".to_string(),
+                            reason: "must be greater than zero and at most 100%".to_string(),
+                        }
+                    );
+
+                    ensure!(
+                        *draw_time > self.start_time && *draw_time <= self.end_time,
+                        ContractError::InvalidCampaignParam {
+                            param: "draw_time".to_string(),
+                            reason: "must be within the campaign times".to_string(),
+                        }
+                    );
+
+                    ensure!(
+                        *winners > 0 && *winners <= MAX_RAFFLE_WINNERS,
+                        ContractError::InvalidCampaignParam {
+                            param: "winners".to_string(),
+                            reason: format!("must be between 1 and {MAX_RAFFLE_WINNERS}"),
+                        }
+                    );
+
+                    continue;
+                }
+            };
+
+            ensure!(
+                percentage != Decimal::zero(),
+                ContractError::ZeroDistributionPercentage
+            );
+
+            total_percentage = total_percentage.checked_add(*percentage)?;
+
+            ensure!(
+                *start_time >= self.start_time,
+                ContractError::InvalidStartDistributionTime {
+                    start_time: start_time.seconds(),
+                    campaign_start_time: self.start_time.seconds(),
+                }
+            );
+
+            // validate the end time. Applies for the vesting distribution types only
+            if let Some(end_time) = end_time {
+                ensure!(
+                    end_time > start_time,
+                    ContractError::InvalidDistributionTimes {
+                        start_time: start_time.seconds(),
+                        end_time: end_time.seconds(),
+                    }
+                );
+
+                ensure!(
+                    *end_time <= self.end_time,
+                    ContractError::InvalidEndDistributionTime {
+                        end_time: end_time.seconds(),
+                        campaign_end_time: self.end_time.seconds(),
+                    }
+                );
+            }
+
+            // validate the cliff duration
+            if let Some(cliff_duration) = cliff_duration {
+                ensure!(
+                    *cliff_duration > 0u64,
+                    ContractError::InvalidCampaignParam {
+                        param: "cliff_duration".to_string(),
+                        reason: "cannot be zero".to_string(),
+                    }
+                );
+
+                match end_time {
+                    Some(end_time) => ensure!(
+                        *cliff_duration < end_time.seconds() - start_time.seconds(),
+                        ContractError::InvalidCampaignParam {
+                            param: "cliff_duration".to_string(),
+                            reason: "cannot be greater or equal than the distribution duration"
+                                .to_string(),
+                        }
+                    ),
+                    // lump sum distributions must be unlocked before the campaign ends
+                    None => ensure!(
+                        start_time.plus_seconds(*cliff_duration) <= self.end_time,
+                        ContractError::InvalidCampaignParam {
+                            param: "cliff_duration".to_string(),
+                            reason: "cannot end after the campaign end time".to_string(),
+                        }
+                    ),
+                }
+            }
+        }
+
+        ensure!(
+            total_percentage == Decimal::percent(100),
+            ContractError::InvalidDistributionPercentage {
+                expected: Decimal::percent(100),
+                actual: total_percentage,
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Validates the total reward amount
+    pub fn validate_rewards(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.total_reward.amount > Uint128::zero(),
+            ContractError::InvalidCampaignParam {
+                param: "total_reward".to_string(),
+                reason: "cannot be zero".to_string()
+            }
+        );
+
+        validate_denom(&self.total_reward.denom)
+    }
+
+    /// Validates the campaign cliff
+    pub fn validate_campaign_cliff(&self) -> Result<(), ContractError> {
+        if let Some(cliff_duration) = self.cliff_duration {
+            ensure!(
+                cliff_duration > 0u64,
+                ContractError::InvalidCampaignParam {
+                    param: "cliff_duration".to_string(),
+                    reason: "cannot be zero".to_string(),
+                }
+            );
+
+            ensure!(
+                cliff_duration
+                    < self
+                        .end_time
+                        .seconds()
+                        .saturating_sub(self.start_time.seconds()),
+                ContractError::InvalidCampaignParam {
+                    param: "cliff_duration".to_string(),
+                    reason: "cannot be greater or equal than the campaign duration".to_string(),
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the claim cooldown
+    pub fn validate_claim_cooldown(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.claim_cooldown_seconds != Some(0),
+            ContractError::InvalidCampaignParam {
+                param: "claim_cooldown_seconds".to_string(),
+                reason: "cannot be zero".to_string()
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Validates the minimum claim amount
+    pub fn validate_min_claim_amount(&self) -> Result<(), ContractError> {
+        if let Some(min_claim_amount) = self.min_claim_amount {
+            ensure!(
+                !min_claim_amount.is_zero() && min_claim_amount <= self.total_reward.amount,
+                ContractError::InvalidCampaignParam {
+                    param: "min_claim_amount".to_string(),
+                    reason: "must be greater than zero and at most the total reward".to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the allocation and claim caps are greater than zero and at most the total reward,
+    /// and that the minimum claim amount doesn't exceed the maximum single claim
+    pub fn validate_caps(&self) -> Result<(), ContractError> {
+        for (param, cap) in [
+            (
+                "max_allocation_per_address",
+                self.max_allocation_per_address,
+            ),
+            ("max_single_claim", self.max_single_claim),
+        ] {
+            if let Some(cap) = cap {
+                ensure!(
+                    !cap.is_zero() && cap <= self.total_reward.amount,
+                    ContractError::InvalidCampaignParam {
+                        param: param.to_string(),
+                        reason: "must be greater than zero and at most the total reward"
+                            .to_string()
+                    }
+                );
+            }
+        }
+
+        if let (Some(min_claim_amount), Some(max_single_claim)) =
+            (self.min_claim_amount, self.max_single_claim)
+        {
+            ensure!(
+                min_claim_amount <= max_single_claim,
+                ContractError::InvalidCampaignParam {
+                    param: "max_single_claim".to_string(),
+                    reason: "must be at least the minimum claim amount".to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the fee grant has a spend limit and doesn't expire in the past
+    pub fn validate_fee_grant(&self, current_time: Timestamp) -> Result<(), ContractError> {
+        if let Some(fee_grant) = &self.fee_grant {
+            ensure!(
+                !fee_grant.spend_limit.amount.is_zero() && !fee_grant.spend_limit.denom.is_empty(),
+                ContractError::InvalidCampaignParam {
+                    param: "fee_grant".to_string(),
+                    reason: "the spend limit must be greater than zero".to_string()
+                }
+            );
+            ensure!(
+                fee_grant
+                    .expiration
+                    .is_none_or(|expiration| expiration > current_time),
+                ContractError::InvalidCampaignParam {
+                    param: "fee_grant".to_string(),
+                    reason: "the expiration must be in the future".to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the claim window is greater than zero and comes with a community pool
+    pub fn validate_claim_window(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.claim_window_seconds.is_some() == self.community_pool.is_some(),
+            ContractError::InvalidCampaignParam {
+                param: "claim_window_seconds".to_string(),
+                reason: "the claim window and the community pool must be set together".to_string()
+            }
+        );
+        ensure!(
+            self.claim_window_seconds != Some(0),
+            ContractError::InvalidCampaignParam {
+                param: "claim_window_seconds".to_string(),
+                reason: "the claim window must be greater than zero".to_string()
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Validates the lock boost pool and tiers
+    pub fn validate_lock_boost(&self) -> Result<(), ContractError> {
+        if let Some(lock_boost) = &self.lock_boost {
+            ensure!(
+                !lock_boost.pool_percentage.is_zero()
+                    && lock_boost.pool_percentage <= Decimal::one(),
+                ContractError::InvalidCampaignParam {
+                    param: "lock_boost".to_string(),
+                    reason: "the pool percentage must be greater than zero and at most 100%"
+                        .to_string()
+                }
+            );
+            ensure!(
+                !lock_boost.tiers.is_empty()
+                    && lock_boost
+                        .tiers
+                        .iter()
+                        .enumerate()
+                        .all(|(index, (tier, _))| {
+                            lock_boost.tiers[..index]
+                                .iter()
+                                .all(|(other, _)| other != tier)
+                        }),
+                ContractError::InvalidCampaignParam {
+                    param: "lock_boost".to_string(),
+                    reason: "the tiers must be non-empty and unique".to_string()
+                }
+            );
+            ensure!(
+                lock_boost
+                    .tiers
+                    .iter()
+                    .all(|(_, multiplier)| *multiplier >= Decimal::one()),
+                ContractError::InvalidCampaignParam {
+                    param: "lock_boost".to_string(),
+                    reason: "the multipliers must be at least 1".to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the claim fee percentage
+    pub fn validate_claim_fee(&self) -> Result<(), ContractError> {
+        if let Some(claim_fee) = &self.claim_fee {
+            ensure!(
+                !claim_fee.percentage.is_zero() && claim_fee.percentage < Decimal::one(),
+                ContractError::InvalidCampaignParam {
+                    param: "claim_fee".to_string(),
+                    reason: "the percentage must be between 0 and 1, exclusive".to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the payout message template includes the receiver, so the tokens are credited to
+    /// the right address by the payout contract
+    pub fn validate_payout_target(&self) -> Result<(), ContractError> {
+        if let PayoutTarget::Contract { msg_template, .. } = &self.payout_target {
+            ensure!(
+                msg_template.contains(PAYOUT_RECEIVER_PLACEHOLDER),
+                ContractError::InvalidCampaignParam {
+                    param: "payout_target".to_string(),
+                    reason: format!(
+                        "the message template must contain the {PAYOUT_RECEIVER_PLACEHOLDER} placeholder"
+                    )
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the eligibility query template contains the address placeholder
+    pub fn validate_eligibility(&self) -> Result<(), ContractError> {
+        if let Some(Eligibility::OracleCheck { query_template, .. }) = &self.eligibility {
+            ensure!(
+                query_template.contains(ELIGIBILITY_ADDRESS_PLACEHOLDER),
+                ContractError::InvalidCampaignParam {
+                    param: "eligibility".to_string(),
+                    reason: format!(
+                        "the query template must contain the {ELIGIBILITY_ADDRESS_PLACEHOLDER} placeholder"
+                    )
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validates the reward denom can be minted by the contract when the campaign rewards are
+    /// minted on claim
+    pub fn validate_reward_source(&self, contract_address: &Addr) -> Result<(), ContractError> {
+        if self.reward_source == RewardSource::MintOnClaim {
+            ensure!(
+                self.total_reward
+                    .denom
+                    .starts_with(&format!("factory/{contract_address}/")),
+                ContractError::InvalidCampaignParam {
+                    param: "reward_source".to_string(),
+                    reason:
+                        "the reward denom must be a tokenfactory denom administered by the contract"
+                            .to_string()
+                }
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Length of the hash of an IBC denom, i.e. `ibc/{hash}`, in hex characters
+pub const IBC_DENOM_HASH_LENGTH: usize = 64;
+
+/// Validates the format of a denom following the rules of the bank module, i.e. 3 to 128
+/// characters starting with a letter. Tokenfactory denoms must be `factory/{creator}/{subdenom}`
+/// and IBC denoms `ibc/{hash}`, with the uppercase hex sha256 hash of the denom trace.
+pub fn validate_denom(denom: &str) -> Result<(), ContractError> {
+    let invalid_denom = |reason: &str| ContractError::InvalidDenom {
+        denom: denom.to_string(),
+        reason: reason.to_string(),
+    };
+
+    ensure!(
+        (3..=128).contains(&denom.len()),
+        invalid_denom("must be between 3 and 128 characters long")
+    );
+    ensure!(
+        denom.starts_with(|c: char| c.is_ascii_alphabetic()),
+        invalid_denom("must start with a letter")
+    );
+    ensure!(
+        denom
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c)),
+        invalid_denom("contains invalid characters")
+    );
+
+    if let Some(hash) = denom.strip_prefix("ibc/") {
+        ensure!(
+            hash.len() == IBC_DENOM_HASH_LENGTH
+                && hash
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c)),
+            invalid_denom("the IBC hash must be 64 uppercase hex characters")
+        );
+    } else if let Some(path) = denom.strip_prefix("factory/") {
+        ensure!(
+            matches!(path.split_once('/'), Some((creator, subdenom))
+                if !creator.is_empty() && !subdenom.is_empty()),
+            invalid_denom("tokenfactory denoms must be factory/{creator}/{subdenom}")
+        );
+    }
+
+    Ok(())
+}
+
+/// Validates the campaign name
+pub fn validate_name(name: &str) -> Result<(), ContractError> {
+    validate_text_param("name", name, MAX_NAME_LENGTH)
+}
+
+/// Validates the campaign description
+pub fn validate_description(description: &str) -> Result<(), ContractError> {
+    validate_text_param("description", description, MAX_DESCRIPTION_LENGTH)
+}
+
+/// Validates the campaign type
+pub fn validate_type(ty: &str) -> Result<(), ContractError> {
+    validate_text_param("type", ty, MAX_TYPE_LENGTH)
+}
+
+/// Validates a text param of the campaign, which can't be empty nor longer than the given length
+fn validate_text_param(param: &str, value: &str, max_length: usize) -> Result<(), ContractError> {
+    ensure!(
+        !value.is_empty(),
+        ContractError::InvalidCampaignParam {
+            param: param.to_string(),
+            reason: "cannot be empty".to_string(),
+        }
+    );
+
+    ensure!(
+        value.len() <= max_length,
+        ContractError::InvalidCampaignParam {
+            param: param.to_string(),
+            reason: format!("cannot be longer than {} characters", max_length),
+        }
+    );
+
+    Ok(())
+}
+
+/// Validates the campaign metadata uri
+pub fn validate_metadata_uri(metadata_uri: &str) -> Result<(), ContractError> {
+    ensure!(
+        !metadata_uri.is_empty(),
+        ContractError::InvalidCampaignParam {
+            param: "metadata_uri".to_string(),
+            reason: "cannot be empty".to_string(),
+        }
+    );
+
+    ensure!(
+        metadata_uri.len() <= MAX_METADATA_URI_LENGTH,
+        ContractError::InvalidCampaignParam {
+            param: "metadata_uri".to_string(),
+            reason: format!(
+                "cannot be longer than {} characters",
+                MAX_METADATA_URI_LENGTH
+            ),
+        }
+    );
+
+    Ok(())
+}
+
+/// Timestamps earlier than this one (2000-01-01T00:00:00Z), in seconds, are rejected, as they most
+/// likely are expressed in the wrong unit, i.e. milliseconds instead of nanoseconds.
+pub const MIN_TIMESTAMP_SECONDS: u64 = 946_684_800;
+
+/// Validates the given timestamp is not expressed in the wrong unit
+fn validate_timestamp(param: &str, timestamp: &Timestamp) -> Result<(), ContractError> {
+    ensure!(
+        timestamp.seconds() >= MIN_TIMESTAMP_SECONDS,
+        ContractError::InvalidCampaignParam {
+            param: param.to_string(),
+            reason: "looks like it's expressed in milliseconds, expected nanoseconds".to_string(),
+        }
+    );
+
+    Ok(())
+}
+
+#[cw_serde]
+pub enum DistributionType {
+    /// The distribution is done in a linear vesting schedule
+    LinearVesting {
+        /// The percentage of the total reward to be distributed with a linear vesting schedule
+        percentage: Decimal,
+        /// The time when this distribution type starts
+        start_time: Timestamp,
+        /// The time when this distribution type ends
+        end_time: Timestamp,
+        /// The duration of the cliff, in seconds
+        cliff_duration: Option<u64>,
+        /// What happens at the end of the cliff, defined by [CliffBehavior]. Defaults to
+        /// [CliffBehavior::ReleaseAccrued].
+        #[serde(default)]
+        cliff_behavior: Option<CliffBehavior>,
+    },
+    /// The distribution is vested between the start and end times following a [Curve]
+    CurveVesting {
+        /// The percentage of the total reward to be distributed with this vesting schedule
+        percentage: Decimal,
+        /// The time when this distribution type starts
+        start_time: Timestamp,
+        /// The time when this distribution type ends, at which point it's fully vested
+        end_time: Timestamp,
+        /// The shape of the vesting schedule
+        curve: Curve,
+    },
+    /// The distribution is done in a single lump sum, i.e. no vesting period
+    LumpSum {
+        percentage: Decimal,
+        /// The time when this distribution type starts
+        start_time: Timestamp,
+        /// The duration of the cliff, in seconds. The lump sum is unlocked once the cliff has passed.
+        cliff_duration: Option<u64>,
+    },
+    /// The distribution is done in a single lump sum unlocked at the start of the campaign, with an
+    /// extra bonus for the users claiming it before the deadline. The bonuses are paid from a pool
+    /// of `bonus_percentage` of the total reward, reserved on top of the allocations, until it's
+    /// depleted.
+    EarlyBirdBonus {
+        /// The percentage of the total reward to be distributed with this distribution type
+        base_percentage: Decimal,
+        /// The percentage of the allocation paid as a bonus to the early claimers
+        bonus_percentage: Decimal,
+        /// The time until which the claims receive the bonus
+        bonus_deadline: Timestamp,
+    },
+    /// A raffle among the allocation holders, drawn once the draw time has passed. The prize pool
+    /// is `prize_pool_percentage` of the total reward, reserved on top of the allocations, and is
+    /// split evenly among the winners, crediting their allocations.
+    Raffle {
+        /// The percentage of the total reward awarded to the winners
+        prize_pool_percentage: Decimal,
+        /// The time after which the raffle can be drawn
+        draw_time: Timestamp,
+        /// The number of prizes to draw
+        winners: u32,
+    },
+}
+
+/// What happens at the end of the cliff of a [DistributionType::LinearVesting] schedule. Nothing
+/// is claimable before the cliff either way.
+#[cw_serde]
+#[derive(Default)]
+pub enum CliffBehavior {
+    /// The vesting accrues from the start time, so the amount accrued during the cliff unlocks all
+    /// at once at its end, i.e. the industry standard cliff
+    #[default]
+    ReleaseAccrued,
+    /// The vesting only starts accruing at the end of the cliff, so the slot vests linearly between
+    /// the end of the cliff and the end time
+    DelayOnly,
+}
+
+/// Maximum number of points of a piecewise linear [Curve]
+pub const MAX_CURVE_POINTS: usize = 50;
+/// Maximum rate of an exponential [Curve], keeping its computation bounded
+pub const MAX_CURVE_EXPONENTIAL_RATE: u64 = 10;
+
+/// The shape of a [DistributionType::CurveVesting] schedule, mapping the elapsed share of the
+/// vesting duration to the vested share of the slot allocation. Every curve starts at 0 and is
+/// fully vested at the end time.
+#[cw_serde]
+pub enum Curve {
+    /// Back-loaded vesting, where the vested share at the elapsed share `x` of the duration is
+    /// `(e^(rate * x) - 1) / (e^rate - 1)`. The greater the rate, the more back-loaded the vesting.
+    Exponential {
+        /// The growth rate of the curve, greater than zero and at most [MAX_CURVE_EXPONENTIAL_RATE]
+        rate: Decimal,
+    },
+    /// Vesting interpolated linearly between points, from 0 at the start time to 1 at the end time
+    PiecewiseLinear {
+        /// The intermediate points of the curve, as the seconds elapsed since the start time and
+        /// the share vested by then. The seconds must be strictly increasing and within the
+        /// vesting duration, and the shares non-decreasing and at most 1.
+        points: Vec<(u64, Decimal)>,
+    },
+}
+
+impl Curve {
+    /// Validates the curve parameters, given the duration of the vesting in seconds
+    pub fn validate(&self, duration: u64) -> Result<(), ContractError> {
+        match self {
+            Curve::Exponential { rate } => ensure!(
+                !rate.is_zero() && *rate <= Decimal::from_ratio(MAX_CURVE_EXPONENTIAL_RATE, 1u64),
+                ContractError::InvalidCampaignParam {
+                    param: "curve".to_string(),
+                    reason: format!(
+                        "the rate must be greater than zero and at most {MAX_CURVE_EXPONENTIAL_RATE}"
+                    ),
+                }
+            ),
+            Curve::PiecewiseLinear { points } => {
+                ensure!(
+                    !points.is_empty() && points.len() <= MAX_CURVE_POINTS,
+                    ContractError::InvalidCampaignParam {
+                        param: "curve".to_string(),
+                        reason: format!("must have between 1 and {MAX_CURVE_POINTS} points"),
+                    }
+                );
+
+                let mut previous = (0u64, Decimal::zero());
+                for (index, (elapsed, vested)) in points.iter().enumerate() {
+                    ensure!(
+                        (index == 0 || *elapsed > previous.0) && *elapsed < duration,
+                        ContractError::InvalidCampaignParam {
+                            param: "curve".to_string(),
+                            reason: "the points must be strictly increasing in time and within the vesting duration".to_string(),
+                        }
+                    );
+                    ensure!(
+                        *vested >= previous.1 && *vested <= Decimal::one(),
+                        ContractError::InvalidCampaignParam {
+                            param: "curve".to_string(),
+                            reason: "the vested shares must be non-decreasing and at most 1"
+                                .to_string(),
+                        }
+                    );
+
+                    previous = (*elapsed, *vested);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DistributionType {
+    /// Returns the distribution with all its times shifted by the given number of seconds
+    pub fn shifted(&self, offset_seconds: u64) -> DistributionType {
+        let mut distribution = self.clone();
+
+        match &mut distribution {
+            DistributionType::LinearVesting {
+                start_time,
+                end_time,
+                ..
+            }
+            | DistributionType::CurveVesting {
+                start_time,
+                end_time,
+                ..
+            } => {
+                *start_time = start_time.plus_seconds(offset_seconds);
+                *end_time = end_time.plus_seconds(offset_seconds);
+            }
+            DistributionType::LumpSum { start_time, .. } => {
+                *start_time = start_time.plus_seconds(offset_seconds);
+            }
+            DistributionType::EarlyBirdBonus { bonus_deadline, .. } => {
+                *bonus_deadline = bonus_deadline.plus_seconds(offset_seconds);
+            }
+            DistributionType::Raffle { draw_time, .. } => {
+                *draw_time = draw_time.plus_seconds(offset_seconds);
+            }
+        }
+
+        distribution
+    }
+
+    /// Returns the name of the distribution type, as serialized
+    pub fn name(&self) -> &'static str {
+        match self {
+            DistributionType::LinearVesting { .. } => "linear_vesting",
+            DistributionType::CurveVesting { .. } => "curve_vesting",
+            DistributionType::LumpSum { .. } => "lump_sum",
+            DistributionType::EarlyBirdBonus { .. } => "early_bird_bonus",
+            DistributionType::Raffle { .. } => "raffle",
+        }
+    }
+
+    pub fn has_started(&self, current_time: &Timestamp) -> bool {
+        let start_time = match self {
+            DistributionType::LinearVesting { start_time, .. }
+            | DistributionType::CurveVesting { start_time, .. }
+            | DistributionType::LumpSum { start_time, .. } => start_time,
+            // unlocked at the start of the campaign
+            DistributionType::EarlyBirdBonus { .. } => return true,
+            DistributionType::Raffle { draw_time, .. } => draw_time,
+        };
+
+        current_time >= start_time
+    }
+
+    /// Returns the time at which the distribution starts paying out, i.e. its start time plus its
+    /// cliff. The raffle only pays out to its winners, so it's not considered.
+    pub fn unlock_time(&self, campaign_start_time: &Timestamp) -> Option<Timestamp> {
+        match self {
+            DistributionType::LinearVesting {
+                start_time,
+                cliff_duration,
+                ..
+            }
+            | DistributionType::LumpSum {
+                start_time,
+                cliff_duration,
+                ..
+            } => Some(start_time.plus_seconds(cliff_duration.unwrap_or_default())),
+            DistributionType::CurveVesting { start_time, .. } => Some(*start_time),
+            DistributionType::EarlyBirdBonus { .. } => Some(*campaign_start_time),
+            DistributionType::Raffle { .. } => None,
+        }
+    }
+
+    /// Returns the time from which a vesting distribution accrues, i.e. its start time, or the end
+    /// of its cliff if its [CliffBehavior] is [CliffBehavior::DelayOnly]. The other distribution
+    /// types don't vest, so they have none.
+    pub fn vesting_start_time(&self) -> Option<Timestamp> {
+        match self {
+            DistributionType::LinearVesting {
+                start_time,
+                cliff_duration,
+                cliff_behavior: Some(CliffBehavior::DelayOnly),
+                ..
+            } => Some(start_time.plus_seconds(cliff_duration.unwrap_or_default())),
+            DistributionType::LinearVesting { start_time, .. }
+            | DistributionType::CurveVesting { start_time, .. } => Some(*start_time),
+            DistributionType::LumpSum { .. }
+            | DistributionType::EarlyBirdBonus { .. }
+            | DistributionType::Raffle { .. } => None,
+        }
+    }
+
+    /// Checks if the cliff period of the distribution has passed. Distributions without a cliff
+    /// are considered to have passed it.
+    pub fn has_cliff_passed(&self, current_time: &Timestamp) -> bool {
+        let (start_time, cliff_duration) = match self {
+            DistributionType::LinearVesting {
+                start_time,
+                cliff_duration,
+                ..
+            } => (start_time, cliff_duration),
+            DistributionType::LumpSum {
+                start_time,
+                cliff_duration,
+                ..
+            } => (start_time, cliff_duration),
+            DistributionType::CurveVesting { .. }
+            | DistributionType::EarlyBirdBonus { .. }
+            | DistributionType::Raffle { .. } => return true,
+        };
+
+        current_time >= &start_time.plus_seconds(cliff_duration.unwrap_or_default())
+    }
+}
@@ -1,21 +1,104 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use cosmwasm_std::{ensure, BankMsg, Coin, DepsMut, Env, Event, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    coin, ensure, to_json_string, to_json_vec, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal,
+    Deps, DepsMut, Env, Event, HexBinary, IbcMsg, IbcTimeout, Int128, MessageInfo, Order,
+    QueryRequest, Response, StdError, StdResult, Storage, Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
 
+use crate::events::{
+    ActionAttributes, AllocationDeltaEvent, AllocationsAttributes, ClaimAttributes,
+    CloseCampaignAttributes, HoldEvent, LockEvent, OwnershipHandoffEvent, SlotClaimEvent,
+    SweepEvent, TransferIntent, TransferKind,
+};
 use crate::helpers::{self, validate_raw_address};
+use crate::ibc::IbcPayoutMemo;
+use crate::stargate;
 use crate::state::{
-    assert_authorized, get_allocation, get_claims_for_address, is_authorized, is_blacklisted,
-    Claim, DistributionSlot, ALLOCATIONS, AUTHORIZED_WALLETS, BLACKLIST, CAMPAIGN, CLAIMS,
+    active_hold, adjust_total_allocated, assert_authorized, decrease_count, get_allocation,
+    get_claims_for_address, get_count, get_exhausted_slots, get_slots_claimed,
+    get_total_claims_amount_for_address, increase_count, is_allowlisted, is_authorized,
+    is_blacklisted, is_frozen, record_claim_history, record_funding, update_stats,
+    update_tag_stats, Allocation, Budget, Claim, DistributionSlot, ExhaustedSlots, FundingRecord,
+    Group, GroupMember, ProcessedBatch, ADDRESS_REPLACEMENT_REQUESTS, ALLOCATIONS,
+    ALLOCATIONS_COUNT, ALLOWLIST, AUTHORIZED_WALLETS, BLACKLIST, BLACKLIST_COUNT, BUDGET, CAMPAIGN,
+    CIRCUIT_BREAKER_WINDOW, CLAIMANTS_COUNT, CLAIMS, CLAIM_HOOKS, CLAIM_NONCES, CONFIG,
+    DAILY_CLAIM_VOLUME, EARLY_BIRD_BONUS_CLAIMED, EXHAUSTED_SLOTS, FORFEITED, FORFEITED_TOTAL,
+    FROZEN, FUNDERS, GROUPS, GROUP_MEMBERS, HOLDS, IBC_PAYOUTS, IBC_PAYOUT_COUNT, LOCKED_TOTAL,
+    LOCKS, LOCK_BOOST_CLAIMED, PENDING_AUTHORIZED_WALLETS, PENDING_SWEEP, PROCESSED_BATCHES,
+    PROPOSALS, PROPOSAL_COUNT, RAFFLE_SEED, RAFFLE_WINNERS, REDISTRIBUTION, REGISTERED_VOUCHERS,
+    SECONDS_PER_DAY, SLOT_CLAIMED, SWEPT_WITHOUT_APPROVAL, TERMS_ACCEPTANCES, VESTING_ACTIVATIONS,
+    WALLET_ROLES,
 };
 use mantra_claimdrop_std::error::ContractError;
-use mantra_claimdrop_std::msg::{Campaign, CampaignAction, CampaignParams, DistributionType};
+use mantra_claimdrop_std::msg::{
+    validate_description, validate_metadata_uri, validate_name, validate_type, AllocationMode,
+    AllocationVoucher, AllocationsResponse, BlacklistEntry, Campaign, CampaignAction,
+    CampaignParams, CampaignPhase, CampaignStatus, CampaignStatusResponse, CircuitBreaker,
+    ClaimHookMsg, ClaimMode, ClaimSignaturePayload, ClaimedResponse, CloneOverrides,
+    DistributionType, Eligibility, FrozenEntry, FrozenResolution, HoldEntry, IbcAdmin, IbcReceiver,
+    Limits, LinkAddressPayload, LockTier, PauseScope, PayoutTarget, PendingSweep, Proposal,
+    ProposalAction, QueryMsg, RefundPolicy, RegistryUpdateMsg, RewardSource, Role, ScheduledDelay,
+    DEFAULT_MAX_DISTRIBUTION_SLOTS, MAX_AUTHORIZED_WALLET_DELAY, MAX_DISTRIBUTION_SLOTS,
+    SWEEP_APPROVAL_WINDOW,
+};
 
 /// Maximum number of allocations that can be added in a single batch
-pub const MAX_ALLOCATION_BATCH_SIZE: usize = 3000;
+pub const MAX_ALLOCATION_BATCH_SIZE: usize = 6000;
+
+/// Maximum number of entries that can be pruned in a single batch
+pub const MAX_PRUNE_BATCH_SIZE: u16 = 500;
+
+/// Maximum number of allocations that can be imported from another contract in a single batch, as
+/// the claims of every imported address are queried on the source contract
+pub const MAX_IMPORT_ALLOCATIONS_BATCH_SIZE: u16 = 100;
+
+/// Maximum number of validators whose delegators can be snapshotted in a single call
+pub const MAX_SNAPSHOT_VALIDATORS: usize = 10;
 
 /// Maximum number of authorized wallets that can be managed in a single batch operation
 pub const MAX_AUTHORIZED_WALLETS_BATCH_SIZE: usize = 1000;
 
+/// Maximum number of address pairs that can be replaced in a single batch
+pub const MAX_REPLACE_ADDRESSES_BATCH_SIZE: usize = 1000;
+
+/// Maximum number of receivers that can be claimed for in a single batch
+pub const MAX_CLAIM_BATCH_SIZE: usize = 50;
+
+/// Maximum number of contracts that can be registered as claim hooks
+pub const MAX_CLAIM_HOOKS: usize = 10;
+
+/// Maximum number of addresses that can be added to/removed from the allowlist in a single batch
+pub const MAX_ALLOWLIST_BATCH_SIZE: usize = 3000;
+
+/// Maximum number of addresses that can be blacklisted/unblacklisted in a single batch
+pub const MAX_BLACKLIST_BATCH_SIZE: usize = 1000;
+
+/// Maximum length for the reason of blacklisting an address
+pub const MAX_BLACKLIST_REASON_LENGTH: usize = 500;
+
+/// Maximum number of groups sharing a pool of the campaign rewards
+pub const MAX_GROUPS: usize = 20;
+
+/// Maximum number of funders topping up the campaign, each of them being refunded with a message
+/// when closing the campaign
+pub const MAX_FUNDERS: usize = 50;
+
+/// Maximum length for the name of a group
+pub const MAX_GROUP_NAME_LENGTH: usize = 100;
+
+/// Maximum number of members that can be added to/removed from a group in a single batch
+pub const MAX_GROUP_MEMBERS_BATCH_SIZE: usize = 1000;
+
+/// Maximum length for the tag of a cohort of allocations
+pub const MAX_TAG_LENGTH: usize = 64;
+
+/// Maximum length for the identifier of an allocation batch
+pub const MAX_BATCH_ID_LENGTH: usize = 128;
+
 /// Manages a campaign
 pub(crate) fn manage_campaign(
     deps: DepsMut,
@@ -23,14 +106,41 @@ pub(crate) fn manage_campaign(
     info: MessageInfo,
     campaign_action: CampaignAction,
 ) -> Result<Response, ContractError> {
-    assert_authorized(deps.as_ref(), &info.sender)?;
+    assert_authorized(deps.as_ref(), &info.sender, Role::CampaignManager)?;
 
     match campaign_action {
         CampaignAction::CreateCampaign { params } => create_campaign(deps, env, info, *params),
+        // closing the campaign is irreversible, so authorized wallets can only propose it
         CampaignAction::CloseCampaign {} => {
             cw_utils::nonpayable(&info)?;
+            cw_ownable::assert_owner(deps.storage, &info.sender)?;
             close_campaign(deps, env)
         }
+        CampaignAction::UpdateCampaign { params } => {
+            cw_utils::nonpayable(&info)?;
+            update_campaign(deps, env, *params)
+        }
+        CampaignAction::UpdateMetadataUri { metadata_uri } => {
+            cw_utils::nonpayable(&info)?;
+            update_metadata_uri(deps, env, metadata_uri)
+        }
+        CampaignAction::UpdateMetadata {
+            name,
+            description,
+            ty,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            update_metadata(deps, env, name, description, ty)
+        }
+        CampaignAction::CloneCampaign {
+            source_contract,
+            start_time,
+            overrides,
+        } => clone_campaign(deps, env, info, source_contract, start_time, *overrides),
+        CampaignAction::AdvancePhase {} => {
+            cw_utils::nonpayable(&info)?;
+            advance_phase(deps, env)
+        }
     }
 }
 
@@ -44,587 +154,5531 @@ fn create_campaign(
     cw_utils::nonpayable(&info)?;
     let campaign: Option<Campaign> = CAMPAIGN.may_load(deps.storage)?;
 
+    ensure!(campaign.is_none(), ContractError::CampaignAlreadyExists);
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    helpers::validate_campaign_params(
+        env.block.time,
+        &env.contract.address,
+        &config.limits,
+        &campaign_params,
+    )?;
+    if let Some(claim_fee) = &campaign_params.claim_fee {
+        deps.api.addr_validate(&claim_fee.recipient)?;
+    }
+    if let PayoutTarget::Contract { addr, .. } = &campaign_params.payout_target {
+        deps.api.addr_validate(addr)?;
+    }
+    if let Some(community_pool) = &campaign_params.community_pool {
+        deps.api.addr_validate(community_pool)?;
+    }
+    if let Some(Eligibility::OracleCheck { contract, .. }) = &campaign_params.eligibility {
+        deps.api.addr_validate(contract)?;
+    }
+
+    // campaigns minting on claim create the supply of their denom
+    if config.limits.require_existing_reward_denom
+        && campaign_params.reward_source == RewardSource::PreFunded
+    {
+        let denom = &campaign_params.total_reward.denom;
+        ensure!(
+            !deps.querier.query_supply(denom)?.amount.is_zero(),
+            ContractError::InvalidDenom {
+                denom: denom.to_string(),
+                reason: "the denom has no supply on chain".to_string(),
+            }
+        );
+    }
+
+    validate_existing_allocations(deps.as_ref(), campaign_params.max_allocation_per_address)?;
+
+    let campaign = Campaign::from_params(campaign_params);
+    CAMPAIGN.save(deps.storage, &campaign)?;
+
+    let registry_messages = helpers::registry_update_message(
+        deps.as_ref(),
+        RegistryUpdateMsg::CampaignCreated {
+            name: campaign.name.clone(),
+            ty: campaign.ty.clone(),
+            total_reward: campaign.total_reward.clone(),
+            start_time: campaign.start_time,
+            end_time: campaign.end_time,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_messages(registry_messages)
+        .add_attributes(vec![
+            ("action", "create_campaign".to_string()),
+            ("campaign", campaign.to_string()),
+        ]))
+}
+
+/// Creates a new campaign from the configuration of a finished campaign on another claimdrop
+/// contract, shifting its schedule to the new start time. The new campaign is validated same as
+/// when creating it.
+fn clone_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    source_contract: String,
+    start_time: Timestamp,
+    overrides: CloneOverrides,
+) -> Result<Response, ContractError> {
+    let source_contract = deps.api.addr_validate(&source_contract)?;
+    let CampaignStatusResponse {
+        campaign: source_campaign,
+        status,
+        ..
+    } = deps
+        .querier
+        .query_wasm_smart(&source_contract, &QueryMsg::CampaignStatus {})?;
+
     ensure!(
-        campaign.is_none(),
+        matches!(status, CampaignStatus::Ended | CampaignStatus::Closed),
         ContractError::CampaignError {
-            reason: "existing campaign".to_string()
+            reason: "only finished campaigns can be cloned".to_string()
+        }
+    );
+    ensure!(
+        start_time >= source_campaign.start_time,
+        ContractError::InvalidCampaignParam {
+            param: "start_time".to_string(),
+            reason: "the clone can't start before the source campaign".to_string(),
         }
     );
 
-    helpers::validate_campaign_params(env.block.time, &campaign_params)?;
+    let mut params = source_campaign.to_shifted_params(
+        start_time
+            .seconds()
+            .saturating_sub(source_campaign.start_time.seconds()),
+    );
+    let CloneOverrides {
+        name,
+        description,
+        total_reward,
+        metadata_uri,
+        allocations_hash,
+    } = overrides;
+    params.name = name.unwrap_or(params.name);
+    params.description = description.unwrap_or(params.description);
+    params.total_reward = total_reward.unwrap_or(params.total_reward);
+    params.metadata_uri = metadata_uri.or(params.metadata_uri);
+    params.allocations_hash = allocations_hash.or(params.allocations_hash);
 
-    let campaign = Campaign::from_params(campaign_params);
-    CAMPAIGN.save(deps.storage, &campaign)?;
+    let response = create_campaign(deps, env, info, params)?;
 
-    Ok(Response::default().add_attributes(vec![
-        ("action", "create_campaign".to_string()),
-        ("campaign", campaign.to_string()),
-    ]))
+    Ok(response.add_attribute("source_contract", source_contract))
 }
 
 /// Closes the existing airdrop campaign. Only the owner can end the campaign.
 /// The remaining funds in the campaign are refunded to the owner.
-fn close_campaign(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+pub(crate) fn close_campaign(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    close(deps, env, None)
+}
+
+/// Closes the existing airdrop campaign, refunding the remaining funds to the owner, and notifies
+/// the registry that it was closed, or finalized by the given address.
+fn close(deps: DepsMut, env: Env, finalized_by: Option<Addr>) -> Result<Response, ContractError> {
     let mut campaign = CAMPAIGN
         .may_load(deps.storage)?
-        .ok_or(ContractError::CampaignError {
-            reason: "there's not an active campaign".to_string(),
-        })?;
+        .ok_or(ContractError::NoActiveCampaign)?;
 
-    ensure!(
-        campaign.closed.is_none(),
-        ContractError::CampaignError {
-            reason: "campaign has already been closed".to_string()
-        }
-    );
+    campaign.ensure_phase(
+        &env.block.time,
+        &[
+            CampaignPhase::Upcoming,
+            CampaignPhase::AllocationUpload,
+            CampaignPhase::Active,
+            CampaignPhase::Ended,
+        ],
+        "campaign has already been closed",
+    )?;
 
-    let refund: Coin = deps
-        .querier
-        .query_balance(env.contract.address, &campaign.total_reward.denom)?;
+    let refund: Coin =
+        helpers::query_reward_balance(deps.as_ref(), &env.contract.address, &campaign)?;
 
     let mut messages = vec![];
+    let mut transfer_events = vec![];
 
-    if !refund.amount.is_zero() {
-        let owner = cw_ownable::get_ownership(deps.storage)?.owner.unwrap();
-
+    for (receiver, amount) in refund_shares(deps.as_ref(), &campaign, &refund)? {
         messages.push(BankMsg::Send {
-            to_address: owner.to_string(),
-            amount: vec![refund.clone()],
+            to_address: receiver.clone(),
+            amount: vec![amount.clone()],
         });
+        transfer_events.push(
+            TransferIntent {
+                kind: TransferKind::Refund,
+                from: env.contract.address.as_str(),
+                to: &receiver,
+                amount: &amount,
+            }
+            .into_event(),
+        );
     }
 
     campaign.closed = Some(env.block.time.seconds());
 
     CAMPAIGN.save(deps.storage, &campaign)?;
 
+    let (name, claimed, refunded) = (
+        campaign.name.clone(),
+        campaign.claimed.clone(),
+        refund.clone(),
+    );
+    let registry_messages = helpers::registry_update_message(
+        deps.as_ref(),
+        match finalized_by {
+            Some(finalized_by) => RegistryUpdateMsg::CampaignFinalized {
+                name,
+                claimed,
+                refunded,
+                finalized_by: finalized_by.to_string(),
+            },
+            None => RegistryUpdateMsg::CampaignClosed {
+                name,
+                claimed,
+                refunded,
+            },
+        },
+    )?;
+
     Ok(Response::default()
         .add_messages(messages)
-        .add_attributes(vec![
-            ("action", "close_campaign".to_string()),
-            ("campaign", campaign.to_string()),
-            ("refund", refund.to_string()),
-        ]))
+        .add_messages(registry_messages)
+        .add_events(transfer_events)
+        .add_attributes(
+            CloseCampaignAttributes {
+                campaign: campaign.to_string(),
+                refund: &refund,
+            }
+            .into_attributes(),
+        ))
 }
 
-/// Sweep recovers non-reward tokens accidentally sent to the contract.
-/// This prevents permanent loss of user funds while protecting campaign assets.
+/// Splits the refund of a closing campaign according to its [RefundPolicy]. With the pro-rata
+/// policy, the funds not attributed to any funder, i.e. sent with a plain bank transfer rather
+/// than topped up, are refunded to the owner first. Each funder then gets a share of the rest
+/// proportional to its contributions, the rounding dust going to the owner.
 ///
-/// # Security
-/// - Owner-only operation via `cw_ownable::assert_owner`
-/// - Cannot sweep campaign reward tokens (must use CloseCampaign instead)
-/// - Validates amounts and balances before sweeping
-/// - Works whether campaign exists or not
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `campaign` - The campaign being closed
+/// * `refund` - The funds left to refund
+///
+/// # Returns
+/// * `Result<Vec<(String, Coin)>, ContractError>` - The receivers of the refund and their shares
+fn refund_shares(
+    deps: Deps,
+    campaign: &Campaign,
+    refund: &Coin,
+) -> Result<Vec<(String, Coin)>, ContractError> {
+    if refund.amount.is_zero() {
+        return Ok(vec![]);
+    }
+
+    let mut shares = vec![];
+    if campaign.refund_policy == RefundPolicy::ProRata {
+        let funders = FUNDERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+        let total_contributed = funders
+            .iter()
+            .try_fold(Uint128::zero(), |total, (_, contributed)| {
+                total.checked_add(*contributed)
+            })?;
+
+        // the claims are paid from the unattributed funds first, so only the funds exceeding the
+        // contributions are the owner's
+        let attributed = refund.amount.min(total_contributed);
+
+        if !total_contributed.is_zero() {
+            for (funder, contributed) in funders {
+                let share = attributed.multiply_ratio(contributed, total_contributed);
+                if !share.is_zero() {
+                    shares.push((funder, coin(share.u128(), &refund.denom)));
+                }
+            }
+        }
+    }
+
+    let refunded = shares
+        .iter()
+        .fold(Uint128::zero(), |total, (_, share)| total + share.amount);
+    let dust = refund.amount.checked_sub(refunded)?;
+    if !dust.is_zero() {
+        let owner = cw_ownable::get_ownership(deps.storage)?
+            .owner
+            .ok_or(ContractError::Unauthorized)?;
+        shares.push((owner.to_string(), coin(dust.u128(), &refund.denom)));
+    }
+
+    Ok(shares)
+}
+
+/// Updates the campaign with new parameters. This can only be done before the campaign has
+/// started and before any claims. The new parameters are validated same as when creating the
+/// campaign, and an event with the fields that changed is emitted.
 ///
 /// # Arguments
-/// * `deps` - Dependencies for storage and querier access
-/// * `env` - Environment information including contract address
-/// * `info` - Message info containing sender (must be owner)
-/// * `denom` - The token denomination to sweep
-/// * `amount` - Optional amount to sweep (None = sweep entire balance)
-pub(crate) fn sweep(
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `campaign_params` - The new parameters of the campaign
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+fn update_campaign(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    denom: String,
-    amount: Option<Uint128>,
+    campaign_params: CampaignParams,
 ) -> Result<Response, ContractError> {
-    // Only owner can sweep tokens
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
 
-    // Get the campaign if it exists to check reward denom
-    let campaign = CAMPAIGN.may_load(deps.storage)?;
+    ensure!(
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
+    );
 
-    // Prevent sweeping the reward denom if a campaign exists
-    if let Some(campaign) = campaign {
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Upcoming, CampaignPhase::AllocationUpload],
+        "cannot update the campaign after it has started",
+    )?;
+    ensure!(
+        campaign.claimed.amount.is_zero(),
+        ContractError::CampaignAlreadyStarted {
+            started_at: campaign.start_time
+        }
+    );
+
+    ensure!(
+        campaign_params.total_reward.denom == campaign.total_reward.denom,
+        ContractError::InvalidCampaignParam {
+            param: "total_reward".to_string(),
+            reason: "the reward denom can't be updated".to_string(),
+        }
+    );
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    helpers::validate_campaign_params(
+        env.block.time,
+        &env.contract.address,
+        &config.limits,
+        &campaign_params,
+    )?;
+    if let Some(claim_fee) = &campaign_params.claim_fee {
+        deps.api.addr_validate(&claim_fee.recipient)?;
+    }
+    if let PayoutTarget::Contract { addr, .. } = &campaign_params.payout_target {
+        deps.api.addr_validate(addr)?;
+    }
+    if let Some(community_pool) = &campaign_params.community_pool {
+        deps.api.addr_validate(community_pool)?;
+    }
+    if let Some(Eligibility::OracleCheck { contract, .. }) = &campaign_params.eligibility {
+        deps.api.addr_validate(contract)?;
+    }
+
+    validate_existing_allocations(deps.as_ref(), campaign_params.max_allocation_per_address)?;
+
+    let mut updated_campaign = Campaign::from_params(campaign_params);
+    // campaigns that keep their manual phases stay in the phase they were advanced to
+    if campaign.manual_phase.is_some() && updated_campaign.manual_phase.is_some() {
+        updated_campaign.manual_phase = campaign.manual_phase.clone();
+    }
+    CAMPAIGN.save(deps.storage, &updated_campaign)?;
+
+    Ok(Response::default()
+        .add_event(campaign_diff_event(&campaign, &updated_campaign))
+        .add_attributes(vec![
+            ("action", "update_campaign".to_string()),
+            ("campaign", updated_campaign.to_string()),
+        ]))
+}
+
+/// Validates the allocations uploaded so far don't exceed the maximum allocation per address, by
+/// checking the largest one.
+fn validate_existing_allocations(
+    deps: Deps,
+    max_allocation_per_address: Option<Uint128>,
+) -> Result<(), ContractError> {
+    let Some(max_allocation_per_address) = max_allocation_per_address else {
+        return Ok(());
+    };
+
+    if let Some(largest_allocation) = ALLOCATIONS
+        .idx
+        .amount
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, allocation)| allocation.amount)
+    {
         ensure!(
-            denom != campaign.total_reward.denom,
-            ContractError::CampaignError {
+            largest_allocation <= max_allocation_per_address,
+            ContractError::InvalidCampaignParam {
+                param: "max_allocation_per_address".to_string(),
                 reason: format!(
-                    "Cannot sweep reward denom '{}'. Use CloseCampaign instead",
-                    campaign.total_reward.denom
+                    "an existing allocation of {largest_allocation} exceeds the maximum"
                 )
             }
         );
     }
 
-    // Query the balance of the specified denom
-    let balance = deps.querier.query_balance(&env.contract.address, &denom)?;
+    Ok(())
+}
 
-    // Determine the amount to sweep
-    let sweep_amount = match amount {
-        Some(amt) => {
-            ensure!(
-                amt <= balance.amount,
-                ContractError::InvalidCampaignParam {
-                    param: "amount".to_string(),
-                    reason: format!(
-                        "Requested amount {} exceeds available balance {}",
-                        amt, balance.amount
-                    )
-                }
-            );
-            amt
-        }
-        None => balance.amount,
-    };
+/// Builds an event with the fields that changed between two versions of the campaign, with the
+/// values before and after the update.
+fn campaign_diff_event(old: &Campaign, new: &Campaign) -> Event {
+    let fields = [
+        ("name", old.name.clone(), new.name.clone()),
+        (
+            "description",
+            old.description.clone(),
+            new.description.clone(),
+        ),
+        ("type", old.ty.clone(), new.ty.clone()),
+        (
+            "total_reward",
+            old.total_reward.to_string(),
+            new.total_reward.to_string(),
+        ),
+        (
+            "distribution_type",
+            format!("{:?}", old.distribution_type),
+            format!("{:?}", new.distribution_type),
+        ),
+        (
+            "start_time",
+            old.start_time.seconds().to_string(),
+            new.start_time.seconds().to_string(),
+        ),
+        (
+            "end_time",
+            old.end_time.seconds().to_string(),
+            new.end_time.seconds().to_string(),
+        ),
+        (
+            "claim_mode",
+            format!("{:?}", old.claim_mode),
+            format!("{:?}", new.claim_mode),
+        ),
+        (
+            "claim_cooldown_seconds",
+            format!("{:?}", old.claim_cooldown_seconds),
+            format!("{:?}", new.claim_cooldown_seconds),
+        ),
+        (
+            "cliff_duration",
+            format!("{:?}", old.cliff_duration),
+            format!("{:?}", new.cliff_duration),
+        ),
+        (
+            "reward_source",
+            format!("{:?}", old.reward_source),
+            format!("{:?}", new.reward_source),
+        ),
+        (
+            "metadata_uri",
+            format!("{:?}", old.metadata_uri),
+            format!("{:?}", new.metadata_uri),
+        ),
+        (
+            "allocations_hash",
+            format!("{:?}", old.allocations_hash),
+            format!("{:?}", new.allocations_hash),
+        ),
+        (
+            "claim_fee",
+            format!("{:?}", old.claim_fee),
+            format!("{:?}", new.claim_fee),
+        ),
+        (
+            "allocation_transfers_disabled",
+            old.allocation_transfers_disabled.to_string(),
+            new.allocation_transfers_disabled.to_string(),
+        ),
+        (
+            "grace_period",
+            format!("{:?}", old.grace_period),
+            format!("{:?}", new.grace_period),
+        ),
+        (
+            "payout_target",
+            format!("{:?}", old.payout_target),
+            format!("{:?}", new.payout_target),
+        ),
+        (
+            "min_claim_amount",
+            format!("{:?}", old.min_claim_amount),
+            format!("{:?}", new.min_claim_amount),
+        ),
+        (
+            "manual_phase",
+            format!("{:?}", old.manual_phase),
+            format!("{:?}", new.manual_phase),
+        ),
+        (
+            "fee_grant",
+            format!("{:?}", old.fee_grant),
+            format!("{:?}", new.fee_grant),
+        ),
+        (
+            "max_allocation_per_address",
+            format!("{:?}", old.max_allocation_per_address),
+            format!("{:?}", new.max_allocation_per_address),
+        ),
+        (
+            "max_single_claim",
+            format!("{:?}", old.max_single_claim),
+            format!("{:?}", new.max_single_claim),
+        ),
+        (
+            "vesting_from_activation",
+            old.vesting_from_activation.to_string(),
+            new.vesting_from_activation.to_string(),
+        ),
+        (
+            "accrual_from",
+            format!("{:?}", old.accrual_from),
+            format!("{:?}", new.accrual_from),
+        ),
+        (
+            "claim_priority",
+            format!("{:?}", old.claim_priority),
+            format!("{:?}", new.claim_priority),
+        ),
+        (
+            "refund_policy",
+            format!("{:?}", old.refund_policy),
+            format!("{:?}", new.refund_policy),
+        ),
+        (
+            "eligibility",
+            format!("{:?}", old.eligibility),
+            format!("{:?}", new.eligibility),
+        ),
+        (
+            "require_full_funding",
+            old.require_full_funding.to_string(),
+            new.require_full_funding.to_string(),
+        ),
+        (
+            "lock_boost",
+            format!("{:?}", old.lock_boost),
+            format!("{:?}", new.lock_boost),
+        ),
+        (
+            "claim_window_seconds",
+            format!("{:?}", old.claim_window_seconds),
+            format!("{:?}", new.claim_window_seconds),
+        ),
+        (
+            "community_pool",
+            format!("{:?}", old.community_pool),
+            format!("{:?}", new.community_pool),
+        ),
+        (
+            "terms_hash",
+            format!("{:?}", old.terms_hash),
+            format!("{:?}", new.terms_hash),
+        ),
+    ];
+
+    fields.into_iter().filter(|(_, old, new)| old != new).fold(
+        Event::new("claimdrop/update_campaign"),
+        |event, (field, old, new)| {
+            event
+                .add_attribute(format!("old_{field}"), old)
+                .add_attribute(format!("new_{field}"), new)
+        },
+    )
+}
+
+/// Updates the metadata uri of the campaign. This can only be done before the campaign has started.
+fn update_metadata_uri(
+    deps: DepsMut,
+    env: Env,
+    metadata_uri: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
 
-    // Check if there's anything to sweep
     ensure!(
-        !sweep_amount.is_zero(),
-        ContractError::CampaignError {
-            reason: format!("No {denom} tokens to sweep")
-        }
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
     );
 
-    // Get the owner address
-    let owner = cw_ownable::get_ownership(deps.storage)?.owner.unwrap();
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Upcoming, CampaignPhase::AllocationUpload],
+        "cannot update the metadata uri after campaign has started",
+    )?;
 
-    // Create the bank send message
-    let send_msg = BankMsg::Send {
-        to_address: owner.to_string(),
-        amount: vec![Coin {
-            denom: denom.clone(),
-            amount: sweep_amount,
-        }],
-    };
+    if let Some(metadata_uri) = &metadata_uri {
+        validate_metadata_uri(metadata_uri)?;
+    }
 
-    // Create a custom event for better indexing
-    let sweep_event = Event::new("sweep_tokens")
-        .add_attribute("denom", &denom)
-        .add_attribute("amount", sweep_amount.to_string())
-        .add_attribute("recipient", owner.as_ref());
+    campaign.metadata_uri = metadata_uri;
+    CAMPAIGN.save(deps.storage, &campaign)?;
 
-    Ok(Response::new()
-        .add_message(send_msg)
-        .add_event(sweep_event)
-        .add_attributes(vec![
-            ("action", "sweep"),
-            ("denom", &denom),
-            ("amount", &sweep_amount.to_string()),
-            ("recipient", owner.as_ref()),
-        ]))
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_metadata_uri".to_string()),
+        ("metadata_uri", campaign.metadata_uri.unwrap_or_default()),
+    ]))
 }
 
-pub(crate) fn claim(
+/// Updates the name, description and type of the campaign, leaving the fields not set unchanged.
+/// This can be done any time before the campaign is closed, as it doesn't touch the economic
+/// parameters.
+fn update_metadata(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    receiver: Option<String>,
-    amount: Option<Uint128>,
+    name: Option<String>,
+    description: Option<String>,
+    ty: Option<String>,
 ) -> Result<Response, ContractError> {
     let mut campaign = CAMPAIGN
         .may_load(deps.storage)?
-        .ok_or(ContractError::CampaignError {
-            reason: "there's not an active campaign".to_string(),
-        })?;
+        .ok_or(ContractError::NoActiveCampaign)?;
 
     ensure!(
-        campaign.has_started(&env.block.time),
-        ContractError::CampaignError {
-            reason: "not started".to_string()
-        }
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
     );
-
     ensure!(
-        campaign.closed.is_none(),
-        ContractError::CampaignError {
-            reason: "has been closed, cannot claim".to_string()
+        name.is_some() || description.is_some() || ty.is_some(),
+        ContractError::InvalidInput {
+            reason: "nothing to update".to_string(),
         }
     );
 
-    // Note: Campaign end_time is intentionally not checked here.
-    // Users should be able to claim their allocated tokens even after the campaign end_time has passed,
-    // as long as the campaign has not been manually closed by the owner.
+    let mut event = Event::new("claimdrop/update_metadata");
+    for (field, value, validate, current) in [
+        (
+            "name",
+            name,
+            validate_name as fn(&str) -> Result<(), ContractError>,
+            &mut campaign.name,
+        ),
+        (
+            "description",
+            description,
+            validate_description,
+            &mut campaign.description,
+        ),
+        ("type", ty, validate_type, &mut campaign.ty),
+    ] {
+        let Some(value) = value else {
+            continue;
+        };
+        validate(&value)?;
 
-    let receiver = receiver
-        .map(|addr| deps.api.addr_validate(&addr))
-        .transpose()?
-        .unwrap_or_else(|| info.sender.clone());
+        event = event
+            .add_attribute(format!("old_{field}"), current.as_str())
+            .add_attribute(format!("new_{field}"), value.as_str());
+        *current = value;
+    }
 
-    // Check if the caller is authorized to claim:
-    // Owner, authorized wallet, OR the wallet with the allocation can claim
-    let is_authorized_user = is_authorized(deps.as_ref(), &info.sender)?;
+    CAMPAIGN.save(deps.storage, &campaign)?;
 
-    ensure!(
-        is_authorized_user || info.sender == receiver,
-        ContractError::Unauthorized
-    );
+    Ok(Response::default()
+        .add_event(event)
+        .add_attribute("action", "update_metadata"))
+}
+
+/// Advances a campaign with manual phases to its next phase. The campaign goes from
+/// [CampaignPhase::Upcoming] to [CampaignPhase::AllocationUpload], where it accepts allocations,
+/// and from there to [CampaignPhase::Active], where it can be claimed from.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+fn advance_phase(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let mut campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
 
     ensure!(
-        !is_blacklisted(deps.as_ref(), receiver.as_ref())?,
-        ContractError::AddressBlacklisted
+        campaign.manual_phase.is_some(),
+        ContractError::CampaignError {
+            reason: "the campaign phases follow the campaign times".to_string()
+        }
     );
 
-    // Get allocation for the address
-    let total_user_allocation = get_allocation(deps.as_ref(), receiver.as_ref())?.ok_or(
-        ContractError::NoAllocationFound {
-            address: receiver.to_string(),
-        },
-    )?;
+    let phase = campaign.phase(&env.block.time);
+    let next_phase = match phase {
+        CampaignPhase::Upcoming => CampaignPhase::AllocationUpload,
+        CampaignPhase::AllocationUpload => CampaignPhase::Active,
+        _ => {
+            return Err(ContractError::CampaignError {
+                reason: format!("the campaign can't be advanced from the {phase} phase"),
+            })
+        }
+    };
 
-    // new_claims is HashMap<DistributionSlot, Claim=(amount, timestamp)> representing newly available amounts per slot
-    let (max_claimable_amount_coin, new_claims, previous_claims) =
-        helpers::compute_claimable_amount(
-            deps.as_ref(),
-            &campaign,
-            &env.block.time,
-            receiver.as_ref(),
-            total_user_allocation,
+    campaign.manual_phase = Some(next_phase.clone());
+    CAMPAIGN.save(deps.storage, &campaign)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "advance_phase".to_string()),
+        ("from_phase", phase.to_string()),
+        ("to_phase", next_phase.to_string()),
+    ]))
+}
+
+/// Closes the campaign once the grace period after its end time has passed, refunding the
+/// remaining funds to the owner same as [close_campaign]. Anyone can finalize the campaign, so
+/// the funds don't remain locked if the owner forgets to close it.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub(crate) fn finalize_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let grace_period = campaign.grace_period.ok_or(ContractError::CampaignError {
+        reason: "the campaign has no grace period, only the owner can close it".to_string(),
+    })?;
+
+    let finalizable_at = campaign.end_time.plus_seconds(grace_period);
+    ensure!(
+        env.block.time >= finalizable_at,
+        ContractError::CampaignError {
+            reason: format!(
+                "the campaign can't be finalized until {}",
+                finalizable_at.seconds()
+            )
+        }
+    );
+
+    Ok(close(deps, env, Some(info.sender.clone()))?.add_attribute("finalized_by", info.sender))
+}
+
+/// Closes the existing airdrop campaign on behalf of the chain governance, via sudo.
+/// The remaining funds in the campaign are refunded to the owner, same as [close_campaign].
+pub(crate) fn force_close_campaign(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    Ok(close_campaign(deps, env)?.add_attribute("forced_by", "governance"))
+}
+
+/// Blacklists an address on behalf of the chain governance, via sudo. Contrary to
+/// [blacklist_address], there are no restrictions on the address to blacklist.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `address` - The address to blacklist
+/// * `reason` - The reason for blacklisting the address
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub(crate) fn force_blacklist(
+    deps: DepsMut,
+    env: Env,
+    address: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+    validate_blacklist_reason(&reason)?;
+
+    if !is_blacklisted(deps.as_ref(), address.as_str())? {
+        BLACKLIST.save(
+            deps.storage,
+            address.as_str(),
+            &BlacklistEntry {
+                blacklisted_by: "governance".to_string(),
+                blacklisted_at: env.block.time.seconds(),
+                reason,
+            },
         )?;
+        increase_count(deps.storage, &BLACKLIST_COUNT)?;
+    }
 
-    let actual_claim_amount_coin = match amount {
-        Some(requested_amount) => {
-            ensure!(
-                requested_amount > Uint128::zero(),
-                ContractError::InvalidClaimAmount {
-                    reason: "amount must be greater than zero".to_string()
-                }
+    Ok(Response::default()
+        .add_attribute("action", "blacklist_address".to_string())
+        .add_attribute("address", address)
+        .add_attribute("blacklisted", true.to_string())
+        .add_attribute("forced_by", "governance"))
+}
+
+/// Updates the ownership of the contract with the two-step transfer of cw_ownable. The ownership
+/// can't be transferred to a blacklisted or frozen address, and once the new owner accepts it, the
+/// owner protections are handed off to it, see [hand_off_ownership].
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `action` - The ownership action
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub(crate) fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: cw_ownable::Action,
+) -> Result<Response, ContractError> {
+    if let cw_ownable::Action::TransferOwnership { new_owner, .. } = &action {
+        let new_owner = deps.api.addr_validate(new_owner)?;
+        ensure!(
+            !is_blacklisted(deps.as_ref(), new_owner.as_str())?,
+            ContractError::CampaignError {
+                reason: "Cannot transfer the ownership to a blacklisted address".to_string(),
+            }
+        );
+        ensure!(
+            !is_frozen(deps.as_ref(), new_owner.as_str())?,
+            ContractError::AddressFrozen
+        );
+    }
+
+    let accepted = action == cw_ownable::Action::AcceptOwnership;
+    let previous_owner = cw_ownable::get_ownership(deps.storage)?.owner;
+    let ownership = cw_ownable::update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+
+    let mut response = Response::default();
+    if accepted {
+        response = response.add_event(hand_off_ownership(
+            deps.storage,
+            previous_owner.as_ref(),
+            &info.sender,
+        )?);
+    }
+
+    Ok(response
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
+}
+
+/// Hands off the owner protections to a new owner:
+/// - its authorized wallet entry and roles are revoked, being redundant with the ownership and
+///   outliving it otherwise
+/// - it's lifted from the blacklist and the frozen addresses, as the owner can be neither
+/// - the pending sweep requested by the previous owner is discarded, as it would pay out to the
+///   new owner
+///
+/// The previous owner loses the protections with the ownership, i.e. it can be blacklisted.
+/// Returns the `claimdrop/ownership_handoff` event recording the protections re-evaluated.
+fn hand_off_ownership(
+    storage: &mut dyn Storage,
+    previous_owner: Option<&Addr>,
+    new_owner: &Addr,
+) -> Result<Event, ContractError> {
+    AUTHORIZED_WALLETS.remove(storage, new_owner.as_str());
+    for role in Role::ALL {
+        WALLET_ROLES.remove(storage, (role.name(), new_owner.as_str()));
+    }
+
+    let unblacklisted = BLACKLIST.has(storage, new_owner.as_str());
+    if unblacklisted {
+        BLACKLIST.remove(storage, new_owner.as_str());
+        decrease_count(storage, &BLACKLIST_COUNT)?;
+    }
+
+    let unfrozen = FROZEN.has(storage, new_owner.as_str());
+    FROZEN.remove(storage, new_owner.as_str());
+
+    let pending_sweep_discarded = PENDING_SWEEP.may_load(storage)?.is_some_and(|sweep| {
+        previous_owner.is_some_and(|owner| owner.as_str() == sweep.requested_by)
+    });
+    if pending_sweep_discarded {
+        PENDING_SWEEP.remove(storage);
+    }
+
+    Ok(OwnershipHandoffEvent {
+        previous_owner: previous_owner.map(|owner| owner.as_str()),
+        new_owner: new_owner.as_str(),
+        unblacklisted,
+        unfrozen,
+        pending_sweep_discarded,
+    }
+    .into_event())
+}
+
+/// Transfers the ownership of the contract on behalf of the chain governance, via sudo.
+/// Any pending ownership transfer is discarded.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `new_owner` - The new owner of the contract
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub(crate) fn force_transfer_ownership(
+    deps: DepsMut,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let previous_owner = cw_ownable::get_ownership(deps.storage)?.owner;
+    let ownership = cw_ownable::initialize_owner(deps.storage, deps.api, Some(new_owner.as_str()))?;
+    let handoff_event = hand_off_ownership(deps.storage, previous_owner.as_ref(), &new_owner)?;
+
+    Ok(Response::default()
+        .add_event(handoff_event)
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes())
+        .add_attribute("forced_by", "governance"))
+}
+
+/// Deletes the allocations, claims and blacklist entries of a closed campaign, in that order and
+/// up to `limit` entries, to reclaim the chain storage. Emits a `claimdrop/prune_state` event with
+/// the number of entries deleted and whether the pruning is done. Only the owner can prune.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `limit` - The maximum number of entries to delete
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with the progress event
+pub(crate) fn prune_state(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    ensure!(
+        campaign.closed.is_some(),
+        ContractError::CampaignError {
+            reason: "the campaign must be closed to prune its state".to_string()
+        }
+    );
+
+    let mut remaining = limit
+        .unwrap_or(MAX_PRUNE_BATCH_SIZE)
+        .min(MAX_PRUNE_BATCH_SIZE) as usize;
+
+    let allocations = ALLOCATIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(remaining)
+        .collect::<StdResult<Vec<String>>>()?;
+    for address in &allocations {
+        ALLOCATIONS.remove(deps.storage, address.as_str())?;
+    }
+    remaining -= allocations.len();
+
+    let claims = CLAIMS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(remaining)
+        .collect::<StdResult<Vec<String>>>()?;
+    for address in &claims {
+        CLAIMS.remove(deps.storage, address.clone());
+    }
+    remaining -= claims.len();
+
+    let blacklist = BLACKLIST
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(remaining)
+        .collect::<StdResult<Vec<String>>>()?;
+    for address in &blacklist {
+        BLACKLIST.remove(deps.storage, address.as_str());
+    }
+
+    for (counter, pruned) in [
+        (&ALLOCATIONS_COUNT, allocations.len()),
+        (&CLAIMANTS_COUNT, claims.len()),
+        (&BLACKLIST_COUNT, blacklist.len()),
+    ] {
+        let count = get_count(deps.storage, counter)?.saturating_sub(pruned as u64);
+        counter.save(deps.storage, &count)?;
+    }
+
+    let done = ALLOCATIONS.is_empty(deps.storage)
+        && CLAIMS.is_empty(deps.storage)
+        && BLACKLIST.is_empty(deps.storage);
+
+    Ok(Response::default()
+        .add_event(
+            Event::new("claimdrop/prune_state")
+                .add_attribute("allocations", allocations.len().to_string())
+                .add_attribute("claims", claims.len().to_string())
+                .add_attribute("blacklist", blacklist.len().to_string())
+                .add_attribute("done", done.to_string()),
+        )
+        .add_attribute("action", "prune_state"))
+}
+
+/// Marks the distribution slots the address has fully claimed, so the following claimable amount
+/// computations skip them. The slots with no allocation, i.e. the raffle, are never marked.
+fn save_exhausted_slots(
+    storage: &mut dyn Storage,
+    campaign: &Campaign,
+    address: &str,
+    allocation: Uint128,
+    claims: &HashMap<DistributionSlot, Claim>,
+) -> Result<(), ContractError> {
+    let slot_allocations = helpers::compute_slot_allocations(campaign, allocation)?;
+    let slots = slot_allocations
+        .iter()
+        .enumerate()
+        .take(MAX_DISTRIBUTION_SLOTS as usize)
+        .filter(|(slot, (slot_allocation, _))| {
+            !slot_allocation.is_zero()
+                && claims
+                    .get(slot)
+                    .is_some_and(|(claimed, _)| claimed >= slot_allocation)
+        })
+        .fold(0u64, |mask, (slot, _)| mask | (1 << slot));
+
+    if slots != get_exhausted_slots(storage, address, allocation)? {
+        EXHAUSTED_SLOTS.save(storage, address, &ExhaustedSlots { allocation, slots })?;
+    }
+
+    Ok(())
+}
+
+/// Redistributes the unclaimed allocations of the blacklisted addresses to the other addresses,
+/// proportionally to their allocations, processing up to `limit` allocations per call. The first
+/// pass reduces the blacklisted allocations to what they already claimed, pooling the rest, and the
+/// second pass increases the other allocations by their share of the pool. The rounding dust stays
+/// in the contract. The forfeited allocations are left out, as their tokens already left the
+/// contract, and so are the frozen ones until their freeze is resolved. Only the owner can
+/// redistribute, once the campaign has started so the allocations can't be changed meanwhile.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `limit` - The maximum number of allocations to process
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - A `claimdrop/reallocation` event per allocation changed,
+///   and a `claimdrop/redistribute` event with the progress
+pub(crate) fn redistribute_forfeited(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "the campaign must be started to redistribute the allocations",
+    )?;
+
+    let limit = limit
+        .unwrap_or(MAX_PRUNE_BATCH_SIZE)
+        .min(MAX_PRUNE_BATCH_SIZE) as usize;
+    let mut redistribution = REDISTRIBUTION.may_load(deps.storage)?.unwrap_or_default();
+
+    let allocations = ALLOCATIONS
+        .range(
+            deps.storage,
+            redistribution.cursor.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<(String, Allocation)>>>()?;
+
+    let mut reallocation_events = vec![];
+    let (mut added, mut removed) = (Uint128::zero(), Uint128::zero());
+    for (address, allocation) in &allocations {
+        // the frozen allocations are left out until the freeze is resolved
+        if FORFEITED.has(deps.storage, address.as_str())
+            || FROZEN.has(deps.storage, address.as_str())
+        {
+            continue;
+        }
+
+        let old_amount = allocation.amount;
+        let new_amount = if is_blacklisted(deps.as_ref(), address)? {
+            if redistribution.distributing {
+                continue;
+            }
+            // the blacklisted allocations keep what they already claimed
+            let claimed =
+                get_total_claims_amount_for_address(deps.as_ref(), address)?.min(old_amount);
+            redistribution.pool = redistribution
+                .pool
+                .checked_add(old_amount.checked_sub(claimed)?)?;
+            claimed
+        } else if redistribution.distributing {
+            let share = redistribution
+                .pool
+                .checked_multiply_ratio(old_amount, redistribution.eligible_total)
+                .map_err(|e| StdError::generic_err(e.to_string()))?
+                // an address unblacklisted meanwhile can't push the total over the pool
+                .min(
+                    redistribution
+                        .pool
+                        .checked_sub(redistribution.distributed)?,
+                );
+            redistribution.distributed = redistribution.distributed.checked_add(share)?;
+            old_amount.checked_add(share)?
+        } else {
+            redistribution.eligible_total =
+                redistribution.eligible_total.checked_add(old_amount)?;
+            continue;
+        };
+
+        if new_amount != old_amount {
+            let new_allocation = Allocation {
+                amount: new_amount,
+                ..allocation.clone()
+            };
+            ALLOCATIONS.save(deps.storage, address.as_str(), &new_allocation)?;
+            count_in_tag_stats(deps.storage, allocation, false)?;
+            count_in_tag_stats(deps.storage, &new_allocation, true)?;
+            added = added.checked_add(new_amount)?;
+            removed = removed.checked_add(old_amount)?;
+            reallocation_events.push(
+                Event::new("claimdrop/reallocation")
+                    .add_attribute("address", address.as_str())
+                    .add_attribute("old_amount", old_amount.to_string())
+                    .add_attribute("new_amount", new_amount.to_string()),
             );
+        }
+    }
+    adjust_total_allocated(deps.storage, added, removed)?;
+
+    // a short page means the pass is over
+    let pass_done = allocations.len() < limit;
+    redistribution.cursor = allocations.last().map(|(address, _)| address.clone());
+    let done = pass_done
+        && (redistribution.distributing
+            || redistribution.pool.is_zero()
+            || redistribution.eligible_total.is_zero());
+    if pass_done && !done {
+        redistribution.distributing = true;
+        redistribution.cursor = None;
+    }
+
+    let event = Event::new("claimdrop/redistribute")
+        .add_attribute(
+            "phase",
+            if redistribution.distributing {
+                "distributing"
+            } else {
+                "reclaiming"
+            },
+        )
+        .add_attribute("processed", allocations.len().to_string())
+        .add_attribute("pool", redistribution.pool.to_string())
+        .add_attribute("distributed", redistribution.distributed.to_string())
+        .add_attribute("done", done.to_string());
+
+    if done {
+        REDISTRIBUTION.remove(deps.storage);
+    } else {
+        REDISTRIBUTION.save(deps.storage, &redistribution)?;
+    }
+
+    Ok(Response::default()
+        .add_events(reallocation_events)
+        .add_event(event)
+        .add_attribute("action", "redistribute_forfeited"))
+}
+
+/// Sweep recovers non-reward tokens accidentally sent to the contract.
+/// This prevents permanent loss of user funds while protecting campaign assets.
+///
+/// # Security
+/// - Owner-only operation via `cw_ownable::assert_owner`
+/// - Cannot sweep campaign reward tokens (must use CloseCampaign instead)
+/// - Validates amounts and balances before sweeping
+/// - Works whether campaign exists or not
+///
+/// # Arguments
+/// * `deps` - Dependencies for storage and querier access
+/// * `env` - Environment information including contract address
+/// * `info` - Message info containing sender (must be owner)
+/// * `denom` - The token denomination to sweep
+/// * `amount` - Optional amount to sweep (None = sweep entire balance)
+pub(crate) fn sweep(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // Only owner can sweep tokens
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let sweep_amount = get_sweep_amount(deps.as_ref(), &env, &denom, amount)?;
+
+    // the sweeps taking the amount swept since the last approved sweep above the threshold wait
+    // for a second approval, so a single compromised owner key can't drain the contract
+    let sweep_threshold = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .sweep_threshold;
+    let swept = SWEPT_WITHOUT_APPROVAL
+        .may_load(deps.storage, &denom)?
+        .unwrap_or_default()
+        .checked_add(sweep_amount)?;
+    if sweep_threshold.is_some_and(|threshold| swept > threshold) {
+        // a pending sweep can't be replaced before it expires, so the approvers always review
+        // the sweep they approve
+        if let Some(pending_sweep) = PENDING_SWEEP.may_load(deps.storage)? {
             ensure!(
-                requested_amount <= max_claimable_amount_coin.amount,
-                ContractError::InvalidClaimAmount {
+                env.block.time > pending_sweep.expires_at,
+                ContractError::InvalidInput {
                     reason: format!(
-                        "requested amount {} exceeds available claimable amount {}",
-                        requested_amount, max_claimable_amount_coin.amount
-                    )
+                        "a sweep of {} is already pending until {}",
+                        pending_sweep.amount, pending_sweep.expires_at
+                    ),
                 }
             );
-            Coin {
-                denom: campaign.total_reward.denom.clone(),
-                amount: requested_amount,
-            }
         }
-        None => max_claimable_amount_coin,
-    };
+
+        let pending_sweep = PendingSweep {
+            amount: coin(sweep_amount.u128(), &denom),
+            requested_by: info.sender.to_string(),
+            requested_at: env.block.time,
+            expires_at: env.block.time.plus_seconds(SWEEP_APPROVAL_WINDOW),
+        };
+        PENDING_SWEEP.save(deps.storage, &pending_sweep)?;
+
+        return Ok(Response::default().add_attributes(
+            ActionAttributes::new("request_sweep")
+                .add("amount", pending_sweep.amount)
+                .add("expires_at", pending_sweep.expires_at)
+                .build(),
+        ));
+    }
+    if sweep_threshold.is_some() {
+        SWEPT_WITHOUT_APPROVAL.save(deps.storage, &denom, &swept)?;
+    }
+
+    send_sweep(deps, env, coin(sweep_amount.u128(), &denom))
+}
+
+/// Approves the pending sweep, performing it. Any authorized wallet other than the one that
+/// requested the sweep can approve it, within the sweep approval window.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response of the sweep
+pub(crate) fn approve_sweep(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_sweep =
+        PENDING_SWEEP
+            .may_load(deps.storage)?
+            .ok_or(ContractError::InvalidInput {
+                reason: "there is no pending sweep".to_string(),
+            })?;
 
     ensure!(
-        actual_claim_amount_coin.amount > Uint128::zero(),
-        ContractError::NothingToClaim
+        is_authorized(deps.as_ref(), &info.sender)?
+            && info.sender.as_str() != pending_sweep.requested_by,
+        ContractError::Unauthorized
+    );
+    ensure!(
+        env.block.time <= pending_sweep.expires_at,
+        ContractError::InvalidInput {
+            reason: "the pending sweep has expired".to_string(),
+        }
     );
+    PENDING_SWEEP.remove(deps.storage);
+    SWEPT_WITHOUT_APPROVAL.remove(deps.storage, &pending_sweep.amount.denom);
+
+    // the balance or the campaign may have changed since the sweep was requested
+    let sweep_amount = get_sweep_amount(
+        deps.as_ref(),
+        &env,
+        &pending_sweep.amount.denom,
+        Some(pending_sweep.amount.amount),
+    )?;
+
+    Ok(send_sweep(
+        deps,
+        env,
+        coin(sweep_amount.u128(), &pending_sweep.amount.denom),
+    )?
+    .add_attribute("approved_by", info.sender))
+}
+
+/// Validates a sweep, returning the amount to sweep.
+fn get_sweep_amount(
+    deps: Deps,
+    env: &Env,
+    denom: &str,
+    amount: Option<Uint128>,
+) -> Result<Uint128, ContractError> {
+    // Get the campaign if it exists to check reward denom
+    let campaign = CAMPAIGN.may_load(deps.storage)?;
+
+    // Prevent sweeping the reward denom if a campaign exists
+    if let Some(campaign) = campaign {
+        ensure!(
+            denom != campaign.total_reward.denom,
+            ContractError::CampaignError {
+                reason: format!(
+                    "Cannot sweep reward denom '{}'. Use CloseCampaign instead",
+                    campaign.total_reward.denom
+                )
+            }
+        );
+    }
+
+    // Query the balance of the specified denom
+    let balance = deps.querier.query_balance(&env.contract.address, denom)?;
+
+    // Determine the amount to sweep
+    let sweep_amount = match amount {
+        Some(amt) => {
+            ensure!(
+                amt <= balance.amount,
+                ContractError::InvalidCampaignParam {
+                    param: "amount".to_string(),
+                    reason: format!(
+                        "Requested amount {} exceeds available balance {}",
+                        amt, balance.amount
+                    )
+                }
+            );
+            amt
+        }
+        None => balance.amount,
+    };
+
+    // Check if there's anything to sweep
+    ensure!(
+        !sweep_amount.is_zero(),
+        ContractError::CampaignError {
+            reason: format!("No {denom} tokens to sweep")
+        }
+    );
+
+    Ok(sweep_amount)
+}
+
+/// Sends the swept tokens to the owner.
+fn send_sweep(deps: DepsMut, env: Env, swept: Coin) -> Result<Response, ContractError> {
+    // Get the owner address, which is gone once the ownership is renounced
+    let owner = cw_ownable::get_ownership(deps.storage)?
+        .owner
+        .ok_or(ContractError::Unauthorized)?;
+
+    // Create the bank send message
+    let send_msg = BankMsg::Send {
+        to_address: owner.to_string(),
+        amount: vec![swept.clone()],
+    };
+
+    // Create a custom event for better indexing
+    let sweep_event = SweepEvent {
+        amount: &swept,
+        recipient: owner.as_str(),
+    };
+
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_event(sweep_event.to_event())
+        .add_event(
+            TransferIntent {
+                kind: TransferKind::Sweep,
+                from: env.contract.address.as_str(),
+                to: owner.as_str(),
+                amount: &swept,
+            }
+            .into_event(),
+        )
+        .add_attributes(sweep_event.to_attributes()))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receiver: Option<String>,
+    amount: Option<Uint128>,
+    percentage: Option<Decimal>,
+    slots: Option<Vec<DistributionSlot>>,
+    into_vesting_account: bool,
+    ibc_receiver: Option<IbcReceiver>,
+    lock_option: Option<LockTier>,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::Claims)?;
+
+    let mut campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "not started",
+    )?;
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    ensure!(!config.paused, ContractError::ClaimsPaused);
+
+    helpers::ensure_funded(deps.as_ref(), &env.contract.address, &campaign)?;
+
+    // Note: Campaign end_time is intentionally not checked here.
+    // Users should be able to claim their allocated tokens even after the campaign end_time has passed,
+    // as long as the campaign has not been manually closed by the owner.
+
+    let receiver = receiver
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .unwrap_or_else(|| info.sender.clone());
+
+    // Check if the caller is authorized to claim:
+    // Owner, authorized wallet, OR the wallet with the allocation can claim
+    let is_authorized_user = is_authorized(deps.as_ref(), &info.sender)?;
+
+    ensure!(
+        is_authorized_user || info.sender == receiver,
+        ContractError::Unauthorized
+    );
+
+    ensure!(
+        !is_blacklisted(deps.as_ref(), receiver.as_ref())?,
+        ContractError::AddressBlacklisted
+    );
+    ensure!(
+        !is_frozen(deps.as_ref(), receiver.as_ref())?,
+        ContractError::AddressFrozen
+    );
+    ensure_not_on_hold(deps.as_ref(), receiver.as_ref(), &env.block.time)?;
+
+    // only the receiver can redirect its rewards to a remote chain
+    if let Some(ibc_receiver) = &ibc_receiver {
+        ensure!(info.sender == receiver, ContractError::Unauthorized);
+        ensure!(
+            !into_vesting_account,
+            ContractError::InvalidInput {
+                reason: "cannot claim into a vesting account over IBC".to_string()
+            }
+        );
+        ensure!(
+            campaign.payout_target == PayoutTarget::Wallet,
+            ContractError::InvalidInput {
+                reason: "cannot claim over IBC when the payout target is a contract".to_string()
+            }
+        );
+        ensure!(
+            config
+                .allowed_ibc_channels
+                .contains(&ibc_receiver.channel_id),
+            ContractError::IbcChannelNotAllowed {
+                channel_id: ibc_receiver.channel_id.clone()
+            }
+        );
+        ensure!(
+            !ibc_receiver.to_address.is_empty() && ibc_receiver.timeout_seconds > 0,
+            ContractError::InvalidInput {
+                reason: "invalid IBC receiver".to_string()
+            }
+        );
+    }
+
+    // only the receiver can lock its rewards, which stay in the contract until the lock expires
+    if lock_option.is_some() {
+        ensure!(info.sender == receiver, ContractError::Unauthorized);
+        ensure!(
+            ibc_receiver.is_none()
+                && !into_vesting_account
+                && campaign.payout_target == PayoutTarget::Wallet,
+            ContractError::InvalidInput {
+                reason:
+                    "cannot lock a claim sent over IBC, into a vesting account or to a contract"
+                        .to_string()
+            }
+        );
+    }
+
+    if campaign.claim_mode == ClaimMode::AllowlistOnly {
+        ensure!(
+            is_allowlisted(deps.as_ref(), receiver.as_ref())?,
+            ContractError::AddressNotAllowlisted
+        );
+    }
+
+    if let Some(Eligibility::OracleCheck {
+        contract,
+        query_template,
+    }) = &campaign.eligibility
+    {
+        let eligible: bool = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: contract.clone(),
+            msg: Eligibility::query_msg(query_template, receiver.as_str()),
+        }))?;
+        ensure!(
+            eligible,
+            ContractError::AddressNotEligible {
+                address: receiver.to_string(),
+            }
+        );
+    }
+
+    // Get allocation for the address
+    let allocation = ALLOCATIONS
+        .may_load(deps.storage, receiver.as_str())?
+        .ok_or(ContractError::NoAllocationFound {
+            address: receiver.to_string(),
+        })?;
+    let total_user_allocation = allocation.amount;
+
+    if let Some(expires_at) =
+        helpers::claim_window_expiry(deps.as_ref(), &campaign, receiver.as_str())?
+    {
+        ensure!(
+            env.block.time < expires_at,
+            ContractError::ClaimWindowExpired {
+                expired_at: expires_at
+            }
+        );
+    }
+
+    helpers::ensure_terms_accepted(deps.storage, &campaign, receiver.as_str())?;
+
+    // locked allocations can only pay out to their locked receiver, whoever claims them
+    if allocation.locked_receiver.is_some() {
+        ensure!(
+            ibc_receiver.is_none() && !into_vesting_account && lock_option.is_none(),
+            ContractError::InvalidInput {
+                reason: "the payouts of the allocation are locked to a receiver".to_string()
+            }
+        );
+    }
+    let payout_address = allocation.locked_receiver.unwrap_or(receiver.clone());
+
+    // the schedules of the receiver are offset by its activation, if the campaign vests from it
+    let receiver_campaign =
+        helpers::campaign_for_address(deps.as_ref(), &campaign, receiver.as_ref())?.ok_or(
+            ContractError::CampaignError {
+                reason: "the vesting has not been activated".to_string(),
+            },
+        )?;
+
+    // new_claims is HashMap<DistributionSlot, Claim=(amount, timestamp)> representing newly available amounts per slot
+    let (max_claimable_amount_coin, new_claims, previous_claims) =
+        helpers::compute_claimable_amount(
+            deps.as_ref(),
+            &campaign,
+            &env.block.time,
+            receiver.as_ref(),
+            total_user_allocation,
+        )?;
+
+    // Rate-limit the claims of the receiver if the campaign has a cooldown
+    helpers::ensure_claim_cooldown_elapsed(&campaign, &previous_claims, &env.block.time)?;
+
+    // only claim from the requested slots, if any
+    let (max_claimable_amount_coin, new_claims) = match &slots {
+        Some(slots) => helpers::select_claim_slots(&campaign, new_claims, slots)?,
+        None => (max_claimable_amount_coin, new_claims),
+    };
+
+    // when exiting into a vesting account, the unvested tokens are claimed along with the vested
+    // ones, and locked in the vesting account
+    let (max_claimable_amount_coin, new_claims, vesting) = if into_vesting_account {
+        ensure!(
+            amount.is_none() && percentage.is_none() && slots.is_none(),
+            ContractError::InvalidInput {
+                reason: "amount and slots cannot be set when claiming into a vesting account"
+                    .to_string()
+            }
+        );
+        // the vesting module refuses to create a vesting account over an existing account, so
+        // only the fresh receivers, claimed for by an authorized wallet, can exit this way
+        ensure!(
+            !stargate::account_exists(&deps.querier, &receiver),
+            ContractError::ReceiverAccountExists {
+                address: receiver.to_string()
+            }
+        );
+
+        let (unvested_claims, vesting_end_time) = helpers::compute_unvested_claims(
+            &receiver_campaign,
+            &env.block.time,
+            total_user_allocation,
+            &helpers::aggregate_claims(&previous_claims, &new_claims)?,
+        )?;
+        let unvested_amount = unvested_claims
+            .values()
+            .try_fold(Uint128::zero(), |acc, (amount, _)| acc.checked_add(*amount))?;
+
+        (
+            Coin {
+                denom: max_claimable_amount_coin.denom,
+                amount: max_claimable_amount_coin
+                    .amount
+                    .checked_add(unvested_amount)?,
+            },
+            helpers::aggregate_claims(&new_claims, &unvested_claims)?,
+            Some((unvested_amount, vesting_end_time)),
+        )
+    } else {
+        (max_claimable_amount_coin, new_claims, None)
+    };
+
+    // a percentage is converted to an amount against what's claimable right now
+    let amount = match percentage {
+        Some(percentage) => {
+            ensure!(
+                amount.is_none(),
+                ContractError::InvalidInput {
+                    reason: "amount and percentage cannot be set together".to_string()
+                }
+            );
+            ensure!(
+                percentage > Decimal::zero() && percentage <= Decimal::one(),
+                ContractError::InvalidClaimAmount {
+                    reason: "percentage must be within (0, 1]".to_string()
+                }
+            );
+            let amount = max_claimable_amount_coin.amount.mul_floor(percentage);
+            ensure!(!amount.is_zero(), ContractError::NothingToClaim);
+            Some(amount)
+        }
+        None => amount,
+    };
+
+    let actual_claim_amount_coin = match amount {
+        Some(requested_amount) => {
+            ensure!(
+                requested_amount > Uint128::zero(),
+                ContractError::InvalidClaimAmount {
+                    reason: "amount must be greater than zero".to_string()
+                }
+            );
+            ensure!(
+                requested_amount <= max_claimable_amount_coin.amount,
+                ContractError::InvalidClaimAmount {
+                    reason: format!(
+                        "requested amount {} exceeds available claimable amount {}",
+                        requested_amount, max_claimable_amount_coin.amount
+                    )
+                }
+            );
+            Coin {
+                denom: campaign.total_reward.denom.clone(),
+                amount: requested_amount,
+            }
+        }
+        None => max_claimable_amount_coin,
+    };
+
+    ensure!(
+        actual_claim_amount_coin.amount > Uint128::zero(),
+        ContractError::NothingToClaim
+    );
+
+    if let Some(max_single_claim) = campaign.max_single_claim {
+        ensure!(
+            actual_claim_amount_coin.amount <= max_single_claim,
+            ContractError::InvalidClaimAmount {
+                reason: format!(
+                    "claim amount {} exceeds the maximum single claim {}",
+                    actual_claim_amount_coin.amount, max_single_claim
+                )
+            }
+        );
+    }
+
+    // small claims are rejected, unless they exhaust the allocation
+    if let Some(min_claim_amount) = campaign.min_claim_amount {
+        let previously_claimed = previous_claims
+            .values()
+            .try_fold(Uint128::zero(), |acc, (amount, _)| acc.checked_add(*amount))?;
+        let is_final_claim = previously_claimed.checked_add(actual_claim_amount_coin.amount)?
+            >= total_user_allocation;
+
+        ensure!(
+            actual_claim_amount_coin.amount >= min_claim_amount || is_final_claim,
+            ContractError::InvalidClaimAmount {
+                reason: format!(
+                    "claim amount {} is below the minimum claim amount {}",
+                    actual_claim_amount_coin.amount, min_claim_amount
+                )
+            }
+        );
+    }
+
+    let claims_to_record = helpers::split_claim_across_slots(
+        &campaign,
+        &new_claims,
+        actual_claim_amount_coin.amount,
+        &env.block.time,
+    )?;
+
+    // early claimers get a bonus on top of their allocation, paid from the bonus pool
+    let bonus_claimed = EARLY_BIRD_BONUS_CLAIMED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let early_bird_bonus = helpers::compute_early_bird_bonus(
+        &campaign,
+        &env.block.time,
+        total_user_allocation,
+        &previous_claims,
+        &claims_to_record,
+        bonus_claimed,
+    )?;
+
+    // locked claims get a boost on top of their allocation, paid from the lock boost pool
+    let lock_boost_claimed = LOCK_BOOST_CLAIMED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let lock_boost = match &lock_option {
+        Some(tier) => helpers::compute_lock_boost(
+            &campaign,
+            tier,
+            actual_claim_amount_coin.amount,
+            lock_boost_claimed,
+        )?,
+        None => Uint128::zero(),
+    };
+
+    let payout_coin = coin(
+        actual_claim_amount_coin
+            .amount
+            .checked_add(early_bird_bonus)?
+            .checked_add(lock_boost)?
+            .u128(),
+        &campaign.total_reward.denom,
+    );
+
+    // Pause the claims if the amount claimed within the circuit breaker window exceeds the
+    // threshold. The claim is not performed, but the response succeeds so the pause is persisted.
+    if let Some(circuit_breaker) = &config.circuit_breaker {
+        let current_window = circuit_breaker.window.current(&env.block);
+        let claimed_in_window = match CIRCUIT_BREAKER_WINDOW.may_load(deps.storage)? {
+            Some((window, claimed)) if window == current_window => claimed,
+            _ => Uint128::zero(),
+        }
+        .checked_add(payout_coin.amount)?;
+
+        if claimed_in_window > circuit_breaker.max_claim_amount {
+            config.paused = true;
+            CONFIG.save(deps.storage, &config)?;
+
+            return Ok(Response::default()
+                .add_event(
+                    Event::new("circuit_breaker_tripped")
+                        .add_attribute("receiver", receiver.to_string())
+                        .add_attribute("claimed_in_window", claimed_in_window.to_string())
+                        .add_attribute(
+                            "max_claim_amount",
+                            circuit_breaker.max_claim_amount.to_string(),
+                        ),
+                )
+                .add_attributes(
+                    ClaimAttributes {
+                        receiver: receiver.as_str(),
+                        claimed_amount: Uint128::zero().to_string(),
+                        claim_fee: None,
+                        early_bird_bonus: None,
+                        ibc_channel: None,
+                        ibc_receiver: None,
+                    }
+                    .into_attributes(),
+                ));
+        }
+
+        CIRCUIT_BREAKER_WINDOW.save(deps.storage, &(current_window, claimed_in_window))?;
+    }
+
+    // the amount paid out per slot, the early bird bonus being paid from the early bird bonus slot
+    let mut slot_payouts: BTreeMap<DistributionSlot, Uint128> = claims_to_record
+        .iter()
+        .map(|(slot, (amount, _))| (*slot, *amount))
+        .collect();
+    if let Some(early_bird_slot) = campaign
+        .distribution_type
+        .iter()
+        .position(|dist| matches!(dist, DistributionType::EarlyBirdBonus { .. }))
+        .filter(|_| !early_bird_bonus.is_zero())
+    {
+        let payout = slot_payouts.entry(early_bird_slot).or_default();
+        *payout = payout.checked_add(early_bird_bonus)?;
+    }
+
+    // campaigns minting on claim don't hold the rewards, so there are no funds to check
+    let mint_message = match campaign.reward_source {
+        RewardSource::PreFunded => {
+            let available_funds =
+                helpers::query_reward_balance(deps.as_ref(), &env.contract.address, &campaign)?;
+
+            ensure!(
+                payout_coin.amount <= available_funds.amount,
+                ContractError::InsufficientFunds {
+                    required: payout_coin.amount,
+                    available: available_funds.amount,
+                }
+            );
+
+            // partially funded campaigns only pay out the pro-rata funding of each slot
+            let slot_solvency = helpers::compute_slot_solvency(
+                &campaign,
+                available_funds.amount,
+                &get_slots_claimed(deps.storage)?,
+            )?;
+            for solvency in slot_solvency
+                .iter()
+                .filter(|solvency| solvency.funded < solvency.obligation)
+            {
+                let Some(payout) = slot_payouts.get(&solvency.slot) else {
+                    continue;
+                };
+
+                ensure!(
+                    solvency.claimed.checked_add(*payout)? <= solvency.funded,
+                    ContractError::SlotNotFunded {
+                        slot: solvency.slot,
+                        requested: *payout,
+                        available: solvency.funded.saturating_sub(solvency.claimed),
+                    }
+                );
+            }
+
+            helpers::spend_budget(deps.storage, payout_coin.amount)?;
+
+            None
+        }
+        RewardSource::MintOnClaim => Some(stargate::mint_msg(&env.contract.address, &payout_coin)),
+    };
+
+    let updated_claims = helpers::aggregate_claims(&previous_claims, &claims_to_record)?;
+
+    // the claim fee is charged on the liquid tokens, the ones locked in a vesting account are exempt
+    let fee_amount = match &campaign.claim_fee {
+        Some(claim_fee) => {
+            let unvested_amount = vesting.map(|(amount, _)| amount).unwrap_or_default();
+            payout_coin
+                .amount
+                .checked_sub(unvested_amount)?
+                .mul_floor(claim_fee.percentage)
+        }
+        None => Uint128::zero(),
+    };
+
+    campaign.claimed.amount = campaign.claimed.amount.checked_add(payout_coin.amount)?;
+    DAILY_CLAIM_VOLUME.update(
+        deps.storage,
+        env.block.time.seconds() / SECONDS_PER_DAY,
+        |volume| -> StdResult<_> {
+            Ok(volume.unwrap_or_default().checked_add(payout_coin.amount)?)
+        },
+    )?;
+    campaign.fees_collected = campaign.fees_collected.checked_add(fee_amount)?;
+    if !early_bird_bonus.is_zero() {
+        EARLY_BIRD_BONUS_CLAIMED
+            .save(deps.storage, &bonus_claimed.checked_add(early_bird_bonus)?)?;
+    }
+    if !lock_boost.is_zero() {
+        LOCK_BOOST_CLAIMED.save(deps.storage, &lock_boost_claimed.checked_add(lock_boost)?)?;
+    }
+
+    CAMPAIGN.save(deps.storage, &campaign)?;
+    CLAIMS.save(deps.storage, receiver.to_string(), &updated_claims)?;
+    save_exhausted_slots(
+        deps.storage,
+        &campaign,
+        receiver.as_str(),
+        total_user_allocation,
+        &updated_claims,
+    )?;
+    for (slot, payout) in &slot_payouts {
+        SLOT_CLAIMED.update(deps.storage, *slot as u64, |claimed| -> StdResult<_> {
+            Ok(claimed.unwrap_or_default().checked_add(*payout)?)
+        })?;
+    }
+    update_stats(
+        deps.storage,
+        payout_coin.amount,
+        previous_claims.is_empty(),
+        env.block.time,
+    )?;
+    update_tag_stats(deps.storage, allocation.tag.as_deref(), |stats| {
+        stats.total_claimed = stats.total_claimed.checked_add(payout_coin.amount)?;
+        if previous_claims.is_empty() {
+            stats.claimants = stats.claimants.saturating_add(1);
+        }
+        Ok(())
+    })?;
+    if previous_claims.is_empty() {
+        increase_count(deps.storage, &CLAIMANTS_COUNT)?;
+        ALLOCATIONS.update(deps.storage, receiver.as_str(), |allocation| {
+            let mut allocation = allocation.ok_or(ContractError::NoAllocationFound {
+                address: receiver.to_string(),
+            })?;
+            allocation.claimed = true;
+            Ok::<_, ContractError>(allocation)
+        })?;
+    }
+
+    // Calculate total claims from updated_claims instead of making another storage call
+    let total_claimed = updated_claims
+        .iter()
+        .fold(Uint128::zero(), |acc, (_, (amount, _))| {
+            acc.checked_add(*amount).unwrap()
+        });
+
+    ensure!(
+        total_user_allocation >= total_claimed,
+        ContractError::ExceededMaxClaimAmount
+    );
+
+    // record the claim receipts, sorted by slot so the history and the events are deterministic
+    let mut receipts: Vec<_> = claims_to_record.iter().collect();
+    receipts.sort_by_key(|(slot, _)| **slot);
+    let mut slot_events = vec![];
+    for (slot, (amount, timestamp)) in receipts {
+        record_claim_history(
+            deps.storage,
+            receiver.as_str(),
+            &(*amount, *timestamp, *slot),
+        )?;
+
+        slot_events.push(
+            SlotClaimEvent {
+                receiver: receiver.as_str(),
+                slot: *slot,
+                distribution_type: campaign.distribution_type[*slot].name(),
+                amount: *amount,
+                slot_claimed: updated_claims
+                    .get(slot)
+                    .map(|(claimed, _)| *claimed)
+                    .unwrap_or_default(),
+                remaining_allocation: total_user_allocation.checked_sub(total_claimed)?,
+            }
+            .into_event(),
+        );
+    }
+
+    // notify the registered hooks about the claim
+    let hook_messages = CLAIM_HOOKS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|hook| {
+            ClaimHookMsg {
+                receiver: receiver.to_string(),
+                amount: actual_claim_amount_coin.clone(),
+                campaign: campaign.name.clone(),
+            }
+            .into_cosmos_msg(hook?)
+        })
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
+    // lock the unvested tokens in a vesting account, and send the vested ones
+    let mut payout_messages = vec![];
+    let mut transfer_events = vec![];
+    let mut liquid_amount = payout_coin.amount;
+    if let Some((unvested_amount, vesting_end_time)) = vesting {
+        let unvested = coin(unvested_amount.u128(), &campaign.total_reward.denom);
+        payout_messages.push(stargate::create_vesting_account_msg(
+            &env.contract.address,
+            &receiver,
+            &unvested,
+            &vesting_end_time,
+        ));
+        transfer_events.push(
+            TransferIntent {
+                kind: TransferKind::VestingAccount,
+                from: env.contract.address.as_str(),
+                to: receiver.as_str(),
+                amount: &unvested,
+            }
+            .into_event(),
+        );
+        liquid_amount = liquid_amount.checked_sub(unvested_amount)?;
+    }
+    if let Some(claim_fee) = campaign
+        .claim_fee
+        .as_ref()
+        .filter(|_| !fee_amount.is_zero())
+    {
+        let fee = coin(fee_amount.u128(), &campaign.total_reward.denom);
+        payout_messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: claim_fee.recipient.clone(),
+            amount: vec![fee.clone()],
+        }));
+        transfer_events.push(
+            TransferIntent {
+                kind: TransferKind::ClaimFee,
+                from: env.contract.address.as_str(),
+                to: claim_fee.recipient.as_str(),
+                amount: &fee,
+            }
+            .into_event(),
+        );
+        liquid_amount = liquid_amount.checked_sub(fee_amount)?;
+    }
+    let mut lock_events = vec![];
+    if let Some(tier) = lock_option.filter(|_| !liquid_amount.is_zero()) {
+        // the liquid tokens stay in the contract until the lock expires
+        let unlocks_at = env.block.time.plus_seconds(tier.duration());
+        LOCKS.update(
+            deps.storage,
+            (receiver.as_str(), unlocks_at.seconds()),
+            |locked| -> StdResult<_> { Ok(locked.unwrap_or_default().checked_add(liquid_amount)?) },
+        )?;
+        let locked_total = LOCKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+        LOCKED_TOTAL.save(deps.storage, &locked_total.checked_add(liquid_amount)?)?;
+        lock_events.push(
+            LockEvent {
+                receiver: receiver.as_str(),
+                amount: liquid_amount,
+                boost: lock_boost,
+                unlocks_at,
+            }
+            .into_event(),
+        );
+    } else if !liquid_amount.is_zero() {
+        let amount = coin(liquid_amount.u128(), &campaign.total_reward.denom);
+        transfer_events.push(
+            TransferIntent {
+                kind: TransferKind::Claim,
+                from: env.contract.address.as_str(),
+                to: match (&ibc_receiver, &campaign.payout_target) {
+                    (Some(ibc_receiver), _) => ibc_receiver.to_address.as_str(),
+                    (None, PayoutTarget::Wallet) => payout_address.as_str(),
+                    (None, PayoutTarget::Contract { addr, .. }) => addr.as_str(),
+                },
+                amount: &amount,
+            }
+            .into_event(),
+        );
+        payout_messages.push(match &ibc_receiver {
+            Some(ibc_receiver) => {
+                // the claim is already recorded, so the payout is tracked until the transfer is
+                // acknowledged and credited back to the receiver if it fails
+                let payout_id = increase_count(deps.storage, &IBC_PAYOUT_COUNT)?;
+                IBC_PAYOUTS.save(deps.storage, payout_id, &(receiver.clone(), liquid_amount))?;
+
+                CosmosMsg::Ibc(IbcMsg::Transfer {
+                    channel_id: ibc_receiver.channel_id.clone(),
+                    to_address: ibc_receiver.to_address.clone(),
+                    amount,
+                    timeout: IbcTimeout::with_timestamp(
+                        env.block.time.plus_seconds(ibc_receiver.timeout_seconds),
+                    ),
+                    memo: Some(to_json_string(&IbcPayoutMemo::new(
+                        &env.contract.address,
+                        payout_id,
+                    ))?),
+                })
+            }
+            None => match &campaign.payout_target {
+                PayoutTarget::Wallet => CosmosMsg::Bank(BankMsg::Send {
+                    to_address: payout_address.to_string(),
+                    amount: vec![amount],
+                }),
+                PayoutTarget::Contract { addr, msg_template } => {
+                    CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: addr.clone(),
+                        msg: PayoutTarget::payout_msg(msg_template, payout_address.as_str()),
+                        funds: vec![amount],
+                    })
+                }
+            },
+        });
+    }
+
+    Ok(Response::default()
+        .add_messages(mint_message)
+        .add_messages(payout_messages)
+        .add_messages(hook_messages)
+        .add_events(slot_events)
+        .add_events(transfer_events)
+        .add_events(lock_events)
+        .add_attributes(
+            ClaimAttributes {
+                receiver: receiver.as_str(),
+                claimed_amount: actual_claim_amount_coin.to_string(),
+                claim_fee: (!fee_amount.is_zero()).then_some(fee_amount),
+                early_bird_bonus: (!early_bird_bonus.is_zero()).then_some(early_bird_bonus),
+                ibc_channel: ibc_receiver
+                    .as_ref()
+                    .map(|ibc_receiver| ibc_receiver.channel_id.as_str()),
+                ibc_receiver: ibc_receiver
+                    .as_ref()
+                    .map(|ibc_receiver| ibc_receiver.to_address.as_str()),
+            }
+            .into_attributes(),
+        ))
+}
+
+/// Claims the rewards of the sender vested up to the given time, which can't be in the future. The
+/// amount claimable at that time is claimed, so the integrations settling on fixed intervals get
+/// deterministic amounts regardless of when their claim is executed.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `timestamp` - The time up to which the vested rewards are claimed
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response of the claim
+pub(crate) fn claim_up_to(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    timestamp: Timestamp,
+) -> Result<Response, ContractError> {
+    ensure!(
+        timestamp <= env.block.time,
+        ContractError::InvalidInput {
+            reason: format!("cannot claim up to {timestamp}, which is in the future"),
+        }
+    );
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "not started",
+    )?;
+
+    let receiver = info.sender.to_string();
+    let total_allocation =
+        get_allocation(deps.as_ref(), &receiver)?.ok_or(ContractError::NoAllocationFound {
+            address: receiver.clone(),
+        })?;
+
+    // before the campaign start nothing has vested
+    let claimable = if campaign.has_started(&timestamp) {
+        helpers::compute_claimable_amount(
+            deps.as_ref(),
+            &campaign,
+            &timestamp,
+            &receiver,
+            total_allocation,
+        )?
+        .0
+        .amount
+    } else {
+        Uint128::zero()
+    };
+    ensure!(!claimable.is_zero(), ContractError::NothingToClaim);
+
+    claim(
+        deps,
+        env,
+        info,
+        None,
+        Some(claimable),
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+}
+
+/// Claims the rewards on behalf of the receiver, who signed a [ClaimSignaturePayload] off-chain
+/// wrapped in an ADR-36 sign doc. Anyone can relay the claim, the rewards are sent to the receiver.
+/// The nonce isn't consumed if the claim trips the circuit breaker, as nothing is paid out.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `receiver` - The address claiming the rewards, which signed the payload
+/// * `amount` - The amount to claim, or all the available tokens if not set
+/// * `nonce` - The nonce of the receiver
+/// * `pubkey` - The compressed secp256k1 public key of the receiver
+/// * `signature` - The signature of the ADR-36 sign doc
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response of the claim
+pub(crate) fn claim_with_signature(
+    mut deps: DepsMut,
+    env: Env,
+    receiver: String,
+    amount: Option<Uint128>,
+    nonce: u64,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let receiver = deps.api.addr_validate(&receiver)?;
+
+    // the public key must belong to the receiver
+    ensure!(
+        deps.api.addr_canonicalize(receiver.as_str())?.as_slice()
+            == helpers::pubkey_to_canonical_address(&pubkey).as_slice(),
+        ContractError::InvalidSignature
+    );
+
+    let expected_nonce = CLAIM_NONCES
+        .may_load(deps.storage, receiver.as_str())?
+        .unwrap_or_default();
+    ensure!(
+        nonce == expected_nonce,
+        ContractError::InvalidNonce {
+            expected: expected_nonce,
+            actual: nonce
+        }
+    );
+
+    let payload = to_json_vec(&ClaimSignaturePayload {
+        chain_id: env.block.chain_id.clone(),
+        contract: env.contract.address.to_string(),
+        amount,
+        nonce,
+    })?;
+    let sign_bytes = helpers::adr36_sign_bytes(receiver.as_str(), &payload);
+    ensure!(
+        deps.api
+            .secp256k1_verify(&Sha256::digest(sign_bytes), &signature, &pubkey)
+            .unwrap_or(false),
+        ContractError::InvalidSignature
+    );
+
+    let response = claim(
+        deps.branch(),
+        env,
+        MessageInfo {
+            sender: receiver.clone(),
+            funds: vec![],
+        },
+        None,
+        amount,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )?;
+
+    // the nonce is only consumed once the claim is paid out. If the circuit breaker was tripped
+    // instead, the signed claim can be relayed again once the claims are resumed.
+    if !CONFIG.may_load(deps.storage)?.unwrap_or_default().paused {
+        CLAIM_NONCES.save(deps.storage, receiver.as_str(), &nonce.saturating_add(1))?;
+    }
+
+    Ok(response.add_attribute("nonce", nonce.to_string()))
+}
+
+/// Claims rewards on behalf of the receiver, which granted the sender an authz authorization to
+/// claim from this contract on its behalf, see [stargate::has_authz_grant]. The rewards are sent
+/// to the receiver.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `receiver` - The address claiming the rewards, which granted the authorization
+/// * `amount` - The amount to claim, or all the available tokens if not set
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub(crate) fn claim_authz(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receiver: String,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let receiver = deps.api.addr_validate(&receiver)?;
+
+    ensure!(
+        stargate::has_authz_grant(
+            &deps.querier,
+            &env.contract.address,
+            &receiver,
+            &info.sender,
+            &env.block.time
+        )?,
+        ContractError::Unauthorized
+    );
+
+    let response = claim(
+        deps,
+        env,
+        MessageInfo {
+            sender: receiver,
+            funds: vec![],
+        },
+        None,
+        amount,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )?;
+
+    Ok(response.add_attribute("authz_grantee", info.sender))
+}
+
+/// Claims all the available rewards of a batch of receivers, on behalf of an authorized wallet.
+/// Each receiver gets its payout in a single message, and a `claimdrop/claim_batch` event details
+/// the amount claimed for each of them. The receivers with nothing to claim are skipped and
+/// reported in the event, while if any other claim fails, the whole batch fails. If the circuit
+/// breaker pauses the claims, the remaining receivers are skipped.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `receivers` - The addresses to claim the rewards of
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with the payouts of the receivers
+pub(crate) fn claim_batch(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receivers: Vec<String>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        is_authorized(deps.as_ref(), &info.sender)?,
+        ContractError::Unauthorized
+    );
+
+    ensure!(
+        !receivers.is_empty(),
+        ContractError::InvalidInput {
+            reason: "receivers cannot be empty".to_string(),
+        }
+    );
+    ensure!(
+        receivers.len() <= MAX_CLAIM_BATCH_SIZE,
+        ContractError::BatchSizeLimitExceeded {
+            actual: receivers.len(),
+            max: MAX_CLAIM_BATCH_SIZE,
+        }
+    );
+    ensure!(
+        receivers.iter().collect::<BTreeSet<_>>().len() == receivers.len(),
+        ContractError::InvalidInput {
+            reason: "receivers cannot contain duplicates".to_string(),
+        }
+    );
+
+    let mut response = Response::default();
+    let mut batch_event = Event::new("claimdrop/claim_batch");
+    let mut total_claimed = Uint128::zero();
+    let mut count = 0usize;
+    let mut skipped = 0usize;
+
+    for receiver in receivers {
+        let claimed_before = get_campaign_claimed(deps.as_ref())?;
+        let claim_response = match claim(
+            deps.branch(),
+            env.clone(),
+            info.clone(),
+            Some(receiver.clone()),
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+        ) {
+            Ok(claim_response) => claim_response,
+            // the claim fails before writing anything, so the batch goes on without the receiver
+            Err(ContractError::NothingToClaim) => {
+                batch_event = batch_event.add_attribute("skipped", receiver);
+                skipped += 1;
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let claimed = get_campaign_claimed(deps.as_ref())?.checked_sub(claimed_before)?;
+
+        response = response
+            .add_submessages(claim_response.messages)
+            .add_events(claim_response.events);
+        batch_event = batch_event
+            .add_attribute("receiver", receiver)
+            .add_attribute("amount", claimed.to_string());
+        total_claimed = total_claimed.checked_add(claimed)?;
+        count += 1;
+
+        // the circuit breaker paused the claims, the rest of the batch can't be claimed
+        if CONFIG.may_load(deps.storage)?.unwrap_or_default().paused {
+            break;
+        }
+    }
+
+    Ok(response.add_event(batch_event).add_attributes(
+        ActionAttributes::new("claim_batch")
+            .add("count", count)
+            .add("skipped", skipped)
+            .add("total_claimed", total_claimed)
+            .build(),
+    ))
+}
+
+/// Returns the amount claimed from the campaign so far.
+fn get_campaign_claimed(deps: Deps) -> Result<Uint128, ContractError> {
+    Ok(CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|campaign| campaign.claimed.amount)
+        .unwrap_or_default())
+}
+
+/// Updates the contract configuration. Only the owner can update the configuration.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `circuit_breaker` - The circuit breaker to set, if any
+/// * `limits` - The limits to set, if any
+/// * `replacements_require_request` - Whether the address replacements require a request, if set
+/// * `voucher_pubkey` - The public key verifying the allocation vouchers, if set
+/// * `allowed_prefixes` - The foreign bech32 prefixes accepted in the addresses, if set
+/// * `authorized_wallet_delay` - The delay before the authorized wallets added take effect, if set
+/// * `sweep_threshold` - The amount above which the sweeps require a second approval, if set
+/// * `registry` - The campaign registry notified on the lifecycle events of the campaign, if set
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    circuit_breaker: Option<CircuitBreaker>,
+    limits: Option<Limits>,
+    replacements_require_request: Option<bool>,
+    voucher_pubkey: Option<Binary>,
+    allowed_prefixes: Option<Vec<String>>,
+    authorized_wallet_delay: Option<u64>,
+    sweep_threshold: Option<Uint128>,
+    registry: Option<String>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if let Some(circuit_breaker) = &circuit_breaker {
+        circuit_breaker.validate()?;
+    }
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    config.circuit_breaker = circuit_breaker;
+    if let Some(limits) = limits {
+        limits.validate()?;
+        config.limits = limits;
+    }
+    if let Some(replacements_require_request) = replacements_require_request {
+        config.replacements_require_request = replacements_require_request;
+    }
+    if let Some(voucher_pubkey) = voucher_pubkey {
+        config.voucher_pubkey = if voucher_pubkey.is_empty() {
+            None
+        } else {
+            // the vouchers are signed by the owner
+            ensure!(
+                deps.api.addr_canonicalize(info.sender.as_str())?.as_slice()
+                    == helpers::pubkey_to_canonical_address(&voucher_pubkey).as_slice(),
+                ContractError::InvalidInput {
+                    reason: "the voucher public key doesn't belong to the owner".to_string()
+                }
+            );
+            Some(voucher_pubkey)
+        };
+    }
+    if let Some(allowed_prefixes) = allowed_prefixes {
+        config.allowed_prefixes = helpers::validate_bech32_prefixes(allowed_prefixes)?;
+    }
+    if let Some(delay) = authorized_wallet_delay {
+        ensure!(
+            delay <= MAX_AUTHORIZED_WALLET_DELAY,
+            ContractError::InvalidInput {
+                reason: format!(
+                    "authorized_wallet_delay cannot exceed {MAX_AUTHORIZED_WALLET_DELAY} seconds"
+                ),
+            }
+        );
+
+        // a compromised owner key can't shortcut the delay, lowering it takes the current delay
+        let current_delay = config.authorized_wallet_delay(&env.block.time);
+        if delay >= current_delay {
+            config.authorized_wallet_delay = delay;
+            config.scheduled_authorized_wallet_delay = None;
+        } else {
+            config.authorized_wallet_delay = current_delay;
+            config.scheduled_authorized_wallet_delay = Some(ScheduledDelay {
+                delay,
+                effective_at: env.block.time.plus_seconds(current_delay),
+            });
+        }
+    }
+    if let Some(sweep_threshold) = sweep_threshold {
+        // a compromised owner key can't lift the dual control of the sweeps
+        ensure!(
+            config
+                .sweep_threshold
+                .is_none_or(|current| !sweep_threshold.is_zero() && sweep_threshold <= current),
+            ContractError::InvalidInput {
+                reason: "raising or disabling the sweep threshold requires a proposal".to_string(),
+            }
+        );
+        config.sweep_threshold = (!sweep_threshold.is_zero()).then_some(sweep_threshold);
+    }
+    if let Some(registry) = registry {
+        config.registry = if registry.is_empty() {
+            None
+        } else {
+            Some(deps.api.addr_validate(&registry)?.to_string())
+        };
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_config".to_string()),
+        ("circuit_breaker", format!("{:?}", config.circuit_breaker)),
+        ("limits", format!("{:?}", config.limits)),
+        (
+            "replacements_require_request",
+            config.replacements_require_request.to_string(),
+        ),
+        (
+            "voucher_pubkey",
+            config
+                .voucher_pubkey
+                .map(|pubkey| pubkey.to_base64())
+                .unwrap_or_default(),
+        ),
+        ("allowed_prefixes", config.allowed_prefixes.join(",")),
+        (
+            "authorized_wallet_delay",
+            config.authorized_wallet_delay.to_string(),
+        ),
+        (
+            "sweep_threshold",
+            config
+                .sweep_threshold
+                .map(|threshold| threshold.to_string())
+                .unwrap_or_default(),
+        ),
+        ("registry", config.registry.unwrap_or_default()),
+    ]))
+}
+
+/// Resumes the claims after the circuit breaker has been tripped. Only the owner can resume the
+/// claims. The amount claimed within the current circuit breaker window is reset. The pause set
+/// with [set_pause] is independent, so it's left as is.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn resume_claims(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    ensure!(
+        config.paused,
+        ContractError::InvalidInput {
+            reason: "claims are not paused".to_string()
+        }
+    );
+
+    config.paused = false;
+    CONFIG.save(deps.storage, &config)?;
+    CIRCUIT_BREAKER_WINDOW.remove(deps.storage);
+
+    Ok(Response::default().add_attribute("action", "resume_claims"))
+}
+
+/// Pauses the given scope of the contract, or lifts the pause if no scope is given. Only the owner
+/// can pause the contract. Lifting the pause doesn't resume the claims paused by the circuit
+/// breaker, which is done with [resume_claims].
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `scope` - The scope to pause
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn set_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    scope: Option<PauseScope>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    config.pause = scope;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("set_pause")
+            .add(
+                "scope",
+                config.pause.as_ref().map_or("none", |scope| scope.as_str()),
+            )
+            .build(),
+    ))
+}
+
+/// Sets the counterparty allowed to administer the campaign over IBC, or disables the IBC
+/// administration. Only the owner can update it.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `ibc_admin` - The counterparty allowed to open the IBC admin channel
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn update_ibc_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    ibc_admin: Option<IbcAdmin>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if let Some(ibc_admin) = &ibc_admin {
+        ensure!(
+            ibc_admin.connection_id.starts_with("connection-"),
+            ContractError::InvalidInput {
+                reason: format!("invalid connection id: {}", ibc_admin.connection_id)
+            }
+        );
+        ensure!(
+            !ibc_admin.counterparty_port_id.is_empty(),
+            ContractError::InvalidInput {
+                reason: "counterparty_port_id cannot be empty".to_string()
+            }
+        );
+    }
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    config.ibc_admin = ibc_admin;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_ibc_admin".to_string()),
+        ("ibc_admin", format!("{:?}", config.ibc_admin)),
+    ]))
+}
+
+/// Adds or removes IBC channels from the ones the rewards can be delivered over. Only the owner can
+/// manage the IBC channels.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `channel_ids` - Vector of channel ids to allow/disallow
+/// * `allowed` - Whether to allow or disallow the channels
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn manage_ibc_channels(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel_ids: Vec<String>,
+    allowed: bool,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    ensure!(
+        !channel_ids.is_empty(),
+        ContractError::InvalidInput {
+            reason: "channel_ids cannot be empty".to_string()
+        }
+    );
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+
+    for channel_id in &channel_ids {
+        ensure!(
+            channel_id.starts_with("channel-"),
+            ContractError::InvalidInput {
+                reason: format!("invalid channel id: {channel_id}")
+            }
+        );
+
+        let is_allowed = config.allowed_ibc_channels.contains(channel_id);
+        if allowed && !is_allowed {
+            config.allowed_ibc_channels.push(channel_id.clone());
+        } else if !allowed && is_allowed {
+            config.allowed_ibc_channels.retain(|id| id != channel_id);
+        }
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "manage_ibc_channels".to_string()),
+        ("channel_ids", channel_ids.join(",")),
+        ("allowed", allowed.to_string()),
+    ]))
+}
+
+/// Submits the seed the raffle winners are drawn with. Only the owner or an authorized wallet, i.e.
+/// an oracle relaying a drand beacon, can submit the seed, once the raffle draw time has passed.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `seed` - The random seed
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn submit_raffle_seed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    seed: HexBinary,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::CampaignManager)?;
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    ensure_raffle_can_be_drawn(&campaign, &env)?;
+
+    ensure!(
+        !seed.is_empty(),
+        ContractError::InvalidInput {
+            reason: "seed cannot be empty".to_string()
+        }
+    );
+    ensure!(
+        RAFFLE_SEED.may_load(deps.storage)?.is_none(),
+        ContractError::CampaignError {
+            reason: "the raffle seed has already been submitted".to_string()
+        }
+    );
+
+    RAFFLE_SEED.save(deps.storage, &seed)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "submit_raffle_seed".to_string()),
+        ("seed", seed.to_hex()),
+    ]))
+}
+
+/// Draws the raffle winners among the allocation holders that are not blacklisted, weighted by
+/// their allocations, and credits the prizes to their allocations. Anyone can draw the raffle once
+/// the seed has been submitted.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn draw_raffle(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    let (prize_pool_percentage, winners) = ensure_raffle_can_be_drawn(&campaign, &env)?;
+
+    let seed = RAFFLE_SEED
+        .may_load(deps.storage)?
+        .ok_or(ContractError::CampaignError {
+            reason: "the raffle seed has not been submitted".to_string(),
+        })?;
+    ensure!(
+        RAFFLE_WINNERS.may_load(deps.storage)?.is_none(),
+        ContractError::CampaignError {
+            reason: "the raffle has already been drawn".to_string()
+        }
+    );
+
+    let entrants = ALLOCATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|entry| {
+            entry.as_ref().map_or(true, |(address, _)| {
+                !BLACKLIST.has(deps.storage, address.as_str())
+            })
+        })
+        .map(|entry| entry.map(|(address, allocation)| (address, allocation.amount)))
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+
+    let drawn_winners = helpers::draw_raffle_winners(seed.as_slice(), &entrants, winners);
+    ensure!(
+        !drawn_winners.is_empty(),
+        ContractError::CampaignError {
+            reason: "there are no raffle entrants".to_string()
+        }
+    );
+
+    let prize = campaign
+        .total_reward
+        .amount
+        .mul_floor(prize_pool_percentage)
+        .checked_div(Uint128::from(winners))
+        .map_err(StdError::from)?;
+
+    for winner in &drawn_winners {
+        let allocation = ALLOCATIONS.update(deps.storage, winner.as_str(), |allocation| {
+            let mut allocation = allocation.ok_or(ContractError::NoAllocationFound {
+                address: winner.to_string(),
+            })?;
+            allocation.amount = allocation.amount.checked_add(prize)?;
+            Ok::<_, ContractError>(allocation)
+        })?;
+        update_tag_stats(deps.storage, allocation.tag.as_deref(), |stats| {
+            stats.total_allocated = stats.total_allocated.checked_add(prize)?;
+            Ok(())
+        })?;
+    }
+    adjust_total_allocated(
+        deps.storage,
+        prize.checked_mul(Uint128::new(drawn_winners.len() as u128))?,
+        Uint128::zero(),
+    )?;
+
+    let prizes = drawn_winners
+        .into_iter()
+        .map(|winner| (winner, coin(prize.u128(), &campaign.total_reward.denom)))
+        .collect::<Vec<_>>();
+    RAFFLE_WINNERS.save(deps.storage, &prizes)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "draw_raffle".to_string()),
+        (
+            "winners",
+            prizes
+                .iter()
+                .map(|(winner, _)| winner.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        (
+            "prize",
+            coin(prize.u128(), &campaign.total_reward.denom).to_string(),
+        ),
+    ]))
+}
+
+/// Ensures the campaign has a raffle that can be drawn, i.e. the campaign is not closed and the
+/// draw time has passed. Returns the prize pool percentage and the number of winners of the raffle.
+fn ensure_raffle_can_be_drawn(
+    campaign: &Campaign,
+    env: &Env,
+) -> Result<(Decimal, u32), ContractError> {
+    let (prize_pool_percentage, draw_time, winners) = campaign
+        .distribution_type
+        .iter()
+        .find_map(|distribution| match distribution {
+            DistributionType::Raffle {
+                prize_pool_percentage,
+                draw_time,
+                winners,
+            } => Some((*prize_pool_percentage, *draw_time, *winners)),
+            _ => None,
+        })
+        .ok_or(ContractError::CampaignError {
+            reason: "the campaign doesn't have a raffle".to_string(),
+        })?;
+
+    ensure!(campaign.closed.is_none(), ContractError::CampaignClosed);
+    ensure!(
+        env.block.time >= draw_time,
+        ContractError::CampaignError {
+            reason: "the raffle draw time has not passed yet".to_string()
+        }
+    );
+
+    Ok((prize_pool_percentage, winners))
+}
+
+/// Proposes an irreversible action, to be approved by the owner. Only the owner or an authorized
+/// wallet can make proposals.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `action` - The action to perform once approved
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: ProposalAction,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::CampaignManager)?;
+
+    let id = increase_count(deps.storage, &PROPOSAL_COUNT)?;
+    PROPOSALS.save(
+        deps.storage,
+        id,
+        &Proposal {
+            id,
+            action: action.clone(),
+            proposer: info.sender.to_string(),
+            proposed_at: env.block.time.seconds(),
+            approved_at: None,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "propose".to_string()),
+        ("proposal_id", id.to_string()),
+        ("proposal_action", format!("{action:?}")),
+        ("proposer", info.sender.to_string()),
+    ]))
+}
+
+/// Approves a proposal, performing its action. Only the owner can approve proposals, and each
+/// proposal can only be approved once. The owner can't approve the sweep proposals it made, as
+/// they bypass the sweep threshold.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `proposal_id` - The id of the proposal to approve
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response of the approved action
+pub fn approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut proposal =
+        PROPOSALS
+            .may_load(deps.storage, proposal_id)?
+            .ok_or(ContractError::InvalidInput {
+                reason: format!("proposal {proposal_id} doesn't exist"),
+            })?;
+
+    ensure!(
+        proposal.approved_at.is_none(),
+        ContractError::InvalidInput {
+            reason: format!("proposal {proposal_id} has already been approved"),
+        }
+    );
+    // the sweep proposals stand for the second approval of the sweeps, so they take two wallets
+    if matches!(
+        proposal.action,
+        ProposalAction::Sweep { .. } | ProposalAction::SetSweepThreshold { .. }
+    ) {
+        ensure!(
+            info.sender.as_str() != proposal.proposer,
+            ContractError::Unauthorized
+        );
+    }
+
+    proposal.approved_at = Some(env.block.time.seconds());
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let response = match proposal.action {
+        ProposalAction::CloseCampaign {} => close_campaign(deps, env)?,
+        // the proposal was made and approved by two wallets, so the sweep threshold doesn't apply
+        ProposalAction::Sweep { denom, amount } => {
+            let sweep_amount = get_sweep_amount(deps.as_ref(), &env, &denom, amount)?;
+            SWEPT_WITHOUT_APPROVAL.remove(deps.storage, &denom);
+            send_sweep(deps, env, coin(sweep_amount.u128(), &denom))?
+        }
+        ProposalAction::SetSweepThreshold { threshold } => {
+            let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+            config.sweep_threshold = (!threshold.is_zero()).then_some(threshold);
+            CONFIG.save(deps.storage, &config)?;
+
+            Response::default().add_attributes(vec![
+                ("action", "set_sweep_threshold".to_string()),
+                ("sweep_threshold", threshold.to_string()),
+            ])
+        }
+    };
+
+    Ok(response.add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+/// Adds a batch of addresses and their allocations. This can only be done before the campaign has started.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env`  - The env context
+/// * `info` - The message info
+/// * `allocations` - Vector of (address, amount) pairs
+/// * `skip_duplicates` - Whether to skip the addresses that already have an allocation instead of failing
+/// * `tag` - The tag of the cohort the addresses belong to, if any
+/// * `batch_id` - The identifier of the batch, to ignore it if it was already processed
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn add_allocations(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    allocations: Vec<(String, Uint128)>,
+    skip_duplicates: bool,
+    tag: Option<String>,
+    batch_id: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    if let Some(batch_id) = &batch_id {
+        ensure!(
+            !batch_id.is_empty() && batch_id.len() <= MAX_BATCH_ID_LENGTH,
+            ContractError::InvalidInput {
+                reason: format!(
+                    "the batch id must be between 1 and {MAX_BATCH_ID_LENGTH} characters"
+                ),
+            }
+        );
+
+        // a re-broadcast batch is a cheap no-op, so the uploads can be retried safely
+        if PROCESSED_BATCHES.has(deps.storage, batch_id) {
+            return Ok(Response::default().add_attributes(
+                AllocationsAttributes::AlreadyProcessed { batch_id }.into_attributes(),
+            ));
+        }
+    }
+
+    // Check batch size limit
+    let max_batch_size = max_allocation_batch_size(deps.as_ref())?;
+    ensure!(
+        allocations.len() <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: allocations.len(),
+            max: max_batch_size,
+        }
+    );
+
+    let allocations_len = allocations.len();
+
+    let (addresses, _) = store_allocations(
+        deps.branch(),
+        &env,
+        &info.sender,
+        allocations,
+        AllocationMode::CreateOnly,
+        skip_duplicates,
+        tag,
+    )?;
+    if let Some(batch_id) = &batch_id {
+        PROCESSED_BATCHES.save(
+            deps.storage,
+            batch_id,
+            &ProcessedBatch {
+                uploaded_by: info.sender.clone(),
+                count: addresses.len() as u64,
+                processed_at: env.block.time,
+            },
+        )?;
+    }
+    let fee_grant_messages = fee_grant_messages(deps.as_ref(), &env, &addresses)?;
+
+    Ok(Response::default()
+        .add_messages(fee_grant_messages)
+        .add_attributes(
+            AllocationsAttributes::Added {
+                count: addresses.len(),
+                skipped: allocations_len - addresses.len(),
+                batch_id: batch_id.as_deref(),
+            }
+            .into_attributes(),
+        ))
+}
+
+/// Registers the allocation of a voucher signed off-chain by the owner, wrapped in an ADR-36 sign
+/// doc. Anyone can submit the voucher, the allocation being added for the address of the voucher
+/// same as if the owner uploaded it. Each voucher can only be registered once.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `voucher` - The voucher signed by the owner
+/// * `signature` - The signature of the ADR-36 sign doc
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn register_allocation(
+    mut deps: DepsMut,
+    env: Env,
+    voucher: AllocationVoucher,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let pubkey = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .voucher_pubkey
+        .ok_or(ContractError::CampaignError {
+            reason: "allocation vouchers are disabled".to_string(),
+        })?;
+    let owner = cw_ownable::get_ownership(deps.storage)?
+        .owner
+        .ok_or(ContractError::InvalidSignature)?;
+
+    // the key might belong to a previous owner
+    ensure!(
+        deps.api.addr_canonicalize(owner.as_str())?.as_slice()
+            == helpers::pubkey_to_canonical_address(&pubkey).as_slice(),
+        ContractError::InvalidSignature
+    );
+    ensure!(
+        voucher.campaign_id == env.contract.address.as_str(),
+        ContractError::InvalidInput {
+            reason: "the voucher was issued for another campaign".to_string()
+        }
+    );
+    ensure!(
+        env.block.time < voucher.expiry,
+        ContractError::VoucherExpired {
+            expired_at: voucher.expiry
+        }
+    );
+
+    let sign_bytes = helpers::adr36_sign_bytes(owner.as_str(), &to_json_vec(&voucher)?);
+    let digest = Sha256::digest(sign_bytes);
+    ensure!(
+        deps.api
+            .secp256k1_verify(&digest, &signature, &pubkey)
+            .unwrap_or(false),
+        ContractError::InvalidSignature
+    );
+    ensure!(
+        !REGISTERED_VOUCHERS.has(deps.storage, &digest),
+        ContractError::VoucherAlreadyRegistered
+    );
+    REGISTERED_VOUCHERS.save(deps.storage, &digest, &())?;
+
+    let (addresses, _) = store_allocations(
+        deps.branch(),
+        &env,
+        &owner,
+        vec![(voucher.address, voucher.amount)],
+        AllocationMode::CreateOnly,
+        false,
+        None,
+    )?;
+    let fee_grant_messages = fee_grant_messages(deps.as_ref(), &env, &addresses)?;
+
+    Ok(Response::default()
+        .add_messages(fee_grant_messages)
+        .add_attributes(
+            AllocationsAttributes::Registered {
+                address: &addresses[0],
+                amount: voucher.amount,
+            }
+            .into_attributes(),
+        ))
+}
+
+/// Builds the messages granting the campaign fee allowance to the given addresses, if the campaign
+/// has a [FeeGrant]. Addresses that aren't native, i.e. placeholders, are skipped, as they can't
+/// pay fees.
+fn fee_grant_messages(
+    deps: Deps,
+    env: &Env,
+    addresses: &[String],
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let Some(fee_grant) = CAMPAIGN
+        .may_load(deps.storage)?
+        .and_then(|campaign| campaign.fee_grant)
+    else {
+        return Ok(vec![]);
+    };
+
+    Ok(addresses
+        .iter()
+        .filter_map(|address| deps.api.addr_validate(address).ok())
+        .map(|grantee| {
+            stargate::grant_allowance_msg(
+                &env.contract.address,
+                &grantee,
+                &fee_grant.spend_limit,
+                fee_grant.expiration.as_ref(),
+            )
+        })
+        .collect())
+}
+
+/// Returns the maximum number of allocations that can be added in a single batch, as configured in
+/// the contract limits or [MAX_ALLOCATION_BATCH_SIZE] by default.
+fn max_allocation_batch_size(deps: Deps) -> StdResult<usize> {
+    Ok(CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .limits
+        .max_allocation_batch_size
+        .map_or(MAX_ALLOCATION_BATCH_SIZE, |max| max as usize))
+}
+
+/// Sets a batch of allocations according to the given mode, creating the missing allocations and
+/// overwriting or incrementing the existing ones. This can only be done before the campaign has
+/// started.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env`  - The env context
+/// * `info` - The message info
+/// * `entries` - Vector of (address, amount) pairs
+/// * `mode` - How the entries are applied to the existing allocations
+/// * `tag` - The tag of the cohort the addresses belong to, kept as is for the existing
+///   allocations if not set
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn set_allocations(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entries: Vec<(String, Uint128)>,
+    mode: AllocationMode,
+    tag: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    let max_batch_size = max_allocation_batch_size(deps.as_ref())?;
+    ensure!(
+        entries.len() <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: entries.len(),
+            max: max_batch_size,
+        }
+    );
+
+    let (addresses, updated) = store_allocations(
+        deps.branch(),
+        &env,
+        &info.sender,
+        entries,
+        mode.clone(),
+        false,
+        tag,
+    )?;
+    let fee_grant_messages = fee_grant_messages(deps.as_ref(), &env, &addresses)?;
+
+    Ok(Response::default()
+        .add_messages(fee_grant_messages)
+        .add_attributes(
+            AllocationsAttributes::Set {
+                mode: &mode,
+                created: addresses.len(),
+                updated,
+            }
+            .into_attributes(),
+        ))
+}
+
+/// Applies a diff to the allocations, creating the additions, removing the removals and
+/// increasing or decreasing the adjusted allocations by their signed change. The whole diff is
+/// validated before touching the storage, so it's applied entirely or not at all. This can only
+/// be done before the campaign has started.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `additions` - The (address, amount) pairs of the new allocations
+/// * `removals` - The addresses whose allocation is removed
+/// * `adjustments` - The (address, change) pairs of the existing allocations to adjust
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn apply_allocation_delta(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    additions: Vec<(String, Uint128)>,
+    removals: Vec<String>,
+    adjustments: Vec<(String, Int128)>,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    let entries = additions.len() + removals.len() + adjustments.len();
+    let max_batch_size = max_allocation_batch_size(deps.as_ref())?;
+    ensure!(
+        entries <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: entries,
+            max: max_batch_size,
+        }
+    );
+    ensure!(
+        entries > 0,
+        ContractError::InvalidInput {
+            reason: "the allocation delta is empty".to_string(),
+        }
+    );
+
+    let campaign = CAMPAIGN.may_load(deps.storage)?;
+    let max_allocation_per_address = campaign
+        .as_ref()
+        .and_then(|campaign| campaign.max_allocation_per_address);
+    if let Some(campaign) = campaign {
+        // the started campaigns fail with CampaignAlreadyStarted, so the reason is for the
+        // upcoming ones
+        campaign.ensure_phase(
+            &env.block.time,
+            &[CampaignPhase::AllocationUpload],
+            "the campaign doesn't accept allocations yet",
+        )?;
+    }
+    let ensure_within_cap = |address: &str, amount: Uint128| -> Result<(), ContractError> {
+        if let Some(max_allocation_per_address) = max_allocation_per_address {
+            ensure!(
+                amount <= max_allocation_per_address,
+                ContractError::InvalidInput {
+                    reason: format!(
+                        "the allocation of {address} exceeds the maximum allocation per address {max_allocation_per_address}"
+                    )
+                }
+            );
+        }
+
+        Ok(())
+    };
+
+    // every address can only appear once in the whole delta, so the changes don't depend on
+    // the order they are applied in
+    let mut seen = BTreeSet::new();
+    let mut validate_address = |address_raw: &str| -> Result<String, ContractError> {
+        let address = validate_raw_address(deps.as_ref(), address_raw)?;
+        ensure!(
+            seen.insert(address.clone()),
+            ContractError::InvalidInput {
+                reason: format!("the address {address} appears more than once in the delta"),
+            }
+        );
+
+        Ok(address)
+    };
+    let load_allocation = |address: &str| -> Result<Allocation, ContractError> {
+        ALLOCATIONS
+            .may_load(deps.storage, address)?
+            .ok_or(ContractError::NoAllocationFound {
+                address: address.to_string(),
+            })
+    };
+
+    let additions = additions
+        .into_iter()
+        .map(|(address_raw, amount)| {
+            let address = validate_address(&address_raw)?;
+            ensure!(
+                !ALLOCATIONS.has(deps.storage, address.as_str()),
+                ContractError::AllocationAlreadyExists { address }
+            );
+            ensure_within_cap(&address, amount)?;
+
+            Ok((address, amount))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    let removals = removals
+        .into_iter()
+        .map(|address_raw| {
+            let address = validate_address(&address_raw)?;
+            let allocation = load_allocation(&address)?;
+
+            Ok((address, allocation))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    let adjustments = adjustments
+        .into_iter()
+        .map(|(address_raw, change)| {
+            let address = validate_address(&address_raw)?;
+            let allocation = load_allocation(&address)?;
+            let change_amount = Uint128::new(change.unsigned_abs().u128());
+            let new_amount = if change.is_negative() {
+                ensure!(
+                    change_amount <= allocation.amount,
+                    ContractError::InvalidInput {
+                        reason: format!(
+                            "the adjustment of {change} exceeds the allocation of {address} of {}",
+                            allocation.amount
+                        ),
+                    }
+                );
+                allocation.amount.checked_sub(change_amount)?
+            } else {
+                allocation.amount.checked_add(change_amount)?
+            };
+            ensure!(
+                !new_amount.is_zero(),
+                ContractError::InvalidInput {
+                    reason: format!(
+                        "the adjustment drops the allocation of {address} to zero, remove it instead"
+                    ),
+                }
+            );
+            ensure_within_cap(&address, new_amount)?;
+
+            Ok((address, allocation, new_amount))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    let (mut added, mut removed) = (Uint128::zero(), Uint128::zero());
+    let mut addresses = Vec::with_capacity(additions.len());
+    for (address, amount) in additions {
+        // no previous allocation, so there are no index entries to clean up
+        let new_allocation = Allocation::new(amount, info.sender.clone(), env.block.time);
+        ALLOCATIONS.replace(deps.storage, address.as_str(), Some(&new_allocation), None)?;
+        count_in_tag_stats(deps.storage, &new_allocation, true)?;
+        added = added.checked_add(amount)?;
+        addresses.push(address);
+    }
+    for (address, allocation) in &removals {
+        ALLOCATIONS.replace(deps.storage, address.as_str(), None, Some(allocation))?;
+        count_in_tag_stats(deps.storage, allocation, false)?;
+        remove_address_state(deps.storage, address)?;
+        removed = removed.checked_add(allocation.amount)?;
+    }
+    for (address, allocation, new_amount) in &adjustments {
+        // the uploader, the upload time and the tag are kept, only the amount changes
+        let new_allocation = Allocation {
+            amount: *new_amount,
+            ..allocation.clone()
+        };
+        ALLOCATIONS.replace(
+            deps.storage,
+            address.as_str(),
+            Some(&new_allocation),
+            Some(allocation),
+        )?;
+        count_in_tag_stats(deps.storage, allocation, false)?;
+        count_in_tag_stats(deps.storage, &new_allocation, true)?;
+        added = added.checked_add(*new_amount)?;
+        removed = removed.checked_add(allocation.amount)?;
+    }
+
+    let count = get_count(deps.storage, &ALLOCATIONS_COUNT)?
+        .saturating_add(addresses.len() as u64)
+        .saturating_sub(removals.len() as u64);
+    ALLOCATIONS_COUNT.save(deps.storage, &count)?;
+    adjust_total_allocated(deps.storage, added, removed)?;
+    helpers::ensure_group_pools_covered(deps.storage, Uint128::zero())?;
+
+    let net_change = Int128::try_from(added)?.checked_sub(Int128::try_from(removed)?)?;
+    let fee_grant_messages = fee_grant_messages(deps.as_ref(), &env, &addresses)?;
+
+    Ok(Response::default()
+        .add_messages(fee_grant_messages)
+        .add_event(
+            AllocationDeltaEvent {
+                applied_by: info.sender.as_str(),
+                added: addresses.len(),
+                removed: removals.len(),
+                adjusted: adjustments.len(),
+                net_change,
+            }
+            .into_event(),
+        )
+        .add_attributes(ActionAttributes::new("apply_allocation_delta").build()))
+}
+
+/// Stores the given allocations on behalf of the uploader, failing if the campaign has started.
+/// The existing allocations are updated per the [AllocationMode], or fail the batch in the
+/// `CreateOnly` mode, unless the duplicates are skipped. Returns the validated addresses whose
+/// allocation was created, and the number of allocations updated. The allocations get the given
+/// tag, if any, while the updated ones keep their tag otherwise.
+///
+/// The whole batch is validated before touching the storage, then each allocation costs a single
+/// read, the one needed to detect a duplicate, and the allocations count is written once.
+fn store_allocations(
+    deps: DepsMut,
+    env: &Env,
+    uploader: &Addr,
+    allocations: Vec<(String, Uint128)>,
+    mode: AllocationMode,
+    skip_duplicates: bool,
+    tag: Option<String>,
+) -> Result<(Vec<String>, usize), ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+
+    let mut addresses = Vec::with_capacity(allocations.len());
+    let mut updated = 0usize;
+
+    if let Some(tag) = &tag {
+        validate_tag(tag)?;
+    }
+
+    // Check if campaign has started
+    let campaign = CAMPAIGN.may_load(deps.storage)?;
+    let max_allocation_per_address = campaign
+        .as_ref()
+        .and_then(|campaign| campaign.max_allocation_per_address);
+
+    if let Some(campaign) = campaign {
+        ensure!(
+            campaign.phase(&env.block.time) != CampaignPhase::Upcoming,
+            ContractError::CampaignError {
+                reason: "the campaign doesn't accept allocations yet".to_string(),
+            }
+        );
+
+        campaign.ensure_phase(
+            &env.block.time,
+            &[CampaignPhase::AllocationUpload],
+            "cannot upload allocations after campaign has started",
+        )?;
+    }
+
+    let validated_allocations = allocations
+        .into_iter()
+        .map(|(address_raw, amount)| {
+            let validated_receiver_string = validate_raw_address(deps.as_ref(), &address_raw)?;
+
+            if let Some(max_allocation_per_address) = max_allocation_per_address {
+                ensure!(
+                    amount <= max_allocation_per_address,
+                    ContractError::InvalidInput {
+                        reason: format!(
+                            "the allocation of {validated_receiver_string} exceeds the maximum allocation per address {max_allocation_per_address}"
+                        )
+                    }
+                );
+            }
+
+            Ok((validated_receiver_string, amount))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    let (mut added, mut removed) = (Uint128::zero(), Uint128::zero());
+    for (validated_receiver_string, amount) in validated_allocations {
+        // a single read detects the duplicates, whether stored earlier or in this batch
+        if let Some(allocation) =
+            ALLOCATIONS.may_load(deps.storage, validated_receiver_string.as_str())?
+        {
+            let new_amount = match mode {
+                AllocationMode::CreateOnly => {
+                    ensure!(
+                        skip_duplicates,
+                        ContractError::AllocationAlreadyExists {
+                            address: validated_receiver_string,
+                        }
+                    );
+                    continue;
+                }
+                AllocationMode::UpsertBeforeStart => amount,
+                AllocationMode::IncrementBeforeStart => allocation.amount.checked_add(amount)?,
+            };
+
+            if let Some(max_allocation_per_address) = max_allocation_per_address {
+                ensure!(
+                    new_amount <= max_allocation_per_address,
+                    ContractError::InvalidInput {
+                        reason: format!(
+                            "the allocation of {validated_receiver_string} exceeds the maximum allocation per address {max_allocation_per_address}"
+                        )
+                    }
+                );
+            }
+
+            // the uploader and the upload time are kept, only the amount and the tag can change
+            let new_allocation = Allocation {
+                amount: new_amount,
+                tag: tag.clone().or_else(|| allocation.tag.clone()),
+                ..allocation.clone()
+            };
+            ALLOCATIONS.replace(
+                deps.storage,
+                validated_receiver_string.as_str(),
+                Some(&new_allocation),
+                Some(&allocation),
+            )?;
+            count_in_tag_stats(deps.storage, &allocation, false)?;
+            count_in_tag_stats(deps.storage, &new_allocation, true)?;
+            added = added.checked_add(new_allocation.amount)?;
+            removed = removed.checked_add(allocation.amount)?;
+            updated += 1;
+            continue;
+        }
+
+        // no previous allocation, so there are no index entries to clean up
+        let new_allocation = Allocation {
+            tag: tag.clone(),
+            ..Allocation::new(amount, uploader.clone(), env.block.time)
+        };
+        ALLOCATIONS.replace(
+            deps.storage,
+            validated_receiver_string.as_str(),
+            Some(&new_allocation),
+            None,
+        )?;
+        count_in_tag_stats(deps.storage, &new_allocation, true)?;
+        added = added.checked_add(new_allocation.amount)?;
+        addresses.push(validated_receiver_string);
+    }
+
+    let count = get_count(deps.storage, &ALLOCATIONS_COUNT)?.saturating_add(addresses.len() as u64);
+    ALLOCATIONS_COUNT.save(deps.storage, &count)?;
+    adjust_total_allocated(deps.storage, added, removed)?;
+    helpers::ensure_group_pools_covered(deps.storage, Uint128::zero())?;
+
+    Ok((addresses, updated))
+}
+
+/// Validates the tag of a cohort of allocations.
+fn validate_tag(tag: &str) -> Result<(), ContractError> {
+    ensure!(
+        !tag.is_empty() && tag.len() <= MAX_TAG_LENGTH,
+        ContractError::InvalidInput {
+            reason: format!("the tag must be between 1 and {MAX_TAG_LENGTH} characters"),
+        }
+    );
+
+    Ok(())
+}
+
+/// Counts an allocation in the stats of its tag if `counted` is true, or out of them otherwise.
+fn count_in_tag_stats(
+    storage: &mut dyn Storage,
+    allocation: &Allocation,
+    counted: bool,
+) -> StdResult<()> {
+    update_tag_stats(storage, allocation.tag.as_deref(), |stats| {
+        if counted {
+            stats.allocations = stats.allocations.saturating_add(1);
+            stats.total_allocated = stats.total_allocated.checked_add(allocation.amount)?;
+        } else {
+            stats.allocations = stats.allocations.saturating_sub(1);
+            stats.total_allocated = stats.total_allocated.saturating_sub(allocation.amount);
+        }
+        Ok(())
+    })
+}
+
+/// Imports a page of allocations from a previous claimdrop contract, skipping the addresses that
+/// already claimed on it unless told otherwise. This can only be done before the campaign has
+/// started.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env`  - The env context
+/// * `info` - The message info
+/// * `source_contract` - The claimdrop contract to import the allocations from
+/// * `start_after` - The address to start importing after, used for paginating the import
+/// * `limit` - The maximum number of allocations to import
+/// * `include_claimed` - Whether to import the addresses that already claimed on the source contract
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn import_allocations(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    source_contract: String,
+    start_after: Option<String>,
+    limit: Option<u16>,
+    include_claimed: bool,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    let source_contract = deps.api.addr_validate(&source_contract)?;
+    let limit = limit
+        .unwrap_or(MAX_IMPORT_ALLOCATIONS_BATCH_SIZE)
+        .min(MAX_IMPORT_ALLOCATIONS_BATCH_SIZE);
+
+    let AllocationsResponse { allocations, .. } = deps.querier.query_wasm_smart(
+        &source_contract,
+        &QueryMsg::Allocations {
+            address: None,
+            start_after,
+            limit: Some(limit),
+            min_amount: None,
+            has_claimed: None,
+        },
+    )?;
+
+    let last_address = allocations
+        .last()
+        .map(|(address, _)| address.clone())
+        .unwrap_or_default();
+    let mut skipped = 0usize;
+    let mut allocations_to_import = vec![];
+
+    for (address, allocation) in allocations {
+        // placeholder addresses can't have claimed on the source contract
+        if !include_claimed && deps.api.addr_validate(&address).is_ok() {
+            let ClaimedResponse { claimed } = deps.querier.query_wasm_smart(
+                &source_contract,
+                &QueryMsg::Claimed {
+                    address: Some(address.clone()),
+                    start_from: None,
+                    limit: None,
+                },
+            )?;
+
+            if !claimed.is_empty() {
+                skipped += 1;
+                continue;
+            }
+        }
+
+        allocations_to_import.push((address, allocation.amount));
+    }
+
+    let imported = allocations_to_import.len();
+
+    store_allocations(
+        deps,
+        &env,
+        &info.sender,
+        allocations_to_import,
+        AllocationMode::CreateOnly,
+        false,
+        None,
+    )?;
+
+    Ok(Response::default().add_attributes(
+        AllocationsAttributes::Imported {
+            source_contract: source_contract.as_str(),
+            imported,
+            skipped,
+            last_address: &last_address,
+        }
+        .into_attributes(),
+    ))
+}
+
+/// Snapshots the delegators of the given validators, adding an allocation of `stake * ratio` for
+/// every delegator staking at least `min_stake` across the validators. This can only be done by the
+/// owner, before the campaign has started.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env`  - The env context
+/// * `info` - The message info
+/// * `validator_addrs` - The operator addresses of the validators to snapshot
+/// * `min_stake` - The minimum stake for a delegator to get an allocation
+/// * `ratio` - The amount of reward allocated per staked token
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn snapshot_delegators(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    validator_addrs: Vec<String>,
+    min_stake: Uint128,
+    ratio: Decimal,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    ensure!(
+        !validator_addrs.is_empty() && validator_addrs.len() <= MAX_SNAPSHOT_VALIDATORS,
+        ContractError::InvalidInput {
+            reason: format!(
+                "between 1 and {} validators can be snapshotted at once",
+                MAX_SNAPSHOT_VALIDATORS
+            )
+        }
+    );
+    ensure!(
+        !ratio.is_zero(),
+        ContractError::InvalidInput {
+            reason: "ratio cannot be zero".to_string()
+        }
+    );
+
+    // a delegator's stake is summed across all the snapshotted validators
+    let mut stakes: BTreeMap<String, Uint128> = BTreeMap::new();
+    for validator in validator_addrs.iter().collect::<BTreeSet<_>>() {
+        for (delegator, amount) in stargate::query_validator_delegations(&deps.querier, validator)?
+        {
+            let stake = stakes.entry(delegator).or_default();
+            *stake = stake.checked_add(amount)?;
+        }
+    }
+
+    let allocations = stakes
+        .into_iter()
+        .filter(|(_, stake)| *stake >= min_stake)
+        .map(|(delegator, stake)| (delegator, stake.mul_floor(ratio)))
+        .filter(|(_, allocation)| !allocation.is_zero())
+        .collect::<Vec<_>>();
+
+    let max_batch_size = max_allocation_batch_size(deps.as_ref())?;
+    ensure!(
+        allocations.len() <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: allocations.len(),
+            max: max_batch_size,
+        }
+    );
+
+    let allocations_len = allocations.len();
+
+    store_allocations(
+        deps,
+        &env,
+        &info.sender,
+        allocations,
+        AllocationMode::CreateOnly,
+        false,
+        None,
+    )?;
+
+    Ok(Response::default().add_attributes(
+        AllocationsAttributes::Snapshotted {
+            validators: &validator_addrs,
+            count: allocations_len,
+        }
+        .into_attributes(),
+    ))
+}
+
+/// Requests the replacement of the sender's address by a new address, to be approved by an admin
+/// with [replace_address]. If the new address is not set, the pending request is withdrawn.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `new_address` - The address to replace the sender with
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn request_address_replacement(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_address: Option<String>,
+) -> Result<Response, ContractError> {
+    let old_address = info.sender.to_string();
+
+    let Some(new_address) = new_address else {
+        ensure!(
+            ADDRESS_REPLACEMENT_REQUESTS.has(deps.storage, &old_address),
+            ContractError::InvalidInput {
+                reason: "there is no pending replacement request".to_string()
+            }
+        );
+        ADDRESS_REPLACEMENT_REQUESTS.remove(deps.storage, &old_address);
+
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "withdraw_address_replacement".to_string()),
+            ("old_address", old_address),
+        ]));
+    };
+
+    ensure!(
+        ALLOCATIONS.has(deps.storage, &old_address),
+        ContractError::NoAllocationFound {
+            address: old_address
+        }
+    );
+
+    let new_address = validate_raw_address(deps.as_ref(), &new_address)?;
+    ensure!(
+        new_address != old_address,
+        ContractError::InvalidInput {
+            reason: "cannot replace an address with itself".to_string()
+        }
+    );
+
+    ADDRESS_REPLACEMENT_REQUESTS.save(deps.storage, &old_address, &new_address)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "request_address_replacement".to_string()),
+        ("old_address", old_address),
+        ("new_address", new_address),
+    ]))
+}
+
+/// Replaces an address in the allocation list. This can be done at any time during the campaign.
+/// When the config requires it, the old address must have requested the replacement first.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `old_address` - The old address to replace
+/// * `new_address` - The new address to use
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn replace_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    old_address_raw: String,
+    new_address_raw: String,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    ensure_replacement_requested(deps.as_ref(), &old_address_raw, &new_address_raw)?;
+    move_address(deps, &old_address_raw, &new_address_raw)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "replace_address".to_string()),
+        ("old_address", old_address_raw),
+        ("new_address", new_address_raw),
+    ]))
+}
+
+/// Starts the vesting of the sender, when the campaign vests from the activation. The distribution
+/// schedules of the sender are offset by the time elapsed since the campaign start.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn activate_vesting(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    ensure!(
+        campaign.vesting_from_activation,
+        ContractError::CampaignError {
+            reason: "the campaign doesn't vest from the activation".to_string()
+        }
+    );
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "not started",
+    )?;
+
+    ensure!(
+        ALLOCATIONS.has(deps.storage, info.sender.as_str()),
+        ContractError::NoAllocationFound {
+            address: info.sender.to_string()
+        }
+    );
+    ensure!(
+        !VESTING_ACTIVATIONS.has(deps.storage, info.sender.as_str()),
+        ContractError::CampaignError {
+            reason: "the vesting has already been activated".to_string()
+        }
+    );
+
+    VESTING_ACTIVATIONS.save(deps.storage, info.sender.as_str(), &env.block.time)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "activate_vesting".to_string()),
+        ("address", info.sender.to_string()),
+        ("activated_at", env.block.time.to_string()),
+    ]))
+}
+
+/// Transfers the allocation and claims of the sender to another address it controls. This can be
+/// done at any time, unless the campaign disabled the allocation transfers.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `new_address` - The address to transfer the allocation to
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn transfer_allocation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_address: String,
+) -> Result<Response, ContractError> {
+    if let Some(campaign) = CAMPAIGN.may_load(deps.storage)? {
+        ensure!(
+            !campaign.allocation_transfers_disabled,
+            ContractError::CampaignError {
+                reason: "allocation transfers are disabled".to_string()
+            }
+        );
+    }
+
+    let new_address = deps.api.addr_validate(&new_address)?;
+    ensure!(
+        new_address != info.sender,
+        ContractError::InvalidInput {
+            reason: "cannot transfer the allocation to the same address".to_string()
+        }
+    );
+
+    // blacklisted users can't escape the blacklist, nor transfer to a blacklisted address
+    ensure!(
+        !is_blacklisted(deps.as_ref(), info.sender.as_str())?
+            && !is_blacklisted(deps.as_ref(), new_address.as_str())?,
+        ContractError::AddressBlacklisted
+    );
+    ensure!(
+        !is_frozen(deps.as_ref(), info.sender.as_str())?,
+        ContractError::AddressFrozen
+    );
+    ensure_not_on_hold(deps.as_ref(), info.sender.as_str(), &env.block.time)?;
+
+    move_address(deps, info.sender.as_str(), new_address.as_str())?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "transfer_allocation".to_string()),
+        ("old_address", info.sender.to_string()),
+        ("new_address", new_address.to_string()),
+    ]))
+}
+
+/// Links a foreign, i.e. Ethereum, address to the sender, moving the allocation and claims of the
+/// foreign address to the sender. The foreign key must have signed a [LinkAddressPayload] for the
+/// sender as an EIP-191 personal message.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `foreign_address` - The foreign address holding the allocation
+/// * `signature` - The `r || s || v` signature of the EIP-191 message
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn link_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    foreign_address: String,
+    signature: HexBinary,
+) -> Result<Response, ContractError> {
+    let foreign_address = helpers::validate_ethereum_address(&foreign_address)?;
+
+    // the recovery id is either 0/1 or 27/28, as set by the legacy Ethereum wallets
+    let (signature, recovery_id) = match signature.as_slice() {
+        [signature @ .., v] if signature.len() == 64 => (signature, v % 27),
+        _ => return Err(ContractError::InvalidSignature),
+    };
+
+    let message = to_json_vec(&LinkAddressPayload {
+        chain_id: env.block.chain_id,
+        contract: env.contract.address.to_string(),
+        address: info.sender.to_string(),
+    })?;
+    let pubkey = deps
+        .api
+        .secp256k1_recover_pubkey(&helpers::eip191_hash(&message), signature, recovery_id)
+        .map_err(|_| ContractError::InvalidSignature)?;
+    ensure!(
+        helpers::pubkey_to_ethereum_address(&pubkey) == foreign_address,
+        ContractError::InvalidSignature
+    );
+
+    // same as the transfers, blacklisted users can't escape the blacklist
+    ensure!(
+        !is_frozen(deps.as_ref(), &foreign_address)?,
+        ContractError::AddressFrozen
+    );
+    ensure!(
+        !is_blacklisted(deps.as_ref(), &foreign_address)?
+            && !is_blacklisted(deps.as_ref(), info.sender.as_str())?,
+        ContractError::AddressBlacklisted
+    );
+
+    move_address(deps, &foreign_address, info.sender.as_str())?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "link_address".to_string()),
+        ("foreign_address", foreign_address),
+        ("address", info.sender.to_string()),
+    ]))
+}
+
+/// Replaces a batch of addresses in the allocation list. This can be done at any time during the
+/// campaign. If any of the pairs is invalid, the whole batch fails.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `pairs` - Vector of (old_address, new_address) pairs
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn replace_addresses(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    pairs: Vec<(String, String)>,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    ensure!(
+        !pairs.is_empty(),
+        ContractError::InvalidInput {
+            reason: "pairs cannot be empty".to_string(),
+        }
+    );
+
+    ensure!(
+        pairs.len() <= MAX_REPLACE_ADDRESSES_BATCH_SIZE,
+        ContractError::BatchSizeLimitExceeded {
+            actual: pairs.len(),
+            max: MAX_REPLACE_ADDRESSES_BATCH_SIZE,
+        }
+    );
+
+    let pairs_len = pairs.len().to_string();
+
+    for (old_address_raw, new_address_raw) in pairs.iter() {
+        ensure_replacement_requested(deps.as_ref(), old_address_raw, new_address_raw)?;
+        move_address(deps.branch(), old_address_raw, new_address_raw)?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "replace_addresses".to_string()),
+        ("count", pairs_len),
+    ]))
+}
+
+/// Ensures the old address requested its replacement by the new address, when the config requires
+/// the replacements to be requested.
+fn ensure_replacement_requested(
+    deps: Deps,
+    old_address_raw: &str,
+    new_address_raw: &str,
+) -> Result<(), ContractError> {
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if !config.replacements_require_request {
+        return Ok(());
+    }
+
+    let old_address = validate_raw_address(deps, old_address_raw)?;
+    let new_address = validate_raw_address(deps, new_address_raw)?;
+    let requested = ADDRESS_REPLACEMENT_REQUESTS.may_load(deps.storage, old_address.as_str())?;
+
+    ensure!(
+        requested.as_deref() == Some(new_address.as_str()),
+        ContractError::InvalidInput {
+            reason: format!("{old_address} didn't request to be replaced by {new_address}")
+        }
+    );
+
+    Ok(())
+}
+
+/// Moves the allocation, claims, blacklist and allowlist entries of an address to a new address.
+fn move_address(
+    deps: DepsMut,
+    old_address_raw: &str,
+    new_address_raw: &str,
+) -> Result<(), ContractError> {
+    let old_address_canonical = validate_raw_address(deps.as_ref(), old_address_raw)?;
+    // New address should be validated the same way as when adding allocations
+    let new_address_validated = validate_raw_address(deps.as_ref(), new_address_raw)?;
+
+    let old_allocation = ALLOCATIONS
+        .may_load(deps.storage, old_address_canonical.as_str())?
+        .ok_or(ContractError::NoAllocationFound {
+            address: old_address_raw.to_string(),
+        })?;
+
+    // Ensure the new address doesn't have an allocation already
+    ensure!(
+        !ALLOCATIONS.has(deps.storage, new_address_validated.as_str()),
+        ContractError::AllocationAlreadyExists {
+            address: new_address_raw.to_string()
+        }
+    );
+    ALLOCATIONS.remove(deps.storage, old_address_canonical.as_str())?;
+    ADDRESS_REPLACEMENT_REQUESTS.remove(deps.storage, old_address_canonical.as_str());
+    if let Some(activated_at) =
+        VESTING_ACTIVATIONS.may_load(deps.storage, old_address_canonical.as_str())?
+    {
+        VESTING_ACTIVATIONS.remove(deps.storage, old_address_canonical.as_str());
+        VESTING_ACTIVATIONS.save(deps.storage, new_address_validated.as_str(), &activated_at)?;
+    }
+    if let Some(exhausted) =
+        EXHAUSTED_SLOTS.may_load(deps.storage, old_address_canonical.as_str())?
+    {
+        EXHAUSTED_SLOTS.remove(deps.storage, old_address_canonical.as_str());
+        EXHAUSTED_SLOTS.save(deps.storage, new_address_validated.as_str(), &exhausted)?;
+    }
+    ALLOCATIONS.save(
+        deps.storage,
+        new_address_validated.as_str(),
+        &old_allocation,
+    )?;
+
+    // Update claims and blacklist if the address has claimed rewards or is blacklisted
+    let claims = get_claims_for_address(deps.as_ref(), old_address_canonical.clone())?;
+    if !claims.is_empty() {
+        CLAIMS.remove(deps.storage, old_address_canonical.clone());
+        CLAIMS.save(deps.storage, new_address_validated.to_string(), &claims)?;
+    }
+
+    if let Some(entry) = BLACKLIST.may_load(deps.storage, old_address_canonical.as_str())? {
+        BLACKLIST.remove(deps.storage, old_address_canonical.as_str());
+        // the entries are merged if the new address was already blacklisted, keeping the
+        // metadata of the old address
+        if is_blacklisted(deps.as_ref(), new_address_validated.as_str())? {
+            decrease_count(deps.storage, &BLACKLIST_COUNT)?;
+        }
+        BLACKLIST.save(deps.storage, new_address_validated.as_str(), &entry)?;
+    }
+
+    if let Some(entry) = FROZEN.may_load(deps.storage, old_address_canonical.as_str())? {
+        FROZEN.remove(deps.storage, old_address_canonical.as_str());
+        FROZEN.save(deps.storage, new_address_validated.as_str(), &entry)?;
+    }
+
+    if let Some(hold) = HOLDS.may_load(deps.storage, old_address_canonical.as_str())? {
+        HOLDS.remove(deps.storage, old_address_canonical.as_str());
+        HOLDS.save(deps.storage, new_address_validated.as_str(), &hold)?;
+    }
+
+    if is_allowlisted(deps.as_ref(), old_address_canonical.as_str())? {
+        ALLOWLIST.remove(deps.storage, old_address_canonical.as_str());
+        ALLOWLIST.save(deps.storage, new_address_validated.as_str(), &())?;
+    }
+
+    Ok(())
+}
+
+/// Removes an address from the allocation list. This can only be done before the campaign has started.
+/// Trying to remove an address that doesn't exist in the list won't result in an error.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env`  - The env context
+/// * `info` - The message info
+/// * `address` - The address to remove
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn remove_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::AllocationManagement)?;
+
+    assert_authorized(deps.as_ref(), &info.sender, Role::AllocationManager)?;
+
+    // Check if campaign has started
+    let campaign = CAMPAIGN.may_load(deps.storage)?;
+
+    if let Some(campaign) = campaign {
+        campaign.ensure_phase(
+            &env.block.time,
+            &[CampaignPhase::Upcoming, CampaignPhase::AllocationUpload],
+            "cannot remove an address allocation after campaign has started",
+        )?;
+    }
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+
+    if let Some(allocation) = ALLOCATIONS.may_load(deps.storage, address.as_str())? {
+        ALLOCATIONS.remove(deps.storage, address.as_str())?;
+        decrease_count(deps.storage, &ALLOCATIONS_COUNT)?;
+        adjust_total_allocated(deps.storage, Uint128::zero(), allocation.amount)?;
+        count_in_tag_stats(deps.storage, &allocation, false)?;
+    }
+    remove_address_state(deps.storage, &address)?;
+
+    Ok(Response::default()
+        .add_attributes(AllocationsAttributes::Removed { address: &address }.into_attributes()))
+}
+
+/// Removes what's kept for a removed address besides its allocation, i.e. its pending
+/// replacement request, blacklist entry, freeze, hold and allowlist entry, so none of them
+/// persists for an address that's no longer in the protocol.
+fn remove_address_state(storage: &mut dyn Storage, address: &str) -> StdResult<()> {
+    ADDRESS_REPLACEMENT_REQUESTS.remove(storage, address);
+    if BLACKLIST.has(storage, address) {
+        BLACKLIST.remove(storage, address);
+        decrease_count(storage, &BLACKLIST_COUNT)?;
+    }
+    FROZEN.remove(storage, address);
+    HOLDS.remove(storage, address);
+    ALLOWLIST.remove(storage, address);
+
+    Ok(())
+}
+
+/// Blacklists or unblacklists an address. This can be done at any time. Who blacklisted the
+/// address, when and why is recorded for auditing purposes.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `address` - The address to blacklist/unblacklist
+/// * `blacklist` - Whether to blacklist or unblacklist
+/// * `reason` - The reason for blacklisting the address
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn blacklist_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    blacklist: bool,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+    validate_blacklist_reason(&reason)?;
+
+    // Prevent blacklisting the owner, or the pending owner about to become it
+    let ownership = cw_ownable::get_ownership(deps.storage)?;
+    if let Some(owner) = ownership.owner {
+        ensure!(
+            owner.to_string() != address,
+            ContractError::CampaignError {
+                reason: "Cannot blacklist the campaign owner".to_string(),
+            }
+        );
+    }
+    if let Some(pending_owner) = ownership.pending_owner {
+        ensure!(
+            pending_owner.to_string() != address,
+            ContractError::CampaignError {
+                reason: "Cannot blacklist the pending owner".to_string(),
+            }
+        );
+    }
+
+    set_blacklisted(deps, &env, &info.sender, &address, blacklist, reason)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "blacklist_address".to_string())
+        .add_attribute("address", address)
+        .add_attribute("blacklisted", blacklist.to_string()))
+}
+
+/// Blacklists or unblacklists a batch of addresses. This can be done at any time. The owner and
+/// the pending owner are skipped, and reported in the `skipped` attribute, instead of failing the
+/// whole batch.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `addresses` - Vector of addresses to blacklist/unblacklist
+/// * `blacklist` - Whether to blacklist or unblacklist the addresses
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn manage_blacklist(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+    blacklist: bool,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    ensure!(
+        addresses.len() <= MAX_BLACKLIST_BATCH_SIZE,
+        ContractError::BatchSizeLimitExceeded {
+            actual: addresses.len(),
+            max: MAX_BLACKLIST_BATCH_SIZE,
+        }
+    );
+    ensure!(
+        !addresses.is_empty(),
+        ContractError::InvalidInput {
+            reason: "addresses cannot be empty".to_string(),
+        }
+    );
+
+    let ownership = cw_ownable::get_ownership(deps.storage)?;
+    let mut skipped = vec![];
+    for address in addresses.iter() {
+        let address = validate_raw_address(deps.as_ref(), address)?;
+
+        if ownership
+            .owner
+            .as_ref()
+            .is_some_and(|owner| owner.as_str() == address)
+            || ownership
+                .pending_owner
+                .as_ref()
+                .is_some_and(|pending_owner| pending_owner.as_str() == address)
+        {
+            skipped.push(address);
+            continue;
+        }
+
+        set_blacklisted(deps.branch(), &env, &info.sender, &address, blacklist, None)?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "manage_blacklist".to_string()),
+        ("count", (addresses.len() - skipped.len()).to_string()),
+        ("blacklisted", blacklist.to_string()),
+        ("skipped", skipped.join(",")),
+    ]))
+}
+
+/// Blacklists or unblacklists a validated address, keeping the blacklist count in sync.
+fn set_blacklisted(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    address: &str,
+    blacklist: bool,
+    reason: Option<String>,
+) -> Result<(), ContractError> {
+    let already_blacklisted = is_blacklisted(deps.as_ref(), address)?;
+    if blacklist && !already_blacklisted {
+        BLACKLIST.save(
+            deps.storage,
+            address,
+            &BlacklistEntry {
+                blacklisted_by: sender.to_string(),
+                blacklisted_at: env.block.time.seconds(),
+                reason,
+            },
+        )?;
+        increase_count(deps.storage, &BLACKLIST_COUNT)?;
+    } else if !blacklist && already_blacklisted {
+        BLACKLIST.remove(deps.storage, address);
+        decrease_count(deps.storage, &BLACKLIST_COUNT)?;
+    }
+
+    Ok(())
+}
+
+/// Freezes an address under investigation. The frozen addresses can't claim, and are left out of
+/// the redistributions until the freeze is resolved. Who froze the address, when and why is
+/// recorded for auditing purposes.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `address` - The address to freeze
+/// * `reason` - The reason for freezing the address
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn freeze_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+    validate_blacklist_reason(&reason)?;
+
+    let ownership = cw_ownable::get_ownership(deps.storage)?;
+    ensure!(
+        ownership
+            .owner
+            .is_none_or(|owner| owner.as_str() != address),
+        ContractError::CampaignError {
+            reason: "Cannot freeze the campaign owner".to_string(),
+        }
+    );
+    ensure!(
+        ownership
+            .pending_owner
+            .is_none_or(|pending_owner| pending_owner.as_str() != address),
+        ContractError::CampaignError {
+            reason: "Cannot freeze the pending owner".to_string(),
+        }
+    );
+    ensure!(
+        !is_blacklisted(deps.as_ref(), address.as_str())?,
+        ContractError::AddressBlacklisted
+    );
+    ensure!(
+        !is_frozen(deps.as_ref(), address.as_str())?,
+        ContractError::AddressFrozen
+    );
+
+    FROZEN.save(
+        deps.storage,
+        address.as_str(),
+        &FrozenEntry {
+            frozen_by: info.sender.to_string(),
+            frozen_at: env.block.time.seconds(),
+            reason,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("freeze_address")
+            .add("address", address)
+            .build(),
+    ))
+}
+
+/// Holds the claims of an address until the given time, or lifts the hold if no time is given.
+/// The hold expires on its own and, unlike the blacklist, doesn't forfeit the allocation.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `address` - The address to hold
+/// * `until` - The time until which the claims are held, in seconds
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn hold_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    until: Option<u64>,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+
+    match until {
+        Some(until) => {
+            ensure!(
+                until > env.block.time.seconds(),
+                ContractError::InvalidInput {
+                    reason: "the hold must end in the future".to_string(),
+                }
+            );
+
+            let ownership = cw_ownable::get_ownership(deps.storage)?;
+            ensure!(
+                ownership
+                    .owner
+                    .is_none_or(|owner| owner.as_str() != address),
+                ContractError::CampaignError {
+                    reason: "Cannot hold the campaign owner".to_string(),
+                }
+            );
+
+            HOLDS.save(
+                deps.storage,
+                address.as_str(),
+                &HoldEntry {
+                    held_by: info.sender.to_string(),
+                    held_at: env.block.time.seconds(),
+                    until,
+                },
+            )?;
+        }
+        None => HOLDS.remove(deps.storage, address.as_str()),
+    }
+
+    Ok(Response::default()
+        .add_event(
+            HoldEvent {
+                address: address.as_str(),
+                updated_by: info.sender.as_str(),
+                until,
+            }
+            .into_event(),
+        )
+        .add_attributes(
+            ActionAttributes::new("hold_address")
+                .add("address", &address)
+                .build(),
+        ))
+}
+
+/// Fails if the claims of the address are on hold.
+fn ensure_not_on_hold(deps: Deps, address: &str, now: &Timestamp) -> Result<(), ContractError> {
+    match active_hold(deps, address, now)? {
+        Some(hold) => Err(ContractError::AddressOnHold { until: hold.until }),
+        None => Ok(()),
+    }
+}
+
+/// Resolves the freeze of an address, either forfeiting its allocation by blacklisting it with
+/// the reason of the freeze, or releasing it so it can claim again.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `info` - The message info
+/// * `address` - The frozen address
+/// * `action` - The resolution of the freeze
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn resolve_frozen(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    action: FrozenResolution,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+    let entry = FROZEN
+        .may_load(deps.storage, address.as_str())?
+        .ok_or_else(|| ContractError::InvalidInput {
+            reason: format!("{address} is not frozen"),
+        })?;
+    FROZEN.remove(deps.storage, address.as_str());
+
+    // an address blacklisted while frozen keeps its original blacklist entry
+    if action == FrozenResolution::Forfeit {
+        set_blacklisted(deps, &env, &info.sender, &address, true, entry.reason)?;
+    }
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("resolve_frozen")
+            .add("address", address)
+            .add(
+                "resolution",
+                match action {
+                    FrozenResolution::Forfeit => "forfeit",
+                    FrozenResolution::Release => "release",
+                },
+            )
+            .build(),
+    ))
+}
+
+/// Validates the reason for blacklisting an address, if any.
+fn validate_blacklist_reason(reason: &Option<String>) -> Result<(), ContractError> {
+    if let Some(reason) = reason {
+        ensure!(
+            !reason.is_empty() && reason.len() <= MAX_BLACKLIST_REASON_LENGTH,
+            ContractError::InvalidInput {
+                reason: format!(
+                    "blacklist reason must be between 1 and {MAX_BLACKLIST_REASON_LENGTH} characters long"
+                )
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Adds or removes a batch of addresses from the allowlist. This can be done at any time.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `addresses` - Vector of addresses to add to/remove from the allowlist
+/// * `allowlist` - Whether to add or remove the addresses from the allowlist
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn manage_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    addresses: Vec<String>,
+    allowlist: bool,
+) -> Result<Response, ContractError> {
+    assert_authorized(deps.as_ref(), &info.sender, Role::BlacklistManager)?;
+
+    // Check batch size limit
+    let max_batch_size = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .limits
+        .max_allowlist_batch_size
+        .map_or(MAX_ALLOWLIST_BATCH_SIZE, |max| max as usize);
+    ensure!(
+        addresses.len() <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: addresses.len(),
+            max: max_batch_size,
+        }
+    );
+
+    ensure!(
+        !addresses.is_empty(),
+        ContractError::InvalidInput {
+            reason: "addresses cannot be empty".to_string(),
+        }
+    );
+
+    for address in addresses.iter() {
+        let address = validate_raw_address(deps.as_ref(), address)?;
+
+        if allowlist {
+            ALLOWLIST.save(deps.storage, address.as_str(), &())?;
+        } else {
+            ALLOWLIST.remove(deps.storage, address.as_str());
+        }
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "manage_allowlist".to_string()),
+        ("count", addresses.len().to_string()),
+        ("allowlisted", allowlist.to_string()),
+    ]))
+}
+
+/// Locks the payouts of an allocation to a pre-approved address, or removes the lock. Only the
+/// owner can lock receivers.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `info` - The message info
+/// * `address` - The address of the allocation
+/// * `locked_receiver` - The address the claims pay out to, or None to remove the lock
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn set_locked_receiver(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    locked_receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let address = validate_raw_address(deps.as_ref(), &address)?;
+    let locked_receiver = locked_receiver
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    ALLOCATIONS.update(deps.storage, address.as_str(), |allocation| {
+        let mut allocation = allocation.ok_or(ContractError::NoAllocationFound {
+            address: address.clone(),
+        })?;
+        allocation.locked_receiver = locked_receiver.clone();
+        Ok::<_, ContractError>(allocation)
+    })?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "set_locked_receiver".to_string()),
+        ("address", address),
+        (
+            "locked_receiver",
+            locked_receiver
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
+        ),
+    ]))
+}
+
+/// Forfeits the allocations whose claim window expired, sending what's left unclaimed to the
+/// community pool of the campaign. Anyone can forfeit the expired allocations, as the tokens can
+/// only go to the community pool.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `addresses` - The addresses whose allocations expired
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with the forfeited tokens sent to the
+///   community pool
+pub fn forfeit_expired_allocations(
+    deps: DepsMut,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "not started",
+    )?;
+
+    let community_pool = campaign
+        .community_pool
+        .clone()
+        .ok_or(ContractError::CampaignError {
+            reason: "the campaign has no claim window".to_string(),
+        })?;
+
+    let max_batch_size = max_allocation_batch_size(deps.as_ref())?;
+    ensure!(
+        addresses.len() <= max_batch_size,
+        ContractError::BatchSizeLimitExceeded {
+            actual: addresses.len(),
+            max: max_batch_size,
+        }
+    );
+
+    let mut forfeited_total = Uint128::zero();
+    let mut forfeited_count = 0usize;
+    for address in addresses {
+        let address = validate_raw_address(deps.as_ref(), &address)?;
+        if FORFEITED.has(deps.storage, address.as_str()) {
+            continue;
+        }
+
+        let allocation = ALLOCATIONS
+            .may_load(deps.storage, address.as_str())?
+            .ok_or(ContractError::NoAllocationFound {
+                address: address.clone(),
+            })?;
+        let expires_at = helpers::claim_window_expiry(deps.as_ref(), &campaign, &address)?;
+        ensure!(
+            expires_at.is_some_and(|expires_at| env.block.time >= expires_at),
+            ContractError::InvalidInput {
+                reason: format!("the claim window of {address} hasn't expired"),
+            }
+        );
+
+        let unclaimed = allocation
+            .amount
+            .saturating_sub(get_total_claims_amount_for_address(
+                deps.as_ref(),
+                &address,
+            )?);
+        FORFEITED.save(deps.storage, address.as_str(), &unclaimed)?;
+        forfeited_total = forfeited_total.checked_add(unclaimed)?;
+        forfeited_count += 1;
+    }
+
+    ensure!(!forfeited_total.is_zero(), ContractError::NothingToClaim);
+
+    let previously_forfeited = FORFEITED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+    FORFEITED_TOTAL.save(
+        deps.storage,
+        &previously_forfeited.checked_add(forfeited_total)?,
+    )?;
+
+    let forfeited = coin(forfeited_total.u128(), &campaign.total_reward.denom);
+    let mint_message = match campaign.reward_source {
+        RewardSource::PreFunded => {
+            helpers::spend_budget(deps.storage, forfeited_total)?;
+            None
+        }
+        RewardSource::MintOnClaim => Some(stargate::mint_msg(&env.contract.address, &forfeited)),
+    };
+
+    Ok(Response::default()
+        .add_messages(mint_message)
+        .add_message(BankMsg::Send {
+            to_address: community_pool.clone(),
+            amount: vec![forfeited.clone()],
+        })
+        .add_event(
+            TransferIntent {
+                kind: TransferKind::Forfeit,
+                from: env.contract.address.as_str(),
+                to: community_pool.as_str(),
+                amount: &forfeited,
+            }
+            .into_event(),
+        )
+        .add_attributes(
+            ActionAttributes::new("forfeit_expired_allocations")
+                .add("count", forfeited_count)
+                .add("amount", &forfeited)
+                .build(),
+        ))
+}
+
+/// Accepts the terms and conditions of the campaign on behalf of the sender. The hash must match
+/// the current terms of the campaign, so a user can't accept outdated terms. Accepting again, i.e.
+/// after the terms were updated, overwrites the previous acceptance.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `terms_hash` - The hash of the terms accepted
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn accept_terms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    terms_hash: HexBinary,
+) -> Result<Response, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    ensure!(
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
+    );
+
+    let current_hash = campaign.terms_hash.ok_or(ContractError::InvalidInput {
+        reason: "the campaign has no terms to accept".to_string(),
+    })?;
+    ensure!(
+        terms_hash == current_hash,
+        ContractError::InvalidInput {
+            reason: format!("the terms {terms_hash} are not the current terms {current_hash}"),
+        }
+    );
+
+    TERMS_ACCEPTANCES.save(
+        deps.storage,
+        info.sender.as_str(),
+        &(terms_hash.clone(), env.block.time),
+    )?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("accept_terms")
+            .add("address", &info.sender)
+            .add("terms_hash", terms_hash)
+            .build(),
+    ))
+}
+
+/// Tops up the campaign with the reward denom sent along. The deposit must be a single coin of the
+/// reward denom, and can't fund the campaign beyond what it still owes unless allowed. The
+/// deposit is recorded in the funding history.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `allow_overfund` - Whether to accept funding the campaign beyond what it still owes
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn top_up_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    allow_overfund: bool,
+) -> Result<Response, ContractError> {
+    let deposit = cw_utils::one_coin(&info)?;
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    ensure!(
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
+    );
+    ensure!(
+        campaign.reward_source == RewardSource::PreFunded,
+        ContractError::CampaignError {
+            reason: "the campaign mints its rewards on claim".to_string(),
+        }
+    );
+    ensure!(
+        deposit.denom == campaign.total_reward.denom,
+        ContractError::InvalidDenom {
+            denom: deposit.denom,
+            reason: format!("the campaign is funded in {}", campaign.total_reward.denom),
+        }
+    );
+
+    // the deposit is already part of the balance
+    if !allow_overfund {
+        let required = helpers::remaining_obligation(deps.as_ref(), &campaign)?;
+        let funded =
+            helpers::query_reward_balance(deps.as_ref(), &env.contract.address, &campaign)?.amount;
+        ensure!(
+            funded <= required,
+            ContractError::CampaignOverfunded { required, funded }
+        );
+    }
+
+    let contributed = FUNDERS.may_load(deps.storage, info.sender.as_str())?;
+    if contributed.is_none() {
+        ensure!(
+            FUNDERS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .take(MAX_FUNDERS)
+                .count()
+                < MAX_FUNDERS,
+            ContractError::InvalidInput {
+                reason: format!("there can be at most {MAX_FUNDERS} funders"),
+            }
+        );
+    }
+    FUNDERS.save(
+        deps.storage,
+        info.sender.as_str(),
+        &contributed
+            .unwrap_or_default()
+            .checked_add(deposit.amount)?,
+    )?;
+
+    let sequence = record_funding(
+        deps.storage,
+        &FundingRecord {
+            funder: info.sender.clone(),
+            amount: deposit.amount,
+            deposited_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("top_up_campaign")
+            .add("funder", &info.sender)
+            .add("amount", &deposit)
+            .add("sequence", sequence)
+            .build(),
+    ))
+}
+
+/// Creates a group sharing a pool of the campaign rewards, vested following its own schedule. The
+/// pools of the groups are paid out of the campaign total reward along with the allocations, so
+/// together they can't exceed it.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `name` - The name of the group
+/// * `schedule` - The vesting schedule of the pool
+/// * `total` - The size of the pool
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn create_group(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    schedule: Vec<DistributionType>,
+    total: Uint128,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Upcoming, CampaignPhase::AllocationUpload],
+        "groups can only be created before the campaign starts",
+    )?;
+
+    ensure!(
+        !name.is_empty() && name.len() <= MAX_GROUP_NAME_LENGTH,
+        ContractError::InvalidInput {
+            reason: format!(
+                "the group name must be between 1 and {MAX_GROUP_NAME_LENGTH} characters"
+            ),
+        }
+    );
+    ensure!(
+        !GROUPS.has(deps.storage, &name),
+        ContractError::InvalidInput {
+            reason: format!("the group {name} already exists"),
+        }
+    );
+    ensure!(
+        !total.is_zero(),
+        ContractError::InvalidInput {
+            reason: "the group total cannot be zero".to_string(),
+        }
+    );
+
+    // the bonuses and the raffle are reserved on top of the campaign allocations
+    ensure!(
+        schedule.iter().all(|distribution| matches!(
+            distribution,
+            DistributionType::LinearVesting { .. }
+                | DistributionType::CurveVesting { .. }
+                | DistributionType::LumpSum { .. }
+        )),
+        ContractError::InvalidCampaignParam {
+            param: "schedule".to_string(),
+            reason: "only linear, curve and lump sum distributions are supported".to_string(),
+        }
+    );
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    CampaignParams {
+        distribution_type: schedule.clone(),
+        start_time: campaign.start_time,
+        end_time: campaign.end_time,
+        ..Default::default()
+    }
+    .validate_campaign_distribution(
+        config
+            .limits
+            .max_distribution_slots
+            .unwrap_or(DEFAULT_MAX_DISTRIBUTION_SLOTS),
+    )?;
+
+    ensure!(
+        GROUPS
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .count()
+            < MAX_GROUPS,
+        ContractError::InvalidInput {
+            reason: format!("there can be at most {MAX_GROUPS} groups"),
+        }
+    );
+    helpers::ensure_group_pools_covered(deps.storage, total)?;
+
+    GROUPS.save(
+        deps.storage,
+        &name,
+        &Group {
+            schedule,
+            total,
+            total_weight: Uint128::zero(),
+            members: 0,
+            claimed: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("create_group")
+            .add("group", &name)
+            .add("total", coin(total.u128(), &campaign.total_reward.denom))
+            .build(),
+    ))
+}
+
+/// Adds members to a group, or updates their weights, and removes members from it. The members
+/// can only be managed before the campaign starts, so the shares don't change once claimable.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `name` - The name of the group
+/// * `add` - The members to add or update, with their weights
+/// * `remove` - The members to remove
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn update_group_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    add: Vec<(String, Uint128)>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
-    let available_funds = deps
-        .querier
-        .query_balance(env.contract.address, &campaign.total_reward.denom)?;
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Upcoming, CampaignPhase::AllocationUpload],
+        "group members can only be managed before the campaign starts",
+    )?;
 
+    let batch_size = add.len().saturating_add(remove.len());
     ensure!(
-        actual_claim_amount_coin.amount <= available_funds.amount,
-        ContractError::CampaignError {
-            reason: "no funds available to claim".to_string()
+        batch_size <= MAX_GROUP_MEMBERS_BATCH_SIZE,
+        ContractError::BatchSizeLimitExceeded {
+            actual: batch_size,
+            max: MAX_GROUP_MEMBERS_BATCH_SIZE,
         }
     );
 
-    let mut claims_to_record: HashMap<DistributionSlot, Claim> = HashMap::new();
-    let mut remaining_to_distribute = actual_claim_amount_coin.amount;
+    let mut group = GROUPS
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::GroupNotFound { name: name.clone() })?;
 
-    // remaining_to_distribute is guaranteed to be > 0 from earlier validation
-    let mut lump_sum_slots_with_new_claims: Vec<DistributionSlot> = vec![];
-    let mut linear_vesting_slots_with_new_claims: Vec<DistributionSlot> = vec![];
+    for (address, weight) in &add {
+        let address = deps.api.addr_validate(address)?;
+        ensure!(
+            !weight.is_zero(),
+            ContractError::InvalidInput {
+                reason: format!("the weight of {address} cannot be zero"),
+            }
+        );
 
-    for (idx, dist_type) in campaign.distribution_type.iter().enumerate() {
-        if new_claims.contains_key(&idx) {
-            // Only consider slots that have new claimable amounts
-            match dist_type {
-                DistributionType::LumpSum { .. } => lump_sum_slots_with_new_claims.push(idx),
-                DistributionType::LinearVesting { .. } => {
-                    linear_vesting_slots_with_new_claims.push(idx)
-                }
+        let key = (name.as_str(), address.as_str());
+        match GROUP_MEMBERS.may_load(deps.storage, key)? {
+            Some(member) => {
+                group.total_weight = group.total_weight.checked_sub(member.weight)?;
             }
+            None => group.members = group.members.saturating_add(1),
         }
-    }
+        group.total_weight = group.total_weight.checked_add(*weight)?;
 
-    lump_sum_slots_with_new_claims.sort();
-    linear_vesting_slots_with_new_claims.sort();
+        GROUP_MEMBERS.save(
+            deps.storage,
+            key,
+            &GroupMember {
+                weight: *weight,
+                claims: HashMap::new(),
+            },
+        )?;
+    }
 
-    // Helper function to distribute tokens to a list of slots
-    let distribute_to_slots =
-        |slots: Vec<DistributionSlot>,
-         remaining: &mut Uint128,
-         claims: &mut HashMap<DistributionSlot, Claim>| {
-            for slot_idx in slots {
-                if *remaining == Uint128::zero() {
-                    break;
-                }
-                // new_claims.get(&slot_idx) is guaranteed to return Some since slot_idx comes from slots with new claims
-                let (available_from_slot, _) = new_claims
-                    .get(&slot_idx)
-                    .expect("slot_idx must exist in new_claims");
-                let take_from_slot = std::cmp::min(*remaining, *available_from_slot);
-                if take_from_slot > Uint128::zero() {
-                    claims.insert(slot_idx, (take_from_slot, env.block.time.seconds()));
-                    *remaining = remaining.saturating_sub(take_from_slot);
-                }
+    for address in &remove {
+        let key = (name.as_str(), address.as_str());
+        let member = GROUP_MEMBERS.may_load(deps.storage, key)?.ok_or_else(|| {
+            ContractError::NotGroupMember {
+                group: name.clone(),
+                address: address.to_string(),
             }
-        };
+        })?;
 
-    // Phase 1: Distribute to LumpSum slots from new_claims
-    distribute_to_slots(
-        lump_sum_slots_with_new_claims,
-        &mut remaining_to_distribute,
-        &mut claims_to_record,
-    );
+        group.total_weight = group.total_weight.checked_sub(member.weight)?;
+        group.members = group.members.saturating_sub(1);
+        GROUP_MEMBERS.remove(deps.storage, key);
+    }
 
-    // Phase 2: Distribute remaining to LinearVesting slots from new_claims
-    distribute_to_slots(
-        linear_vesting_slots_with_new_claims,
-        &mut remaining_to_distribute,
-        &mut claims_to_record,
-    );
+    GROUPS.save(deps.storage, &name, &group)?;
+
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("update_group_members")
+            .add("group", &name)
+            .add("added", add.len())
+            .add("removed", remove.len())
+            .add("members", group.members)
+            .add("total_weight", group.total_weight)
+            .build(),
+    ))
+}
+
+/// Claims the share of the sender in a group pool that has vested so far. The claims of the group
+/// members go through the same terms and cooldown checks as the allocation claims. The claim
+/// window doesn't apply, as it's tracked per allocation, so the group pools can't be forfeited.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `info` - The message info
+/// * `name` - The name of the group
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with the claimed tokens sent to the sender
+pub fn claim_group(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::Claims)?;
+
+    let mut campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    campaign.ensure_phase(
+        &env.block.time,
+        &[CampaignPhase::Active, CampaignPhase::Ended],
+        "not started",
+    )?;
 
-    // Enforce the invariant that all requested tokens have been distributed
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    ensure!(!config.paused, ContractError::ClaimsPaused);
     ensure!(
-        remaining_to_distribute == Uint128::zero(),
-        ContractError::CampaignError {
-            reason: format!(
-                "Distribution error: {remaining_to_distribute} tokens remain undistributed. This indicates a bug in the claimable amount calculation."
-            )
-        }
+        !is_blacklisted(deps.as_ref(), info.sender.as_str())?,
+        ContractError::AddressBlacklisted
     );
+    ensure!(
+        !is_frozen(deps.as_ref(), info.sender.as_str())?,
+        ContractError::AddressFrozen
+    );
+    ensure_not_on_hold(deps.as_ref(), info.sender.as_str(), &env.block.time)?;
+    helpers::ensure_funded(deps.as_ref(), &env.contract.address, &campaign)?;
 
-    let updated_claims = helpers::aggregate_claims(&previous_claims, &claims_to_record)?;
+    let mut group = GROUPS
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::GroupNotFound { name: name.clone() })?;
+    let key = (name.as_str(), info.sender.as_str());
+    let mut member = GROUP_MEMBERS.may_load(deps.storage, key)?.ok_or_else(|| {
+        ContractError::NotGroupMember {
+            group: name.clone(),
+            address: info.sender.to_string(),
+        }
+    })?;
 
-    campaign.claimed.amount = campaign
-        .claimed
-        .amount
-        .checked_add(actual_claim_amount_coin.amount)?;
+    helpers::ensure_terms_accepted(deps.storage, &campaign, info.sender.as_str())?;
 
-    CAMPAIGN.save(deps.storage, &campaign)?;
-    CLAIMS.save(deps.storage, receiver.to_string(), &updated_claims)?;
+    let (_, (claimable, new_claims, previous_claims)) =
+        helpers::compute_group_claimable_amount(&campaign, &group, &member, &env.block.time)?;
 
-    // Calculate total claims from updated_claims instead of making another storage call
-    let total_claimed = updated_claims
-        .iter()
-        .fold(Uint128::zero(), |acc, (_, (amount, _))| {
-            acc.checked_add(*amount).unwrap()
-        });
+    helpers::ensure_claim_cooldown_elapsed(&campaign, &previous_claims, &env.block.time)?;
+    ensure!(!claimable.amount.is_zero(), ContractError::NothingToClaim);
 
-    ensure!(
-        total_user_allocation >= total_claimed,
-        ContractError::ExceededMaxClaimAmount
-    );
+    let mint_message = match campaign.reward_source {
+        RewardSource::PreFunded => {
+            helpers::spend_budget(deps.storage, claimable.amount)?;
+
+            None
+        }
+        RewardSource::MintOnClaim => Some(stargate::mint_msg(&env.contract.address, &claimable)),
+    };
+
+    member.claims = helpers::aggregate_claims(&previous_claims, &new_claims)?;
+    group.claimed = group.claimed.checked_add(claimable.amount)?;
+    campaign.claimed.amount = campaign.claimed.amount.checked_add(claimable.amount)?;
+
+    GROUP_MEMBERS.save(deps.storage, key, &member)?;
+    GROUPS.save(deps.storage, &name, &group)?;
+    CAMPAIGN.save(deps.storage, &campaign)?;
 
     Ok(Response::default()
+        .add_messages(mint_message)
         .add_message(BankMsg::Send {
-            to_address: receiver.to_string(),
-            amount: vec![actual_claim_amount_coin.clone()],
+            to_address: info.sender.to_string(),
+            amount: vec![claimable.clone()],
         })
-        .add_attributes(vec![
-            ("action", "claim".to_string()),
-            ("receiver", receiver.to_string()),
-            ("claimed_amount", actual_claim_amount_coin.to_string()),
-        ]))
+        .add_event(
+            TransferIntent {
+                kind: TransferKind::Claim,
+                from: env.contract.address.as_str(),
+                to: info.sender.as_str(),
+                amount: &claimable,
+            }
+            .into_event(),
+        )
+        .add_attributes(
+            ActionAttributes::new("claim_group")
+                .add("group", &name)
+                .add("receiver", &info.sender)
+                .add("claimed_amount", &claimable)
+                .build(),
+        ))
 }
 
-/// Adds a batch of addresses and their allocations. This can only be done before the campaign has started.
+/// Sets the budget of the campaign, the amount of the reward denom it can pay out of the contract
+/// balance. The claims can't exceed the budget, and closing the campaign only refunds what's left
+/// of it. Only the owner can set the budget, before the campaign is closed.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
-/// * `env`  - The env context
+/// * `env` - The env context
 /// * `info` - The message info
-/// * `allocations` - Vector of (address, amount) pairs
+/// * `amount` - The budget
 ///
 /// # Returns
 /// * `Result<Response, ContractError>` - The response with attributes
-pub fn add_allocations(
+pub fn set_budget(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    allocations: Vec<(String, Uint128)>,
+    amount: Uint128,
 ) -> Result<Response, ContractError> {
-    assert_authorized(deps.as_ref(), &info.sender)?;
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
-    // Check batch size limit
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
     ensure!(
-        allocations.len() <= MAX_ALLOCATION_BATCH_SIZE,
-        ContractError::BatchSizeLimitExceeded {
-            actual: allocations.len(),
-            max: MAX_ALLOCATION_BATCH_SIZE,
-        }
+        campaign.phase(&env.block.time) != CampaignPhase::Closed,
+        ContractError::CampaignClosed
     );
 
-    // Check if campaign has started
-    let campaign = CAMPAIGN.may_load(deps.storage)?;
-
-    if let Some(campaign) = campaign {
-        ensure!(
-            !campaign.has_started(&env.block.time),
-            ContractError::CampaignError {
-                reason: "cannot upload allocations after campaign has started".to_string(),
-            }
-        );
-    }
-
-    let allocations_len = allocations.len().to_string();
-
-    for (address_raw, amount) in allocations.into_iter() {
-        let validated_receiver_string = validate_raw_address(deps.as_ref(), &address_raw)?;
-
-        ensure!(
-            !ALLOCATIONS.has(deps.storage, validated_receiver_string.as_str()),
-            ContractError::AllocationAlreadyExists {
-                address: validated_receiver_string.clone(),
-            }
-        );
-        ALLOCATIONS.save(deps.storage, validated_receiver_string.as_str(), &amount)?;
-    }
+    let used = BUDGET
+        .may_load(deps.storage)?
+        .map(|budget| budget.used)
+        .unwrap_or_default();
+    ensure!(
+        amount >= used,
+        ContractError::InvalidInput {
+            reason: format!("the budget cannot be lower than the {used} already used"),
+        }
+    );
+    BUDGET.save(deps.storage, &Budget { amount, used })?;
 
-    Ok(Response::default()
-        .add_attribute("action", "add_allocations")
-        .add_attribute("count", allocations_len))
+    Ok(Response::default().add_attributes(
+        ActionAttributes::new("set_budget")
+            .add("amount", coin(amount.u128(), &campaign.total_reward.denom))
+            .add("used", used)
+            .build(),
+    ))
 }
 
-/// Replaces an address in the allocation list. This can be done at any time during the campaign.
+/// Withdraws the tokens of the sender's locks that have expired. The locks survive the closing of
+/// the campaign, so the tokens can be withdrawn at any time once unlocked.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
+/// * `env` - The env context
 /// * `info` - The message info
-/// * `old_address` - The old address to replace
-/// * `new_address` - The new address to use
 ///
 /// # Returns
-/// * `Result<Response, ContractError>` - The response with attributes
-pub fn replace_address(
+/// * `Result<Response, ContractError>` - The response with the unlocked tokens sent to the sender
+pub fn withdraw_unlocked(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    old_address_raw: String,
-    new_address_raw: String,
 ) -> Result<Response, ContractError> {
-    assert_authorized(deps.as_ref(), &info.sender)?;
-
-    let old_address_canonical = validate_raw_address(deps.as_ref(), &old_address_raw)?;
-    // New address should be validated the same way as when adding allocations
-    let new_address_validated = validate_raw_address(deps.as_ref(), &new_address_raw)?;
-
-    let old_allocation = ALLOCATIONS
-        .may_load(deps.storage, old_address_canonical.as_str())?
-        .ok_or(ContractError::NoAllocationFound {
-            address: old_address_raw.clone(),
-        })?;
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
 
-    // Ensure the new address doesn't have an allocation already
-    ensure!(
-        !ALLOCATIONS.has(deps.storage, new_address_validated.as_str()),
-        ContractError::AllocationAlreadyExists {
-            address: new_address_raw.clone()
-        }
-    );
-    ALLOCATIONS.remove(deps.storage, old_address_canonical.as_str());
-    ALLOCATIONS.save(
-        deps.storage,
-        new_address_validated.as_str(),
-        &old_allocation,
-    )?;
+    let unlocked = LOCKS
+        .prefix(info.sender.as_str())
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(env.block.time.seconds())),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
 
-    // Update claims and blacklist if the address has claimed rewards or is blacklisted
-    let claims = get_claims_for_address(deps.as_ref(), old_address_canonical.clone())?;
-    if !claims.is_empty() {
-        CLAIMS.remove(deps.storage, old_address_canonical.clone());
-        CLAIMS.save(deps.storage, new_address_validated.to_string(), &claims)?;
+    let mut amount = Uint128::zero();
+    for (unlocks_at, locked) in unlocked {
+        LOCKS.remove(deps.storage, (info.sender.as_str(), unlocks_at));
+        amount = amount.checked_add(locked)?;
     }
+    ensure!(!amount.is_zero(), ContractError::NothingToClaim);
 
-    if is_blacklisted(deps.as_ref(), old_address_canonical.as_str())? {
-        BLACKLIST.remove(deps.storage, old_address_canonical.as_str());
-        BLACKLIST.save(deps.storage, new_address_validated.as_str(), &())?;
-    }
+    let locked_total = LOCKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+    LOCKED_TOTAL.save(deps.storage, &locked_total.checked_sub(amount)?)?;
 
-    Ok(Response::default().add_attributes(vec![
-        ("action", "replace_address".to_string()),
-        ("old_address", old_address_raw),
-        ("new_address", new_address_raw),
-    ]))
+    let unlocked = coin(amount.u128(), &campaign.total_reward.denom);
+
+    Ok(Response::default()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![unlocked.clone()],
+        })
+        .add_event(
+            TransferIntent {
+                kind: TransferKind::Unlock,
+                from: env.contract.address.as_str(),
+                to: info.sender.as_str(),
+                amount: &unlocked,
+            }
+            .into_event(),
+        )
+        .add_attributes(
+            ActionAttributes::new("withdraw_unlocked")
+                .add("receiver", &info.sender)
+                .add("amount", &unlocked)
+                .build(),
+        ))
 }
 
-/// Removes an address from the allocation list. This can only be done before the campaign has started.
-/// Trying to remove an address that doesn't exist in the list won't result in an error.
+/// Registers or unregisters contracts to be notified whenever a claim succeeds. Only the owner can
+/// manage the claim hooks. Note that a failing hook makes the claim fail as well.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
-/// * `env`  - The env context
 /// * `info` - The message info
-/// * `address` - The address to remove
+/// * `addresses` - Vector of contract addresses to register/unregister
+/// * `registered` - Whether to register or unregister the addresses
 ///
 /// # Returns
 /// * `Result<Response, ContractError>` - The response with attributes
-pub fn remove_address(
+pub fn manage_claim_hooks(
     deps: DepsMut,
-    env: Env,
     info: MessageInfo,
-    address: String,
+    addresses: Vec<String>,
+    registered: bool,
 ) -> Result<Response, ContractError> {
-    assert_authorized(deps.as_ref(), &info.sender)?;
+    // Only owner can manage the claim hooks
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
-    // Check if campaign has started
-    let campaign = CAMPAIGN.may_load(deps.storage)?;
+    ensure!(
+        !addresses.is_empty(),
+        ContractError::InvalidInput {
+            reason: "addresses cannot be empty".to_string(),
+        }
+    );
 
-    if let Some(campaign) = campaign {
-        ensure!(
-            !campaign.has_started(&env.block.time),
-            ContractError::CampaignError {
-                reason: "cannot remove an address allocation after campaign has started"
-                    .to_string(),
-            }
-        );
-    }
+    for address in addresses.iter() {
+        let validated_address = deps.api.addr_validate(address)?;
 
-    let address = validate_raw_address(deps.as_ref(), &address)?;
+        if registered {
+            CLAIM_HOOKS.save(deps.storage, validated_address.as_str(), &())?;
+        } else {
+            CLAIM_HOOKS.remove(deps.storage, validated_address.as_str());
+        }
+    }
 
-    ALLOCATIONS.remove(deps.storage, address.as_str());
+    let hooks_count = CLAIM_HOOKS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count();
 
-    // Also remove the blacklist entry when removing the address to maintain consistency
-    // This ensures blacklist doesn't persist for addresses that are no longer in the protocol
-    BLACKLIST.remove(deps.storage, address.as_str());
+    ensure!(
+        hooks_count <= MAX_CLAIM_HOOKS,
+        ContractError::BatchSizeLimitExceeded {
+            actual: hooks_count,
+            max: MAX_CLAIM_HOOKS,
+        }
+    );
 
-    Ok(Response::default()
-        .add_attribute("action", "remove_address")
-        .add_attribute("removed", address))
+    Ok(Response::default().add_attributes(vec![
+        ("action", "manage_claim_hooks".to_string()),
+        ("count", addresses.len().to_string()),
+        ("registered", registered.to_string()),
+    ]))
 }
 
-/// Blacklists or unblacklists an address. This can be done at any time.
+/// Grants or revokes roles to a wallet. Only the owner can manage the roles.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
 /// * `info` - The message info
-/// * `address` - The address to blacklist/unblacklist
-/// * `blacklist` - Whether to blacklist or unblacklist
+/// * `address` - The address to grant/revoke the roles to
+/// * `roles` - The roles to grant/revoke
+/// * `granted` - Whether to grant or revoke the roles
 ///
 /// # Returns
 /// * `Result<Response, ContractError>` - The response with attributes
-pub fn blacklist_address(
+pub fn manage_roles(
     deps: DepsMut,
     info: MessageInfo,
     address: String,
-    blacklist: bool,
+    roles: Vec<Role>,
+    granted: bool,
 ) -> Result<Response, ContractError> {
-    assert_authorized(deps.as_ref(), &info.sender)?;
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
-    let address = validate_raw_address(deps.as_ref(), &address)?;
+    ensure!(
+        !roles.is_empty(),
+        ContractError::InvalidInput {
+            reason: "roles cannot be empty".to_string(),
+        }
+    );
 
-    // Prevent blacklisting the owner
-    let ownership = cw_ownable::get_ownership(deps.storage)?;
-    if let Some(owner) = ownership.owner {
-        ensure!(
-            owner.to_string() != address,
-            ContractError::CampaignError {
-                reason: "Cannot blacklist the campaign owner".to_string(),
-            }
-        );
-    }
+    let address = deps.api.addr_validate(&address)?;
 
-    if blacklist {
-        BLACKLIST.save(deps.storage, address.as_str(), &())?;
-    } else {
-        BLACKLIST.remove(deps.storage, address.as_str());
+    for role in roles.iter() {
+        if granted {
+            WALLET_ROLES.save(deps.storage, (role.name(), address.as_str()), &())?;
+        } else {
+            WALLET_ROLES.remove(deps.storage, (role.name(), address.as_str()));
+        }
     }
 
-    Ok(Response::default()
-        .add_attribute("action", "blacklist_address".to_string())
-        .add_attribute("address", address)
-        .add_attribute("blacklisted", blacklist.to_string()))
+    Ok(Response::default().add_attributes(vec![
+        (
+            "action",
+            if granted {
+                "grant_roles"
+            } else {
+                "revoke_roles"
+            }
+            .to_string(),
+        ),
+        ("address", address.to_string()),
+        (
+            "roles",
+            roles
+                .iter()
+                .map(|role| role.name())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    ]))
 }
 
 /// Manages authorized wallets that can perform admin actions. Only the owner can manage the authorized wallets list.
+/// The wallets added are pending until the authorized wallet delay has elapsed, if set, while the
+/// removals are immediate.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
+/// * `env` - The env context
 /// * `info` - The message info
 /// * `addresses` - Vector of addresses to authorize/unauthorize
 /// * `authorized` - Whether to authorize or unauthorize the addresses
@@ -633,6 +5687,7 @@ pub fn blacklist_address(
 /// * `Result<Response, ContractError>` - The response with attributes
 pub fn manage_authorized_wallets(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     addresses: Vec<String>,
     authorized: bool,
@@ -656,13 +5711,29 @@ pub fn manage_authorized_wallets(
         }
     );
 
+    let delay = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .authorized_wallet_delay(&env.block.time);
+
     for address in addresses.iter() {
         let validated_address = deps.api.addr_validate(address)?;
 
-        if authorized {
-            AUTHORIZED_WALLETS.save(deps.storage, validated_address.as_str(), &())?;
-        } else {
+        if !authorized {
             AUTHORIZED_WALLETS.remove(deps.storage, validated_address.as_str());
+            PENDING_AUTHORIZED_WALLETS.remove(deps.storage, validated_address.as_str());
+        } else if delay == 0 {
+            AUTHORIZED_WALLETS.save(deps.storage, validated_address.as_str(), &())?;
+            PENDING_AUTHORIZED_WALLETS.remove(deps.storage, validated_address.as_str());
+        } else if !AUTHORIZED_WALLETS.has(deps.storage, validated_address.as_str()) {
+            // adding a pending wallet again doesn't restart its delay
+            PENDING_AUTHORIZED_WALLETS.update(
+                deps.storage,
+                validated_address.as_str(),
+                |activates_at| -> StdResult<_> {
+                    Ok(activates_at.unwrap_or(env.block.time.plus_seconds(delay)))
+                },
+            )?;
         }
     }
 
@@ -670,5 +5741,58 @@ pub fn manage_authorized_wallets(
         ("action", "manage_authorized_wallets".to_string()),
         ("count", addresses.len().to_string()),
         ("authorized", authorized.to_string()),
+        ("delay", delay.to_string()),
+    ]))
+}
+
+/// Activates the pending authorized wallets whose delay has elapsed. Anyone can activate them.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `addresses` - The pending authorized wallets to activate
+///
+/// # Returns
+/// * `Result<Response, ContractError>` - The response with attributes
+pub fn activate_authorized_wallets(
+    deps: DepsMut,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        addresses.len() <= MAX_AUTHORIZED_WALLETS_BATCH_SIZE,
+        ContractError::BatchSizeLimitExceeded {
+            actual: addresses.len(),
+            max: MAX_AUTHORIZED_WALLETS_BATCH_SIZE,
+        }
+    );
+
+    ensure!(
+        !addresses.is_empty(),
+        ContractError::InvalidInput {
+            reason: "addresses cannot be empty".to_string(),
+        }
+    );
+
+    for address in addresses.iter() {
+        let activates_at = PENDING_AUTHORIZED_WALLETS
+            .may_load(deps.storage, address)?
+            .ok_or_else(|| ContractError::InvalidInput {
+                reason: format!("{address} is not a pending authorized wallet"),
+            })?;
+        ensure!(
+            activates_at <= env.block.time,
+            ContractError::InvalidInput {
+                reason: format!("{address} can only be activated from {activates_at}"),
+            }
+        );
+
+        PENDING_AUTHORIZED_WALLETS.remove(deps.storage, address);
+        AUTHORIZED_WALLETS.save(deps.storage, address, &())?;
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "activate_authorized_wallets".to_string()),
+        ("addresses", addresses.join(",")),
     ]))
 }
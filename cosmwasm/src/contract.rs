@@ -2,9 +2,12 @@ use cosmwasm_std::{entry_point, to_json_binary};
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use cw2::set_contract_version;
 
-use crate::{commands, queries, validate_contract};
+use crate::state::CONFIG;
+use crate::{commands, guards, helpers, migrations, queries, validate_contract};
 use mantra_claimdrop_std::error::ContractError;
-use mantra_claimdrop_std::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use mantra_claimdrop_std::msg::{
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, PauseScope, QueryMsg, SudoMsg,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "mantra_claimdrop-contract";
@@ -25,6 +28,17 @@ pub fn instantiate(
     };
     cw_ownable::initialize_owner(deps.storage, deps.api, Some(owner.as_str()))?;
 
+    if let Some(limits) = msg.limits {
+        limits.validate()?;
+        CONFIG.save(
+            deps.storage,
+            &Config {
+                limits,
+                ..Default::default()
+            },
+        )?;
+    }
+
     let mut info = info.clone();
     info.sender = owner.clone();
     let mut response = Response::default()
@@ -50,6 +64,31 @@ pub fn instantiate(
 
 #[entry_point]
 pub fn execute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // the owner can lift a global pause, and the ownership can still be managed
+    if !matches!(
+        msg,
+        ExecuteMsg::SetPause { .. } | ExecuteMsg::UpdateOwnership(_)
+    ) {
+        helpers::assert_not_paused(deps.as_ref(), PauseScope::All)?;
+    }
+
+    let affected_addresses = guards::affected_addresses(&info, &msg);
+    let response = execute_msg(deps.branch(), env.clone(), info, msg)?;
+
+    if guards::INVARIANT_GUARDS_ENABLED {
+        guards::check_invariants(deps.as_ref(), &env, &affected_addresses, &response)?;
+    }
+
+    Ok(response)
+}
+
+/// Dispatches the execute messages to their commands.
+fn execute_msg(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
@@ -57,13 +96,166 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::ManageCampaign { action } => commands::manage_campaign(deps, env, info, action),
-        ExecuteMsg::Claim { receiver, amount } => {
+        ExecuteMsg::Claim {
+            receiver,
+            amount,
+            percentage,
+            slots,
+            into_vesting_account,
+            ibc_receiver,
+            lock_option,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::claim(
+                deps,
+                env,
+                info,
+                receiver,
+                amount,
+                percentage,
+                slots,
+                into_vesting_account.unwrap_or_default(),
+                ibc_receiver,
+                lock_option,
+            )
+        }
+        ExecuteMsg::ClaimUpTo { timestamp } => {
             cw_utils::nonpayable(&info)?;
-            commands::claim(deps, env, info, receiver, amount)
+            commands::claim_up_to(deps, env, info, timestamp)
         }
-        ExecuteMsg::AddAllocations { allocations } => {
+        ExecuteMsg::WithdrawUnlocked {} => {
             cw_utils::nonpayable(&info)?;
-            commands::add_allocations(deps, env, info, allocations)
+            commands::withdraw_unlocked(deps, env, info)
+        }
+        ExecuteMsg::ForfeitExpiredAllocations { addresses } => {
+            cw_utils::nonpayable(&info)?;
+            commands::forfeit_expired_allocations(deps, env, addresses)
+        }
+        ExecuteMsg::AcceptTerms { terms_hash } => {
+            cw_utils::nonpayable(&info)?;
+            commands::accept_terms(deps, env, info, terms_hash)
+        }
+        ExecuteMsg::TopUpCampaign { allow_overfund } => {
+            commands::top_up_campaign(deps, env, info, allow_overfund.unwrap_or_default())
+        }
+        ExecuteMsg::CreateGroup {
+            name,
+            schedule,
+            total,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::create_group(deps, env, info, name, schedule, total)
+        }
+        ExecuteMsg::UpdateGroupMembers { name, add, remove } => {
+            cw_utils::nonpayable(&info)?;
+            commands::update_group_members(deps, env, info, name, add, remove)
+        }
+        ExecuteMsg::ClaimGroup { name } => {
+            cw_utils::nonpayable(&info)?;
+            commands::claim_group(deps, env, info, name)
+        }
+        ExecuteMsg::SetBudget { amount } => {
+            cw_utils::nonpayable(&info)?;
+            commands::set_budget(deps, env, info, amount)
+        }
+        ExecuteMsg::ClaimWithSignature {
+            receiver,
+            amount,
+            nonce,
+            pubkey,
+            signature,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::claim_with_signature(deps, env, receiver, amount, nonce, pubkey, signature)
+        }
+        ExecuteMsg::ClaimAuthz { receiver, amount } => {
+            cw_utils::nonpayable(&info)?;
+            commands::claim_authz(deps, env, info, receiver, amount)
+        }
+        ExecuteMsg::ClaimBatch { receivers } => {
+            cw_utils::nonpayable(&info)?;
+            commands::claim_batch(deps, env, info, receivers)
+        }
+        ExecuteMsg::RegisterAllocation { voucher, signature } => {
+            cw_utils::nonpayable(&info)?;
+            commands::register_allocation(deps, env, voucher, signature)
+        }
+        ExecuteMsg::AddAllocations {
+            allocations,
+            skip_duplicates,
+            tag,
+            batch_id,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::add_allocations(
+                deps,
+                env,
+                info,
+                allocations,
+                skip_duplicates.unwrap_or_default(),
+                tag,
+                batch_id,
+            )
+        }
+        ExecuteMsg::SetAllocations { entries, mode, tag } => {
+            cw_utils::nonpayable(&info)?;
+            commands::set_allocations(deps, env, info, entries, mode, tag)
+        }
+        ExecuteMsg::ApplyAllocationDelta {
+            additions,
+            removals,
+            adjustments,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::apply_allocation_delta(deps, env, info, additions, removals, adjustments)
+        }
+        ExecuteMsg::ImportAllocations {
+            source_contract,
+            start_after,
+            limit,
+            include_claimed,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::import_allocations(
+                deps,
+                env,
+                info,
+                source_contract,
+                start_after,
+                limit,
+                include_claimed.unwrap_or_default(),
+            )
+        }
+        ExecuteMsg::SnapshotDelegators {
+            validator_addrs,
+            min_stake,
+            ratio,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::snapshot_delegators(deps, env, info, validator_addrs, min_stake, ratio)
+        }
+        ExecuteMsg::ActivateVesting {} => {
+            cw_utils::nonpayable(&info)?;
+            commands::activate_vesting(deps, env, info)
+        }
+        ExecuteMsg::TransferAllocation { new_address } => {
+            cw_utils::nonpayable(&info)?;
+            commands::transfer_allocation(deps, env, info, new_address)
+        }
+        ExecuteMsg::LinkAddress {
+            foreign_address,
+            signature,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::link_address(deps, env, info, foreign_address, signature)
+        }
+        ExecuteMsg::FinalizeCampaign {} => {
+            cw_utils::nonpayable(&info)?;
+            commands::finalize_campaign(deps, env, info)
+        }
+        ExecuteMsg::RequestAddressReplacement { new_address } => {
+            cw_utils::nonpayable(&info)?;
+            commands::request_address_replacement(deps, info, new_address)
         }
         ExecuteMsg::ReplaceAddress {
             old_address,
@@ -72,37 +264,176 @@ pub fn execute(
             cw_utils::nonpayable(&info)?;
             commands::replace_address(deps, info, old_address, new_address)
         }
+        ExecuteMsg::ReplaceAddresses { pairs } => {
+            cw_utils::nonpayable(&info)?;
+            commands::replace_addresses(deps, info, pairs)
+        }
         ExecuteMsg::RemoveAddress { address } => {
             cw_utils::nonpayable(&info)?;
             commands::remove_address(deps, env, info, address)
         }
-        ExecuteMsg::BlacklistAddress { address, blacklist } => {
+        ExecuteMsg::BlacklistAddress {
+            address,
+            blacklist,
+            reason,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::blacklist_address(deps, env, info, address, blacklist, reason)
+        }
+        ExecuteMsg::ManageBlacklist {
+            addresses,
+            blacklist,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::manage_blacklist(deps, env, info, addresses, blacklist)
+        }
+        ExecuteMsg::FreezeAddress { address, reason } => {
+            cw_utils::nonpayable(&info)?;
+            commands::freeze_address(deps, env, info, address, reason)
+        }
+        ExecuteMsg::ResolveFrozen { address, action } => {
+            cw_utils::nonpayable(&info)?;
+            commands::resolve_frozen(deps, env, info, address, action)
+        }
+        ExecuteMsg::HoldAddress { address, until } => {
+            cw_utils::nonpayable(&info)?;
+            commands::hold_address(deps, env, info, address, until)
+        }
+        ExecuteMsg::ManageAllowlist {
+            addresses,
+            allowlist,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::manage_allowlist(deps, info, addresses, allowlist)
+        }
+        ExecuteMsg::SetLockedReceiver {
+            address,
+            locked_receiver,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::set_locked_receiver(deps, info, address, locked_receiver)
+        }
+        ExecuteMsg::ManageClaimHooks {
+            addresses,
+            registered,
+        } => {
             cw_utils::nonpayable(&info)?;
-            commands::blacklist_address(deps, info, address, blacklist)
+            commands::manage_claim_hooks(deps, info, addresses, registered)
         }
         ExecuteMsg::ManageAuthorizedWallets {
             addresses,
             authorized,
         } => {
             cw_utils::nonpayable(&info)?;
-            commands::manage_authorized_wallets(deps, info, addresses, authorized)
+            commands::manage_authorized_wallets(deps, env, info, addresses, authorized)
+        }
+        ExecuteMsg::ActivateAuthorizedWallets { addresses } => {
+            cw_utils::nonpayable(&info)?;
+            commands::activate_authorized_wallets(deps, env, addresses)
+        }
+        ExecuteMsg::GrantRoles { address, roles } => {
+            cw_utils::nonpayable(&info)?;
+            commands::manage_roles(deps, info, address, roles, true)
+        }
+        ExecuteMsg::RevokeRoles { address, roles } => {
+            cw_utils::nonpayable(&info)?;
+            commands::manage_roles(deps, info, address, roles, false)
         }
         ExecuteMsg::UpdateOwnership(action) => {
             cw_utils::nonpayable(&info)?;
-            Ok(
-                cw_ownable::update_ownership(deps, &env.block, &info.sender, action).map(
-                    |ownership| {
-                        Response::default()
-                            .add_attribute("action", "update_ownership")
-                            .add_attributes(ownership.into_attributes())
-                    },
-                )?,
-            )
+            commands::update_ownership(deps, env, info, action)
         }
+        // the owner sweeps directly rather than through a proposal it would approve itself, the
+        // sweep threshold bounding what it can sweep on its own
         ExecuteMsg::Sweep { denom, amount } => {
             cw_utils::nonpayable(&info)?;
             commands::sweep(deps, env, info, denom, amount)
         }
+        ExecuteMsg::ApproveSweep {} => {
+            cw_utils::nonpayable(&info)?;
+            commands::approve_sweep(deps, env, info)
+        }
+        ExecuteMsg::PruneState { limit } => {
+            cw_utils::nonpayable(&info)?;
+            commands::prune_state(deps, info, limit)
+        }
+        ExecuteMsg::RedistributeForfeited { limit } => {
+            cw_utils::nonpayable(&info)?;
+            commands::redistribute_forfeited(deps, env, info, limit)
+        }
+        ExecuteMsg::UpdateConfig {
+            circuit_breaker,
+            limits,
+            replacements_require_request,
+            voucher_pubkey,
+            allowed_prefixes,
+            authorized_wallet_delay,
+            sweep_threshold,
+            registry,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::update_config(
+                deps,
+                env,
+                info,
+                circuit_breaker,
+                limits,
+                replacements_require_request,
+                voucher_pubkey,
+                allowed_prefixes,
+                authorized_wallet_delay,
+                sweep_threshold,
+                registry,
+            )
+        }
+        ExecuteMsg::ResumeClaims {} => {
+            cw_utils::nonpayable(&info)?;
+            commands::resume_claims(deps, info)
+        }
+        ExecuteMsg::SetPause { scope } => {
+            cw_utils::nonpayable(&info)?;
+            commands::set_pause(deps, info, scope)
+        }
+        ExecuteMsg::ManageIbcChannels {
+            channel_ids,
+            allowed,
+        } => {
+            cw_utils::nonpayable(&info)?;
+            commands::manage_ibc_channels(deps, info, channel_ids, allowed)
+        }
+        ExecuteMsg::UpdateIbcAdmin { ibc_admin } => {
+            cw_utils::nonpayable(&info)?;
+            commands::update_ibc_admin(deps, info, ibc_admin)
+        }
+        ExecuteMsg::SubmitRaffleSeed { seed } => {
+            cw_utils::nonpayable(&info)?;
+            commands::submit_raffle_seed(deps, env, info, seed)
+        }
+        ExecuteMsg::DrawRaffle {} => {
+            cw_utils::nonpayable(&info)?;
+            commands::draw_raffle(deps, env)
+        }
+        ExecuteMsg::Propose { action } => {
+            cw_utils::nonpayable(&info)?;
+            commands::propose(deps, env, info, action)
+        }
+        ExecuteMsg::Approve { proposal_id } => {
+            cw_utils::nonpayable(&info)?;
+            commands::approve(deps, env, info, proposal_id)
+        }
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::ForceCloseCampaign {} => commands::force_close_campaign(deps, env),
+        SudoMsg::ForceBlacklist { address, reason } => {
+            commands::force_blacklist(deps, env, address, reason)
+        }
+        SudoMsg::TransferOwnership { new_owner } => {
+            commands::force_transfer_ownership(deps, new_owner)
+        }
     }
 }
 
@@ -110,9 +441,22 @@ pub fn execute(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::Campaign {} => Ok(to_json_binary(&queries::query_campaign(deps)?)?),
+        QueryMsg::CampaignStatus {} => {
+            Ok(to_json_binary(&queries::query_campaign_status(deps, env)?)?)
+        }
+        QueryMsg::CampaignPhase {} => {
+            Ok(to_json_binary(&queries::query_campaign_phase(deps, env)?)?)
+        }
+        QueryMsg::IsLive {} => Ok(to_json_binary(&queries::query_is_live(deps, env)?)?),
         QueryMsg::Rewards { receiver } => Ok(to_json_binary(&queries::query_rewards(
             deps, env, receiver,
         )?)?),
+        QueryMsg::BatchRewards { addresses } => Ok(to_json_binary(&queries::query_batch_rewards(
+            deps, env, addresses,
+        )?)?),
+        QueryMsg::SimulateClaim { address } => Ok(to_json_binary(&queries::query_simulate_claim(
+            deps, env, address,
+        )?)?),
         QueryMsg::Ownership {} => Ok(to_json_binary(&cw_ownable::get_ownership(deps.storage)?)?),
         QueryMsg::Claimed {
             address,
@@ -121,31 +465,180 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         } => Ok(to_json_binary(&queries::query_claimed(
             deps, address, start_from, limit,
         )?)?),
+        QueryMsg::ClaimedBySlot {
+            address,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_claimed_by_slot(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::ClaimHistory {
+            address,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_claim_history(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::ClaimVolume { from_day, to_day } => Ok(to_json_binary(
+            &queries::query_claim_volume(deps, from_day, to_day)?,
+        )?),
         QueryMsg::Allocations {
             address,
             start_after,
             limit,
+            min_amount,
+            has_claimed,
         } => Ok(to_json_binary(&queries::query_allocation(
             deps,
             address,
             start_after,
             limit,
+            min_amount,
+            has_claimed,
+        )?)?),
+        QueryMsg::TopAllocations { limit } => Ok(to_json_binary(&queries::query_top_allocations(
+            deps, limit,
+        )?)?),
+        QueryMsg::UnclaimedAllocations { start_after, limit } => Ok(to_json_binary(
+            &queries::query_unclaimed_allocations(deps, start_after, limit)?,
+        )?),
+        QueryMsg::AllocationsByTag {
+            tag,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_allocations_by_tag(
+            deps,
+            tag,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::TagStats { tag } => Ok(to_json_binary(&queries::query_tag_stats(deps, tag)?)?),
+        QueryMsg::AllocationsByUploader {
+            uploader,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_allocations_by_uploader(
+            deps,
+            uploader,
+            start_after,
+            limit,
         )?)?),
         QueryMsg::IsBlacklisted { address } => Ok(to_json_binary(&queries::query_is_blacklisted(
             deps, address,
         )?)?),
+        QueryMsg::BlacklistEntry { address } => Ok(to_json_binary(
+            &queries::query_blacklist_entry(deps, address)?,
+        )?),
+        QueryMsg::PendingSweep {} => Ok(to_json_binary(&queries::query_pending_sweep(deps)?)?),
+        QueryMsg::FrozenEntry { address } => Ok(to_json_binary(&queries::query_frozen_entry(
+            deps, address,
+        )?)?),
+        QueryMsg::Hold { address } => {
+            Ok(to_json_binary(&queries::query_hold(deps, env, address)?)?)
+        }
+        QueryMsg::ClaimNonce { address } => {
+            Ok(to_json_binary(&queries::query_claim_nonce(deps, address)?)?)
+        }
+        QueryMsg::AddressReplacementRequest { address } => Ok(to_json_binary(
+            &queries::query_address_replacement_request(deps, address)?,
+        )?),
+        QueryMsg::VestingActivation { address } => Ok(to_json_binary(
+            &queries::query_vesting_activation(deps, address)?,
+        )?),
+        QueryMsg::IsAllowlisted { address } => Ok(to_json_binary(&queries::query_is_allowlisted(
+            deps, address,
+        )?)?),
+        QueryMsg::LockedReceiver { address } => Ok(to_json_binary(
+            &queries::query_locked_receiver(deps, address)?,
+        )?),
+        QueryMsg::Locks { address } => Ok(to_json_binary(&queries::query_locks(deps, address)?)?),
+        QueryMsg::Budget {} => Ok(to_json_binary(&queries::query_budget(deps)?)?),
+        QueryMsg::Group { name } => Ok(to_json_binary(&queries::query_group(deps, name)?)?),
+        QueryMsg::GroupMember { name, address } => Ok(to_json_binary(
+            &queries::query_group_member(deps, env, name, address)?,
+        )?),
+        QueryMsg::FundingHistory { start_after, limit } => Ok(to_json_binary(
+            &queries::query_funding_history(deps, start_after, limit)?,
+        )?),
+        QueryMsg::Funders { start_after, limit } => Ok(to_json_binary(&queries::query_funders(
+            deps,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::TermsAcceptance { address } => Ok(to_json_binary(
+            &queries::query_terms_acceptance(deps, address)?,
+        )?),
+        QueryMsg::ClaimWindow { address } => Ok(to_json_binary(&queries::query_claim_window(
+            deps, address,
+        )?)?),
+        QueryMsg::ExportState {
+            section,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_export_state(
+            deps,
+            section,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::ClaimHooks {} => Ok(to_json_binary(&queries::query_claim_hooks(deps)?)?),
+        QueryMsg::Config {} => Ok(to_json_binary(&queries::query_config(deps)?)?),
+        QueryMsg::SlotSolvency {} => Ok(to_json_binary(&queries::query_slot_solvency(deps, env)?)?),
+        QueryMsg::PauseStatus {} => Ok(to_json_binary(&queries::query_pause_status(deps)?)?),
+        QueryMsg::Liabilities { start_after, limit } => Ok(to_json_binary(
+            &queries::query_liabilities(deps, env, start_after, limit)?,
+        )?),
+        QueryMsg::CampaignStats {} => Ok(to_json_binary(&queries::query_campaign_stats(deps)?)?),
+        QueryMsg::ContractInfo {} => Ok(to_json_binary(&queries::query_contract_info(deps)?)?),
+        QueryMsg::Counts {} => Ok(to_json_binary(&queries::query_counts(deps)?)?),
+        QueryMsg::Raffle {} => Ok(to_json_binary(&queries::query_raffle(deps)?)?),
+        QueryMsg::Proposals { start_after, limit } => Ok(to_json_binary(
+            &queries::query_proposals(deps, start_after, limit)?,
+        )?),
         QueryMsg::IsAuthorized { address } => Ok(to_json_binary(&queries::query_is_authorized(
             deps, address,
         )?)?),
         QueryMsg::AuthorizedWallets { start_after, limit } => Ok(to_json_binary(
             &queries::query_authorized_wallets(deps, start_after, limit)?,
         )?),
+        QueryMsg::PendingAuthorizedWallets { start_after, limit } => Ok(to_json_binary(
+            &queries::query_pending_authorized_wallets(deps, start_after, limit)?,
+        )?),
+        QueryMsg::Roles { address } => Ok(to_json_binary(&queries::query_roles(deps, address)?)?),
+        QueryMsg::RoleHolders {
+            role,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_role_holders(
+            deps,
+            role,
+            start_after,
+            limit,
+        )?)?),
     }
 }
 
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     validate_contract!(deps, CONTRACT_NAME, CONTRACT_VERSION);
+
+    let stored_version: semver::Version =
+        cw2::get_contract_version(deps.storage)?.version.parse()?;
+    let new_version: semver::Version = CONTRACT_VERSION.parse()?;
+
+    let events = migrations::migrate_state(deps.branch(), &env, &stored_version, &new_version)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::default())
+
+    Ok(Response::default()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", new_version.to_string())
+        .add_events(events))
 }
@@ -0,0 +1,379 @@
+use cosmwasm_std::{Attribute, Coin, Event, Int128, Timestamp, Uint128};
+use mantra_claimdrop_std::msg::AllocationMode;
+
+/// The version of the events schema, carried by every event and set of wasm attributes built here.
+/// It must be bumped whenever an event type or an attribute is renamed or removed, so indexers can
+/// tell the layouts apart.
+pub const EVENTS_SCHEMA_VERSION: &str = "1";
+
+/// The attribute carrying the [EVENTS_SCHEMA_VERSION]
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// The wasm attributes of an operation, starting with its action and ending with the schema
+/// version.
+pub(crate) struct ActionAttributes {
+    attributes: Vec<Attribute>,
+}
+
+impl ActionAttributes {
+    /// Starts the attributes of the given action
+    pub(crate) fn new(action: &str) -> Self {
+        Self {
+            attributes: vec![Attribute::new("action", action)],
+        }
+    }
+
+    /// Adds an attribute
+    pub(crate) fn add(mut self, key: &str, value: impl ToString) -> Self {
+        self.attributes.push(Attribute::new(key, value.to_string()));
+        self
+    }
+
+    /// Adds an attribute if the value is set
+    pub(crate) fn add_opt(self, key: &str, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.add(key, value),
+            None => self,
+        }
+    }
+
+    /// Returns the attributes, stamped with the schema version
+    pub(crate) fn build(self) -> Vec<Attribute> {
+        let mut attributes = self.attributes;
+        attributes.push(Attribute::new(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION));
+        attributes
+    }
+}
+
+/// Stamps an event with the schema version.
+fn versioned(event: Event) -> Event {
+    event.add_attribute(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION)
+}
+
+/// The reason tokens leave the contract, recorded by a [TransferIntent].
+pub(crate) enum TransferKind {
+    /// The payout of a claim to its receiver, or its destination
+    Claim,
+    /// The unvested tokens of a claim locked in a vesting account
+    VestingAccount,
+    /// The fee charged on a claim
+    ClaimFee,
+    /// The remaining rewards refunded to the owner when the campaign is closed
+    Refund,
+    /// Non-reward tokens swept to the owner
+    Sweep,
+    /// The tokens of the expired locks withdrawn by their owner
+    Unlock,
+    /// The allocations left unclaimed after their claim window, sent to the community pool
+    Forfeit,
+}
+
+impl TransferKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TransferKind::Claim => "claim",
+            TransferKind::VestingAccount => "vesting_account",
+            TransferKind::ClaimFee => "claim_fee",
+            TransferKind::Refund => "refund",
+            TransferKind::Sweep => "sweep",
+            TransferKind::Unlock => "unlock",
+            TransferKind::Forfeit => "forfeit",
+        }
+    }
+}
+
+/// A cw20-style `transfer` of tokens out of the contract, emitted along with the message moving
+/// the tokens so indexers can follow the funds without decoding the messages.
+pub(crate) struct TransferIntent<'a> {
+    pub kind: TransferKind,
+    pub from: &'a str,
+    pub to: &'a str,
+    pub amount: &'a Coin,
+}
+
+impl TransferIntent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/transfer")
+                .add_attribute("kind", self.kind.as_str())
+                .add_attribute("from", self.from)
+                .add_attribute("to", self.to)
+                .add_attribute("amount", self.amount.to_string()),
+        )
+    }
+}
+
+/// The handoff of the owner protections to a new owner, once the ownership is transferred.
+pub(crate) struct OwnershipHandoffEvent<'a> {
+    pub previous_owner: Option<&'a str>,
+    pub new_owner: &'a str,
+    pub unblacklisted: bool,
+    pub unfrozen: bool,
+    pub pending_sweep_discarded: bool,
+}
+
+impl OwnershipHandoffEvent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/ownership_handoff")
+                .add_attribute("previous_owner", self.previous_owner.unwrap_or_default())
+                .add_attribute("new_owner", self.new_owner)
+                .add_attribute("unblacklisted", self.unblacklisted.to_string())
+                .add_attribute("unfrozen", self.unfrozen.to_string())
+                .add_attribute(
+                    "pending_sweep_discarded",
+                    self.pending_sweep_discarded.to_string(),
+                ),
+        )
+    }
+}
+
+/// A hold placed on the claims of an address, or lifted.
+pub(crate) struct HoldEvent<'a> {
+    pub address: &'a str,
+    pub updated_by: &'a str,
+    pub until: Option<u64>,
+}
+
+impl HoldEvent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/hold")
+                .add_attribute("address", self.address)
+                .add_attribute("updated_by", self.updated_by)
+                .add_attribute("held", self.until.is_some().to_string())
+                .add_attribute(
+                    "until",
+                    self.until
+                        .map(|until| until.to_string())
+                        .unwrap_or_default(),
+                ),
+        )
+    }
+}
+
+/// The claim of a distribution slot, emitted once per slot claimed from.
+pub(crate) struct SlotClaimEvent<'a> {
+    pub receiver: &'a str,
+    pub slot: usize,
+    pub distribution_type: &'a str,
+    pub amount: Uint128,
+    pub slot_claimed: Uint128,
+    pub remaining_allocation: Uint128,
+}
+
+impl SlotClaimEvent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/claim")
+                .add_attribute("receiver", self.receiver)
+                .add_attribute("slot", self.slot.to_string())
+                .add_attribute("distribution_type", self.distribution_type)
+                .add_attribute("amount", self.amount.to_string())
+                .add_attribute("slot_claimed", self.slot_claimed.to_string())
+                .add_attribute(
+                    "remaining_allocation",
+                    self.remaining_allocation.to_string(),
+                ),
+        )
+    }
+}
+
+/// A diff applied to the allocations, with the number of changes and the net change of the
+/// allocated amount.
+pub(crate) struct AllocationDeltaEvent<'a> {
+    pub applied_by: &'a str,
+    pub added: usize,
+    pub removed: usize,
+    pub adjusted: usize,
+    pub net_change: Int128,
+}
+
+impl AllocationDeltaEvent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/allocation_delta")
+                .add_attribute("applied_by", self.applied_by)
+                .add_attribute("added", self.added.to_string())
+                .add_attribute("removed", self.removed.to_string())
+                .add_attribute("adjusted", self.adjusted.to_string())
+                .add_attribute("net_change", self.net_change.to_string()),
+        )
+    }
+}
+
+/// The lock of the liquid tokens of a claim in the contract, along with the boost they earned.
+pub(crate) struct LockEvent<'a> {
+    pub receiver: &'a str,
+    pub amount: Uint128,
+    pub boost: Uint128,
+    pub unlocks_at: Timestamp,
+}
+
+impl LockEvent<'_> {
+    pub(crate) fn into_event(self) -> Event {
+        versioned(
+            Event::new("claimdrop/lock")
+                .add_attribute("receiver", self.receiver)
+                .add_attribute("amount", self.amount.to_string())
+                .add_attribute("boost", self.boost.to_string())
+                .add_attribute("unlocks_at", self.unlocks_at.seconds().to_string()),
+        )
+    }
+}
+
+/// The wasm attributes of a claim.
+pub(crate) struct ClaimAttributes<'a> {
+    pub receiver: &'a str,
+    pub claimed_amount: String,
+    pub claim_fee: Option<Uint128>,
+    pub early_bird_bonus: Option<Uint128>,
+    pub ibc_channel: Option<&'a str>,
+    pub ibc_receiver: Option<&'a str>,
+}
+
+impl ClaimAttributes<'_> {
+    pub(crate) fn into_attributes(self) -> Vec<Attribute> {
+        ActionAttributes::new("claim")
+            .add("receiver", self.receiver)
+            .add("claimed_amount", self.claimed_amount)
+            .add_opt("claim_fee", self.claim_fee)
+            .add_opt("early_bird_bonus", self.early_bird_bonus)
+            .add_opt("ibc_channel", self.ibc_channel)
+            .add_opt("ibc_receiver", self.ibc_receiver)
+            .build()
+    }
+}
+
+/// The wasm attributes of the closing of a campaign.
+pub(crate) struct CloseCampaignAttributes<'a> {
+    pub campaign: String,
+    pub refund: &'a Coin,
+}
+
+impl CloseCampaignAttributes<'_> {
+    pub(crate) fn into_attributes(self) -> Vec<Attribute> {
+        ActionAttributes::new("close_campaign")
+            .add("campaign", self.campaign)
+            .add("refund", self.refund)
+            .build()
+    }
+}
+
+/// The sweep of non-reward tokens to the owner.
+pub(crate) struct SweepEvent<'a> {
+    pub amount: &'a Coin,
+    pub recipient: &'a str,
+}
+
+impl SweepEvent<'_> {
+    pub(crate) fn to_event(&self) -> Event {
+        versioned(
+            Event::new("sweep_tokens")
+                .add_attribute("denom", &self.amount.denom)
+                .add_attribute("amount", self.amount.amount.to_string())
+                .add_attribute("recipient", self.recipient),
+        )
+    }
+
+    pub(crate) fn to_attributes(&self) -> Vec<Attribute> {
+        ActionAttributes::new("sweep")
+            .add("denom", &self.amount.denom)
+            .add("amount", self.amount.amount)
+            .add("recipient", self.recipient)
+            .build()
+    }
+}
+
+/// The wasm attributes of a change to the allocations.
+pub(crate) enum AllocationsAttributes<'a> {
+    /// A batch of allocations added, skipping the duplicates if requested
+    Added {
+        count: usize,
+        skipped: usize,
+        batch_id: Option<&'a str>,
+    },
+    /// A batch of allocations re-submitted, ignored as it was already processed
+    AlreadyProcessed { batch_id: &'a str },
+    /// A batch of allocations set, creating or updating the allocations per the mode
+    Set {
+        mode: &'a AllocationMode,
+        created: usize,
+        updated: usize,
+    },
+    /// A page of allocations imported from another claimdrop contract
+    Imported {
+        source_contract: &'a str,
+        imported: usize,
+        skipped: usize,
+        last_address: &'a str,
+    },
+    /// The allocations of the delegators of a set of validators added
+    Snapshotted {
+        validators: &'a [String],
+        count: usize,
+    },
+    /// An allocation registered with a voucher signed by the owner
+    Registered { address: &'a str, amount: Uint128 },
+    /// An allocation removed
+    Removed { address: &'a str },
+}
+
+impl AllocationsAttributes<'_> {
+    pub(crate) fn into_attributes(self) -> Vec<Attribute> {
+        match self {
+            AllocationsAttributes::Added {
+                count,
+                skipped,
+                batch_id,
+            } => ActionAttributes::new("add_allocations")
+                .add("count", count)
+                .add("skipped", skipped)
+                .add_opt("batch_id", batch_id),
+            AllocationsAttributes::AlreadyProcessed { batch_id } => {
+                ActionAttributes::new("add_allocations")
+                    .add("batch_id", batch_id)
+                    .add("already_processed", true)
+            }
+            AllocationsAttributes::Set {
+                mode,
+                created,
+                updated,
+            } => ActionAttributes::new("set_allocations")
+                .add(
+                    "mode",
+                    match mode {
+                        AllocationMode::CreateOnly => "create_only",
+                        AllocationMode::UpsertBeforeStart => "upsert_before_start",
+                        AllocationMode::IncrementBeforeStart => "increment_before_start",
+                    },
+                )
+                .add("created", created)
+                .add("updated", updated),
+            AllocationsAttributes::Imported {
+                source_contract,
+                imported,
+                skipped,
+                last_address,
+            } => ActionAttributes::new("import_allocations")
+                .add("source_contract", source_contract)
+                .add("imported", imported)
+                .add("skipped", skipped)
+                .add("last_address", last_address),
+            AllocationsAttributes::Snapshotted { validators, count } => {
+                ActionAttributes::new("snapshot_delegators")
+                    .add("validators", validators.join(","))
+                    .add("count", count)
+            }
+            AllocationsAttributes::Registered { address, amount } => {
+                ActionAttributes::new("register_allocation")
+                    .add("address", address)
+                    .add("amount", amount)
+            }
+            AllocationsAttributes::Removed { address } => {
+                ActionAttributes::new("remove_address").add("removed", address)
+            }
+        }
+        .build()
+    }
+}
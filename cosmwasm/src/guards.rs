@@ -0,0 +1,154 @@
+use cosmwasm_std::{
+    ensure, BankMsg, CosmosMsg, Deps, Env, IbcMsg, MessageInfo, Response, SubMsg, Uint128, WasmMsg,
+};
+
+use crate::state::{get_allocation, get_total_claims_amount_for_address, CAMPAIGN};
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{Campaign, DistributionType, ExecuteMsg, RewardSource};
+
+/// Whether the invariants are checked after every execution. They are always checked in debug
+/// builds, and in release builds when the `invariant-guards` feature is enabled.
+pub const INVARIANT_GUARDS_ENABLED: bool =
+    cfg!(any(debug_assertions, feature = "invariant-guards"));
+
+/// Returns the addresses whose claims can be changed by the given message, i.e. the sender and the
+/// receivers of a claim.
+///
+/// # Arguments
+/// * `info` - The message info
+/// * `msg` - The executed message
+///
+/// # Returns
+/// * `Vec<String>` - The addresses to check the claims of
+pub(crate) fn affected_addresses(info: &MessageInfo, msg: &ExecuteMsg) -> Vec<String> {
+    let mut addresses = vec![info.sender.to_string()];
+
+    match msg {
+        ExecuteMsg::Claim {
+            receiver: Some(receiver),
+            ..
+        }
+        | ExecuteMsg::ClaimWithSignature { receiver, .. }
+        | ExecuteMsg::ClaimAuthz { receiver, .. } => addresses.push(receiver.clone()),
+        ExecuteMsg::ClaimBatch { receivers } => addresses.extend(receivers.iter().cloned()),
+        ExecuteMsg::TransferAllocation { new_address } => addresses.push(new_address.clone()),
+        _ => {}
+    }
+
+    addresses
+}
+
+/// Checks the global invariants of the contract after an execution:
+/// - the amount claimed from the campaign doesn't exceed its total reward, including the pools
+///   reserved on top of the allocations
+/// - the contract holds the reward tokens paid out by the messages of the response
+/// - the claims of the affected addresses don't exceed their allocations
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `addresses` - The addresses whose claims may have changed
+/// * `response` - The response of the execution
+///
+/// # Returns
+/// * `Result<(), ContractError>` - An [ContractError::InvariantViolation] if an invariant is broken
+pub(crate) fn check_invariants(
+    deps: Deps,
+    env: &Env,
+    addresses: &[String],
+    response: &Response,
+) -> Result<(), ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(deps.storage)? else {
+        return Ok(());
+    };
+
+    let max_payout = max_payout(&campaign);
+    ensure!(
+        campaign.claimed.amount <= max_payout,
+        ContractError::InvariantViolation {
+            invariant: format!(
+                "the claimed amount {} exceeds the maximum payout of {}{}",
+                campaign.claimed, max_payout, campaign.total_reward.denom
+            )
+        }
+    );
+
+    // campaigns minting on claim don't hold the rewards they pay out
+    if campaign.reward_source == RewardSource::PreFunded {
+        let denom = &campaign.total_reward.denom;
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, denom)?
+            .amount;
+        let liabilities = outgoing_amount(&response.messages, denom)?;
+
+        ensure!(
+            liabilities <= balance,
+            ContractError::InvariantViolation {
+                invariant: format!(
+                    "the payouts of {liabilities}{denom} exceed the balance of {balance}{denom}"
+                )
+            }
+        );
+    }
+
+    for address in addresses {
+        // addresses that aren't valid or have no allocation have nothing to check
+        let Ok(Some(allocation)) = get_allocation(deps, address) else {
+            continue;
+        };
+
+        let claimed = get_total_claims_amount_for_address(deps, address)?;
+        ensure!(
+            claimed <= allocation,
+            ContractError::InvariantViolation {
+                invariant: format!(
+                    "the claims of {address} of {claimed} exceed its allocation of {allocation}"
+                )
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the most a campaign can pay out, i.e. its total reward plus the early bird bonus, raffle
+/// prize and lock boost pools reserved on top of the allocations.
+fn max_payout(campaign: &Campaign) -> Uint128 {
+    let total_reward = campaign.total_reward.amount;
+    let lock_boost_pool = campaign
+        .lock_boost
+        .as_ref()
+        .map(|lock_boost| total_reward.mul_floor(lock_boost.pool_percentage))
+        .unwrap_or_default();
+
+    campaign.distribution_type.iter().fold(
+        total_reward.saturating_add(lock_boost_pool),
+        |max_payout, distribution| match distribution {
+            DistributionType::EarlyBirdBonus {
+                bonus_percentage: pool_percentage,
+                ..
+            }
+            | DistributionType::Raffle {
+                prize_pool_percentage: pool_percentage,
+                ..
+            } => max_payout.saturating_add(total_reward.mul_floor(*pool_percentage)),
+            _ => max_payout,
+        },
+    )
+}
+
+/// Sums the amount of the given denom sent out by the messages, through bank sends, IBC transfers
+/// and contract executions.
+fn outgoing_amount(messages: &[SubMsg], denom: &str) -> Result<Uint128, ContractError> {
+    Ok(messages
+        .iter()
+        .flat_map(|sub_msg| match &sub_msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount.as_slice(),
+            CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) => funds.as_slice(),
+            CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => std::slice::from_ref(amount),
+            _ => &[],
+        })
+        .filter(|coin| coin.denom == denom)
+        .try_fold(Uint128::zero(), |acc, coin| acc.checked_add(coin.amount))?)
+}
@@ -1,21 +1,85 @@
 use std::collections::HashMap;
 
-use cosmwasm_std::{ensure, Coin, Decimal256, Deps, Timestamp, Uint128, Uint256};
+use cosmwasm_std::{
+    coin, ensure, Addr, Binary, CanonicalAddr, Coin, CosmosMsg, Decimal, Decimal256, Deps,
+    HexBinary, Order, Storage, Timestamp, Uint128, Uint256,
+};
 
-use crate::state::{get_claims_for_address, Claim, DistributionSlot};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::state::{
+    get_claims_for_address, get_exhausted_slots, has_accepted_terms, is_slot_exhausted, Claim,
+    DistributionSlot, Group, GroupMember, ALLOCATIONS, BUDGET, CAMPAIGN, CONFIG, FORFEITED_TOTAL,
+    GROUPS, LOCKED_TOTAL, TOTAL_ALLOCATED, VESTING_ACTIVATIONS,
+};
 use mantra_claimdrop_std::error::ContractError;
-use mantra_claimdrop_std::msg::{Campaign, CampaignParams, DistributionType};
+use mantra_claimdrop_std::msg::{
+    AccrualFrom, Campaign, CampaignParams, ClaimPriority, Curve, DistributionType, Limits,
+    LockTier, PauseScope, RegistryUpdateMsg, RewardSource, SlotSolvency,
+    DEFAULT_MAX_DISTRIBUTION_SLOTS,
+};
 
 /// Validates the provided campaign parameters are valid.
 pub(crate) fn validate_campaign_params(
     current_time: Timestamp,
+    contract_address: &Addr,
+    limits: &Limits,
     campaign_params: &CampaignParams,
 ) -> Result<(), ContractError> {
     campaign_params.validate_campaign_name_description()?;
     campaign_params.validate_campaign_type()?;
+    campaign_params.validate_campaign_metadata()?;
     campaign_params.validate_campaign_times(current_time)?;
-    campaign_params.validate_campaign_distribution()?;
+    campaign_params.validate_campaign_distribution(
+        limits
+            .max_distribution_slots
+            .unwrap_or(DEFAULT_MAX_DISTRIBUTION_SLOTS),
+    )?;
     campaign_params.validate_rewards()?;
+    campaign_params.validate_campaign_cliff()?;
+    campaign_params.validate_claim_cooldown()?;
+    campaign_params.validate_min_claim_amount()?;
+    campaign_params.validate_caps()?;
+    campaign_params.validate_claim_fee()?;
+    campaign_params.validate_fee_grant(current_time)?;
+    campaign_params.validate_payout_target()?;
+    campaign_params.validate_eligibility()?;
+    campaign_params.validate_lock_boost()?;
+    campaign_params.validate_claim_window()?;
+    campaign_params.validate_reward_source(contract_address)?;
+
+    if let Some(min_campaign_duration) = limits.min_campaign_duration {
+        ensure!(
+            campaign_params
+                .end_time
+                .seconds()
+                .saturating_sub(campaign_params.start_time.seconds())
+                >= min_campaign_duration,
+            ContractError::InvalidCampaignParam {
+                param: "end_time".to_string(),
+                reason: format!(
+                    "the campaign must last at least {} seconds",
+                    min_campaign_duration
+                ),
+            }
+        );
+    }
+
+    ensure!(
+        limits.allowed_reward_denoms.is_empty()
+            || limits
+                .allowed_reward_denoms
+                .contains(&campaign_params.total_reward.denom),
+        ContractError::InvalidCampaignParam {
+            param: "total_reward".to_string(),
+            reason: format!(
+                "the reward denom {} is not allowed",
+                campaign_params.total_reward.denom
+            ),
+        }
+    );
 
     // Additional validation for Lump Sum distributions
     // The external validate_campaign_distribution incorrectly allows Lump Sum distributions
@@ -27,7 +91,8 @@ pub(crate) fn validate_campaign_params(
                 return Err(ContractError::InvalidInput {
                     reason: format!(
                         "Lump Sum distribution start time ({}) cannot be after campaign end time ({})",
-                        start_time, campaign_params.end_time
+                        start_time.seconds(),
+                        campaign_params.end_time.seconds()
                     ),
                 });
             }
@@ -37,11 +102,8 @@ pub(crate) fn validate_campaign_params(
     Ok(())
 }
 
-/// Constant used for the fallback distribution slot
-const FALLBACK_DISTRIBUTION_SLOT: usize = 0usize;
-
-type DistributionClaims = HashMap<DistributionSlot, Claim>;
-type ClaimableResult = (Coin, DistributionClaims, DistributionClaims);
+pub type DistributionClaims = HashMap<DistributionSlot, Claim>;
+pub type ClaimableResult = (Coin, DistributionClaims, DistributionClaims);
 
 /// Calculates the amount a user can claim at this point in time
 pub(crate) fn compute_claimable_amount(
@@ -50,41 +112,159 @@ pub(crate) fn compute_claimable_amount(
     current_time: &Timestamp,
     address: &str,
     total_claimable_amount: Uint128,
+) -> Result<ClaimableResult, ContractError> {
+    let previous_claims_for_address = get_claims_for_address(deps, address.to_string())?;
+    let exhausted_slots = get_exhausted_slots(deps.storage, address, total_claimable_amount)?;
+
+    match campaign_for_address(deps, campaign, address)? {
+        Some(campaign) => calculate_claimable_amount_skipping(
+            &campaign,
+            current_time,
+            total_claimable_amount,
+            previous_claims_for_address,
+            exhausted_slots,
+        ),
+        // nothing can be claimed until the address activates its vesting
+        None => Ok((
+            coin(0, &campaign.total_reward.denom),
+            HashMap::new(),
+            previous_claims_for_address,
+        )),
+    }
+}
+
+/// Returns the campaign as seen by an address. When the campaign vests from the activation, the
+/// distribution schedules are offset by the time elapsed between the campaign start and the
+/// activation of the address. When the campaign accrues from the allocation time, the vesting
+/// distributions started before the allocation was added only accrue from then on.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `campaign` - The campaign
+/// * `address` - The address
+///
+/// # Returns
+/// * `Result<Option<Campaign>, ContractError>` - The campaign of the address, or None if the
+///   address didn't activate its vesting yet
+pub(crate) fn campaign_for_address(
+    deps: Deps,
+    campaign: &Campaign,
+    address: &str,
+) -> Result<Option<Campaign>, ContractError> {
+    let campaign = if !campaign.vesting_from_activation {
+        campaign.clone()
+    } else {
+        match VESTING_ACTIVATIONS.may_load(deps.storage, address)? {
+            Some(activated_at) => campaign.with_vesting_offset(
+                activated_at
+                    .seconds()
+                    .saturating_sub(campaign.start_time.seconds()),
+            ),
+            None => return Ok(None),
+        }
+    };
+
+    // the addresses added late only accrue from their allocation time
+    if campaign.accrual_from == AccrualFrom::AllocationTime {
+        if let Some(allocation) = ALLOCATIONS.may_load(deps.storage, address)? {
+            return Ok(Some(campaign.with_accrual_start(allocation.added_at)));
+        }
+    }
+
+    Ok(Some(campaign))
+}
+
+/// Calculates the amount a user can claim at this point in time, given the claims previously made
+/// by the user. This is a pure function, it doesn't access the storage, so it can be used to
+/// exercise the vesting math in isolation, i.e. for property testing.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `current_time` - The time at which the claimable amount is computed
+/// * `total_claimable_amount` - The total allocation of the user
+/// * `previous_claims_for_address` - The claims previously made by the user, per distribution slot
+///
+/// # Returns
+/// * `Result<ClaimableResult, ContractError>` - The claimable coin, the new claims per slot, and
+///   the previous claims per slot
+pub fn calculate_claimable_amount(
+    campaign: &Campaign,
+    current_time: &Timestamp,
+    total_claimable_amount: Uint128,
+    previous_claims_for_address: HashMap<DistributionSlot, Claim>,
+) -> Result<ClaimableResult, ContractError> {
+    calculate_claimable_amount_skipping(
+        campaign,
+        current_time,
+        total_claimable_amount,
+        previous_claims_for_address,
+        0,
+    )
+}
+
+/// Same as [calculate_claimable_amount], skipping the vesting math of the slots the user has
+/// already fully claimed, so campaigns with many slots only evaluate the ones still vesting.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `current_time` - The time at which the claimable amount is computed
+/// * `total_claimable_amount` - The total allocation of the user
+/// * `previous_claims_for_address` - The claims previously made by the user, per distribution slot
+/// * `exhausted_slots` - The bitmask of the slots fully claimed by the user
+///
+/// # Returns
+/// * `Result<ClaimableResult, ContractError>` - The claimable coin, the new claims per slot, and
+///   the previous claims per slot
+pub fn calculate_claimable_amount_skipping(
+    campaign: &Campaign,
+    current_time: &Timestamp,
+    total_claimable_amount: Uint128,
+    previous_claims_for_address: HashMap<DistributionSlot, Claim>,
+    exhausted_slots: u64,
 ) -> Result<ClaimableResult, ContractError> {
     let mut claimable_amount = Uint128::zero();
     let mut new_claims = HashMap::new();
-    let previous_claims_for_address = get_claims_for_address(deps, address.to_string())?;
 
     if campaign.has_started(current_time) {
+        let slot_allocations = compute_slot_allocations(campaign, total_claimable_amount)?;
+
+        // nothing can be claimed until the campaign cliff has passed
+        if !campaign.has_cliff_passed(current_time) {
+            return Ok((
+                Coin {
+                    denom: campaign.total_reward.denom.clone(),
+                    amount: Uint128::zero(),
+                },
+                new_claims,
+                previous_claims_for_address,
+            ));
+        }
+
         for (distribution_slot, distribution) in
             campaign.distribution_type.iter().enumerate().clone()
         {
+            // skip distributions that have been fully claimed already
+            if is_slot_exhausted(exhausted_slots, distribution_slot) {
+                continue;
+            }
+
             // skip distributions that have not started yet
             if !distribution.has_started(current_time) {
                 continue;
             }
 
-            // check if the cliff period has passed for linear vesting distributions
-            if let DistributionType::LinearVesting {
-                cliff_duration: Some(cliff_duration),
-                start_time,
-                ..
-            } = distribution
-            {
-                let cliff_end_time = start_time + cliff_duration;
-
-                // if the cliff period has not passed yet, skip
-                if current_time.seconds() < cliff_end_time {
-                    continue;
-                }
+            // if the cliff period of the distribution has not passed yet, skip
+            if !distribution.has_cliff_passed(current_time) {
+                continue;
             }
 
             let previous_claim_for_address_for_distribution =
                 previous_claims_for_address.get(&distribution_slot);
 
+            let (slot_allocation, _) = slot_allocations[distribution_slot];
             let claim_amount = calculate_claim_amount_for_distribution(
                 &current_time,
-                total_claimable_amount,
+                slot_allocation,
                 &distribution,
                 &previous_claim_for_address_for_distribution,
             )?;
@@ -99,31 +279,30 @@ pub(crate) fn compute_claimable_amount(
             new_claims.insert(distribution_slot, (claim_amount, current_time.seconds()));
         }
 
-        let (rounding_error_compensation_amount, slot) = get_compensation_for_rounding_errors(
-            campaign,
-            current_time,
-            total_claimable_amount,
-            previous_claims_for_address.clone(),
-            &new_claims,
-        )?;
-
-        if rounding_error_compensation_amount > Uint128::zero() {
-            claimable_amount = claimable_amount.checked_add(rounding_error_compensation_amount)?;
-
-            let (amount, _) = match new_claims.get_mut(&slot) {
-                Some(existing_claim) => existing_claim,
-                None => {
-                    let new_claim = (Uint128::zero(), current_time.seconds());
-                    new_claims.insert(slot, new_claim);
-                    new_claims.get_mut(&slot).unwrap()
+        // claims made before the rounding dust was attributed to the slots may have taken it from
+        // another slot, so the new claims are capped to what's left of the allocation
+        let previously_claimed = previous_claims_for_address
+            .values()
+            .try_fold(Uint128::zero(), |acc, (amount, _)| acc.checked_add(*amount))?;
+        let remaining_allocation = total_claimable_amount.saturating_sub(previously_claimed);
+
+        if claimable_amount > remaining_allocation {
+            let mut excess = claimable_amount.checked_sub(remaining_allocation)?;
+
+            for slot in (0..campaign.distribution_type.len()).rev() {
+                if let Some((amount, _)) = new_claims.get_mut(&slot) {
+                    let deduction = excess.min(*amount);
+                    *amount = amount.checked_sub(deduction)?;
+                    excess = excess.checked_sub(deduction)?;
                 }
-            };
+            }
 
-            *amount = amount.checked_add(rounding_error_compensation_amount)?;
+            new_claims.retain(|_, (amount, _)| !amount.is_zero());
+            claimable_amount = remaining_allocation;
         }
     } else {
-        return Err(ContractError::CampaignError {
-            reason: "not started".to_string(),
+        return Err(ContractError::CampaignNotStarted {
+            starts_at: campaign.start_time,
         });
     }
 
@@ -137,36 +316,939 @@ pub(crate) fn compute_claimable_amount(
     ))
 }
 
+/// Computes the stream of rewards of an allocation at the given time, i.e. the tokens accrued per
+/// second by the linear vesting distributions in progress, and the upcoming times at which tokens
+/// unlock at once.
+///
+/// # Arguments
+/// * `campaign` - The campaign, as seen by the address
+/// * `current_time` - The time at which the stream is computed
+/// * `total_allocation` - The total allocation of the address
+///
+/// # Returns
+/// * `Result<(Decimal256, Vec<Timestamp>), ContractError>` - The accrual rate per second, and
+///   the upcoming unlocks, sorted
+pub fn compute_reward_stream(
+    campaign: &Campaign,
+    current_time: &Timestamp,
+    total_allocation: Uint128,
+) -> Result<(Decimal256, Vec<Timestamp>), ContractError> {
+    let slot_allocations = compute_slot_allocations(campaign, total_allocation)?;
+    let campaign_cliff_end = campaign
+        .start_time
+        .plus_seconds(campaign.cliff_duration.unwrap_or_default());
+
+    let mut accrual_rate = Decimal256::zero();
+    let mut next_unlocks = vec![];
+    if campaign_cliff_end > *current_time && campaign.cliff_duration.is_some() {
+        next_unlocks.push(campaign_cliff_end);
+    }
+
+    for (slot, distribution) in campaign.distribution_type.iter().enumerate() {
+        match distribution {
+            DistributionType::LinearVesting {
+                start_time,
+                end_time,
+                cliff_duration,
+                ..
+            } => {
+                let cliff_end = start_time.plus_seconds(cliff_duration.unwrap_or_default());
+                if cliff_end > *current_time && cliff_duration.is_some() {
+                    next_unlocks.push(cliff_end);
+                }
+
+                let vesting_start_time = distribution.vesting_start_time().unwrap_or(*start_time);
+                if start_time <= current_time
+                    && current_time < end_time
+                    && distribution.has_cliff_passed(current_time)
+                    && campaign.has_cliff_passed(current_time)
+                {
+                    let (slot_allocation, _) = slot_allocations[slot];
+                    accrual_rate = accrual_rate.checked_add(Decimal256::from_ratio(
+                        slot_allocation,
+                        end_time
+                            .seconds()
+                            .saturating_sub(vesting_start_time.seconds()),
+                    ))?;
+                }
+            }
+            DistributionType::LumpSum {
+                start_time,
+                cliff_duration,
+                ..
+            } => {
+                let unlock = start_time.plus_seconds(cliff_duration.unwrap_or_default());
+                if unlock > *current_time {
+                    next_unlocks.push(unlock);
+                }
+            }
+            DistributionType::EarlyBirdBonus { .. } => {
+                if campaign.start_time > *current_time {
+                    next_unlocks.push(campaign.start_time);
+                }
+            }
+            // the curves accrue at a varying rate, and the raffle prizes credit the allocations
+            DistributionType::CurveVesting { .. } | DistributionType::Raffle { .. } => {}
+        }
+    }
+
+    next_unlocks.sort();
+    next_unlocks.dedup();
+
+    Ok((accrual_rate, next_unlocks))
+}
+
+/// Calculates the amount a group member can claim at this point in time. The member's share of
+/// the pool, pro-rata to its weight, vests following the schedule of the group.
+///
+/// # Arguments
+/// * `campaign` - The campaign
+/// * `group` - The group
+/// * `member` - The member of the group
+/// * `current_time` - The time at which the claimable amount is computed
+///
+/// # Returns
+/// * `Result<(Uint128, ClaimableResult), ContractError>` - The share of the member, and the
+///   claimable coin, the new claims per slot and the previous claims per slot
+pub fn compute_group_claimable_amount(
+    campaign: &Campaign,
+    group: &Group,
+    member: &GroupMember,
+    current_time: &Timestamp,
+) -> Result<(Uint128, ClaimableResult), ContractError> {
+    // the members have a non-zero weight, so the total weight of a group with members isn't zero
+    let share = group
+        .total
+        .multiply_ratio(member.weight, group.total_weight);
+
+    // the campaign drives the phases and the cliff, the group its own distribution schedule
+    let group_campaign = Campaign {
+        distribution_type: group.schedule.clone(),
+        ..campaign.clone()
+    };
+
+    let claimable =
+        calculate_claimable_amount(&group_campaign, current_time, share, member.claims.clone())?;
+
+    Ok((share, claimable))
+}
+
+/// Computes the allocation of a user on each distribution slot. A slot gets its percentage of the
+/// total allocation rounded down, and the dust left by the rounding is attributed one token at a
+/// time to the slots with the largest fractional remainders, ties going to the lowest slot. This
+/// way the slot allocations add up to the total allocation, and the dust of a slot is paid
+/// deterministically on its final claim. The raffle slot has no allocation. When the percentages
+/// add up to less than the whole, i.e. for an address accruing from its allocation time, only the
+/// dust of the exact slot allocations is attributed.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `total_user_allocation` - The total allocation of the user
+///
+/// # Returns
+/// * `Result<Vec<(Uint128, Uint128)>, ContractError>` - The allocation of each slot, including
+///   its rounding dust, and the rounding dust attributed to it, indexed by slot
+pub fn compute_slot_allocations(
+    campaign: &Campaign,
+    total_user_allocation: Uint128,
+) -> Result<Vec<(Uint128, Uint128)>, ContractError> {
+    let total_user_allocation_dec =
+        Decimal256::from_ratio(Uint256::from_uint128(total_user_allocation), Uint256::one());
+
+    let mut slot_allocations = vec![];
+    let mut remainders = vec![];
+    let mut allocated = Uint128::zero();
+    let mut exact_total = Decimal256::zero();
+
+    for (slot, distribution) in campaign.distribution_type.iter().enumerate() {
+        let percentage = match distribution {
+            DistributionType::LinearVesting { percentage, .. }
+            | DistributionType::CurveVesting { percentage, .. }
+            | DistributionType::LumpSum { percentage, .. }
+            | DistributionType::EarlyBirdBonus {
+                base_percentage: percentage,
+                ..
+            } => percentage,
+            DistributionType::Raffle { .. } => {
+                slot_allocations.push((Uint128::zero(), Uint128::zero()));
+                continue;
+            }
+        };
+
+        let exact_allocation =
+            Decimal256::from(*percentage).checked_mul(total_user_allocation_dec)?;
+        let slot_allocation = Uint128::try_from(exact_allocation.to_uint_floor())?;
+
+        allocated = allocated.checked_add(slot_allocation)?;
+        exact_total = exact_total.checked_add(exact_allocation)?;
+        slot_allocations.push((slot_allocation, Uint128::zero()));
+        remainders.push((exact_allocation - exact_allocation.floor(), slot));
+    }
+
+    // stable sort, so the slots with equal remainders keep ascending order
+    remainders.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let dust = total_user_allocation
+        .min(Uint128::try_from(exact_total.to_uint_ceil())?)
+        .saturating_sub(allocated);
+    for (_, slot) in remainders
+        .into_iter()
+        .take(usize::try_from(dust.u128()).unwrap_or(usize::MAX))
+    {
+        let (slot_allocation, slot_dust) = &mut slot_allocations[slot];
+        *slot_allocation = slot_allocation.checked_add(Uint128::one())?;
+        *slot_dust = Uint128::one();
+    }
+
+    Ok(slot_allocations)
+}
+
+/// Returns the message notifying the campaign registry of the given update, if a registry is
+/// configured.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `update` - The lifecycle event of the campaign
+///
+/// # Returns
+/// * `Result<Option<CosmosMsg>, ContractError>` - The message to the registry, if any
+pub fn registry_update_message(
+    deps: Deps,
+    update: RegistryUpdateMsg,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    Ok(CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .registry
+        .map(|registry| update.into_cosmos_msg(registry))
+        .transpose()?)
+}
+
+/// Fails if the owner paused the given scope of the contract, [PauseScope::All] pausing every
+/// scope.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `scope` - The scope of the command
+///
+/// # Returns
+/// * `Result<(), ContractError>` - A [ContractError::ContractPaused] if the scope is paused
+pub fn assert_not_paused(deps: Deps, scope: PauseScope) -> Result<(), ContractError> {
+    if let Some(paused) = CONFIG.may_load(deps.storage)?.unwrap_or_default().pause {
+        ensure!(
+            !paused.covers(&scope),
+            ContractError::ContractPaused {
+                scope: paused.as_str().to_string(),
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits the amount to claim across the distribution slots that have new claimable amounts,
+/// draining the slots in the order set by the [ClaimPriority] of the campaign.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `new_claims` - The claimable amounts per slot, as returned by [calculate_claimable_amount]
+/// * `amount` - The amount to split across the slots
+/// * `current_time` - The time at which the claim is made
+///
+/// # Returns
+/// * `Result<DistributionClaims, ContractError>` - The amount taken from each slot
+pub fn split_claim_across_slots(
+    campaign: &Campaign,
+    new_claims: &DistributionClaims,
+    amount: Uint128,
+    current_time: &Timestamp,
+) -> Result<DistributionClaims, ContractError> {
+    let mut claims_to_record: DistributionClaims = HashMap::new();
+    let mut remaining_to_distribute = amount;
+
+    let mut lump_sum_slots_with_new_claims: Vec<DistributionSlot> = vec![];
+    let mut linear_vesting_slots_with_new_claims: Vec<DistributionSlot> = vec![];
+
+    for (idx, dist_type) in campaign.distribution_type.iter().enumerate() {
+        if new_claims.contains_key(&idx) {
+            // Only consider slots that have new claimable amounts
+            match dist_type {
+                DistributionType::LumpSum { .. }
+                | DistributionType::EarlyBirdBonus { .. }
+                | DistributionType::Raffle { .. } => lump_sum_slots_with_new_claims.push(idx),
+                DistributionType::LinearVesting { .. } | DistributionType::CurveVesting { .. } => {
+                    linear_vesting_slots_with_new_claims.push(idx)
+                }
+            }
+        }
+    }
+
+    let ordered_slots: Vec<DistributionSlot> = match campaign.claim_priority {
+        ClaimPriority::LumpSumFirst => lump_sum_slots_with_new_claims
+            .into_iter()
+            .chain(linear_vesting_slots_with_new_claims)
+            .collect(),
+        ClaimPriority::VestingFirst => linear_vesting_slots_with_new_claims
+            .into_iter()
+            .chain(lump_sum_slots_with_new_claims)
+            .collect(),
+        ClaimPriority::SlotOrder => {
+            let mut slots = lump_sum_slots_with_new_claims;
+            slots.extend(linear_vesting_slots_with_new_claims);
+            slots.sort_unstable();
+            slots
+        }
+    };
+
+    for slot_idx in ordered_slots {
+        if remaining_to_distribute == Uint128::zero() {
+            break;
+        }
+        // new_claims.get(&slot_idx) is guaranteed to return Some since slot_idx comes from slots with new claims
+        let (available_from_slot, _) = new_claims
+            .get(&slot_idx)
+            .expect("slot_idx must exist in new_claims");
+        let take_from_slot = std::cmp::min(remaining_to_distribute, *available_from_slot);
+        if take_from_slot > Uint128::zero() {
+            claims_to_record.insert(slot_idx, (take_from_slot, current_time.seconds()));
+            remaining_to_distribute = remaining_to_distribute.saturating_sub(take_from_slot);
+        }
+    }
+
+    // Enforce the invariant that all requested tokens have been distributed
+    ensure!(
+        remaining_to_distribute == Uint128::zero(),
+        ContractError::CampaignError {
+            reason: format!(
+                "Distribution error: {remaining_to_distribute} tokens remain undistributed. This indicates a bug in the claimable amount calculation."
+            )
+        }
+    );
+
+    Ok(claims_to_record)
+}
+
+/// Restricts the new claims to the given distribution slots, so a user can choose which slots to
+/// claim from.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `new_claims` - The claimable amounts per slot, as returned by [calculate_claimable_amount]
+/// * `slots` - The slots to claim from
+///
+/// # Returns
+/// * `Result<(Coin, DistributionClaims), ContractError>` - The claimable coin and the new claims
+///   of the selected slots
+pub fn select_claim_slots(
+    campaign: &Campaign,
+    mut new_claims: DistributionClaims,
+    slots: &[DistributionSlot],
+) -> Result<(Coin, DistributionClaims), ContractError> {
+    ensure!(
+        !slots.is_empty(),
+        ContractError::InvalidInput {
+            reason: "slots cannot be empty".to_string()
+        }
+    );
+
+    let mut claimable_amount = Uint128::zero();
+    let mut selected_claims = HashMap::new();
+
+    for slot in slots {
+        ensure!(
+            *slot < campaign.distribution_type.len(),
+            ContractError::InvalidInput {
+                reason: format!("distribution slot {slot} doesn't exist")
+            }
+        );
+
+        let claim = new_claims
+            .remove(slot)
+            .filter(|(amount, _)| !amount.is_zero())
+            .ok_or_else(|| ContractError::InvalidInput {
+                reason: format!("nothing to claim from distribution slot {slot}"),
+            })?;
+
+        claimable_amount = claimable_amount.checked_add(claim.0)?;
+        selected_claims.insert(*slot, claim);
+    }
+
+    Ok((
+        Coin {
+            denom: campaign.total_reward.denom.clone(),
+            amount: claimable_amount,
+        },
+        selected_claims,
+    ))
+}
+
+/// Computes the unvested amounts of the linear vesting slots, i.e. what remains to be vested after
+/// the given claims, so they can be locked in a vesting account. All the linear vestings must have
+/// started and passed their cliff.
+/// The curve vesting slots aren't locked, as the vesting accounts vest linearly.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `current_time` - The time at which the claim is made
+/// * `total_user_allocation` - The total allocation of the user
+/// * `claims` - The claims of the user per slot, including the ones being made
+///
+/// # Returns
+/// * `Result<(DistributionClaims, Timestamp), ContractError>` - The unvested amounts per slot, and
+///   the time at which the last linear vesting ends
+pub fn compute_unvested_claims(
+    campaign: &Campaign,
+    current_time: &Timestamp,
+    total_user_allocation: Uint128,
+    claims: &DistributionClaims,
+) -> Result<(DistributionClaims, Timestamp), ContractError> {
+    let mut unvested_claims = HashMap::new();
+    let mut vesting_end_time = *current_time;
+    let slot_allocations = compute_slot_allocations(campaign, total_user_allocation)?;
+
+    for (slot, distribution) in campaign.distribution_type.iter().enumerate() {
+        let DistributionType::LinearVesting { end_time, .. } = distribution else {
+            continue;
+        };
+
+        ensure!(
+            campaign.has_cliff_passed(current_time)
+                && distribution.has_started(current_time)
+                && distribution.has_cliff_passed(current_time),
+            ContractError::CampaignError {
+                reason: "the linear vesting has not started yet".to_string()
+            }
+        );
+
+        let (slot_allocation, _) = slot_allocations[slot];
+        let claimed = claims
+            .get(&slot)
+            .map_or(Uint128::zero(), |(amount, _)| *amount);
+        let unvested = slot_allocation.saturating_sub(claimed);
+
+        if !unvested.is_zero() {
+            unvested_claims.insert(slot, (unvested, current_time.seconds()));
+            vesting_end_time = vesting_end_time.max(*end_time);
+        }
+    }
+
+    ensure!(
+        !unvested_claims.is_empty(),
+        ContractError::CampaignError {
+            reason: "there are no unvested tokens left".to_string()
+        }
+    );
+
+    Ok((unvested_claims, vesting_end_time))
+}
+
+/// Computes the early bird bonus for a claim. The bonus is granted once, on the first claim from
+/// the early bird bonus slot made before the deadline, and is capped by what's left in the bonus
+/// pool so the campaign never pays more bonuses than it reserved.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `current_time` - The time at which the claim is made
+/// * `total_user_allocation` - The total allocation of the user
+/// * `previous_claims` - The claims previously made by the user, per distribution slot
+/// * `claims_to_record` - The claims being made, per distribution slot
+/// * `bonus_claimed` - The amount of the bonus pool paid out so far
+///
+/// # Returns
+/// * `Result<Uint128, ContractError>` - The bonus to pay out along with the claim
+pub fn compute_early_bird_bonus(
+    campaign: &Campaign,
+    current_time: &Timestamp,
+    total_user_allocation: Uint128,
+    previous_claims: &DistributionClaims,
+    claims_to_record: &DistributionClaims,
+    bonus_claimed: Uint128,
+) -> Result<Uint128, ContractError> {
+    let Some((slot, bonus_percentage)) =
+        campaign
+            .distribution_type
+            .iter()
+            .enumerate()
+            .find_map(|(slot, distribution)| match distribution {
+                DistributionType::EarlyBirdBonus {
+                    bonus_percentage,
+                    bonus_deadline,
+                    ..
+                } if current_time < bonus_deadline => Some((slot, bonus_percentage)),
+                _ => None,
+            })
+    else {
+        return Ok(Uint128::zero());
+    };
+
+    if previous_claims.contains_key(&slot) || !claims_to_record.contains_key(&slot) {
+        return Ok(Uint128::zero());
+    }
+
+    let bonus = total_user_allocation.mul_floor(*bonus_percentage);
+    let bonus_pool = campaign.total_reward.amount.mul_floor(*bonus_percentage);
+
+    Ok(bonus.min(bonus_pool.saturating_sub(bonus_claimed)))
+}
+
+/// Computes the boost granted to a claim locked in the contract for the given tier. The boost is
+/// the claimed amount times the multiplier of the tier, minus the claimed amount, and is capped by
+/// what's left in the lock boost pool.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the lock boost
+/// * `tier` - The lock tier chosen by the receiver
+/// * `claim_amount` - The amount being claimed
+/// * `boost_claimed` - The amount of the lock boost pool paid out so far
+///
+/// # Returns
+/// * `Result<Uint128, ContractError>` - The boost to lock along with the claim
+pub fn compute_lock_boost(
+    campaign: &Campaign,
+    tier: &LockTier,
+    claim_amount: Uint128,
+    boost_claimed: Uint128,
+) -> Result<Uint128, ContractError> {
+    let (lock_boost, multiplier) = campaign
+        .lock_boost
+        .as_ref()
+        .and_then(|lock_boost| Some((lock_boost, lock_boost.multiplier(tier)?)))
+        .ok_or(ContractError::InvalidInput {
+            reason: format!("the campaign doesn't offer the lock tier {tier:?}"),
+        })?;
+
+    let boost = claim_amount.mul_floor(multiplier - Decimal::one());
+    let boost_pool = campaign
+        .total_reward
+        .amount
+        .mul_floor(lock_boost.pool_percentage);
+
+    Ok(boost.min(boost_pool.saturating_sub(boost_claimed)))
+}
+
+/// Returns the time at which the claim window of an address expires, i.e. the claim window after
+/// its allocation first becomes claimable.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `campaign` - The campaign
+/// * `address` - The address
+///
+/// # Returns
+/// * `Result<Option<Timestamp>, ContractError>` - The expiry of the claim window, or None if the
+///   campaign has no claim windows or the address didn't activate its vesting yet
+pub fn claim_window_expiry(
+    deps: Deps,
+    campaign: &Campaign,
+    address: &str,
+) -> Result<Option<Timestamp>, ContractError> {
+    let Some(claim_window_seconds) = campaign.claim_window_seconds else {
+        return Ok(None);
+    };
+
+    Ok(
+        campaign_for_address(deps, campaign, address)?.map(|campaign| {
+            campaign
+                .first_unlock_time()
+                .plus_seconds(claim_window_seconds)
+        }),
+    )
+}
+
+/// Returns the balance of the reward denom held by the contract for the campaign, i.e. excluding
+/// the claimed tokens locked in the contract until their lock expires, and capped to what's left
+/// of the campaign budget, if set.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `contract` - The address of the contract
+/// * `campaign` - The campaign
+///
+/// # Returns
+/// * `Result<Coin, ContractError>` - The reward balance available to the campaign
+pub fn query_reward_balance(
+    deps: Deps,
+    contract: &Addr,
+    campaign: &Campaign,
+) -> Result<Coin, ContractError> {
+    let mut balance = deps
+        .querier
+        .query_balance(contract, &campaign.total_reward.denom)?;
+    let locked = LOCKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+    balance.amount = balance.amount.saturating_sub(locked);
+
+    // the campaign can't draw on the balance beyond its budget
+    if let Some(budget) = BUDGET.may_load(deps.storage)? {
+        balance.amount = balance.amount.min(budget.remaining());
+    }
+
+    Ok(balance)
+}
+
+/// Records the amount paid out of the contract balance against the budget of the campaign, if
+/// it has one.
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `amount` - The amount paid out
+///
+/// # Returns
+/// * `Result<(), ContractError>` - Ok if the amount was recorded
+pub(crate) fn spend_budget(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(mut budget) = BUDGET.may_load(storage)? {
+        budget.used = budget.used.checked_add(amount)?;
+        BUDGET.save(storage, &budget)?;
+    }
+
+    Ok(())
+}
+
+/// Ensures the campaign holds the funds it requires to accept claims. Campaigns requiring full
+/// funding must hold the rewards left to claim, i.e. the total reward minus the amount already
+/// claimed. Other campaigns, and campaigns minting on claim, are always considered funded.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `contract` - The address of the contract
+/// * `campaign` - The campaign
+///
+/// # Returns
+/// * `Result<(), ContractError>` - [ContractError::CampaignNotFullyFunded] if the campaign lacks
+///   funds
+pub fn ensure_funded(
+    deps: Deps,
+    contract: &Addr,
+    campaign: &Campaign,
+) -> Result<(), ContractError> {
+    if !campaign.require_full_funding || campaign.reward_source == RewardSource::MintOnClaim {
+        return Ok(());
+    }
+
+    let available = query_reward_balance(deps, contract, campaign)?.amount;
+    let required = remaining_obligation(deps, campaign)?;
+
+    ensure!(
+        available >= required,
+        ContractError::CampaignNotFullyFunded {
+            required,
+            available
+        }
+    );
+
+    Ok(())
+}
+
+/// Returns what the campaign still owes, i.e. the total reward minus the amount already claimed
+/// and the allocations forfeited, which are no longer owed.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `campaign` - The campaign
+///
+/// # Returns
+/// * `Result<Uint128, ContractError>` - The amount the campaign still owes
+pub fn remaining_obligation(deps: Deps, campaign: &Campaign) -> Result<Uint128, ContractError> {
+    let forfeited = FORFEITED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(campaign
+        .total_reward
+        .amount
+        .saturating_sub(campaign.claimed.amount)
+        .saturating_sub(forfeited))
+}
+
+/// Ensures the allocations and the group pools, paid out of the same campaign total reward, don't
+/// exceed it together. Does nothing before the campaign is created, or while there are no groups.
+/// The allocations total is read from [TOTAL_ALLOCATED], which the v3.6.0 migration builds for the
+/// contracts upgraded with allocations.
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `new_pool` - The pool of a group being created, not stored yet
+///
+/// # Returns
+/// * `Result<(), ContractError>` - [ContractError::InvalidInput] if the total reward is exceeded
+pub fn ensure_group_pools_covered(
+    storage: &dyn Storage,
+    new_pool: Uint128,
+) -> Result<(), ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(storage)? else {
+        return Ok(());
+    };
+
+    let groups_total = GROUPS
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(new_pool, |acc, item| -> Result<Uint128, ContractError> {
+            Ok(acc.checked_add(item?.1.total)?)
+        })?;
+    if groups_total.is_zero() {
+        return Ok(());
+    }
+
+    let allocated = TOTAL_ALLOCATED.may_load(storage)?.unwrap_or_default();
+    ensure!(
+        allocated.checked_add(groups_total)? <= campaign.total_reward.amount,
+        ContractError::InvalidInput {
+            reason: format!(
+                "the allocations total {allocated} and the groups total {groups_total} exceed the campaign total reward {}",
+                campaign.total_reward.amount
+            ),
+        }
+    );
+
+    Ok(())
+}
+
+/// Ensures the address accepted the current terms of the campaign, if it has terms.
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `campaign` - The campaign
+/// * `address` - The address claiming
+///
+/// # Returns
+/// * `Result<(), ContractError>` - [ContractError::TermsNotAccepted] if the terms weren't accepted
+pub fn ensure_terms_accepted(
+    storage: &dyn Storage,
+    campaign: &Campaign,
+    address: &str,
+) -> Result<(), ContractError> {
+    if let Some(terms_hash) = &campaign.terms_hash {
+        ensure!(
+            has_accepted_terms(storage, terms_hash, address)?,
+            ContractError::TermsNotAccepted {
+                terms_hash: terms_hash.clone()
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Ensures the cooldown of the campaign, if any, elapsed since the last of the previous claims.
+///
+/// # Arguments
+/// * `campaign` - The campaign
+/// * `previous_claims` - The previous claims of the address
+/// * `current_time` - The current time
+///
+/// # Returns
+/// * `Result<(), ContractError>` - [ContractError::ClaimCooldownActive] if the cooldown is active
+pub fn ensure_claim_cooldown_elapsed(
+    campaign: &Campaign,
+    previous_claims: &DistributionClaims,
+    current_time: &Timestamp,
+) -> Result<(), ContractError> {
+    if let Some(cooldown) = campaign.claim_cooldown_seconds {
+        if let Some(last_claimed_at) = previous_claims.values().map(|(_, time)| *time).max() {
+            let next_claim_at = last_claimed_at.saturating_add(cooldown);
+            ensure!(
+                current_time.seconds() >= next_claim_at,
+                ContractError::ClaimCooldownActive { next_claim_at }
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the funding of the distribution slots of a campaign. The obligation of a slot is its
+/// share of the total reward, plus the pool reserved by the early bird bonus and raffle slots. The
+/// funds received, i.e. the balance plus what has already been paid out, are split across the
+/// slots pro rata to their obligations. Campaigns minting on claim are always fully funded.
+///
+/// # Arguments
+/// * `campaign` - The campaign, containing the distribution schedule
+/// * `balance` - The balance of the reward denom held by the contract
+/// * `slots_claimed` - The amount paid out from each slot
+///
+/// # Returns
+/// * `Result<Vec<SlotSolvency>, ContractError>` - The funding of each slot, sorted by slot
+pub fn compute_slot_solvency(
+    campaign: &Campaign,
+    balance: Uint128,
+    slots_claimed: &HashMap<DistributionSlot, Uint128>,
+) -> Result<Vec<SlotSolvency>, ContractError> {
+    let total_reward = campaign.total_reward.amount;
+    let obligations = campaign
+        .distribution_type
+        .iter()
+        .map(|dist| {
+            Ok(match dist {
+                DistributionType::LinearVesting { percentage, .. }
+                | DistributionType::CurveVesting { percentage, .. }
+                | DistributionType::LumpSum { percentage, .. } => {
+                    total_reward.mul_floor(*percentage)
+                }
+                DistributionType::EarlyBirdBonus {
+                    base_percentage,
+                    bonus_percentage,
+                    ..
+                } => total_reward
+                    .mul_floor(*base_percentage)
+                    .checked_add(total_reward.mul_floor(*bonus_percentage))?,
+                // the prizes are credited to the winners' allocations, but the pool is reserved
+                // by the raffle slot
+                DistributionType::Raffle {
+                    prize_pool_percentage,
+                    ..
+                } => total_reward.mul_floor(*prize_pool_percentage),
+            })
+        })
+        .collect::<Result<Vec<Uint128>, ContractError>>()?;
+
+    let total_obligation = obligations
+        .iter()
+        .try_fold(Uint128::zero(), |acc, obligation| {
+            acc.checked_add(*obligation)
+        })?;
+    let funds_received = balance.checked_add(campaign.claimed.amount)?;
+    let fully_funded =
+        campaign.reward_source == RewardSource::MintOnClaim || funds_received >= total_obligation;
+
+    Ok(obligations
+        .into_iter()
+        .enumerate()
+        .map(|(slot, obligation)| SlotSolvency {
+            slot,
+            obligation,
+            funded: if fully_funded {
+                obligation
+            } else {
+                obligation.multiply_ratio(funds_received, total_obligation)
+            },
+            claimed: slots_claimed.get(&slot).copied().unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Draws the raffle winners among the entrants, weighted by their allocations. The draw is
+/// deterministic given the seed, so anyone can verify the winners. The n-th winner is picked with
+/// the first 16 bytes of `sha256(seed || n)`, and an entrant can win more than once.
+///
+/// # Arguments
+/// * `seed` - The random seed
+/// * `entrants` - The entrants and their allocations
+/// * `winners` - The number of winners to draw
+///
+/// # Returns
+/// * `Vec<String>` - The winners, in the order they were drawn
+pub fn draw_raffle_winners(
+    seed: &[u8],
+    entrants: &[(String, Uint128)],
+    winners: u32,
+) -> Vec<String> {
+    let mut cumulative_weights = Vec::with_capacity(entrants.len());
+    let mut total_weight = 0u128;
+    for (_, allocation) in entrants {
+        total_weight = total_weight.saturating_add(allocation.u128());
+        cumulative_weights.push(total_weight);
+    }
+
+    if total_weight == 0 {
+        return vec![];
+    }
+
+    (0..winners)
+        .map(|draw| {
+            let hash = Sha256::new()
+                .chain_update(seed)
+                .chain_update(draw.to_be_bytes())
+                .finalize();
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(&hash[..16]);
+            let ticket = u128::from_be_bytes(bytes) % total_weight;
+
+            let winner = cumulative_weights.partition_point(|weight| *weight <= ticket);
+            entrants[winner].0.clone()
+        })
+        .collect()
+}
+
+/// Builds the bytes of the ADR-36 sign doc wrapping the given data, as signed by wallets for
+/// arbitrary messages. The sign doc is the canonical amino JSON, with sorted keys and no whitespace.
+///
+/// # Arguments
+/// * `signer` - The address signing the data
+/// * `data` - The signed data
+///
+/// # Returns
+/// * `Vec<u8>` - The sign doc bytes, which are hashed with sha256 before being signed
+pub fn adr36_sign_bytes(signer: &str, data: &[u8]) -> Vec<u8> {
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        Binary::from(data).to_base64(),
+        signer
+    )
+    .into_bytes()
+}
+
+/// Derives the canonical address of a secp256k1 public key, i.e. `ripemd160(sha256(pubkey))`.
+///
+/// # Arguments
+/// * `pubkey` - The compressed secp256k1 public key
+///
+/// # Returns
+/// * `Vec<u8>` - The canonical address bytes
+pub fn pubkey_to_canonical_address(pubkey: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(Sha256::digest(pubkey)).to_vec()
+}
+
+/// Hashes a message as an EIP-191 personal message, i.e.
+/// `keccak256("\x19Ethereum Signed Message:\n" || len(message) || message)`, as signed by the
+/// Ethereum wallets with `personal_sign`.
+///
+/// # Arguments
+/// * `message` - The message to hash
+///
+/// # Returns
+/// * `Vec<u8>` - The 32 bytes message hash
+pub fn eip191_hash(message: &[u8]) -> Vec<u8> {
+    Keccak256::new()
+        .chain_update(format!("\x19Ethereum Signed Message:\n{}", message.len()))
+        .chain_update(message)
+        .finalize()
+        .to_vec()
+}
+
+/// Derives the Ethereum address of a secp256k1 public key, i.e. the last 20 bytes of the
+/// `keccak256` of the uncompressed public key without its `0x04` prefix.
+///
+/// # Arguments
+/// * `pubkey` - The uncompressed secp256k1 public key
+///
+/// # Returns
+/// * `String` - The lowercase 0x-prefixed Ethereum address
+pub fn pubkey_to_ethereum_address(pubkey: &[u8]) -> String {
+    let hash = Keccak256::digest(pubkey.get(1..).unwrap_or_default());
+    format!("0x{}", HexBinary::from(&hash[12..]).to_hex())
+}
+
 /// Calculates the claimable amount for a given distribution, total amount and previous claim.
 fn calculate_claim_amount_for_distribution(
     current_time: &&Timestamp,
-    total_user_allocation: Uint128,
+    amount_allocated_to_this_slot: Uint128,
     distribution_type: &&DistributionType,
     previous_claim_for_this_slot: &Option<&Claim>,
 ) -> Result<Uint128, ContractError> {
     match distribution_type {
-        DistributionType::LinearVesting {
-            percentage,
-            start_time,
-            end_time,
-            ..
-        } => {
-            let amount_allocated_to_this_slot = Uint128::try_from(
-                Decimal256::from(*percentage)
-                    .checked_mul(Decimal256::from_ratio(
-                        Uint256::from_uint128(total_user_allocation),
-                        Uint256::one(),
-                    ))?
-                    .to_uint_floor(),
-            )?;
-
+        DistributionType::LinearVesting { end_time, .. }
+        | DistributionType::CurveVesting { end_time, .. } => {
+            // the delayed linear vestings only accrue from the end of their cliff
+            let start_time = distribution_type
+                .vesting_start_time()
+                .expect("the vesting distributions have a vesting start time");
             let already_claimed =
                 previous_claim_for_this_slot.map_or(Uint128::zero(), |(amount, _)| *amount);
 
             // Calculate distribution duration with proper validation
             // This should be guaranteed by validate_campaign_times but we double-check
+            let (start_time, end_time) = (start_time.seconds(), end_time.seconds());
             let distribution_duration = end_time
-                .checked_sub(*start_time)
+                .checked_sub(start_time)
                 .ok_or_else(|| ContractError::InvalidInput {
                     reason: format!(
                         "Invalid distribution: end_time ({end_time}) is before start_time ({start_time})"
@@ -182,21 +1264,26 @@ fn calculate_claim_amount_for_distribution(
             );
 
             // If current time is before distribution start, nothing is vested yet
-            if current_time.seconds() < *start_time {
+            if current_time.seconds() < start_time {
                 return Ok(Uint128::zero());
             }
 
             let time_passed_since_start = current_time
                 .seconds()
-                .checked_sub(*start_time)
+                .checked_sub(start_time)
                 .expect("current_time >= start_time checked above");
             let effective_time_passed =
                 std::cmp::min(time_passed_since_start, distribution_duration);
 
-            let vesting_progress = Decimal256::from_ratio(
-                Uint256::from(effective_time_passed),
-                Uint256::from(distribution_duration),
-            );
+            let vesting_progress = match distribution_type {
+                DistributionType::CurveVesting { curve, .. } => {
+                    curve_vesting_progress(curve, effective_time_passed, distribution_duration)?
+                }
+                _ => Decimal256::from_ratio(
+                    Uint256::from(effective_time_passed),
+                    Uint256::from(distribution_duration),
+                ),
+            };
 
             let total_vested_for_slot_at_current_time = Uint128::try_from(
                 Decimal256::from_ratio(
@@ -219,15 +1306,8 @@ fn calculate_claim_amount_for_distribution(
                 .checked_sub(already_claimed)
                 .expect("already_claimed <= total_vested checked above"))
         }
-        DistributionType::LumpSum { percentage, .. } => {
-            let total_entitlement_for_lumpsum_slot = Uint128::try_from(
-                Decimal256::from(*percentage)
-                    .checked_mul(Decimal256::from_ratio(
-                        Uint256::from_uint128(total_user_allocation),
-                        Uint256::one(),
-                    ))?
-                    .to_uint_floor(),
-            )?;
+        DistributionType::LumpSum { .. } | DistributionType::EarlyBirdBonus { .. } => {
+            let total_entitlement_for_lumpsum_slot = amount_allocated_to_this_slot;
 
             let already_claimed_for_this_slot =
                 previous_claim_for_this_slot.map_or(Uint128::zero(), |(amount, _)| *amount);
@@ -245,82 +1325,196 @@ fn calculate_claim_amount_for_distribution(
                 .expect("already_claimed <= total_entitlement checked above");
             Ok(newly_claimable)
         }
+        // the raffle prizes are credited to the winners' allocations, so there's nothing to claim
+        // from the raffle slot itself
+        DistributionType::Raffle { .. } => Ok(Uint128::zero()),
     }
 }
 
-/// Returns the compensation for rounding errors if the distribution types have ended. This is to claim
-/// the potential remaining dust in the campaign for the user due to rounding errors.
-fn get_compensation_for_rounding_errors(
-    campaign: &Campaign,
-    current_time: &Timestamp,
-    total_claimable_amount: Uint128,
-    previous_claims_for_address: HashMap<DistributionSlot, Claim>,
-    new_claims: &HashMap<DistributionSlot, Claim>,
-) -> Result<(Uint128, DistributionSlot), ContractError> {
-    if distribution_types_ended(campaign, current_time) {
-        let updated_claims = aggregate_claims(&previous_claims_for_address, new_claims)?;
+/// Computes the vested share of a curve vesting slot, given the time elapsed since its start.
+///
+/// # Arguments
+/// * `curve` - The shape of the vesting schedule
+/// * `elapsed` - The seconds elapsed since the start of the vesting, at most the duration
+/// * `duration` - The duration of the vesting in seconds, greater than zero
+///
+/// # Returns
+/// * `Result<Decimal256, ContractError>` - The vested share, between 0 and 1
+pub fn curve_vesting_progress(
+    curve: &Curve,
+    elapsed: u64,
+    duration: u64,
+) -> Result<Decimal256, ContractError> {
+    if elapsed >= duration {
+        return Ok(Decimal256::one());
+    }
+
+    let progress = match curve {
+        Curve::Exponential { rate } => {
+            let rate = Decimal256::from(*rate);
+            let elapsed_share =
+                Decimal256::from_ratio(Uint256::from(elapsed), Uint256::from(duration));
 
-        let total_claimed =
-            updated_claims
+            (exp(rate.checked_mul(elapsed_share)?)? - Decimal256::one())
+                / (exp(rate)? - Decimal256::one())
+        }
+        Curve::PiecewiseLinear { points } => {
+            let mut previous = (0u64, Decimal256::zero());
+            let mut progress = Decimal256::one();
+
+            // the curve ends fully vested at the end of the duration
+            for (point_elapsed, point_vested) in points
                 .iter()
-                .try_fold(Uint128::zero(), |acc, (_, (amount, _))| {
-                    acc.checked_add(*amount)
-                        .map_err(|_| ContractError::InvalidInput {
-                            reason: "arithmetic overflow calculating total claimed amount"
-                                .to_string(),
-                        })
-                })?;
+                .map(|(point_elapsed, point_vested)| {
+                    (*point_elapsed, Decimal256::from(*point_vested))
+                })
+                .chain(std::iter::once((duration, Decimal256::one())))
+            {
+                if elapsed < point_elapsed {
+                    progress = previous
+                        .1
+                        .checked_add((point_vested - previous.1).checked_mul(
+                            Decimal256::from_ratio(
+                                Uint256::from(elapsed - previous.0),
+                                Uint256::from(point_elapsed - previous.0),
+                            ),
+                        )?)?;
+                    break;
+                }
 
-        // get user dust to claim
-        let (slot, _) = new_claims
-            .iter()
-            .find(|(_, (_, timestamp))| *timestamp == current_time.seconds())
-            .unwrap_or((
-                &FALLBACK_DISTRIBUTION_SLOT,
-                &(Uint128::zero(), Default::default()),
-            ));
+                previous = (point_elapsed, point_vested);
+            }
 
-        return Ok((
-            total_claimable_amount.saturating_sub(total_claimed),
-            slot.to_owned(),
-        ));
-    }
+            progress
+        }
+    };
 
-    Ok((Uint128::zero(), FALLBACK_DISTRIBUTION_SLOT))
+    Ok(progress.min(Decimal256::one()))
 }
 
-/// Checks if all distribution types have ended
-fn distribution_types_ended(campaign: &Campaign, current_time: &Timestamp) -> bool {
-    for distribution_type in campaign.distribution_type.iter() {
-        match distribution_type {
-            DistributionType::LinearVesting { end_time, .. } => {
-                if *end_time > current_time.seconds() {
-                    return false;
-                }
-            }
-            DistributionType::LumpSum { start_time, .. } => {
-                // Lump Sum distributions are considered "ended" once their start_time has passed
-                // since they pay out all tokens at once. However, if the start_time hasn't
-                // occurred yet, the distribution hasn't ended.
-                if *start_time > current_time.seconds() {
-                    return false;
-                }
-            }
-        }
+/// Computes `e^y` with its Taylor series, which converges for the bounded exponents of the
+/// exponential curves.
+fn exp(y: Decimal256) -> Result<Decimal256, ContractError> {
+    let mut sum = Decimal256::one();
+    let mut term = Decimal256::one();
+    let mut n = 1u64;
+
+    while !term.is_zero() {
+        term = term.checked_mul(y)? / Decimal256::from_ratio(n, 1u64);
+        sum = sum.checked_add(term)?;
+        n += 1;
     }
 
-    true
+    Ok(sum)
 }
 
-/// Validates the raw address string.
+/// Validates the raw address string. Addresses with one of the allowed foreign bech32 prefixes
+/// are canonicalized to the local prefix, and base64 compressed secp256k1 public keys prefixed
+/// with [PUBKEY_ADDRESS_PREFIX] are converted to the address they derive. Ethereum addresses are
+/// accepted as keys so they can be linked to a MANTRA address later on, the rest of the
+/// non-bech32 strings being placeholders.
 pub fn validate_raw_address(deps: Deps, address_raw: &str) -> Result<String, ContractError> {
     if let Ok(addr) = deps.api.addr_validate(address_raw) {
         Ok(addr.to_string())
+    } else if let Some(addr) = canonicalize_foreign_address(deps, address_raw)? {
+        Ok(addr)
+    } else if let Some(addr) = derive_pubkey_address(deps, address_raw)? {
+        Ok(addr)
+    } else if is_ethereum_address_like(address_raw) {
+        validate_ethereum_address(address_raw)
     } else {
         Ok(validate_address_placeholder(address_raw)?)
     }
 }
 
+/// Converts a bech32 address with one of the allowed foreign prefixes to the local prefix, keeping
+/// its bytes. Returns None if the address isn't bech32 or its prefix isn't allowed.
+fn canonicalize_foreign_address(
+    deps: Deps,
+    address: &str,
+) -> Result<Option<String>, ContractError> {
+    let Ok((hrp, data)) = bech32::decode(address) else {
+        return Ok(None);
+    };
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if !config.allowed_prefixes.contains(&hrp.to_lowercase()) {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        deps.api
+            .addr_humanize(&CanonicalAddr::from(data))?
+            .to_string(),
+    ))
+}
+
+/// The prefix of the base64 compressed secp256k1 public keys given in place of addresses, so
+/// they're never mistaken for placeholders or the other way around.
+pub const PUBKEY_ADDRESS_PREFIX: &str = "pubkey:";
+
+/// Derives the address of a base64 compressed secp256k1 public key prefixed with
+/// [PUBKEY_ADDRESS_PREFIX], as the snapshots listing the public keys of the accounts provide them.
+/// Returns None if the string isn't prefixed, and an error if what follows the prefix isn't such a
+/// public key.
+fn derive_pubkey_address(deps: Deps, address: &str) -> Result<Option<String>, ContractError> {
+    let Some(encoded) = address.strip_prefix(PUBKEY_ADDRESS_PREFIX) else {
+        return Ok(None);
+    };
+    let pubkey = Binary::from_base64(encoded)
+        .ok()
+        .filter(|pubkey| pubkey.len() == 33 && matches!(pubkey[0], 0x02 | 0x03))
+        .ok_or_else(|| ContractError::InvalidInput {
+            reason: format!("'{encoded}' is not a base64 compressed secp256k1 public key"),
+        })?;
+
+    Ok(Some(
+        deps.api
+            .addr_humanize(&CanonicalAddr::from(pubkey_to_canonical_address(&pubkey)))?
+            .to_string(),
+    ))
+}
+
+/// Validates the foreign bech32 prefixes accepted in the addresses, returning them sorted and
+/// deduplicated.
+pub fn validate_bech32_prefixes(mut prefixes: Vec<String>) -> Result<Vec<String>, ContractError> {
+    for prefix in &prefixes {
+        ensure!(
+            bech32::Hrp::parse(prefix).is_ok() && prefix.to_lowercase() == *prefix,
+            ContractError::InvalidInput {
+                reason: format!("'{prefix}' is not a valid lowercase bech32 prefix"),
+            }
+        );
+    }
+
+    prefixes.sort();
+    prefixes.dedup();
+
+    Ok(prefixes)
+}
+
+/// The length of a 0x-prefixed Ethereum address.
+const ETHEREUM_ADDRESS_LEN: usize = 42;
+
+/// Whether the string is shaped like an Ethereum address, i.e. 0x-prefixed with 40 characters.
+fn is_ethereum_address_like(address: &str) -> bool {
+    address.len() == ETHEREUM_ADDRESS_LEN
+        && (address.starts_with("0x") || address.starts_with("0X"))
+}
+
+/// Validates an Ethereum address, returning it lowercased. The checksum of mixed-case addresses
+/// is not verified.
+pub fn validate_ethereum_address(address: &str) -> Result<String, ContractError> {
+    ensure!(
+        is_ethereum_address_like(address) && address[2..].chars().all(|c| c.is_ascii_hexdigit()),
+        ContractError::InvalidInput {
+            reason: format!("'{address}' is not a valid ethereum address"),
+        }
+    );
+
+    Ok(address.to_lowercase())
+}
+
 // Maximum allowed length for a placeholder address string.
 pub const MAX_PLACEHOLDER_ADDRESS_LEN: usize = 256;
 
@@ -0,0 +1,299 @@
+use cosmwasm_std::{
+    ensure, entry_point, from_json, Addr, Deps, DepsMut, Env, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder,
+    IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
+    IbcSourceCallbackMsg, IbcSrcCallback, MessageInfo, Response, StdAck, StdResult,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::events::{ActionAttributes, LockEvent};
+use crate::state::{CONFIG, IBC_ADMIN_CHANNEL, IBC_PAYOUTS, LOCKED_TOTAL, LOCKS};
+use crate::{commands, guards, helpers};
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{IbcAdminPacket, PauseScope, IBC_ADMIN_VERSION};
+
+/// Validates the admin channel being opened. The channel must be unordered, use the
+/// [IBC_ADMIN_VERSION] and be opened by the counterparty set in the config, and there can't be
+/// another admin channel open.
+#[entry_point]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_admin_channel(deps.as_ref(), msg.channel(), msg.counterparty_version())?;
+
+    Ok(None)
+}
+
+/// Stores the admin channel once the handshake is completed.
+#[entry_point]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_admin_channel(deps.as_ref(), channel, msg.counterparty_version())?;
+
+    IBC_ADMIN_CHANNEL.save(deps.storage, &channel.endpoint.channel_id)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+/// Removes the admin channel once it's closed, so the counterparty can open a new one.
+#[entry_point]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = &msg.channel().endpoint.channel_id;
+
+    if IBC_ADMIN_CHANNEL.may_load(deps.storage)?.as_ref() == Some(channel_id) {
+        IBC_ADMIN_CHANNEL.remove(deps.storage);
+    }
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", channel_id))
+}
+
+/// Executes an [IbcAdminPacket] received over the admin channel, with the authority of the owner.
+/// The contract only writes the success acknowledgement. If the packet fails, the error is returned
+/// as is, so the chain reverts the state changes and acknowledges the packet with the error.
+#[entry_point]
+pub fn ibc_packet_receive(
+    mut deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel_id = &msg.packet.dest.channel_id;
+    ensure!(
+        IBC_ADMIN_CHANNEL.may_load(deps.storage)?.as_ref() == Some(channel_id),
+        ContractError::InvalidIbcAdminChannel {
+            reason: format!("{channel_id} is not the admin channel")
+        }
+    );
+
+    helpers::assert_not_paused(deps.as_ref(), PauseScope::All)?;
+
+    let packet: IbcAdminPacket = from_json(&msg.packet.data)?;
+    let owner = cw_ownable::get_ownership(deps.storage)?
+        .owner
+        .ok_or(ContractError::Unauthorized)?;
+    // the admin packets don't change the claims, so only the owner acting for them is checked
+    let affected_addresses = vec![owner.to_string()];
+    let info = MessageInfo {
+        sender: owner,
+        funds: vec![],
+    };
+
+    let response: Response = match packet {
+        IbcAdminPacket::AddAllocations { allocations } => commands::add_allocations(
+            deps.branch(),
+            env.clone(),
+            info,
+            allocations,
+            false,
+            None,
+            None,
+        )?,
+        IbcAdminPacket::BlacklistAddress {
+            address,
+            blacklist,
+            reason,
+        } => commands::blacklist_address(
+            deps.branch(),
+            env.clone(),
+            info,
+            address,
+            blacklist,
+            reason,
+        )?,
+        IbcAdminPacket::CloseCampaign {} => commands::close_campaign(deps.branch(), env.clone())?,
+    };
+
+    // the admin packets are checked same as the messages executed by the owner
+    if guards::INVARIANT_GUARDS_ENABLED {
+        guards::check_invariants(deps.as_ref(), &env, &affected_addresses, &response)?;
+    }
+
+    Ok(IbcReceiveResponse::new(StdAck::success(b"\x01"))
+        .add_attribute("ibc_admin_channel", channel_id)
+        .add_attributes(response.attributes)
+        .add_submessages(response.messages)
+        .add_events(response.events))
+}
+
+/// The contract doesn't send packets over the admin channel, so there's nothing to do.
+#[entry_point]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(IbcBasicResponse::new())
+}
+
+/// The contract doesn't send packets over the admin channel, so there's nothing to do.
+#[entry_point]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(IbcBasicResponse::new())
+}
+
+/// The memo of the IBC transfers paying out claims. It requests the source callback for the
+/// contract and carries the id of the payout in [IBC_PAYOUTS].
+#[derive(Serialize, Deserialize)]
+pub(crate) struct IbcPayoutMemo {
+    src_callback: IbcSrcCallback,
+    claimdrop_payout: u64,
+}
+
+impl IbcPayoutMemo {
+    pub(crate) fn new(contract: &Addr, payout_id: u64) -> Self {
+        Self {
+            src_callback: IbcSrcCallback {
+                address: contract.clone(),
+                gas_limit: None,
+            },
+            claimdrop_payout: payout_id,
+        }
+    }
+}
+
+/// The fields of the ICS-20 packet data needed to match a transfer with its payout.
+#[derive(Deserialize)]
+struct FungibleTokenPacketData {
+    sender: String,
+    #[serde(default)]
+    memo: String,
+}
+
+/// Settles the IBC transfers paying out claims. Once a transfer is acknowledged successfully its
+/// payout is forgotten, while if it times out or is acknowledged with an error, the refunded
+/// tokens are credited back to the receiver of the claim as a lock that can be withdrawn right away
+/// with [mantra_claimdrop_std::msg::ExecuteMsg::WithdrawUnlocked].
+#[entry_point]
+pub fn ibc_source_callback(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcSourceCallbackMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let (packet, failed) = match &msg {
+        IbcSourceCallbackMsg::Acknowledgement(ack) => (
+            &ack.original_packet,
+            !matches!(
+                from_json::<StdAck>(&ack.acknowledgement.data),
+                Ok(StdAck::Success(_))
+            ),
+        ),
+        IbcSourceCallbackMsg::Timeout(timeout) => (&timeout.packet, true),
+    };
+
+    let Some(payout_id) = payout_id(&env, packet) else {
+        return Ok(IbcBasicResponse::new());
+    };
+    let Some((receiver, amount)) = IBC_PAYOUTS.may_load(deps.storage, payout_id)? else {
+        return Ok(IbcBasicResponse::new());
+    };
+    IBC_PAYOUTS.remove(deps.storage, payout_id);
+
+    let attributes = ActionAttributes::new("ibc_payout_settled")
+        .add("payout_id", payout_id)
+        .add("receiver", &receiver)
+        .add("amount", amount)
+        .add("failed", failed)
+        .build();
+
+    if !failed {
+        return Ok(IbcBasicResponse::new().add_attributes(attributes));
+    }
+
+    let unlocks_at = env.block.time;
+    LOCKS.update(
+        deps.storage,
+        (receiver.as_str(), unlocks_at.seconds()),
+        |locked| -> StdResult<_> { Ok(locked.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    let locked_total = LOCKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+    LOCKED_TOTAL.save(deps.storage, &locked_total.checked_add(amount)?)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attributes(attributes)
+        .add_event(
+            LockEvent {
+                receiver: receiver.as_str(),
+                amount,
+                boost: Default::default(),
+                unlocks_at,
+            }
+            .into_event(),
+        ))
+}
+
+/// Returns the id of the payout the given transfer was sent for, if it was sent by the contract
+/// to pay out a claim.
+fn payout_id(env: &Env, packet: &IbcPacket) -> Option<u64> {
+    let data: FungibleTokenPacketData = from_json(&packet.data).ok()?;
+    if data.sender != env.contract.address.as_str() {
+        return None;
+    }
+
+    from_json::<IbcPayoutMemo>(data.memo.as_bytes())
+        .ok()
+        .map(|memo| memo.claimdrop_payout)
+}
+
+/// Validates the admin channel against the counterparty set in the config.
+fn validate_admin_channel(
+    deps: Deps,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    let ibc_admin = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .ibc_admin
+        .ok_or(ContractError::InvalidIbcAdminChannel {
+            reason: "the IBC administration is disabled".to_string(),
+        })?;
+
+    ensure!(
+        IBC_ADMIN_CHANNEL.may_load(deps.storage)?.is_none(),
+        ContractError::InvalidIbcAdminChannel {
+            reason: "an admin channel is already open".to_string()
+        }
+    );
+
+    ensure!(
+        channel.order == IbcOrder::Unordered,
+        ContractError::InvalidIbcAdminChannel {
+            reason: "the channel must be unordered".to_string()
+        }
+    );
+
+    ensure!(
+        channel.version == IBC_ADMIN_VERSION
+            && counterparty_version.is_none_or(|version| version == IBC_ADMIN_VERSION),
+        ContractError::InvalidIbcAdminChannel {
+            reason: format!("the channel version must be {IBC_ADMIN_VERSION}")
+        }
+    );
+
+    ensure!(
+        channel.connection_id == ibc_admin.connection_id
+            && channel.counterparty_endpoint.port_id == ibc_admin.counterparty_port_id,
+        ContractError::InvalidIbcAdminChannel {
+            reason: "the channel wasn't opened by the IBC admin".to_string()
+        }
+    );
+
+    Ok(())
+}
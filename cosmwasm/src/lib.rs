@@ -1,5 +1,10 @@
 pub mod commands;
 pub mod contract;
+pub mod events;
+mod guards;
 pub mod helpers;
+pub mod ibc;
+mod migrations;
 pub mod queries;
+mod stargate;
 pub mod state;
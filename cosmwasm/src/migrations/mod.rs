@@ -0,0 +1,67 @@
+use cosmwasm_std::{DepsMut, Env, Event};
+use semver::Version;
+
+use mantra_claimdrop_std::error::ContractError;
+
+mod v2_0_0;
+mod v3_0_0;
+mod v3_1_0;
+mod v3_2_0;
+mod v3_3_0;
+mod v3_4_0;
+mod v3_5_0;
+mod v3_6_0;
+
+/// A state migration step, upgrading the state layout to the version it is registered with.
+type MigrationStep = fn(DepsMut, &Env) -> Result<(), ContractError>;
+
+/// The state migration steps, sorted by the version they upgrade the state to.
+const MIGRATIONS: &[(&str, MigrationStep)] = &[
+    ("2.0.0", v2_0_0::migrate),
+    ("3.0.0", v3_0_0::migrate),
+    ("3.1.0", v3_1_0::migrate),
+    ("3.2.0", v3_2_0::migrate),
+    ("3.3.0", v3_3_0::migrate),
+    ("3.4.0", v3_4_0::migrate),
+    ("3.5.0", v3_5_0::migrate),
+    ("3.6.0", v3_6_0::migrate),
+];
+
+/// Runs the state migration steps required to go from the stored version to the new version, in
+/// order. Emits a `migrate_state` event per executed step.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `stored_version` - The version of the contract currently stored on chain
+/// * `new_version` - The version of the contract being migrated to
+///
+/// # Returns
+/// * `Result<Vec<Event>, ContractError>` - The events of the executed migration steps
+pub(crate) fn migrate_state(
+    mut deps: DepsMut,
+    env: &Env,
+    stored_version: &Version,
+    new_version: &Version,
+) -> Result<Vec<Event>, ContractError> {
+    let mut events = vec![];
+    let mut current_version = stored_version.clone();
+
+    for (version, step) in MIGRATIONS {
+        let step_version: Version = version.parse()?;
+
+        if current_version < step_version && step_version <= *new_version {
+            step(deps.branch(), env)?;
+
+            events.push(
+                Event::new("migrate_state")
+                    .add_attribute("from_version", current_version.to_string())
+                    .add_attribute("to_version", step_version.to_string()),
+            );
+
+            current_version = step_version;
+        }
+    }
+
+    Ok(events)
+}
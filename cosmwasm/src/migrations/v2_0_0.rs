@@ -0,0 +1,53 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, to_json_vec, Coin, DepsMut, Env};
+
+use super::v3_0_0::{CampaignV2, DistributionTypeV2};
+use crate::state::CAMPAIGN;
+use mantra_claimdrop_std::error::ContractError;
+
+/// The campaign layout of v1.x, which contained a standalone `reward_denom` field.
+#[cw_serde]
+struct CampaignV1 {
+    name: String,
+    description: String,
+    #[serde(rename = "type")]
+    ty: String,
+    reward_denom: String,
+    total_reward: Coin,
+    claimed: Coin,
+    distribution_type: Vec<DistributionTypeV2>,
+    start_time: u64,
+    end_time: u64,
+    closed: Option<u64>,
+}
+
+/// Migrates the campaign from the v1.x layout, dropping the `reward_denom` field. The denom is
+/// taken from `total_reward.denom` from v2.0.0 onwards.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let Some(raw_campaign) = deps.storage.get(CAMPAIGN.as_slice()) else {
+        return Ok(());
+    };
+
+    // the campaign is already stored with the v2 layout
+    let Ok(campaign_v1) = from_json::<CampaignV1>(&raw_campaign) else {
+        return Ok(());
+    };
+
+    // the campaign is saved with the v2 layout, which is upgraded by the following steps
+    deps.storage.set(
+        CAMPAIGN.as_slice(),
+        &to_json_vec(&CampaignV2 {
+            name: campaign_v1.name,
+            description: campaign_v1.description,
+            ty: campaign_v1.ty,
+            total_reward: campaign_v1.total_reward,
+            claimed: campaign_v1.claimed,
+            distribution_type: campaign_v1.distribution_type,
+            start_time: campaign_v1.start_time,
+            end_time: campaign_v1.end_time,
+            closed: campaign_v1.closed,
+        })?,
+    );
+
+    Ok(())
+}
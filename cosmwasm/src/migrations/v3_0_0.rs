@@ -0,0 +1,140 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, to_json_vec, Coin, Decimal, DepsMut, Env, Timestamp};
+
+use crate::state::CAMPAIGN;
+use mantra_claimdrop_std::error::ContractError;
+
+/// The campaign layout of v2.x, which expressed the times as unix timestamps in seconds.
+#[cw_serde]
+pub(super) struct CampaignV2 {
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub total_reward: Coin,
+    pub claimed: Coin,
+    pub distribution_type: Vec<DistributionTypeV2>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub closed: Option<u64>,
+}
+
+/// The distribution type layout of v2.x, which expressed the times as unix timestamps in seconds.
+#[cw_serde]
+pub(super) enum DistributionTypeV2 {
+    LinearVesting {
+        percentage: Decimal,
+        start_time: u64,
+        end_time: u64,
+        cliff_duration: Option<u64>,
+    },
+    LumpSum {
+        percentage: Decimal,
+        start_time: u64,
+    },
+}
+
+/// The campaign layout of v3.0.0. The fields added by the later versions are either optional or
+/// have a default, so the campaign is read as is from then on.
+#[cw_serde]
+struct CampaignV3_0 {
+    name: String,
+    description: String,
+    #[serde(rename = "type")]
+    ty: String,
+    total_reward: Coin,
+    claimed: Coin,
+    distribution_type: Vec<DistributionTypeV3_0>,
+    start_time: Timestamp,
+    end_time: Timestamp,
+    closed: Option<u64>,
+    claim_mode: ClaimModeV3_0,
+    claim_cooldown_seconds: Option<u64>,
+    cliff_duration: Option<u64>,
+}
+
+/// The claim modes of v3.0.0. The campaigns of v2.x were open to any address with an allocation,
+/// so that's the only mode they're migrated to.
+#[cw_serde]
+enum ClaimModeV3_0 {
+    Open,
+}
+
+/// The distribution type layout of v3.0.0.
+#[cw_serde]
+enum DistributionTypeV3_0 {
+    LinearVesting {
+        percentage: Decimal,
+        start_time: Timestamp,
+        end_time: Timestamp,
+        cliff_duration: Option<u64>,
+    },
+    LumpSum {
+        percentage: Decimal,
+        start_time: Timestamp,
+        cliff_duration: Option<u64>,
+    },
+}
+
+impl From<DistributionTypeV2> for DistributionTypeV3_0 {
+    fn from(distribution_type: DistributionTypeV2) -> Self {
+        match distribution_type {
+            DistributionTypeV2::LinearVesting {
+                percentage,
+                start_time,
+                end_time,
+                cliff_duration,
+            } => DistributionTypeV3_0::LinearVesting {
+                percentage,
+                start_time: Timestamp::from_seconds(start_time),
+                end_time: Timestamp::from_seconds(end_time),
+                cliff_duration,
+            },
+            DistributionTypeV2::LumpSum {
+                percentage,
+                start_time,
+            } => DistributionTypeV3_0::LumpSum {
+                percentage,
+                start_time: Timestamp::from_seconds(start_time),
+                cliff_duration: None,
+            },
+        }
+    }
+}
+
+/// Migrates the campaign from the v2.x layout, converting the times from unix timestamps in
+/// seconds to [Timestamp].
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let Some(raw_campaign) = deps.storage.get(CAMPAIGN.as_slice()) else {
+        return Ok(());
+    };
+
+    // the campaign is already stored with the v3 layout
+    let Ok(campaign_v2) = from_json::<CampaignV2>(&raw_campaign) else {
+        return Ok(());
+    };
+
+    deps.storage.set(
+        CAMPAIGN.as_slice(),
+        &to_json_vec(&CampaignV3_0 {
+            name: campaign_v2.name,
+            description: campaign_v2.description,
+            ty: campaign_v2.ty,
+            total_reward: campaign_v2.total_reward,
+            claimed: campaign_v2.claimed,
+            distribution_type: campaign_v2
+                .distribution_type
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            start_time: Timestamp::from_seconds(campaign_v2.start_time),
+            end_time: Timestamp::from_seconds(campaign_v2.end_time),
+            closed: campaign_v2.closed,
+            claim_mode: ClaimModeV3_0::Open,
+            claim_cooldown_seconds: None,
+            cliff_duration: None,
+        })?,
+    );
+
+    Ok(())
+}
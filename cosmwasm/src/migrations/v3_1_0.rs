@@ -0,0 +1,26 @@
+use cosmwasm_std::{DepsMut, Env, Order};
+
+use crate::state::{
+    ALLOCATIONS, ALLOCATIONS_COUNT, BLACKLIST, BLACKLIST_COUNT, CLAIMANTS_COUNT, CLAIMS,
+};
+use mantra_claimdrop_std::error::ContractError;
+
+/// Initializes the allocations, claimants and blacklist counters, which are maintained in state
+/// from v3.1.0 onwards.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let allocations = ALLOCATIONS
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+    let claimants = CLAIMS
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+    let blacklisted = BLACKLIST
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+
+    ALLOCATIONS_COUNT.save(deps.storage, &allocations)?;
+    CLAIMANTS_COUNT.save(deps.storage, &claimants)?;
+    BLACKLIST_COUNT.save(deps.storage, &blacklisted)?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use cosmwasm_std::{DepsMut, Env, Order, StdResult};
+use cw_storage_plus::Map;
+
+use crate::state::BLACKLIST;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::BlacklistEntry;
+
+/// The blacklist before v3.2.0, which didn't record any metadata.
+const BLACKLIST_V3_1: Map<&str, ()> = Map::new("blacklist");
+
+/// Migrates the blacklist entries to [BlacklistEntry]. The metadata of the existing entries is
+/// unknown, so it's left empty.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let addresses = BLACKLIST_V3_1
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    for address in addresses {
+        BLACKLIST.save(
+            deps.storage,
+            address.as_str(),
+            &BlacklistEntry {
+                blacklisted_by: String::new(),
+                blacklisted_at: 0,
+                reason: None,
+            },
+        )?;
+    }
+
+    Ok(())
+}
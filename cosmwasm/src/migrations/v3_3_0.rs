@@ -0,0 +1,27 @@
+use cosmwasm_std::{DepsMut, Env, Order, StdResult, Uint128};
+use cw_storage_plus::{Index, Map, MultiIndex};
+
+use mantra_claimdrop_std::error::ContractError;
+
+/// The allocations in v3.3.0, which stored the allocated amount only.
+const ALLOCATIONS_V3_3: Map<&str, Uint128> = Map::new("allocations");
+
+/// The amount index of the allocations in v3.3.0.
+const AMOUNT_INDEX_V3_3: MultiIndex<u128, Uint128, String> = MultiIndex::new(
+    |_address, allocation| allocation.u128(),
+    "allocations",
+    "allocations__amount",
+);
+
+/// Builds the amount index of the allocations, which are indexed from v3.3.0 onwards.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let allocations = ALLOCATIONS_V3_3
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+
+    for (address, allocation) in allocations {
+        AMOUNT_INDEX_V3_3.save(deps.storage, address.as_bytes(), &allocation)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{DepsMut, Env, Order, StdResult, Uint128};
+use cw_storage_plus::{Index, Map, MultiIndex};
+
+use crate::state::CLAIMS;
+use mantra_claimdrop_std::error::ContractError;
+
+/// The allocations before v3.4.0, which stored the allocated amount only.
+const ALLOCATIONS_V3_3: Map<&str, Uint128> = Map::new("allocations");
+
+/// The allocations in v3.4.0.
+const ALLOCATIONS_V3_4: Map<&str, AllocationV3_4> = Map::new("allocations");
+
+/// The claimed status index of the allocations in v3.4.0.
+const CLAIMED_INDEX_V3_4: MultiIndex<u8, AllocationV3_4, String> = MultiIndex::new(
+    |_address, allocation| allocation.claimed as u8,
+    "allocations",
+    "allocations__claimed",
+);
+
+/// The allocation in v3.4.0.
+#[cw_serde]
+pub(super) struct AllocationV3_4 {
+    pub amount: Uint128,
+    pub claimed: bool,
+}
+
+/// Migrates the allocations to [AllocationV3_4], recording whether the addresses have claimed,
+/// which builds the claimed status index. The amount index entries stay the same.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let allocations = ALLOCATIONS_V3_3
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+
+    for (address, amount) in allocations {
+        let allocation = AllocationV3_4 {
+            amount,
+            claimed: CLAIMS.has(deps.storage, address.clone()),
+        };
+        ALLOCATIONS_V3_4.save(deps.storage, address.as_str(), &allocation)?;
+        CLAIMED_INDEX_V3_4.save(deps.storage, address.as_bytes(), &allocation)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Env, Order, StdResult, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
+
+use super::v3_4_0::AllocationV3_4;
+use mantra_claimdrop_std::error::ContractError;
+
+/// The allocations in v3.4.0, which didn't record who added them.
+const ALLOCATIONS_V3_4: Map<&str, AllocationV3_4> = Map::new("allocations");
+
+/// The allocations in v3.5.0, indexed by amount, by claimed status and by uploader.
+const ALLOCATIONS_V3_5: IndexedMap<&str, AllocationV3_5, AllocationIndexesV3_5> = IndexedMap::new(
+    "allocations",
+    AllocationIndexesV3_5 {
+        amount: MultiIndex::new(
+            |_address, allocation| allocation.amount.u128(),
+            "allocations",
+            "allocations__amount",
+        ),
+        claimed: MultiIndex::new(
+            |_address, allocation| allocation.claimed as u8,
+            "allocations",
+            "allocations__claimed",
+        ),
+        uploader: MultiIndex::new(
+            |_address, allocation| allocation.added_by.to_string(),
+            "allocations",
+            "allocations__uploader",
+        ),
+    },
+);
+
+/// The allocation in v3.5.0.
+#[cw_serde]
+pub(super) struct AllocationV3_5 {
+    pub amount: Uint128,
+    pub claimed: bool,
+    pub added_by: Addr,
+    pub added_at: Timestamp,
+}
+
+/// The secondary indexes of the allocations in v3.5.0.
+struct AllocationIndexesV3_5<'a> {
+    amount: MultiIndex<'a, u128, AllocationV3_5, String>,
+    claimed: MultiIndex<'a, u8, AllocationV3_5, String>,
+    uploader: MultiIndex<'a, String, AllocationV3_5, String>,
+}
+
+impl IndexList<AllocationV3_5> for AllocationIndexesV3_5<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<AllocationV3_5>> + '_> {
+        let v: Vec<&dyn Index<AllocationV3_5>> = vec![&self.amount, &self.claimed, &self.uploader];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Migrates the allocations to [AllocationV3_5], which builds the uploader index. The wallets that
+/// added the existing allocations weren't recorded, so they're attributed to the owner, or to the
+/// contract itself if there's no owner, at the time of the migration.
+pub(super) fn migrate(deps: DepsMut, env: &Env) -> Result<(), ContractError> {
+    let added_by = cw_ownable::get_ownership(deps.storage)
+        .ok()
+        .and_then(|ownership| ownership.owner)
+        .unwrap_or_else(|| env.contract.address.clone());
+
+    let allocations = ALLOCATIONS_V3_4
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, AllocationV3_4)>>>()?;
+
+    for (address, AllocationV3_4 { amount, claimed }) in allocations {
+        // the stored value is removed first, as it can't be read as an [AllocationV3_5] when
+        // updating the indexes. The amount and claimed index entries are the same, so they're
+        // overwritten.
+        ALLOCATIONS_V3_4.remove(deps.storage, address.as_str());
+        ALLOCATIONS_V3_5.save(
+            deps.storage,
+            address.as_str(),
+            &AllocationV3_5 {
+                amount,
+                claimed,
+                added_by: added_by.clone(),
+                added_at: env.block.time,
+            },
+        )?;
+    }
+
+    Ok(())
+}
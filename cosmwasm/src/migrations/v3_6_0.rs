@@ -0,0 +1,136 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{DepsMut, Env, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, Item, Map, MultiIndex};
+
+use super::v3_5_0::AllocationV3_5;
+use crate::state::CAMPAIGN;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::DistributionType;
+
+/// The allocations in v3.5.0, before they could be tagged.
+const ALLOCATIONS_V3_5: Map<&str, AllocationV3_5> = Map::new("allocations");
+
+/// The tag index of the allocations in v3.6.0, where the untagged allocations are indexed under
+/// the empty tag.
+const TAG_INDEX_V3_6: MultiIndex<String, AllocationV3_5, String> = MultiIndex::new(
+    |_address, _allocation| String::new(),
+    "allocations",
+    "allocations__tag",
+);
+
+/// The claims in v3.5.0, as the amount claimed and the time of the last claim per slot.
+const CLAIMS_V3_5: Map<String, HashMap<usize, (Uint128, u64)>> = Map::new("claims");
+
+/// The claim history in v3.5.0, as the amount, the time and the slot of each claim receipt.
+const CLAIM_HISTORY_V3_5: Map<(&str, u64), (Uint128, u64, usize)> = Map::new("claim_history");
+
+/// The campaign statistics in v3.6.0.
+#[cw_serde]
+struct CampaignStatsV3_6 {
+    claims: u64,
+    claimants: u64,
+    largest_claim: Uint128,
+    first_claim_at: Option<Timestamp>,
+    last_claim_at: Option<Timestamp>,
+}
+
+const STATS_V3_6: Item<CampaignStatsV3_6> = Item::new("stats");
+
+/// The early bird bonuses paid out in v3.5.0.
+const EARLY_BIRD_BONUS_CLAIMED_V3_5: Item<Uint128> = Item::new("early_bird_bonus_claimed");
+
+/// The amount paid out from each distribution slot in v3.6.0.
+const SLOT_CLAIMED_V3_6: Map<u64, Uint128> = Map::new("slot_claimed");
+
+/// The sum of the amounts of the allocations in v3.6.0.
+const TOTAL_ALLOCATED_V3_6: Item<Uint128> = Item::new("total_allocated");
+
+/// Builds the tag index and the total of the allocations, the campaign statistics and the amounts
+/// paid out per slot.
+pub(super) fn migrate(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    index_allocations(deps.storage)?;
+    build_stats(deps.storage)?;
+    build_slots_claimed(deps.storage)?;
+
+    Ok(())
+}
+
+/// Builds the tag index and the total of the allocations. None of them is tagged yet, so they're
+/// all indexed under the empty tag, and there are no tag stats to build. The stored allocations
+/// stay the same, as the tag and locked receiver fields default to none.
+fn index_allocations(storage: &mut dyn Storage) -> StdResult<()> {
+    let allocations = ALLOCATIONS_V3_5
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, AllocationV3_5)>>>()?;
+
+    let mut total_allocated = Uint128::zero();
+    for (address, allocation) in allocations {
+        TAG_INDEX_V3_6.save(storage, address.as_bytes(), &allocation)?;
+        total_allocated = total_allocated.checked_add(allocation.amount)?;
+    }
+
+    TOTAL_ALLOCATED_V3_6.save(storage, &total_allocated)
+}
+
+/// Builds the campaign statistics from the claims made so far. The receipts a claim records in the
+/// claim history share the receiver and the time, so they're counted as a single claim. The early
+/// bird bonuses weren't recorded in the history, so the largest claim leaves them out.
+fn build_stats(storage: &mut dyn Storage) -> StdResult<()> {
+    // the amount of each claim, by receiver and time
+    let mut claims: BTreeMap<(String, u64), Uint128> = BTreeMap::new();
+    for receipt in CLAIM_HISTORY_V3_5.range(storage, None, None, Order::Ascending) {
+        let ((receiver, _), (amount, time, _)) = receipt?;
+        let claim = claims.entry((receiver, time)).or_default();
+        *claim = claim.checked_add(amount)?;
+    }
+
+    let claimants = CLAIMS_V3_5
+        .keys_raw(storage, None, None, Order::Ascending)
+        .count() as u64;
+    let claim_times = claims.keys().map(|(_, time)| *time);
+    STATS_V3_6.save(
+        storage,
+        &CampaignStatsV3_6 {
+            claims: claims.len() as u64,
+            claimants,
+            largest_claim: claims.values().max().copied().unwrap_or_default(),
+            first_claim_at: claim_times.clone().min().map(Timestamp::from_seconds),
+            last_claim_at: claim_times.max().map(Timestamp::from_seconds),
+        },
+    )
+}
+
+/// Builds the amount paid out from each distribution slot from the claims made so far, the early
+/// bird bonuses paid out being counted in the early bird bonus slot.
+fn build_slots_claimed(storage: &mut dyn Storage) -> StdResult<()> {
+    let mut slots_claimed: BTreeMap<usize, Uint128> = BTreeMap::new();
+    for claims in CLAIMS_V3_5.range(storage, None, None, Order::Ascending) {
+        let (_, claims) = claims?;
+        for (slot, (amount, _)) in claims {
+            let claimed = slots_claimed.entry(slot).or_default();
+            *claimed = claimed.checked_add(amount)?;
+        }
+    }
+
+    let early_bird_bonus_claimed = EARLY_BIRD_BONUS_CLAIMED_V3_5
+        .may_load(storage)?
+        .unwrap_or_default();
+    let early_bird_slot = CAMPAIGN.may_load(storage)?.and_then(|campaign| {
+        campaign
+            .distribution_type
+            .iter()
+            .position(|dist| matches!(dist, DistributionType::EarlyBirdBonus { .. }))
+    });
+    if let Some(early_bird_slot) = early_bird_slot.filter(|_| !early_bird_bonus_claimed.is_zero()) {
+        let claimed = slots_claimed.entry(early_bird_slot).or_default();
+        *claimed = claimed.checked_add(early_bird_bonus_claimed)?;
+    }
+
+    for (slot, claimed) in slots_claimed {
+        SLOT_CLAIMED_V3_6.save(storage, slot as u64, &claimed)?;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,42 @@
-use cosmwasm_std::{coin, Coin, Deps, Env, Order, StdResult, Uint128};
+use std::collections::HashMap;
+
+use cosmwasm_std::{
+    coin, ensure, Coin, Decimal256, Deps, Env, Order, StdResult, Timestamp, Uint128,
+};
 use cw_storage_plus::Bound;
 
+use crate::commands::{
+    MAX_ALLOCATION_BATCH_SIZE, MAX_ALLOWLIST_BATCH_SIZE, MAX_AUTHORIZED_WALLETS_BATCH_SIZE,
+    MAX_BLACKLIST_BATCH_SIZE, MAX_CLAIM_BATCH_SIZE, MAX_GROUPS, MAX_GROUP_MEMBERS_BATCH_SIZE,
+};
 use crate::helpers;
 use crate::state::{
-    get_allocation, get_total_claims_amount_for_address, is_authorized, is_blacklisted,
-    ALLOCATIONS, AUTHORIZED_WALLETS, CAMPAIGN, CLAIMS,
+    active_hold, get_allocation, get_count, get_slots_claimed, get_total_claims_amount_for_address,
+    has_role, is_allowlisted, is_authorized, is_blacklisted, Allocation, Budget, Claim,
+    DistributionSlot, ADDRESS_REPLACEMENT_REQUESTS, ALLOCATIONS, ALLOCATIONS_COUNT,
+    AUTHORIZED_WALLETS, BLACKLIST, BLACKLIST_COUNT, BUDGET, CAMPAIGN, CLAIMANTS_COUNT, CLAIMS,
+    CLAIM_HISTORY, CLAIM_HOOKS, CLAIM_NONCES, CONFIG, DAILY_CLAIM_VOLUME, FORFEITED, FROZEN,
+    FUNDERS, FUNDING_HISTORY, GROUPS, GROUP_MEMBERS, LOCKS, PENDING_AUTHORIZED_WALLETS,
+    PENDING_SWEEP, PROPOSALS, RAFFLE_SEED, RAFFLE_WINNERS, STATS, TAG_STATS, TERMS_ACCEPTANCES,
+    TOTAL_ALLOCATED, VESTING_ACTIVATIONS, WALLET_ROLES,
 };
 use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::export::{ExportStateResponse, RawClaim, StateEntry, StateSection};
 use mantra_claimdrop_std::msg::{
-    AllocationsResponse, AuthorizedResponse, AuthorizedWalletsResponse, BlacklistResponse,
-    CampaignResponse, ClaimedResponse, RewardsResponse,
+    AccrualFrom, AddressClaims, AddressReplacementRequestResponse, AddressRewards,
+    AllocationsResponse, AllowlistResponse, AuthorizedResponse, AuthorizedWalletsResponse,
+    BatchRewardsResponse, BlacklistEntryResponse, BlacklistResponse, BudgetResponse, Campaign,
+    CampaignPhase, CampaignPhaseResponse, CampaignResponse, CampaignStats, CampaignStatusResponse,
+    ClaimHistoryResponse, ClaimHooksResponse, ClaimNonceResponse, ClaimReceipt,
+    ClaimVolumeResponse, ClaimWindowResponse, ClaimedBySlotResponse, ClaimedResponse,
+    ConfigResponse, ContractInfoResponse, ContractLimits, CountsResponse, Deposit,
+    FrozenEntryResponse, FundersResponse, FundingHistoryResponse, GroupMemberResponse,
+    GroupResponse, HoldResponse, IsLiveResponse, LiabilitiesResponse, Lock, LockedReceiverResponse,
+    LocksResponse, PauseScope, PauseStatusResponse, PendingAuthorizedWallet,
+    PendingAuthorizedWalletsResponse, PendingSweepResponse, Proposal, ProposalsResponse,
+    RaffleResponse, RewardsResponse, Role, RoleHoldersResponse, RolesResponse,
+    SimulateClaimResponse, SlotClaim, SlotClaimSimulation, SlotSolvencyResponse, TagStatsResponse,
+    TermsAcceptanceResponse, VestingActivationResponse, DEFAULT_MAX_DISTRIBUTION_SLOTS,
 };
 
 /// Returns the active airdrop campaign.
@@ -24,8 +51,97 @@ pub fn query_campaign(deps: Deps) -> Result<CampaignResponse, ContractError> {
     Ok(campaign)
 }
 
+/// Returns the phase of the campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+///
+/// # Returns
+/// * `Result<CampaignPhaseResponse, ContractError>` - The campaign phase
+pub(crate) fn query_campaign_phase(
+    deps: Deps,
+    env: Env,
+) -> Result<CampaignPhaseResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    Ok(CampaignPhaseResponse {
+        phase: campaign.phase(&env.block.time),
+    })
+}
+
+/// Returns whether the campaign accepts claims, i.e. it has started, isn't closed and holds the
+/// rewards left to claim if it requires full funding.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+///
+/// # Returns
+/// * `Result<IsLiveResponse, ContractError>` - Whether the campaign is live
+pub(crate) fn query_is_live(deps: Deps, env: Env) -> Result<IsLiveResponse, ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(deps.storage)? else {
+        return Ok(IsLiveResponse { is_live: false });
+    };
+
+    if !matches!(
+        campaign.phase(&env.block.time),
+        CampaignPhase::Active | CampaignPhase::Ended
+    ) {
+        return Ok(IsLiveResponse { is_live: false });
+    }
+
+    match helpers::ensure_funded(deps, &env.contract.address, &campaign) {
+        Ok(()) => Ok(IsLiveResponse { is_live: true }),
+        Err(ContractError::CampaignNotFullyFunded { .. }) => Ok(IsLiveResponse { is_live: false }),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns an overview of the campaign progress, i.e. allocations, claims and funding.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+///
+/// # Returns
+/// * `Result<CampaignStatusResponse, ContractError>` - The campaign status
+pub(crate) fn query_campaign_status(
+    deps: Deps,
+    env: Env,
+) -> Result<CampaignStatusResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let denom = campaign.total_reward.denom.clone();
+
+    let mut total_allocated = Uint128::zero();
+    for allocation in ALLOCATIONS.range(deps.storage, None, None, Order::Ascending) {
+        let (_, allocation) = allocation?;
+        total_allocated = total_allocated.checked_add(allocation.amount)?;
+    }
+
+    let allocations_count = get_count(deps.storage, &ALLOCATIONS_COUNT)?;
+    let claimants_count = get_count(deps.storage, &CLAIMANTS_COUNT)?;
+
+    let balance = helpers::query_reward_balance(deps, &env.contract.address, &campaign)?;
+
+    Ok(CampaignStatusResponse {
+        status: campaign.status(&env.block.time),
+        total_allocated: coin(total_allocated.u128(), &denom),
+        total_claimed: campaign.claimed.clone(),
+        allocations_count,
+        claimants_count,
+        balance,
+        campaign,
+    })
+}
+
 /// Returns the rewards information for a specific address.
-/// This includes claimed, pending, and available to claim amounts.
+/// This includes the total allocation, claimed, pending, available to claim and locked amounts.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
@@ -41,21 +157,95 @@ pub(crate) fn query_rewards(
 ) -> Result<RewardsResponse, ContractError> {
     let campaign = CAMPAIGN
         .may_load(deps.storage)?
-        .ok_or(ContractError::CampaignError {
-            reason: "there's not an active campaign".to_string(),
-        })?;
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    compute_rewards(deps, &env, &campaign, &receiver)
+}
+
+/// Returns the rewards information for a batch of addresses. The addresses without an allocation
+/// are returned with empty rewards instead of failing the whole batch.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `addresses` - The addresses to get rewards for
+///
+/// # Returns
+/// * `Result<BatchRewardsResponse, ContractError>` - The rewards information of each address
+pub(crate) fn query_batch_rewards(
+    deps: Deps,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<BatchRewardsResponse, ContractError> {
+    ensure!(
+        addresses.len() <= MAX_BATCH_REWARDS_ADDRESSES,
+        ContractError::BatchSizeLimitExceeded {
+            actual: addresses.len(),
+            max: MAX_BATCH_REWARDS_ADDRESSES,
+        }
+    );
+
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let rewards = addresses
+        .into_iter()
+        .map(|address| {
+            let rewards = match compute_rewards(deps, &env, &campaign, &address) {
+                Err(ContractError::NoAllocationFound { .. }) => RewardsResponse {
+                    total_allocation: vec![],
+                    claimed: vec![],
+                    pending: vec![],
+                    available_to_claim: vec![],
+                    locked: vec![],
+                    accrual_rate: Decimal256::zero(),
+                    next_unlocks: vec![],
+                },
+                result => result?,
+            };
+
+            Ok(AddressRewards { address, rewards })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(BatchRewardsResponse { rewards })
+}
 
+/// Computes the total allocation, claimed, pending, available to claim and locked amounts of an
+/// address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `campaign` - The campaign
+/// * `receiver` - The address to get rewards for
+///
+/// # Returns
+/// * `Result<RewardsResponse, ContractError>` - The rewards information
+fn compute_rewards(
+    deps: Deps,
+    env: &Env,
+    campaign: &Campaign,
+    receiver: &str,
+) -> Result<RewardsResponse, ContractError> {
+    let mut total_allocation = vec![];
     let mut available_to_claim = vec![];
     let mut claimed = vec![];
     let mut pending = vec![];
+    let mut locked = vec![];
 
-    let validated_receiver_string = helpers::validate_raw_address(deps, &receiver)?;
+    let validated_receiver_string = helpers::validate_raw_address(deps, receiver)?;
 
     let total_claimable_amount = get_allocation(deps, validated_receiver_string.as_str())?.ok_or(
         ContractError::NoAllocationFound {
             address: receiver.to_string(),
         },
     )?;
+    total_allocation.push(coin(
+        total_claimable_amount.u128(),
+        &campaign.total_reward.denom,
+    ));
 
     let total_claimed: Uint128 =
         get_total_claims_amount_for_address(deps, validated_receiver_string.as_str())?;
@@ -63,8 +253,25 @@ pub(crate) fn query_rewards(
         claimed.push(coin(total_claimed.u128(), &campaign.total_reward.denom));
     }
 
+    // an address added late only accrues part of its allocation
+    let accruing_allocation = match campaign.accrual_from {
+        AccrualFrom::CampaignStart => total_claimable_amount,
+        AccrualFrom::AllocationTime => {
+            match helpers::campaign_for_address(deps, campaign, &validated_receiver_string)? {
+                Some(campaign) => {
+                    helpers::compute_slot_allocations(&campaign, total_claimable_amount)?
+                        .into_iter()
+                        .try_fold(Uint128::zero(), |acc, (slot_allocation, _)| {
+                            acc.checked_add(slot_allocation)
+                        })?
+                }
+                None => total_claimable_amount,
+            }
+        }
+    };
+
     let pending_rewards = coin(
-        total_claimable_amount.saturating_sub(total_claimed).u128(),
+        accruing_allocation.saturating_sub(total_claimed).u128(),
         &campaign.total_reward.denom,
     );
 
@@ -74,27 +281,117 @@ pub(crate) fn query_rewards(
 
     let (claimable_amount, _, _) = helpers::compute_claimable_amount(
         deps,
-        &campaign,
+        campaign,
         &env.block.time,
         &validated_receiver_string,
         total_claimable_amount,
     )?;
 
+    let locked_rewards = coin(
+        accruing_allocation
+            .saturating_sub(total_claimed)
+            .saturating_sub(claimable_amount.amount)
+            .u128(),
+        &campaign.total_reward.denom,
+    );
+
     if claimable_amount.amount > Uint128::zero() {
         available_to_claim.push(claimable_amount);
     }
 
-    // if the campaign is closed, clear the pending and available to claim rewards as there's nothing else
-    // to claim
+    if locked_rewards.amount > Uint128::zero() {
+        locked.push(locked_rewards);
+    }
+
+    // the stream follows the schedule of the address, offset by its vesting activation
+    let (mut accrual_rate, mut next_unlocks) =
+        match helpers::campaign_for_address(deps, campaign, &validated_receiver_string)? {
+            Some(campaign) if !pending.is_empty() => {
+                helpers::compute_reward_stream(&campaign, &env.block.time, total_claimable_amount)?
+            }
+            _ => (Decimal256::zero(), vec![]),
+        };
+
+    // if the campaign is closed, clear the pending, available to claim and locked rewards as there's
+    // nothing else to claim
     if campaign.closed.is_some() {
         pending.clear();
         available_to_claim.clear();
+        locked.clear();
+        accrual_rate = Decimal256::zero();
+        next_unlocks.clear();
     }
 
     Ok(RewardsResponse {
+        total_allocation,
         claimed,
         pending,
         available_to_claim,
+        locked,
+        accrual_rate,
+        next_unlocks,
+    })
+}
+
+/// Simulates a claim of the whole available amount by an address, detailing it per distribution
+/// slot along with the rounding dust attributed to each slot.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `address` - The address to simulate the claim for
+///
+/// # Returns
+/// * `Result<SimulateClaimResponse, ContractError>` - The simulated claim
+pub(crate) fn query_simulate_claim(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> Result<SimulateClaimResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let total_allocation =
+        get_allocation(deps, address.as_str())?.ok_or(ContractError::NoAllocationFound {
+            address: address.clone(),
+        })?;
+
+    let (claimable, new_claims, previous_claims) = helpers::compute_claimable_amount(
+        deps,
+        &campaign,
+        &env.block.time,
+        &address,
+        total_allocation,
+    )?;
+
+    let denom = &campaign.total_reward.denom;
+    let slots = helpers::compute_slot_allocations(&campaign, total_allocation)?
+        .into_iter()
+        .enumerate()
+        .map(|(slot, (allocation, rounding_remainder))| {
+            let claimed = previous_claims
+                .get(&slot)
+                .map_or(Uint128::zero(), |(amount, _)| *amount);
+            let slot_claimable = new_claims
+                .get(&slot)
+                .map_or(Uint128::zero(), |(amount, _)| *amount);
+
+            SlotClaimSimulation {
+                slot,
+                allocation: coin(allocation.u128(), denom),
+                rounding_remainder,
+                claimed: coin(claimed.u128(), denom),
+                claimable: coin(slot_claimable.u128(), denom),
+            }
+        })
+        .collect();
+
+    Ok(SimulateClaimResponse {
+        address,
+        claimable,
+        slots,
     })
 }
 
@@ -102,6 +399,55 @@ pub(crate) fn query_rewards(
 pub(crate) const MAX_LIMIT: u16 = 5_000;
 const DEFAULT_LIMIT: u16 = 100;
 
+/// The maximum number of allocations scanned by a page of [query_allocation], whether they match
+/// the filters or not, to bound the cost of a page
+pub(crate) const MAX_ALLOCATIONS_PAGE_SIZE: u16 = 1_000;
+
+/// The maximum number of addresses that can be queried at once with [query_batch_rewards]
+pub(crate) const MAX_BATCH_REWARDS_ADDRESSES: usize = 100;
+
+/// The features supported by this version of the contract, returned by the ContractInfo query
+const FEATURES: &[&str] = &[
+    "mint_on_claim",
+    "curve_vesting",
+    "signed_claims",
+    "authz_claims",
+    "batch_claims",
+    "claim_up_to",
+    "allocation_vouchers",
+    "allocation_import",
+    "delegator_snapshots",
+    "allocation_transfers",
+    "address_linking",
+    "allowlist",
+    "terms",
+    "groups",
+    "raffle",
+    "lock_boost",
+    "claim_hooks",
+    "circuit_breaker",
+    "ibc_payout",
+    "ibc_admin",
+    "roles",
+    "proposals",
+    "freeze",
+    "claim_holds",
+    "sweep_dual_control",
+    "allocation_tags",
+    "pause_scopes",
+    "liabilities",
+    "batch_blacklist",
+    "campaign_registry",
+    "filtered_allocations",
+    "pro_rata_refunds",
+    "pubkey_addresses",
+    "eligibility_oracle",
+    "allocation_delta",
+];
+
+/// The maximum number of days that can be queried at once with [query_claim_volume]
+pub(crate) const MAX_CLAIM_VOLUME_DAYS: u64 = 366;
+
 /// Returns the claimed amounts for addresses.
 /// Can be filtered by a specific address and paginated.
 ///
@@ -175,109 +521,1382 @@ pub(crate) fn query_claimed(
     Ok(ClaimedResponse { claimed })
 }
 
-/// Returns the allocation for an address.
+/// Returns the claims per distribution slot for addresses.
+/// Can be filtered by a specific address and paginated.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
 /// * `address` - Optional address to filter by
-/// * `start_after` - Optional address to start pagination from
+/// * `start_after` - Optional address to start pagination after
 /// * `limit` - Optional limit for pagination
 ///
 /// # Returns
-/// * `Result<AllocationsResponse, ContractError>` - The allocations information
-pub fn query_allocation(
+/// * `Result<ClaimedBySlotResponse, ContractError>` - The claims per distribution slot
+pub(crate) fn query_claimed_by_slot(
     deps: Deps,
     address: Option<String>,
     start_after: Option<String>,
     limit: Option<u16>,
-) -> Result<AllocationsResponse, ContractError> {
-    let campaign = CAMPAIGN.may_load(deps.storage)?;
-    let denom = campaign
-        .map(|c| c.total_reward.denom)
-        .unwrap_or_else(|| "".to_string());
+) -> Result<ClaimedBySlotResponse, ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(deps.storage)? else {
+        // returns empty if the campaign is not set
+        return Ok(ClaimedBySlotResponse { claims: vec![] });
+    };
 
-    let allocations = if let Some(address) = address {
-        let allocation = get_allocation(deps, &address)?;
-        if let Some(allocation) = allocation {
-            vec![(address, coin(allocation.u128(), denom))]
-        } else {
-            vec![]
-        }
+    let claims = if let Some(address) = address {
+        // For an address to have claimed, it must have been a valid cosmos address
+        let address = deps.api.addr_validate(&address)?.to_string();
+
+        CLAIMS
+            .may_load(deps.storage, address.clone())?
+            .map(|claims| vec![to_address_claims(&campaign, address, claims)])
+            .unwrap_or_default()
     } else {
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-        let start = cw_utils::calc_range_start_string(start_after).map(Bound::ExclusiveRaw);
+        let start = start_after.map(Bound::exclusive);
 
-        ALLOCATIONS
+        CLAIMS
             .range(deps.storage, start, None, Order::Ascending)
             .take(limit)
             .map(|item| {
-                let (address, allocation) = item?;
-                Ok((address, coin(allocation.u128(), denom.clone())))
+                let (address, claims) = item?;
+                Ok(to_address_claims(&campaign, address, claims))
             })
-            .collect::<StdResult<Vec<(String, Coin)>>>()?
+            .collect::<StdResult<Vec<AddressClaims>>>()?
     };
 
-    Ok(AllocationsResponse { allocations })
+    Ok(ClaimedBySlotResponse { claims })
 }
 
-/// Returns whether an address is blacklisted.
+/// Converts the claims stored for an address into [AddressClaims], sorted by distribution slot.
+fn to_address_claims(
+    campaign: &Campaign,
+    address: String,
+    claims: HashMap<DistributionSlot, Claim>,
+) -> AddressClaims {
+    let mut slots: Vec<SlotClaim> = claims
+        .into_iter()
+        .filter_map(|(slot, (amount, timestamp))| {
+            campaign
+                .distribution_type
+                .get(slot)
+                .map(|distribution_type| SlotClaim {
+                    slot,
+                    distribution_type: distribution_type.clone(),
+                    claimed: coin(amount.u128(), &campaign.total_reward.denom),
+                    last_claimed_at: timestamp,
+                })
+        })
+        .collect();
+
+    slots.sort_by_key(|slot_claim| slot_claim.slot);
+
+    AddressClaims { address, slots }
+}
+
+/// Returns the amount claimed per day within the given range of days, inclusive, along with the
+/// total. The range can span up to [MAX_CLAIM_VOLUME_DAYS] days.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
-/// * `address` - The address to check
+/// * `from_day` - The first day of the range, counted since the unix epoch
+/// * `to_day` - The last day of the range, counted since the unix epoch
 ///
 /// # Returns
-/// * `Result<BlacklistResponse, ContractError>` - The blacklist status
-pub fn query_is_blacklisted(
+/// * `Result<ClaimVolumeResponse, ContractError>` - The daily claim volumes
+pub(crate) fn query_claim_volume(
     deps: Deps,
-    address: String,
-) -> Result<BlacklistResponse, ContractError> {
-    let is_blacklisted = is_blacklisted(deps, &address)?;
-    Ok(BlacklistResponse { is_blacklisted })
+    from_day: u64,
+    to_day: u64,
+) -> Result<ClaimVolumeResponse, ContractError> {
+    ensure!(
+        from_day <= to_day && to_day - from_day < MAX_CLAIM_VOLUME_DAYS,
+        ContractError::InvalidInput {
+            reason: format!(
+                "the range must be ordered and span at most {MAX_CLAIM_VOLUME_DAYS} days"
+            )
+        }
+    );
+
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+
+    let volumes = DAILY_CLAIM_VOLUME
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(from_day)),
+            Some(Bound::inclusive(to_day)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<(u64, Uint128)>>>()?;
+
+    let total = volumes
+        .iter()
+        .try_fold(Uint128::zero(), |acc, (_, volume)| acc.checked_add(*volume))?;
+
+    Ok(ClaimVolumeResponse {
+        volumes: volumes
+            .into_iter()
+            .map(|(day, volume)| (day, coin(volume.u128(), &denom)))
+            .collect(),
+        total: coin(total.u128(), denom),
+    })
 }
 
-/// Returns whether an address is authorized (owner or authorized wallet).
+/// Returns the history of the individual claims performed by an address.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
-/// * `address` - The address to check
+/// * `address` - The address to get the claim history for
+/// * `start_after` - Optional sequence number to start pagination after
+/// * `limit` - Optional limit for pagination
 ///
 /// # Returns
-/// * `Result<AuthorizedResponse, ContractError>` - The authorization status
-pub fn query_is_authorized(
+/// * `Result<ClaimHistoryResponse, ContractError>` - The claim receipts
+pub(crate) fn query_claim_history(
     deps: Deps,
     address: String,
-) -> Result<AuthorizedResponse, ContractError> {
-    let validated_address = deps.api.addr_validate(&address)?;
-    let is_authorized = is_authorized(deps, &validated_address)?;
-    Ok(AuthorizedResponse { is_authorized })
+    start_after: Option<u64>,
+    limit: Option<u16>,
+) -> Result<ClaimHistoryResponse, ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(deps.storage)? else {
+        // returns empty if the campaign is not set
+        return Ok(ClaimHistoryResponse { history: vec![] });
+    };
+
+    // For an address to have claimed, it must have been a valid cosmos address
+    let address = deps.api.addr_validate(&address)?.to_string();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let history = CLAIM_HISTORY
+        .prefix(address.as_str())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (sequence, (amount, timestamp, slot)) = item?;
+            Ok(ClaimReceipt {
+                sequence,
+                slot,
+                amount: coin(amount.u128(), &campaign.total_reward.denom),
+                timestamp,
+            })
+        })
+        .collect::<StdResult<Vec<ClaimReceipt>>>()?;
+
+    Ok(ClaimHistoryResponse { history })
 }
 
-/// Returns a list of authorized wallet addresses with pagination support.
+/// Returns the allocation for an address, or a page of allocations sorted by address. The
+/// claimed filter is served by the claimed index, while the amount filter is applied while
+/// scanning. A page scans at most [MAX_ALLOCATIONS_PAGE_SIZE] allocations, returning the last
+/// scanned address as the cursor if the scan stopped before the end.
 ///
 /// # Arguments
 /// * `deps` - The dependencies
+/// * `address` - Optional address to filter by
 /// * `start_after` - Optional address to start pagination from
 /// * `limit` - Optional limit for pagination
+/// * `min_amount` - Optional minimum amount of the allocations to return
+/// * `has_claimed` - Optional claimed status of the allocations to return
 ///
 /// # Returns
-/// * `Result<AuthorizedWalletsResponse, ContractError>` - The authorized wallets list
-pub fn query_authorized_wallets(
+/// * `Result<AllocationsResponse, ContractError>` - The allocations information
+pub fn query_allocation(
+    deps: Deps,
+    address: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u16>,
+    min_amount: Option<Uint128>,
+    has_claimed: Option<bool>,
+) -> Result<AllocationsResponse, ContractError> {
+    let campaign = CAMPAIGN.may_load(deps.storage)?;
+    let denom = campaign
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_else(|| "".to_string());
+    let total_count = Some(get_count(deps.storage, &ALLOCATIONS_COUNT)?);
+
+    if let Some(address) = address {
+        let allocations = get_allocation(deps, &address)?
+            .map(|allocation| vec![(address, coin(allocation.u128(), denom))])
+            .unwrap_or_default();
+
+        return Ok(AllocationsResponse {
+            allocations,
+            total_count,
+            next_start_after: None,
+        });
+    }
+
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_ALLOCATIONS_PAGE_SIZE) as usize;
+    let iter = match has_claimed {
+        Some(claimed) => ALLOCATIONS.idx.claimed.prefix(claimed as u8).range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        ),
+        None => ALLOCATIONS.range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        ),
+    };
+
+    let mut allocations = vec![];
+    let mut last_scanned = None;
+    let mut next_start_after = None;
+    for (scanned, item) in iter.enumerate() {
+        let (address, allocation) = item?;
+        // the scan stops before an unscanned allocation, so the cursor only points to more
+        if allocations.len() == limit || scanned == MAX_ALLOCATIONS_PAGE_SIZE as usize {
+            next_start_after = last_scanned;
+            break;
+        }
+
+        if min_amount.is_none_or(|min_amount| allocation.amount >= min_amount) {
+            allocations.push((
+                address.clone(),
+                coin(allocation.amount.u128(), denom.clone()),
+            ));
+        }
+        last_scanned = Some(address);
+    }
+
+    Ok(AllocationsResponse {
+        allocations,
+        total_count,
+        next_start_after,
+    })
+}
+
+/// Returns the largest allocations, sorted by amount in descending order. Allocations with the
+/// same amount are sorted by address in descending order.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `limit` - The maximum number of allocations to return
+///
+/// # Returns
+/// * `Result<AllocationsResponse, ContractError>` - The largest allocations
+pub fn query_top_allocations(
+    deps: Deps,
+    limit: Option<u16>,
+) -> Result<AllocationsResponse, ContractError> {
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let allocations = ALLOCATIONS
+        .idx
+        .amount
+        .range(deps.storage, None, None, Order::Descending)
+        .take(limit)
+        .map(|item| {
+            let (address, allocation) = item?;
+            Ok((address, coin(allocation.amount.u128(), denom.clone())))
+        })
+        .collect::<StdResult<Vec<(String, Coin)>>>()?;
+
+    Ok(AllocationsResponse {
+        allocations,
+        total_count: None,
+        next_start_after: None,
+    })
+}
+
+/// Returns the allocations of the addresses that haven't claimed yet, sorted by address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - The address to start querying after
+/// * `limit` - The maximum number of allocations to return
+///
+/// # Returns
+/// * `Result<AllocationsResponse, ContractError>` - The unclaimed allocations
+pub fn query_unclaimed_allocations(
     deps: Deps,
     start_after: Option<String>,
-    limit: Option<u32>,
-) -> Result<AuthorizedWalletsResponse, ContractError> {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT as u32).min(MAX_LIMIT as u32) as usize;
-    let start = cw_utils::calc_range_start_string(start_after).map(Bound::ExclusiveRaw);
+    limit: Option<u16>,
+) -> Result<AllocationsResponse, ContractError> {
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
 
-    let wallets: Vec<String> = AUTHORIZED_WALLETS
+    let allocations = ALLOCATIONS
+        .idx
+        .claimed
+        .prefix(0)
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit)
         .map(|item| {
-            let (address, _) = item?;
-            Ok(address)
+            let (address, allocation) = item?;
+            Ok((address, coin(allocation.amount.u128(), denom.clone())))
         })
-        .collect::<StdResult<Vec<String>>>()?;
+        .collect::<StdResult<Vec<(String, Coin)>>>()?;
 
-    Ok(AuthorizedWalletsResponse { wallets })
+    Ok(AllocationsResponse {
+        allocations,
+        total_count: None,
+        next_start_after: None,
+    })
+}
+
+/// Returns the allocations added by the given wallet, sorted by address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `uploader` - The wallet that added the allocations
+/// * `start_after` - The address to start querying after
+/// * `limit` - The maximum number of allocations to return
+///
+/// # Returns
+/// * `Result<AllocationsResponse, ContractError>` - The allocations added by the wallet
+pub fn query_allocations_by_uploader(
+    deps: Deps,
+    uploader: String,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> Result<AllocationsResponse, ContractError> {
+    let uploader = deps.api.addr_validate(&uploader)?;
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let allocations = ALLOCATIONS
+        .idx
+        .uploader
+        .prefix(uploader.to_string())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (address, allocation) = item?;
+            Ok((address, coin(allocation.amount.u128(), denom.clone())))
+        })
+        .collect::<StdResult<Vec<(String, Coin)>>>()?;
+
+    Ok(AllocationsResponse {
+        allocations,
+        total_count: None,
+        next_start_after: None,
+    })
+}
+
+/// Returns the allocations tagged with the given cohort tag, sorted by address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `tag` - The tag of the cohort
+/// * `start_after` - Optional address to start pagination after
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<AllocationsResponse, ContractError>` - The allocations of the cohort
+pub fn query_allocations_by_tag(
+    deps: Deps,
+    tag: String,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> Result<AllocationsResponse, ContractError> {
+    // the untagged allocations are indexed under the empty tag
+    ensure!(
+        !tag.is_empty(),
+        ContractError::InvalidInput {
+            reason: "the tag cannot be empty".to_string(),
+        }
+    );
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let allocations = ALLOCATIONS
+        .idx
+        .tag
+        .prefix(tag)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (address, allocation) = item?;
+            Ok((address, coin(allocation.amount.u128(), denom.clone())))
+        })
+        .collect::<StdResult<Vec<(String, Coin)>>>()?;
+
+    Ok(AllocationsResponse {
+        allocations,
+        total_count: None,
+        next_start_after: None,
+    })
+}
+
+/// Returns the allocation and claim statistics of a cohort tag.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `tag` - The tag of the cohort
+///
+/// # Returns
+/// * `Result<TagStatsResponse, ContractError>` - The statistics of the cohort
+pub fn query_tag_stats(deps: Deps, tag: String) -> Result<TagStatsResponse, ContractError> {
+    let stats = TAG_STATS
+        .may_load(deps.storage, tag.as_str())?
+        .unwrap_or_default();
+
+    Ok(TagStatsResponse { tag, stats })
+}
+
+/// Returns whether an address is blacklisted.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<BlacklistResponse, ContractError>` - The blacklist status
+pub fn query_is_blacklisted(
+    deps: Deps,
+    address: String,
+) -> Result<BlacklistResponse, ContractError> {
+    let is_blacklisted = is_blacklisted(deps, &address)?;
+    Ok(BlacklistResponse { is_blacklisted })
+}
+
+/// Returns the blacklist metadata of an address, i.e. who blacklisted it, when and why.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<BlacklistEntryResponse, ContractError>` - The blacklist metadata, if blacklisted
+pub fn query_blacklist_entry(
+    deps: Deps,
+    address: String,
+) -> Result<BlacklistEntryResponse, ContractError> {
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let entry = BLACKLIST.may_load(deps.storage, address.as_str())?;
+
+    Ok(BlacklistEntryResponse { address, entry })
+}
+
+/// Returns the sweep waiting for a second approval, if any.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<PendingSweepResponse, ContractError>` - The pending sweep, if any
+pub fn query_pending_sweep(deps: Deps) -> Result<PendingSweepResponse, ContractError> {
+    Ok(PendingSweepResponse {
+        pending_sweep: PENDING_SWEEP.may_load(deps.storage)?,
+    })
+}
+
+/// Returns the freeze metadata of an address, i.e. who froze it, when and why.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<FrozenEntryResponse, ContractError>` - The freeze metadata, if frozen
+pub fn query_frozen_entry(
+    deps: Deps,
+    address: String,
+) -> Result<FrozenEntryResponse, ContractError> {
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let entry = FROZEN.may_load(deps.storage, address.as_str())?;
+
+    Ok(FrozenEntryResponse { address, entry })
+}
+
+/// Returns which scopes of the contract are paused, by the owner or by the circuit breaker.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<PauseStatusResponse, ContractError>` - The pause status of the contract
+pub fn query_pause_status(deps: Deps) -> Result<PauseStatusResponse, ContractError> {
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let paused = |scope: PauseScope| {
+        config
+            .pause
+            .as_ref()
+            .is_some_and(|paused| paused.covers(&scope))
+    };
+
+    Ok(PauseStatusResponse {
+        claims_paused: config.paused || paused(PauseScope::Claims),
+        allocation_management_paused: paused(PauseScope::AllocationManagement),
+        circuit_breaker_tripped: config.paused,
+        scope: config.pause.clone(),
+    })
+}
+
+/// Returns the hold on the claims of an address, if any and not expired yet.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<HoldResponse, ContractError>` - The active hold of the address, if any
+pub fn query_hold(deps: Deps, env: Env, address: String) -> Result<HoldResponse, ContractError> {
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let hold = active_hold(deps, &address, &env.block.time)?;
+
+    Ok(HoldResponse { address, hold })
+}
+
+/// Returns the nonce the next signed claim of an address must use.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<ClaimNonceResponse, ContractError>` - The nonce of the address
+pub fn query_claim_nonce(deps: Deps, address: String) -> Result<ClaimNonceResponse, ContractError> {
+    let address = deps.api.addr_validate(&address)?.to_string();
+    let nonce = CLAIM_NONCES
+        .may_load(deps.storage, address.as_str())?
+        .unwrap_or_default();
+
+    Ok(ClaimNonceResponse { address, nonce })
+}
+
+/// Returns the pending replacement request of an address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<AddressReplacementRequestResponse, ContractError>` - The pending request, if any
+pub fn query_address_replacement_request(
+    deps: Deps,
+    address: String,
+) -> Result<AddressReplacementRequestResponse, ContractError> {
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let new_address = ADDRESS_REPLACEMENT_REQUESTS.may_load(deps.storage, address.as_str())?;
+
+    Ok(AddressReplacementRequestResponse {
+        address,
+        new_address,
+    })
+}
+
+/// Returns the time an address activated its vesting.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<VestingActivationResponse, ContractError>` - The activation time, if any
+pub fn query_vesting_activation(
+    deps: Deps,
+    address: String,
+) -> Result<VestingActivationResponse, ContractError> {
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let activated_at = VESTING_ACTIVATIONS.may_load(deps.storage, address.as_str())?;
+
+    Ok(VestingActivationResponse {
+        address,
+        activated_at,
+    })
+}
+
+/// Returns whether an address is allowlisted.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<AllowlistResponse, ContractError>` - The allowlist status
+pub fn query_is_allowlisted(
+    deps: Deps,
+    address: String,
+) -> Result<AllowlistResponse, ContractError> {
+    let is_allowlisted = is_allowlisted(deps, &address)?;
+    Ok(AllowlistResponse { is_allowlisted })
+}
+
+/// Returns the address the claims of an allocation are locked to pay out to.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address of the allocation
+///
+/// # Returns
+/// * `Result<LockedReceiverResponse, ContractError>` - The locked receiver, if any
+pub fn query_locked_receiver(
+    deps: Deps,
+    address: String,
+) -> Result<LockedReceiverResponse, ContractError> {
+    let allocation = ALLOCATIONS
+        .may_load(
+            deps.storage,
+            helpers::validate_raw_address(deps, &address)?.as_str(),
+        )?
+        .ok_or(ContractError::NoAllocationFound { address })?;
+
+    Ok(LockedReceiverResponse {
+        locked_receiver: allocation.locked_receiver.map(|addr| addr.to_string()),
+    })
+}
+
+/// Returns a group sharing a pool of the campaign rewards.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `name` - The name of the group
+///
+/// # Returns
+/// * `Result<GroupResponse, ContractError>` - The group
+pub fn query_group(deps: Deps, name: String) -> Result<GroupResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    let group = GROUPS
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::GroupNotFound { name: name.clone() })?;
+
+    let denom = &campaign.total_reward.denom;
+    Ok(GroupResponse {
+        name,
+        schedule: group.schedule,
+        total: coin(group.total.u128(), denom),
+        total_weight: group.total_weight,
+        members: group.members,
+        claimed: coin(group.claimed.u128(), denom),
+    })
+}
+
+/// Returns the share of a member in a group pool, and how much of it can be claimed now.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The environment
+/// * `name` - The name of the group
+/// * `address` - The address of the member
+///
+/// # Returns
+/// * `Result<GroupMemberResponse, ContractError>` - The share of the member
+pub fn query_group_member(
+    deps: Deps,
+    env: Env,
+    name: String,
+    address: String,
+) -> Result<GroupMemberResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    let group = GROUPS
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::GroupNotFound { name: name.clone() })?;
+    let member = GROUP_MEMBERS
+        .may_load(deps.storage, (name.as_str(), address.as_str()))?
+        .ok_or_else(|| ContractError::NotGroupMember {
+            group: name.clone(),
+            address: address.clone(),
+        })?;
+
+    let denom = &campaign.total_reward.denom;
+    let (share, claimable) = if campaign.has_started(&env.block.time) && campaign.closed.is_none() {
+        let (share, (claimable, _, _)) =
+            helpers::compute_group_claimable_amount(&campaign, &group, &member, &env.block.time)?;
+        (share, claimable)
+    } else {
+        (
+            group
+                .total
+                .multiply_ratio(member.weight, group.total_weight),
+            coin(0, denom),
+        )
+    };
+    let claimed = member
+        .claims
+        .values()
+        .try_fold(Uint128::zero(), |acc, (amount, _)| acc.checked_add(*amount))?;
+
+    Ok(GroupMemberResponse {
+        group: name,
+        address,
+        weight: member.weight,
+        share: coin(share.u128(), denom),
+        claimed: coin(claimed.u128(), denom),
+        claimable,
+    })
+}
+
+/// Returns the total contributed by each funder topping up the campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - Optional address to start pagination after
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<FundersResponse, ContractError>` - The funders and their contributions
+pub fn query_funders(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> Result<FundersResponse, ContractError> {
+    let denom = CAMPAIGN
+        .may_load(deps.storage)?
+        .map(|c| c.total_reward.denom)
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let funders = FUNDERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (funder, contributed) = item?;
+            Ok((funder, coin(contributed.u128(), &denom)))
+        })
+        .collect::<StdResult<Vec<(String, Coin)>>>()?;
+
+    Ok(FundersResponse { funders })
+}
+
+/// Returns the deposits made to top up the campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - Optional sequence number to start pagination after
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<FundingHistoryResponse, ContractError>` - The deposits
+pub fn query_funding_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+) -> Result<FundingHistoryResponse, ContractError> {
+    let Some(campaign) = CAMPAIGN.may_load(deps.storage)? else {
+        return Ok(FundingHistoryResponse { deposits: vec![] });
+    };
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let deposits = FUNDING_HISTORY
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (sequence, record) = item?;
+            Ok(Deposit {
+                sequence,
+                funder: record.funder.to_string(),
+                amount: coin(record.amount.u128(), &campaign.total_reward.denom),
+                deposited_at: record.deposited_at,
+            })
+        })
+        .collect::<StdResult<Vec<Deposit>>>()?;
+
+    Ok(FundingHistoryResponse { deposits })
+}
+
+/// Returns the budget of the campaign, and how much of it was used.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<BudgetResponse, ContractError>` - The budget of the campaign
+pub fn query_budget(deps: Deps) -> Result<BudgetResponse, ContractError> {
+    let budget = BUDGET.may_load(deps.storage)?;
+
+    Ok(BudgetResponse {
+        amount: budget.as_ref().map(|budget| budget.amount),
+        used: budget
+            .as_ref()
+            .map(|budget| budget.used)
+            .unwrap_or_default(),
+        remaining: budget.as_ref().map(Budget::remaining),
+    })
+}
+
+/// Returns whether an address accepted the current terms of the campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address accepting the terms
+///
+/// # Returns
+/// * `Result<TermsAcceptanceResponse, ContractError>` - The terms acceptance of the address
+pub fn query_terms_acceptance(
+    deps: Deps,
+    address: String,
+) -> Result<TermsAcceptanceResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    let address = helpers::validate_raw_address(deps, &address)?;
+    let acceptance = TERMS_ACCEPTANCES.may_load(deps.storage, address.as_str())?;
+
+    Ok(TermsAcceptanceResponse {
+        accepted: campaign.terms_hash.as_ref().is_none_or(|terms_hash| {
+            acceptance
+                .as_ref()
+                .is_some_and(|(accepted_hash, _)| accepted_hash == terms_hash)
+        }),
+        terms_hash: campaign.terms_hash,
+        accepted_hash: acceptance.as_ref().map(|(hash, _)| hash.clone()),
+        accepted_at: acceptance.map(|(_, accepted_at)| accepted_at),
+    })
+}
+
+/// Returns the claim window of an address, and the amount forfeited once it expired.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address of the allocation
+///
+/// # Returns
+/// * `Result<ClaimWindowResponse, ContractError>` - The claim window of the address
+pub fn query_claim_window(
+    deps: Deps,
+    address: String,
+) -> Result<ClaimWindowResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+    let address = helpers::validate_raw_address(deps, &address)?;
+
+    Ok(ClaimWindowResponse {
+        expires_at: helpers::claim_window_expiry(deps, &campaign, &address)?,
+        forfeited: FORFEITED.may_load(deps.storage, address.as_str())?,
+    })
+}
+
+/// Returns the locks of an address, holding the tokens claimed with a lock tier.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address owning the locks
+///
+/// # Returns
+/// * `Result<LocksResponse, ContractError>` - The locks, sorted by unlock time
+pub fn query_locks(deps: Deps, address: String) -> Result<LocksResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let locks = LOCKS
+        .prefix(deps.api.addr_validate(&address)?.as_str())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|lock| {
+            let (unlocks_at, amount) = lock?;
+            Ok(Lock {
+                amount: coin(amount.u128(), &campaign.total_reward.denom),
+                unlocks_at: Timestamp::from_seconds(unlocks_at),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(LocksResponse { locks })
+}
+
+/// Returns the contracts registered as claim hooks.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<ClaimHooksResponse, ContractError>` - The registered claim hooks
+pub fn query_claim_hooks(deps: Deps) -> Result<ClaimHooksResponse, ContractError> {
+    let hooks = CLAIM_HOOKS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(ClaimHooksResponse { hooks })
+}
+
+/// Returns the contract configuration.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<ConfigResponse, ContractError>` - The contract configuration
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Returns the contract name and version, the features it supports and the limits in effect.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<ContractInfoResponse, ContractError>` - The contract info
+pub fn query_contract_info(deps: Deps) -> Result<ContractInfoResponse, ContractError> {
+    let contract_version = cw2::get_contract_version(deps.storage)?;
+    let limits = CONFIG.may_load(deps.storage)?.unwrap_or_default().limits;
+
+    let mut features = FEATURES
+        .iter()
+        .map(|feature| feature.to_string())
+        .collect::<Vec<String>>();
+    if cfg!(feature = "invariant-guards") {
+        features.push("invariant_guards".to_string());
+    }
+
+    Ok(ContractInfoResponse {
+        contract: contract_version.contract,
+        version: contract_version.version,
+        features,
+        limits: ContractLimits {
+            max_allocation_batch_size: limits
+                .max_allocation_batch_size
+                .unwrap_or(MAX_ALLOCATION_BATCH_SIZE as u32),
+            max_allowlist_batch_size: limits
+                .max_allowlist_batch_size
+                .unwrap_or(MAX_ALLOWLIST_BATCH_SIZE as u32),
+            max_distribution_slots: limits
+                .max_distribution_slots
+                .unwrap_or(DEFAULT_MAX_DISTRIBUTION_SLOTS),
+            max_claim_batch_size: MAX_CLAIM_BATCH_SIZE as u32,
+            max_authorized_wallets_batch_size: MAX_AUTHORIZED_WALLETS_BATCH_SIZE as u32,
+            max_blacklist_batch_size: MAX_BLACKLIST_BATCH_SIZE as u32,
+            max_groups: MAX_GROUPS as u32,
+            max_group_members_batch_size: MAX_GROUP_MEMBERS_BATCH_SIZE as u32,
+            max_batch_rewards_addresses: MAX_BATCH_REWARDS_ADDRESSES as u32,
+            max_query_limit: MAX_LIMIT as u32,
+            max_allocations_page_size: MAX_ALLOCATIONS_PAGE_SIZE as u32,
+        },
+    })
+}
+
+/// Returns the number of allocations, claimants and blacklisted addresses, from the counters kept
+/// in state.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<CountsResponse, ContractError>` - The counts
+pub fn query_counts(deps: Deps) -> Result<CountsResponse, ContractError> {
+    Ok(CountsResponse {
+        allocations: get_count(deps.storage, &ALLOCATIONS_COUNT)?,
+        claimants: get_count(deps.storage, &CLAIMANTS_COUNT)?,
+        blacklisted: get_count(deps.storage, &BLACKLIST_COUNT)?,
+    })
+}
+
+/// Returns the funding obligations of each distribution slot against the funds received by the
+/// campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+///
+/// # Returns
+/// * `Result<SlotSolvencyResponse, ContractError>` - The funding of each slot
+pub fn query_slot_solvency(deps: Deps, env: Env) -> Result<SlotSolvencyResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let balance = helpers::query_reward_balance(deps, &env.contract.address, &campaign)?;
+    let slots = helpers::compute_slot_solvency(
+        &campaign,
+        balance.amount,
+        &get_slots_claimed(deps.storage)?,
+    )?;
+
+    Ok(SlotSolvencyResponse {
+        slots,
+        funds_received: coin(
+            balance.amount.checked_add(campaign.claimed.amount)?.u128(),
+            &balance.denom,
+        ),
+        balance,
+    })
+}
+
+/// Returns the liabilities of the campaign against the balance of the contract. The totals are
+/// kept in state, while the amount claimable right now is summed over a page of allocations, so
+/// the whole set is covered by following `next_start_after`.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `env` - The env context
+/// * `start_after` - The address to start summing the claimable amounts after
+/// * `limit` - The maximum number of allocations to sum
+///
+/// # Returns
+/// * `Result<LiabilitiesResponse, ContractError>` - The liabilities of the campaign
+pub fn query_liabilities(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> Result<LiabilitiesResponse, ContractError> {
+    let campaign = CAMPAIGN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoActiveCampaign)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let allocations = ALLOCATIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<(String, Allocation)>>>()?;
+
+    // nothing is owed anymore once the campaign is closed, its remaining funds being refunded
+    let mut claimable = Uint128::zero();
+    if campaign.closed.is_none() {
+        for (address, allocation) in &allocations {
+            let (claimable_amount, _, _) = helpers::compute_claimable_amount(
+                deps,
+                &campaign,
+                &env.block.time,
+                address,
+                allocation.amount,
+            )?;
+            claimable = claimable.checked_add(claimable_amount.amount)?;
+        }
+    }
+
+    let next_start_after = if allocations.len() == limit {
+        allocations.last().map(|(address, _)| address.clone())
+    } else {
+        None
+    };
+
+    let total_allocated = TOTAL_ALLOCATED.may_load(deps.storage)?.unwrap_or_default();
+    let outstanding = if campaign.closed.is_none() {
+        total_allocated.saturating_sub(campaign.claimed.amount)
+    } else {
+        Uint128::zero()
+    };
+    let balance = helpers::query_reward_balance(deps, &env.contract.address, &campaign)?;
+
+    Ok(LiabilitiesResponse {
+        total_allocated,
+        total_claimed: campaign.claimed.amount,
+        outstanding,
+        surplus: balance.amount.saturating_sub(outstanding),
+        shortfall: outstanding.saturating_sub(balance.amount),
+        balance,
+        claimable,
+        next_start_after,
+    })
+}
+
+/// Returns the statistics of the claims made on the campaign.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<CampaignStats, ContractError>` - The campaign statistics
+pub fn query_campaign_stats(deps: Deps) -> Result<CampaignStats, ContractError> {
+    Ok(STATS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Returns the raffle seed and winners.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+///
+/// # Returns
+/// * `Result<RaffleResponse, ContractError>` - The raffle seed and winners
+pub fn query_raffle(deps: Deps) -> Result<RaffleResponse, ContractError> {
+    let winners = RAFFLE_WINNERS.may_load(deps.storage)?;
+
+    Ok(RaffleResponse {
+        seed: RAFFLE_SEED.may_load(deps.storage)?,
+        drawn: winners.is_some(),
+        winners: winners.unwrap_or_default(),
+    })
+}
+
+/// Returns the proposals, by ascending id.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - The proposal id to start querying after
+/// * `limit` - The maximum number of proposals to return
+///
+/// # Returns
+/// * `Result<ProposalsResponse, ContractError>` - The proposals
+pub fn query_proposals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+) -> Result<ProposalsResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let proposals = PROPOSALS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect::<StdResult<Vec<Proposal>>>()?;
+
+    Ok(ProposalsResponse { proposals })
+}
+
+/// Returns whether an address is authorized (owner or authorized wallet).
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<AuthorizedResponse, ContractError>` - The authorization status
+pub fn query_is_authorized(
+    deps: Deps,
+    address: String,
+) -> Result<AuthorizedResponse, ContractError> {
+    let validated_address = deps.api.addr_validate(&address)?;
+    let is_authorized = is_authorized(deps, &validated_address)?;
+    Ok(AuthorizedResponse { is_authorized })
+}
+
+/// Returns a list of authorized wallet addresses with pagination support.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - Optional address to start pagination from
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<AuthorizedWalletsResponse, ContractError>` - The authorized wallets list
+pub fn query_authorized_wallets(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<AuthorizedWalletsResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT as u32).min(MAX_LIMIT as u32) as usize;
+    let start = cw_utils::calc_range_start_string(start_after).map(Bound::ExclusiveRaw);
+
+    let wallets: Vec<String> = AUTHORIZED_WALLETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (address, _) = item?;
+            Ok(address)
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(AuthorizedWalletsResponse { wallets })
+}
+
+/// Returns the authorized wallets waiting for their delay to elapse, with pagination support.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `start_after` - Optional address to start pagination from
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<PendingAuthorizedWalletsResponse, ContractError>` - The pending authorized wallets
+pub fn query_pending_authorized_wallets(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<PendingAuthorizedWalletsResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT as u32).min(MAX_LIMIT as u32) as usize;
+    let start = cw_utils::calc_range_start_string(start_after).map(Bound::ExclusiveRaw);
+
+    let wallets = PENDING_AUTHORIZED_WALLETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (address, activates_at) = item?;
+            Ok(PendingAuthorizedWallet {
+                address,
+                activates_at,
+            })
+        })
+        .collect::<StdResult<Vec<PendingAuthorizedWallet>>>()?;
+
+    Ok(PendingAuthorizedWalletsResponse { wallets })
+}
+
+/// Returns the roles of an address. The owner and the authorized wallets have all the roles.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to get the roles for
+///
+/// # Returns
+/// * `Result<RolesResponse, ContractError>` - The roles of the address
+pub fn query_roles(deps: Deps, address: String) -> Result<RolesResponse, ContractError> {
+    let validated_address = deps.api.addr_validate(&address)?;
+
+    let mut roles = vec![];
+    for role in Role::ALL {
+        if has_role(deps, &validated_address, role)? {
+            roles.push(role);
+        }
+    }
+
+    Ok(RolesResponse {
+        address: validated_address.to_string(),
+        roles,
+    })
+}
+
+/// Returns the wallets granted a role with pagination support.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `role` - The role to get the holders for
+/// * `start_after` - Optional address to start pagination after
+/// * `limit` - Optional limit for pagination
+///
+/// # Returns
+/// * `Result<RoleHoldersResponse, ContractError>` - The wallets granted the role
+pub fn query_role_holders(
+    deps: Deps,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<RoleHoldersResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT as u32).min(MAX_LIMIT as u32) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let holders = WALLET_ROLES
+        .prefix(role.name())
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(RoleHoldersResponse { role, holders })
+}
+
+/// Exports a page of the raw state of a section, sorted by address.
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `section` - The section of the state to export
+/// * `start_after` - The address to start exporting after
+/// * `limit` - The maximum number of entries to return
+///
+/// # Returns
+/// * `Result<ExportStateResponse, ContractError>` - The entries, and where the next page starts
+pub fn query_export_state(
+    deps: Deps,
+    section: StateSection,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> Result<ExportStateResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let entries = match section {
+        StateSection::Allocations => ALLOCATIONS
+            .range(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| {
+                let (address, allocation) = item?;
+                Ok(StateEntry::Allocation {
+                    address,
+                    amount: allocation.amount,
+                    claimed: allocation.claimed,
+                    added_by: allocation.added_by.to_string(),
+                    added_at: allocation.added_at,
+                    locked_receiver: allocation.locked_receiver.map(|addr| addr.to_string()),
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        StateSection::Claims => CLAIMS
+            .range(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| {
+                let (address, claims) = item?;
+                let mut claims: Vec<RawClaim> = claims
+                    .into_iter()
+                    .map(|(slot, (amount, claimed_at))| RawClaim {
+                        slot,
+                        amount,
+                        claimed_at,
+                    })
+                    .collect();
+                claims.sort_by_key(|claim| claim.slot);
+                Ok(StateEntry::Claims { address, claims })
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        StateSection::Blacklist => BLACKLIST
+            .range(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| {
+                let (address, entry) = item?;
+                Ok(StateEntry::Blacklisted { address, entry })
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        StateSection::AuthorizedWallets => AUTHORIZED_WALLETS
+            .keys(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|address| Ok(StateEntry::AuthorizedWallet { address: address? }))
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+
+    // a full page may be followed by more entries
+    let next_start_after =
+        entries
+            .last()
+            .filter(|_| entries.len() == limit)
+            .map(|entry| match entry {
+                StateEntry::Allocation { address, .. }
+                | StateEntry::Claims { address, .. }
+                | StateEntry::Blacklisted { address, .. }
+                | StateEntry::AuthorizedWallet { address } => address.clone(),
+            });
+
+    Ok(ExportStateResponse {
+        entries,
+        next_start_after,
+    })
 }
@@ -0,0 +1,483 @@
+use cosmwasm_std::{
+    Addr, AnyMsg, Coin, CosmosMsg, QuerierWrapper, StdError, StdResult, Timestamp, Uint128,
+};
+
+/// The type url of the tokenfactory mint message
+pub(crate) const MSG_MINT_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgMint";
+
+/// The type url of the vesting account creation message
+pub(crate) const MSG_CREATE_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.MsgCreateVestingAccount";
+
+/// The type url of the fee allowance grant message
+pub(crate) const MSG_GRANT_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+
+/// The type url of the basic fee allowance
+pub(crate) const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+
+/// The grpc path of the staking query returning the delegations of a validator
+pub(crate) const VALIDATOR_DELEGATIONS_QUERY_PATH: &str =
+    "/cosmos.staking.v1beta1.Query/ValidatorDelegations";
+
+/// The grpc path of the auth query returning the account of an address
+pub(crate) const AUTH_ACCOUNT_QUERY_PATH: &str = "/cosmos.auth.v1beta1.Query/Account";
+
+/// The grpc path of the authz query returning the grants between a granter and a grantee
+pub(crate) const AUTHZ_GRANTS_QUERY_PATH: &str = "/cosmos.authz.v1beta1.Query/Grants";
+
+/// The type url of the contract execution message, which must be authorized to claim through authz
+pub(crate) const MSG_EXECUTE_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+/// The type url of the authz authorization of any execution of a message type
+pub(crate) const GENERIC_AUTHORIZATION_TYPE_URL: &str =
+    "/cosmos.authz.v1beta1.GenericAuthorization";
+
+/// The type url of the authz authorization of the execution of specific contracts
+pub(crate) const CONTRACT_EXECUTION_AUTHORIZATION_TYPE_URL: &str =
+    "/cosmwasm.wasm.v1.ContractExecutionAuthorization";
+
+/// The type url of the contract execution limit on the funds sent along
+pub(crate) const MAX_FUNDS_LIMIT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MaxFundsLimit";
+
+/// The type url of the contract execution filter allowing all the messages
+pub(crate) const ALLOW_ALL_MESSAGES_FILTER_TYPE_URL: &str =
+    "/cosmwasm.wasm.v1.AllowAllMessagesFilter";
+
+/// The type url of the contract execution filter allowing the messages with the given top level keys
+pub(crate) const ACCEPTED_MESSAGE_KEYS_FILTER_TYPE_URL: &str =
+    "/cosmwasm.wasm.v1.AcceptedMessageKeysFilter";
+
+/// The top level key of the message claiming through authz
+pub(crate) const CLAIM_AUTHZ_MESSAGE_KEY: &str = "claim_authz";
+
+/// The number of delegations requested per page when querying the delegations of a validator
+const VALIDATOR_DELEGATIONS_PAGE_SIZE: u64 = 100;
+
+/// Creates a tokenfactory message minting the given amount to the sender, which must be the admin
+/// of the denom.
+///
+/// # Arguments
+/// * `sender` - The admin of the denom, i.e. the contract itself
+/// * `amount` - The coin to mint
+///
+/// # Returns
+/// * `CosmosMsg` - The protobuf encoded `MsgMint`, wrapped in a [CosmosMsg::Any]
+pub(crate) fn mint_msg(sender: &Addr, amount: &Coin) -> CosmosMsg {
+    // message MsgMint { string sender = 1; cosmos.base.v1beta1.Coin amount = 2; }
+    let mut value = vec![];
+    encode_bytes(1, sender.as_bytes(), &mut value);
+    encode_bytes(2, &encode_coin(amount), &mut value);
+
+    CosmosMsg::Any(AnyMsg {
+        type_url: MSG_MINT_TYPE_URL.to_string(),
+        value: value.into(),
+    })
+}
+
+/// Creates a message creating a continuous vesting account for the receiver, funded with the given
+/// amount, which vests linearly from the current block time until the end time.
+///
+/// # Arguments
+/// * `sender` - The account funding the vesting account, i.e. the contract itself
+/// * `receiver` - The address of the vesting account to create
+/// * `amount` - The coin to lock in the vesting account
+/// * `end_time` - The time at which the coins are fully vested
+///
+/// # Returns
+/// * `CosmosMsg` - The protobuf encoded `MsgCreateVestingAccount`, wrapped in a [CosmosMsg::Any]
+pub(crate) fn create_vesting_account_msg(
+    sender: &Addr,
+    receiver: &Addr,
+    amount: &Coin,
+    end_time: &Timestamp,
+) -> CosmosMsg {
+    // message MsgCreateVestingAccount {
+    //   string from_address = 1; string to_address = 2; repeated Coin amount = 3;
+    //   int64 end_time = 4; bool delayed = 5;
+    // }
+    let mut value = vec![];
+    encode_bytes(1, sender.as_bytes(), &mut value);
+    encode_bytes(2, receiver.as_bytes(), &mut value);
+    encode_bytes(3, &encode_coin(amount), &mut value);
+    encode_uint(4, end_time.seconds(), &mut value);
+
+    CosmosMsg::Any(AnyMsg {
+        type_url: MSG_CREATE_VESTING_ACCOUNT_TYPE_URL.to_string(),
+        value: value.into(),
+    })
+}
+
+/// Creates a message granting a basic fee allowance to the grantee, paid by the granter.
+///
+/// # Arguments
+/// * `granter` - The account paying the fees, i.e. the contract itself
+/// * `grantee` - The address allowed to spend the allowance on fees
+/// * `spend_limit` - The maximum amount of fees the grantee can spend
+/// * `expiration` - The time at which the allowance expires, if any
+///
+/// # Returns
+/// * `CosmosMsg` - The protobuf encoded `MsgGrantAllowance`, wrapped in a [CosmosMsg::Any]
+pub(crate) fn grant_allowance_msg(
+    granter: &Addr,
+    grantee: &Addr,
+    spend_limit: &Coin,
+    expiration: Option<&Timestamp>,
+) -> CosmosMsg {
+    // message BasicAllowance { repeated Coin spend_limit = 1; google.protobuf.Timestamp expiration = 2; }
+    // message Timestamp { int64 seconds = 1; int32 nanos = 2; }
+    let mut allowance = vec![];
+    encode_bytes(1, &encode_coin(spend_limit), &mut allowance);
+    if let Some(expiration) = expiration {
+        let mut timestamp = vec![];
+        encode_uint(1, expiration.seconds(), &mut timestamp);
+        encode_bytes(2, &timestamp, &mut allowance);
+    }
+
+    // message Any { string type_url = 1; bytes value = 2; }
+    let mut any = vec![];
+    encode_bytes(1, BASIC_ALLOWANCE_TYPE_URL.as_bytes(), &mut any);
+    encode_bytes(2, &allowance, &mut any);
+
+    // message MsgGrantAllowance { string granter = 1; string grantee = 2; google.protobuf.Any allowance = 3; }
+    let mut value = vec![];
+    encode_bytes(1, granter.as_bytes(), &mut value);
+    encode_bytes(2, grantee.as_bytes(), &mut value);
+    encode_bytes(3, &any, &mut value);
+
+    CosmosMsg::Any(AnyMsg {
+        type_url: MSG_GRANT_ALLOWANCE_TYPE_URL.to_string(),
+        value: value.into(),
+    })
+}
+
+/// Queries all the delegations of the given validator from the staking module, following the
+/// pagination until the last page.
+///
+/// # Arguments
+/// * `querier` - The querier
+/// * `validator` - The operator address of the validator
+///
+/// # Returns
+/// * `StdResult<Vec<(String, Uint128)>>` - The (delegator, staked amount) pairs
+pub(crate) fn query_validator_delegations(
+    querier: &QuerierWrapper,
+    validator: &str,
+) -> StdResult<Vec<(String, Uint128)>> {
+    let mut delegations = vec![];
+    let mut next_key: Option<Vec<u8>> = None;
+
+    loop {
+        // message QueryValidatorDelegationsRequest { string validator_addr = 1; PageRequest pagination = 2; }
+        // message PageRequest { bytes key = 1; uint64 offset = 2; uint64 limit = 3; }
+        let mut pagination = vec![];
+        if let Some(key) = &next_key {
+            encode_bytes(1, key, &mut pagination);
+        }
+        encode_uint(3, VALIDATOR_DELEGATIONS_PAGE_SIZE, &mut pagination);
+
+        let mut request = vec![];
+        encode_bytes(1, validator.as_bytes(), &mut request);
+        encode_bytes(2, &pagination, &mut request);
+
+        let response =
+            querier.query_grpc(VALIDATOR_DELEGATIONS_QUERY_PATH.to_string(), request.into())?;
+
+        // message QueryValidatorDelegationsResponse {
+        //   repeated DelegationResponse delegation_responses = 1; PageResponse pagination = 2;
+        // }
+        // message DelegationResponse { Delegation delegation = 1; Coin balance = 2; }
+        // message Delegation { string delegator_address = 1; ... }
+        // message PageResponse { bytes next_key = 1; uint64 total = 2; }
+        next_key = None;
+        for (field_number, value) in decode_fields(response.as_slice())? {
+            match field_number {
+                1 => {
+                    let delegation_response = decode_fields(&value)?;
+                    let delegator = find_field(&delegation_response, 1)
+                        .map(decode_fields)
+                        .transpose()?
+                        .and_then(|delegation| find_field(&delegation, 1).map(decode_string))
+                        .transpose()?
+                        .ok_or_else(|| StdError::generic_err("missing delegator address"))?;
+                    let amount = find_field(&delegation_response, 2)
+                        .map(decode_coin_amount)
+                        .transpose()?
+                        .unwrap_or_default();
+
+                    delegations.push((delegator, amount));
+                }
+                2 => {
+                    next_key = find_field(&decode_fields(&value)?, 1)
+                        .filter(|key| !key.is_empty())
+                        .map(<[u8]>::to_vec);
+                }
+                _ => {}
+            }
+        }
+
+        if next_key.is_none() {
+            return Ok(delegations);
+        }
+    }
+}
+
+/// Checks whether an account exists for the given address. The auth module fails the query when
+/// there's no account, which is considered as a fresh address. A failure for any other reason is
+/// harmless, as the vesting module rejects creating a vesting account over an existing one anyway.
+///
+/// # Arguments
+/// * `querier` - The querier
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `bool` - Whether an account exists for the address
+pub(crate) fn account_exists(querier: &QuerierWrapper, address: &Addr) -> bool {
+    // message QueryAccountRequest { string address = 1; }
+    let mut request = vec![];
+    encode_bytes(1, address.as_bytes(), &mut request);
+
+    querier
+        .query_grpc(AUTH_ACCOUNT_QUERY_PATH.to_string(), request.into())
+        .is_ok()
+}
+
+/// Checks whether the granter has granted the grantee an authz authorization to claim from the
+/// given contract on its behalf, which hasn't expired at the given time. The grantee executes the
+/// contract directly rather than through the authz module, so only the authorizations the contract
+/// can enforce on its own are accepted:
+/// * a `GenericAuthorization` of `MsgExecuteContract`
+/// * a `ContractExecutionAuthorization` with a grant for the contract, allowing the `claim_authz`
+///   message, and limited by the funds sent at most, as no funds are sent to claim. The call limits
+///   are rejected, as the authz module doesn't consume them.
+///
+/// # Arguments
+/// * `querier` - The querier
+/// * `contract` - The address of the contract claimed from, i.e. the contract itself
+/// * `granter` - The address that granted the authorization
+/// * `grantee` - The address the authorization was granted to
+/// * `current_time` - The time to check the grant expiration against
+///
+/// # Returns
+/// * `StdResult<bool>` - Whether there's a valid grant
+pub(crate) fn has_authz_grant(
+    querier: &QuerierWrapper,
+    contract: &Addr,
+    granter: &Addr,
+    grantee: &Addr,
+    current_time: &Timestamp,
+) -> StdResult<bool> {
+    // message QueryGrantsRequest {
+    //   string granter = 1; string grantee = 2; string msg_type_url = 3; PageRequest pagination = 4;
+    // }
+    // the message type isn't set, as the authz module fails the query when there's no grant for it
+    let mut request = vec![];
+    encode_bytes(1, granter.as_bytes(), &mut request);
+    encode_bytes(2, grantee.as_bytes(), &mut request);
+
+    let response = querier.query_grpc(AUTHZ_GRANTS_QUERY_PATH.to_string(), request.into())?;
+
+    // message QueryGrantsResponse { repeated Grant grants = 1; PageResponse pagination = 2; }
+    // message Grant { google.protobuf.Any authorization = 1; google.protobuf.Timestamp expiration = 2; }
+    // message Timestamp { int64 seconds = 1; int32 nanos = 2; }
+    for (field_number, value) in decode_fields(response.as_slice())? {
+        if field_number != 1 {
+            continue;
+        }
+
+        let grant = decode_fields(&value)?;
+        let expiration = find_field(&grant, 2)
+            .map(decode_fields)
+            .transpose()?
+            .map(|timestamp| find_field(&timestamp, 1).map_or(Ok(0), decode_uint))
+            .transpose()?;
+
+        if expiration.is_some_and(|seconds| seconds <= current_time.seconds()) {
+            continue;
+        }
+
+        if let Some(authorization) = find_field(&grant, 1) {
+            if allows_claim(authorization, contract)? {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks whether an authz authorization, encoded as a `google.protobuf.Any`, allows claiming from
+/// the contract, see [has_authz_grant].
+fn allows_claim(authorization: &[u8], contract: &Addr) -> StdResult<bool> {
+    let (type_url, value) = decode_any(authorization)?;
+
+    match type_url.as_str() {
+        // message GenericAuthorization { string msg = 1; }
+        GENERIC_AUTHORIZATION_TYPE_URL => Ok(find_field(&decode_fields(&value)?, 1)
+            .map(decode_string)
+            .transpose()?
+            .is_some_and(|msg| msg == MSG_EXECUTE_CONTRACT_TYPE_URL)),
+        // message ContractExecutionAuthorization { repeated ContractGrant grants = 1; }
+        // message ContractGrant {
+        //   string contract = 1; google.protobuf.Any limit = 2; google.protobuf.Any filter = 3;
+        // }
+        CONTRACT_EXECUTION_AUTHORIZATION_TYPE_URL => {
+            for (field_number, value) in decode_fields(&value)? {
+                if field_number != 1 {
+                    continue;
+                }
+
+                let contract_grant = decode_fields(&value)?;
+                let is_contract = find_field(&contract_grant, 1)
+                    .map(decode_string)
+                    .transpose()?
+                    .is_some_and(|address| address == contract.as_str());
+                let has_no_calls_limit = match find_field(&contract_grant, 2) {
+                    Some(limit) => decode_any(limit)?.0 == MAX_FUNDS_LIMIT_TYPE_URL,
+                    None => true,
+                };
+                let is_allowed = match find_field(&contract_grant, 3) {
+                    Some(filter) => {
+                        let (filter_type_url, filter) = decode_any(filter)?;
+                        match filter_type_url.as_str() {
+                            ALLOW_ALL_MESSAGES_FILTER_TYPE_URL => true,
+                            // message AcceptedMessageKeysFilter { repeated string keys = 1; }
+                            ACCEPTED_MESSAGE_KEYS_FILTER_TYPE_URL => decode_fields(&filter)?
+                                .iter()
+                                .filter(|(field_number, _)| *field_number == 1)
+                                .map(|(_, key)| decode_string(key))
+                                .collect::<StdResult<Vec<String>>>()?
+                                .iter()
+                                .any(|key| key == CLAIM_AUTHZ_MESSAGE_KEY),
+                            _ => false,
+                        }
+                    }
+                    None => false,
+                };
+
+                if is_contract && has_no_calls_limit && is_allowed {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Decodes a `google.protobuf.Any` into its type url and value
+fn decode_any(bytes: &[u8]) -> StdResult<(String, Vec<u8>)> {
+    // message Any { string type_url = 1; bytes value = 2; }
+    let fields = decode_fields(bytes)?;
+    let type_url = find_field(&fields, 1)
+        .map(decode_string)
+        .transpose()?
+        .unwrap_or_default();
+    let value = find_field(&fields, 2).unwrap_or_default().to_vec();
+
+    Ok((type_url, value))
+}
+
+/// Encodes a `cosmos.base.v1beta1.Coin`
+fn encode_coin(coin: &Coin) -> Vec<u8> {
+    // message Coin { string denom = 1; string amount = 2; }
+    let mut buf = vec![];
+    encode_bytes(1, coin.denom.as_bytes(), &mut buf);
+    encode_bytes(2, coin.amount.to_string().as_bytes(), &mut buf);
+    buf
+}
+
+/// Encodes a length-delimited protobuf field
+fn encode_bytes(field_number: u64, bytes: &[u8], buf: &mut Vec<u8>) {
+    encode_varint(field_number << 3 | 2, buf);
+    encode_varint(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+/// Encodes a varint protobuf field
+fn encode_uint(field_number: u64, value: u64, buf: &mut Vec<u8>) {
+    encode_varint(field_number << 3, buf);
+    encode_varint(value, buf);
+}
+
+/// Encodes a protobuf varint
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Decodes the fields of a protobuf message into (field number, value) pairs. Only the varint and
+/// length-delimited wire types are supported, varints being returned as their little endian bytes.
+fn decode_fields(mut bytes: &[u8]) -> StdResult<Vec<(u64, Vec<u8>)>> {
+    let mut fields = vec![];
+    while !bytes.is_empty() {
+        let tag = decode_varint(&mut bytes)?;
+        let value = match tag & 0x7 {
+            0 => decode_varint(&mut bytes)?.to_le_bytes().to_vec(),
+            2 => {
+                let len = decode_varint(&mut bytes)? as usize;
+                if bytes.len() < len {
+                    return Err(StdError::generic_err("truncated protobuf field"));
+                }
+                let (value, rest) = bytes.split_at(len);
+                bytes = rest;
+                value.to_vec()
+            }
+            _ => return Err(StdError::generic_err("unsupported protobuf wire type")),
+        };
+        fields.push((tag >> 3, value));
+    }
+
+    Ok(fields)
+}
+
+/// Returns the value of the first field with the given number
+fn find_field(fields: &[(u64, Vec<u8>)], field_number: u64) -> Option<&[u8]> {
+    fields
+        .iter()
+        .find(|(number, _)| *number == field_number)
+        .map(|(_, value)| value.as_slice())
+}
+
+/// Decodes a protobuf string
+fn decode_string(bytes: &[u8]) -> StdResult<String> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| StdError::generic_err("invalid utf-8 string"))
+}
+
+/// Decodes a protobuf varint, returned as its little endian bytes by [decode_fields]
+fn decode_uint(bytes: &[u8]) -> StdResult<u64> {
+    bytes
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| StdError::generic_err("invalid protobuf varint"))
+}
+
+/// Decodes the amount of a `cosmos.base.v1beta1.Coin`
+fn decode_coin_amount(bytes: &[u8]) -> StdResult<Uint128> {
+    find_field(&decode_fields(bytes)?, 2)
+        .map(decode_string)
+        .transpose()?
+        .unwrap_or_default()
+        .parse()
+        .map_err(|_| StdError::generic_err("invalid coin amount"))
+}
+
+/// Decodes a protobuf varint, advancing the given bytes
+fn decode_varint(bytes: &mut &[u8]) -> StdResult<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (byte, rest) = bytes
+            .split_first()
+            .ok_or_else(|| StdError::generic_err("truncated protobuf varint"))?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(StdError::generic_err("invalid protobuf varint"))
+}
@@ -1,15 +1,73 @@
 use std::collections::HashMap;
 
-use cosmwasm_std::{Addr, Deps, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Deps, HexBinary, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 use crate::helpers;
 use mantra_claimdrop_std::error::ContractError;
-use mantra_claimdrop_std::msg::Campaign;
+use mantra_claimdrop_std::msg::{
+    BlacklistEntry, Campaign, CampaignStats, Config, DistributionType, FrozenEntry, HoldEntry,
+    PendingSweep, Proposal, Role, TagStats,
+};
 
 /// The campaign item that stores the current active campaign
 pub const CAMPAIGN: Item<Campaign> = Item::new("campaign");
 
+/// The contract configuration. Defaults to [Config::default] if it was never set.
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The IBC channel the campaign is administered over, opened by the counterparty set in the
+/// [Config]. Only one admin channel can be open at a time.
+pub const IBC_ADMIN_CHANNEL: Item<String> = Item::new("ibc_admin_channel");
+
+/// The amount claimed within the current circuit breaker window. The value is a tuple with the
+/// window identifier and the amount claimed within it.
+pub const CIRCUIT_BREAKER_WINDOW: Item<(u64, Uint128)> = Item::new("circuit_breaker_window");
+
+/// The amount of the early bird bonus pool paid out so far.
+pub const EARLY_BIRD_BONUS_CLAIMED: Item<Uint128> = Item::new("early_bird_bonus_claimed");
+
+/// The amount claimed per day, keyed by the number of days since the unix epoch. Only the days
+/// with claims are stored.
+pub const DAILY_CLAIM_VOLUME: Map<u64, Uint128> = Map::new("daily_claim_volume");
+
+/// The statistics of the claims made on the campaign.
+pub const STATS: Item<CampaignStats> = Item::new("stats");
+
+/// The statistics of the allocations and claims of each cohort tag.
+pub const TAG_STATS: Map<&str, TagStats> = Map::new("tag_stats");
+
+/// The number of seconds in a day, used to bucket the claims in [DAILY_CLAIM_VOLUME].
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The proposals to perform irreversible actions, by id.
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+
+/// The id of the last proposal made.
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+/// The seed the raffle winners are drawn with.
+pub const RAFFLE_SEED: Item<HexBinary> = Item::new("raffle_seed");
+
+/// The raffle winners and their prizes. Only set once the raffle has been drawn.
+pub const RAFFLE_WINNERS: Item<Vec<(String, Coin)>> = Item::new("raffle_winners");
+
+/// The nonce the next signed claim of an address must use, by address.
+pub const CLAIM_NONCES: Map<&str, u64> = Map::new("claim_nonces");
+
+/// The digests of the sign docs of the allocation vouchers registered, so each voucher can only be
+/// registered once, even after the allocation it added is removed.
+pub const REGISTERED_VOUCHERS: Map<&[u8], ()> = Map::new("registered_vouchers");
+
+/// The time each address activated its vesting, for the campaigns vesting from the activation.
+pub const VESTING_ACTIVATIONS: Map<&str, Timestamp> = Map::new("vesting_activations");
+
+/// The pending address replacement requests, by old address. The value is the requested new
+/// address.
+pub const ADDRESS_REPLACEMENT_REQUESTS: Map<&str, String> =
+    Map::new("address_replacement_requests");
+
 /// Contains information about how much has an address claimed for a given distribution type.
 /// The key is the address and the value is a hashmap where the key is the distribution_type index,
 /// and the value is a tuple with the amount claimed and the timestamp at which it was claimed.
@@ -18,20 +76,364 @@ pub const CLAIMS: Map<String, HashMap<DistributionSlot, Claim>> = Map::new("clai
 
 /// The claim is a tuple of the amount and the timestamp when it was claimed.
 pub type Claim = (Uint128, u64);
+
+/// Stores the distribution slots each address has fully claimed, so the claimable amount
+/// computations skip them. The key is the address.
+pub const EXHAUSTED_SLOTS: Map<&str, ExhaustedSlots> = Map::new("exhausted_slots");
+
+/// The distribution slots an address has fully claimed, computed against its allocation at the
+/// time. The markers are stale once the allocation changes, i.e. when it is redistributed.
+#[cw_serde]
+pub struct ExhaustedSlots {
+    /// The allocation the slots were exhausted for
+    pub allocation: Uint128,
+    /// The bitmask of the exhausted slots, bit `n` being set if slot `n` is exhausted
+    pub slots: u64,
+}
+
+/// Returns the bitmask of the distribution slots the address has fully claimed, or an empty mask
+/// if the markers were computed for another allocation.
+pub fn get_exhausted_slots(
+    storage: &dyn Storage,
+    address: &str,
+    allocation: Uint128,
+) -> StdResult<u64> {
+    Ok(EXHAUSTED_SLOTS
+        .may_load(storage, address)?
+        .filter(|exhausted| exhausted.allocation == allocation)
+        .map_or(0, |exhausted| exhausted.slots))
+}
+
+/// Returns whether the given slot is set in the bitmask of exhausted slots.
+pub fn is_slot_exhausted(exhausted_slots: u64, slot: DistributionSlot) -> bool {
+    u32::try_from(slot)
+        .ok()
+        .and_then(|slot| exhausted_slots.checked_shr(slot))
+        .is_some_and(|mask| mask & 1 == 1)
+}
+
 /// The distribution slot is the index of DistributionType on the campaign.
-pub type DistributionSlot = usize;
+pub type DistributionSlot = mantra_claimdrop_std::msg::DistributionSlot;
+
+/// Append-only history of the claims performed on the campaign, used to reconstruct individual
+/// claim transactions. The key is a tuple with the receiver address and a sequence number that is
+/// incremented for every entry recorded for that receiver.
+/// Note that replacing an address doesn't move its claim history, as it reflects the actual receivers
+/// of the tokens.
+pub const CLAIM_HISTORY: Map<(&str, u64), ClaimHistoryEntry> = Map::new("claim_history");
+
+/// The claim history entry is a tuple of the amount, the timestamp when it was claimed and the
+/// distribution slot it was claimed from.
+pub type ClaimHistoryEntry = (Uint128, u64, DistributionSlot);
+
+/// The amount paid out from each distribution slot, including the early bird bonuses paid along
+/// with the claims from the early bird bonus slot. The key is the distribution slot.
+pub const SLOT_CLAIMED: Map<u64, Uint128> = Map::new("slot_claimed");
+
+/// Stores the tokens claimed with a lock tier, by owner and unlock time in seconds. Claims unlocking
+/// at the same time are merged.
+pub const LOCKS: Map<(&str, u64), Uint128> = Map::new("locks");
+
+/// Stores the total amount of tokens held in [LOCKS], which don't count towards the campaign funds.
+pub const LOCKED_TOTAL: Item<Uint128> = Item::new("locked_total");
+
+/// Stores the claims paid out over IBC whose transfer wasn't acknowledged yet, by payout id. The
+/// value is the receiver of the claim and the amount sent, which is credited back to the receiver
+/// as an unlocked lock if the transfer fails.
+pub const IBC_PAYOUTS: Map<u64, (Addr, Uint128)> = Map::new("ibc_payouts");
+
+/// The id of the last payout stored in [IBC_PAYOUTS].
+pub const IBC_PAYOUT_COUNT: Item<u64> = Item::new("ibc_payout_count");
+
+/// Stores the amount of the lock boost pool paid out so far.
+pub const LOCK_BOOST_CLAIMED: Item<Uint128> = Item::new("lock_boost_claimed");
+
+/// Stores the allocations forfeited to the community pool once their claim window expired. The
+/// key is the address, and the value is the amount that was left unclaimed.
+pub const FORFEITED: Map<&str, Uint128> = Map::new("forfeited");
+
+/// Stores the total amount forfeited to the community pool, which the campaign no longer owes.
+pub const FORFEITED_TOTAL: Item<Uint128> = Item::new("forfeited_total");
+
+/// Stores the terms accepted by each address. The key is the address, and the value is the hash
+/// of the terms accepted and the time of the acceptance.
+pub const TERMS_ACCEPTANCES: Map<&str, (HexBinary, Timestamp)> = Map::new("terms_acceptances");
+
+/// Returns whether the address accepted the current terms of the campaign, if it has terms.
+pub fn has_accepted_terms(
+    storage: &dyn Storage,
+    terms_hash: &HexBinary,
+    address: &str,
+) -> StdResult<bool> {
+    Ok(TERMS_ACCEPTANCES
+        .may_load(storage, address)?
+        .is_some_and(|(accepted_hash, _)| &accepted_hash == terms_hash))
+}
+
+/// Stores the groups sharing a pool of the campaign rewards. The key is the name of the group.
+pub const GROUPS: Map<&str, Group> = Map::new("groups");
+
+/// A group of addresses sharing a pool of the campaign rewards, vested following its own schedule.
+#[cw_serde]
+pub struct Group {
+    /// The vesting schedule of the pool
+    pub schedule: Vec<DistributionType>,
+    /// The size of the pool
+    pub total: Uint128,
+    /// The sum of the weights of the members
+    pub total_weight: Uint128,
+    /// The number of members
+    pub members: u32,
+    /// The amount claimed from the pool so far
+    pub claimed: Uint128,
+}
+
+/// Stores the members of the groups. The key is a tuple with the name of the group and the
+/// address of the member.
+pub const GROUP_MEMBERS: Map<(&str, &str), GroupMember> = Map::new("group_members");
+
+/// A member of a group, claiming the pool pro-rata to its weight.
+#[cw_serde]
+pub struct GroupMember {
+    /// The weight of the member
+    pub weight: Uint128,
+    /// The claims made by the member, per distribution slot of the group schedule
+    pub claims: HashMap<DistributionSlot, Claim>,
+}
+
+/// Stores the deposits topping up the campaign, by sequence number.
+pub const FUNDING_HISTORY: Map<u64, FundingRecord> = Map::new("funding_history");
+
+/// Stores the total contributed by each funder topping up the campaign, to refund the funders
+/// pro-rata when closing the campaign.
+/// Key: funder address, Value: the total contributed
+pub const FUNDERS: Map<&str, Uint128> = Map::new("funders");
+
+/// A deposit topping up the campaign.
+#[cw_serde]
+pub struct FundingRecord {
+    /// The address that made the deposit
+    pub funder: Addr,
+    /// The amount of the reward denom deposited
+    pub amount: Uint128,
+    /// The time of the deposit
+    pub deposited_at: Timestamp,
+}
+
+/// Records a deposit in the funding history.
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `record` - The deposit
+///
+/// # Returns
+/// * `StdResult<u64>` - The sequence number of the recorded deposit
+pub fn record_funding(storage: &mut dyn Storage, record: &FundingRecord) -> StdResult<u64> {
+    let sequence = FUNDING_HISTORY
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last_sequence| last_sequence + 1);
+
+    FUNDING_HISTORY.save(storage, sequence, record)?;
+
+    Ok(sequence)
+}
+
+/// Stores the allocation batches processed, by batch id, so re-submitting a batch is a no-op.
+pub const PROCESSED_BATCHES: Map<&str, ProcessedBatch> = Map::new("processed_batches");
+
+/// An allocation batch processed.
+#[cw_serde]
+pub struct ProcessedBatch {
+    /// The wallet that uploaded the batch
+    pub uploaded_by: Addr,
+    /// The number of allocations added by the batch
+    pub count: u64,
+    /// The time when the batch was processed
+    pub processed_at: Timestamp,
+}
+
+/// Stores the budget of the campaign, if the owner set one.
+pub const BUDGET: Item<Budget> = Item::new("budget");
+
+/// The amount of the reward denom the campaign can pay out of the contract balance, so several
+/// campaigns can be funded from the same balance.
+#[cw_serde]
+pub struct Budget {
+    /// The amount the campaign can pay out
+    pub amount: Uint128,
+    /// The amount paid out since the budget was set
+    pub used: Uint128,
+}
+
+impl Budget {
+    /// Returns what's left of the budget
+    pub fn remaining(&self) -> Uint128 {
+        self.amount.saturating_sub(self.used)
+    }
+}
+
+/// Stores the progress of the redistribution of the blacklisted allocations, while in progress.
+pub const REDISTRIBUTION: Item<Redistribution> = Item::new("redistribution");
+
+/// The progress of the redistribution of the blacklisted allocations, processed in batches. The
+/// allocations are walked twice: a first pass reclaims the unclaimed tokens of the blacklisted
+/// allocations, and a second pass distributes them to the other allocations.
+#[cw_serde]
+#[derive(Default)]
+pub struct Redistribution {
+    /// The tokens reclaimed from the blacklisted allocations
+    pub pool: Uint128,
+    /// The sum of the allocations receiving a share of the pool
+    pub eligible_total: Uint128,
+    /// The tokens distributed so far
+    pub distributed: Uint128,
+    /// Whether the first pass is done and the pool is being distributed
+    pub distributing: bool,
+    /// The last address processed in the current pass
+    pub cursor: Option<String>,
+}
 
 /// Stores the allocation for each address in the airdrop. This is set before the campaign starts
-/// and cannot be modified after that.
-pub const ALLOCATIONS: Map<&str, Uint128> = Map::new("allocations");
+/// and cannot be modified after that. The allocations are indexed by amount, by claimed status, by
+/// uploader and by tag, see [AllocationIndexes].
+pub const ALLOCATIONS: IndexedMap<&str, Allocation, AllocationIndexes> = IndexedMap::new(
+    "allocations",
+    AllocationIndexes {
+        amount: MultiIndex::new(
+            |_address, allocation| allocation.amount.u128(),
+            "allocations",
+            "allocations__amount",
+        ),
+        claimed: MultiIndex::new(
+            |_address, allocation| allocation.claimed as u8,
+            "allocations",
+            "allocations__claimed",
+        ),
+        uploader: MultiIndex::new(
+            |_address, allocation| allocation.added_by.to_string(),
+            "allocations",
+            "allocations__uploader",
+        ),
+        tag: MultiIndex::new(
+            |_address, allocation| allocation.tag.clone().unwrap_or_default(),
+            "allocations",
+            "allocations__tag",
+        ),
+    },
+);
+
+/// The allocation of an address.
+#[cw_serde]
+pub struct Allocation {
+    /// The amount allocated to the address
+    pub amount: Uint128,
+    /// Whether the address has claimed any of its allocation
+    pub claimed: bool,
+    /// The wallet that added the allocation
+    pub added_by: Addr,
+    /// The time when the allocation was added
+    pub added_at: Timestamp,
+    /// The address the claims of the allocation are locked to pay out to, if any
+    #[serde(default)]
+    pub locked_receiver: Option<Addr>,
+    /// The tag of the cohort the address belongs to, if any
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl Allocation {
+    /// Creates an allocation that hasn't been claimed yet, added by the given wallet at the given
+    /// time
+    pub fn new(amount: Uint128, added_by: Addr, added_at: Timestamp) -> Self {
+        Allocation {
+            amount,
+            claimed: false,
+            added_by,
+            added_at,
+            locked_receiver: None,
+            tag: None,
+        }
+    }
+}
+
+/// The secondary indexes of [ALLOCATIONS].
+pub struct AllocationIndexes<'a> {
+    /// Indexes the allocations by amount, to list the largest allocations without scanning the
+    /// whole map
+    pub amount: MultiIndex<'a, u128, Allocation, String>,
+    /// Indexes the allocations by claimed status, 1 if claimed and 0 otherwise, to list the
+    /// addresses that haven't claimed yet
+    pub claimed: MultiIndex<'a, u8, Allocation, String>,
+    /// Indexes the allocations by the wallet that added them, to audit the uploads of each
+    /// authorized wallet
+    pub uploader: MultiIndex<'a, String, Allocation, String>,
+    /// Indexes the allocations by tag, to list the addresses of a cohort. The untagged
+    /// allocations are indexed under the empty tag, which can't be set.
+    pub tag: MultiIndex<'a, String, Allocation, String>,
+}
+
+impl IndexList<Allocation> for AllocationIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Allocation>> + '_> {
+        let v: Vec<&dyn Index<Allocation>> =
+            vec![&self.amount, &self.claimed, &self.uploader, &self.tag];
+        Box::new(v.into_iter())
+    }
+}
 
 /// Stores blacklisted addresses. Blacklisted addresses cannot claim their allocations.
-pub const BLACKLIST: Map<&str, ()> = Map::new("blacklist");
+pub const BLACKLIST: Map<&str, BlacklistEntry> = Map::new("blacklist");
+
+/// Stores the frozen addresses along with who froze them, when and why. The frozen addresses can't
+/// claim, and are left out of the redistributions until the freeze is resolved.
+pub const FROZEN: Map<&str, FrozenEntry> = Map::new("frozen");
+
+/// Stores the temporary holds on the claims of the addresses. The holds expire on their own, so
+/// the expired entries are ignored, see [active_hold].
+pub const HOLDS: Map<&str, HoldEntry> = Map::new("holds");
+
+/// Stores allowlisted addresses. When the campaign is in allowlist mode, only allowlisted addresses
+/// can claim their allocations.
+pub const ALLOWLIST: Map<&str, ()> = Map::new("allowlist");
+
+/// Stores the contracts notified whenever a claim succeeds.
+/// Key: contract address, Value: () (presence indicates registration)
+pub const CLAIM_HOOKS: Map<&str, ()> = Map::new("claim_hooks");
+
+/// The number of entries in [ALLOCATIONS], maintained as a counter to avoid iterating the map.
+pub const ALLOCATIONS_COUNT: Item<u64> = Item::new("allocations_count");
+
+/// The number of addresses that have claimed, i.e. the number of entries in [CLAIMS].
+pub const CLAIMANTS_COUNT: Item<u64> = Item::new("claimants_count");
+
+/// The sum of the amounts of the entries in [ALLOCATIONS], maintained as a counter to avoid
+/// iterating the map.
+pub const TOTAL_ALLOCATED: Item<Uint128> = Item::new("total_allocated");
+
+/// The number of entries in [BLACKLIST].
+pub const BLACKLIST_COUNT: Item<u64> = Item::new("blacklist_count");
 
 /// Stores authorized wallet addresses that can perform admin actions.
 /// Key: address string, Value: () (presence indicates authorization)
 pub const AUTHORIZED_WALLETS: Map<&str, ()> = Map::new("authorized_wallets");
 
+/// Stores the authorized wallets added while the config sets a delay, until they are activated.
+/// Key: address string, Value: the time from which the wallet can be activated
+pub const PENDING_AUTHORIZED_WALLETS: Map<&str, Timestamp> = Map::new("pending_authorized_wallets");
+
+/// Stores the sweep above the sweep threshold waiting for a second approval
+pub const PENDING_SWEEP: Item<PendingSweep> = Item::new("pending_sweep");
+
+/// Stores the amounts swept without a second approval since the last approved sweep, checked
+/// against the sweep threshold.
+/// Key: denom, Value: the amount swept
+pub const SWEPT_WITHOUT_APPROVAL: Map<&str, Uint128> = Map::new("swept_without_approval");
+
+/// Stores the roles granted to wallets, limiting them to the admin actions of the roles.
+/// Key: (role name, address string), Value: () (presence indicates the role is granted)
+pub const WALLET_ROLES: Map<(&str, &str), ()> = Map::new("wallet_roles");
+
 /// Returns the claims that an address has made for a campaign
 ///
 /// # Arguments
@@ -48,6 +450,95 @@ pub fn get_claims_for_address(
     Ok(claimed.unwrap_or_default())
 }
 
+/// Appends an entry to the claim history of the given receiver
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `receiver` - The address that received the claimed tokens
+/// * `entry` - The claim history entry to record
+///
+/// # Returns
+/// * `StdResult<u64>` - The sequence number of the recorded entry
+pub fn record_claim_history(
+    storage: &mut dyn Storage,
+    receiver: &str,
+    entry: &ClaimHistoryEntry,
+) -> StdResult<u64> {
+    let sequence = CLAIM_HISTORY
+        .prefix(receiver)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last_sequence| last_sequence + 1);
+
+    CLAIM_HISTORY.save(storage, (receiver, sequence), entry)?;
+
+    Ok(sequence)
+}
+
+/// Returns the amount paid out from each distribution slot
+///
+/// # Arguments
+/// * `storage` - The storage
+///
+/// # Returns
+/// * `StdResult<HashMap<DistributionSlot, Uint128>>` - The amount paid out per slot
+pub fn get_slots_claimed(storage: &dyn Storage) -> StdResult<HashMap<DistributionSlot, Uint128>> {
+    SLOT_CLAIMED
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(slot, claimed)| (slot as DistributionSlot, claimed)))
+        .collect()
+}
+
+/// Records a claim in the campaign statistics
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `amount` - The amount paid out by the claim
+/// * `new_claimant` - Whether it is the first claim of the receiver
+/// * `time` - The time of the claim
+///
+/// # Returns
+/// * `StdResult<()>` - Ok if the statistics were updated
+pub fn update_stats(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+    new_claimant: bool,
+    time: Timestamp,
+) -> StdResult<()> {
+    let mut stats = STATS.may_load(storage)?.unwrap_or_default();
+
+    stats.claims = stats.claims.saturating_add(1);
+    if new_claimant {
+        stats.claimants = stats.claimants.saturating_add(1);
+    }
+    stats.largest_claim = stats.largest_claim.max(amount);
+    stats.first_claim_at.get_or_insert(time);
+    stats.last_claim_at = Some(time);
+
+    STATS.save(storage, &stats)
+}
+
+/// Applies the given change to the stats of a tag, doing nothing for the untagged allocations.
+///
+/// # Arguments
+/// * `storage` - The storage
+/// * `tag` - The tag of the allocation, if any
+/// * `update` - The change to apply to the stats of the tag
+pub fn update_tag_stats(
+    storage: &mut dyn Storage,
+    tag: Option<&str>,
+    update: impl FnOnce(&mut TagStats) -> StdResult<()>,
+) -> StdResult<()> {
+    let Some(tag) = tag else {
+        return Ok(());
+    };
+
+    let mut stats = TAG_STATS.may_load(storage, tag)?.unwrap_or_default();
+    update(&mut stats)?;
+    TAG_STATS.save(storage, tag, &stats)
+}
+
 /// Returns the total amount of tokens claimed by an address
 ///
 /// # Arguments
@@ -78,10 +569,12 @@ pub fn get_total_claims_amount_for_address(
 /// # Returns
 /// * `Result<Option<Uint128>, ContractError>` - The allocation amount if it exists
 pub fn get_allocation(deps: Deps, address: &str) -> Result<Option<Uint128>, ContractError> {
-    Ok(ALLOCATIONS.may_load(
-        deps.storage,
-        helpers::validate_raw_address(deps, address)?.as_str(),
-    )?)
+    Ok(ALLOCATIONS
+        .may_load(
+            deps.storage,
+            helpers::validate_raw_address(deps, address)?.as_str(),
+        )?
+        .map(|allocation| allocation.amount))
 }
 
 /// Returns whether an address is blacklisted
@@ -99,6 +592,58 @@ pub fn is_blacklisted(deps: Deps, address: &str) -> Result<bool, ContractError>
     ))
 }
 
+/// Checks if an address is frozen
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<bool, ContractError>` - Whether the address is frozen
+pub fn is_frozen(deps: Deps, address: &str) -> Result<bool, ContractError> {
+    Ok(FROZEN.has(
+        deps.storage,
+        helpers::validate_raw_address(deps, address)?.as_str(),
+    ))
+}
+
+/// Returns the hold on the claims of an address, if any and not expired yet
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+/// * `now` - The current time
+///
+/// # Returns
+/// * `Result<Option<HoldEntry>, ContractError>` - The active hold, if any
+pub fn active_hold(
+    deps: Deps,
+    address: &str,
+    now: &Timestamp,
+) -> Result<Option<HoldEntry>, ContractError> {
+    Ok(HOLDS
+        .may_load(
+            deps.storage,
+            helpers::validate_raw_address(deps, address)?.as_str(),
+        )?
+        .filter(|hold| hold.until > now.seconds()))
+}
+
+/// Returns whether an address is allowlisted
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+///
+/// # Returns
+/// * `Result<bool, ContractError>` - Whether the address is allowlisted
+pub fn is_allowlisted(deps: Deps, address: &str) -> Result<bool, ContractError> {
+    Ok(ALLOWLIST.has(
+        deps.storage,
+        helpers::validate_raw_address(deps, address)?.as_str(),
+    ))
+}
+
 /// Checks if an address is authorized (owner or authorized wallet)
 ///
 /// # Arguments
@@ -122,19 +667,70 @@ pub fn is_authorized(deps: Deps, address: &Addr) -> Result<bool, ContractError>
     Ok(is_authorized)
 }
 
-/// Asserts that sender is authorized (owner or authorized wallet)
+/// Checks if an address has a role, i.e. it's authorized (owner or authorized wallet) or it has
+/// been granted the role
+///
+/// # Arguments
+/// * `deps` - The dependencies
+/// * `address` - The address to check
+/// * `role` - The role to check
+///
+/// # Returns
+/// * `Result<bool, ContractError>` - Whether the address has the role
+pub fn has_role(deps: Deps, address: &Addr, role: Role) -> Result<bool, ContractError> {
+    if is_authorized(deps, address)? {
+        return Ok(true);
+    }
+
+    Ok(WALLET_ROLES.has(deps.storage, (role.name(), address.as_str())))
+}
+
+/// Asserts that sender has the given role (owner, authorized wallet or wallet granted the role)
 ///
 /// # Arguments
 /// * `deps` - The dependencies
 /// * `sender` - The sender address to check
+/// * `role` - The role required
 ///
 /// # Returns
 /// * `Result<(), ContractError>` - Success or appropriate error
-pub fn assert_authorized(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
-    if is_authorized(deps, sender)? {
+pub fn assert_authorized(deps: Deps, sender: &Addr, role: Role) -> Result<(), ContractError> {
+    if has_role(deps, sender, role)? {
         Ok(())
     } else {
         // Return the same error type as cw_ownable::assert_owner for consistency
         cw_ownable::assert_owner(deps.storage, sender).map_err(|e| e.into())
     }
 }
+
+/// Adjusts [TOTAL_ALLOCATED] by the amounts added to and removed from the allocations.
+pub fn adjust_total_allocated(
+    storage: &mut dyn Storage,
+    added: Uint128,
+    removed: Uint128,
+) -> StdResult<()> {
+    let total_allocated = TOTAL_ALLOCATED.may_load(storage)?.unwrap_or_default();
+    TOTAL_ALLOCATED.save(
+        storage,
+        &total_allocated.checked_add(added)?.checked_sub(removed)?,
+    )
+}
+
+/// Increases the given counter by one.
+pub fn increase_count(storage: &mut dyn Storage, counter: &Item<u64>) -> StdResult<u64> {
+    let count = get_count(storage, counter)?.saturating_add(1);
+    counter.save(storage, &count)?;
+    Ok(count)
+}
+
+/// Decreases the given counter by one.
+pub fn decrease_count(storage: &mut dyn Storage, counter: &Item<u64>) -> StdResult<u64> {
+    let count = get_count(storage, counter)?.saturating_sub(1);
+    counter.save(storage, &count)?;
+    Ok(count)
+}
+
+/// Returns the value of the given counter, defaulting to zero if it was never set.
+pub fn get_count(storage: &dyn Storage, counter: &Item<u64>) -> StdResult<u64> {
+    Ok(counter.may_load(storage)?.unwrap_or_default())
+}
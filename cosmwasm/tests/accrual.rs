@@ -0,0 +1,126 @@
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{AccrualFrom, CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, accrual_from: AccrualFrom) -> CampaignParams {
+    CampaignParams {
+        distribution_type: vec![DistributionType::LinearVesting {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_days(1),
+            end_time: current_time.plus_days(11),
+            cliff_duration: None,
+            cliff_behavior: None,
+        }],
+        end_time: current_time.plus_days(11),
+        manual_phases: true,
+        accrual_from,
+        ..suite::campaign_params(current_time, 2_000)
+    }
+}
+
+/// Adds alice before the vesting starts, and bob halfway through it, then starts the claims.
+fn setup(suite: &mut TestingSuite, accrual_from: AccrualFrom) {
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(&[], campaign_params(current_time, accrual_from))
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(alice.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    for _ in 0..6 {
+        suite.add_day();
+    }
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn late_allocations_accrue_from_their_allocation_time() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite, AccrualFrom::AllocationTime);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+
+    suite
+        // bob was added halfway through the vesting, so he only accrues half of his allocation
+        .query_rewards(bob, |result| {
+            let rewards = result.unwrap();
+            assert!(rewards.available_to_claim.is_empty());
+            assert_eq!(rewards.pending, vec![coin(500, "uom")]);
+        })
+        .add_day()
+        .add_day()
+        .query_rewards(alice, |result| {
+            assert_eq!(result.unwrap().available_to_claim, vec![coin(700, "uom")]);
+        })
+        .query_rewards(bob, |result| {
+            assert_eq!(result.unwrap().available_to_claim, vec![coin(200, "uom")]);
+        });
+
+    for _ in 0..3 {
+        suite.add_day();
+    }
+
+    suite
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_500));
+        })
+        .query_rewards(bob, |result| {
+            let rewards = result.unwrap();
+            assert!(rewards.available_to_claim.is_empty());
+            assert!(rewards.pending.is_empty());
+        });
+}
+
+#[test]
+fn late_allocations_accrue_from_the_campaign_start_by_default() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite, AccrualFrom::CampaignStart);
+    let bob = &suite.senders[2].clone();
+
+    suite.query_rewards(bob, |result| {
+        assert_eq!(result.unwrap().available_to_claim, vec![coin(500, "uom")]);
+    });
+}
@@ -0,0 +1,137 @@
+use bech32::{Bech32, Hrp};
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+/// Returns the address with the same bytes as the given one, under another prefix
+fn with_prefix(address: &Addr, prefix: &str) -> String {
+    let (_, data) = bech32::decode(address.as_str()).unwrap();
+    bech32::encode::<Bech32>(Hrp::parse(prefix).unwrap(), &data).unwrap()
+}
+
+#[test]
+fn foreign_prefixes_are_canonicalized_to_the_local_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_allowed_prefixes(
+            owner,
+            vec!["Osmo"],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .set_allowed_prefixes(
+            bob,
+            vec!["osmo"],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .set_allowed_prefixes(
+            owner,
+            vec!["osmo", "cosmos"],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![
+                (with_prefix(bob, "osmo"), Uint128::new(100)),
+                (
+                    with_prefix(carol, "cosmos").to_uppercase(),
+                    Uint128::new(200),
+                ),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(Some(bob), None, None, |result| {
+            // no campaign is set yet, so the allocations have no denom
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, bob.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(100));
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, carol.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(200));
+        });
+}
+
+#[test]
+fn foreign_addresses_collide_with_the_local_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_allowed_prefixes(
+            owner,
+            vec!["osmo", "cosmos"],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(100))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    for prefix in ["osmo", "cosmos"] {
+        suite.add_allocations(
+            owner,
+            &vec![(with_prefix(bob, prefix), Uint128::new(100))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { address } => {
+                        assert_eq!(address, bob.to_string())
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        );
+    }
+
+    // prefixes that aren't allowed are kept as placeholders
+    suite
+        .add_allocations(
+            owner,
+            &vec![(with_prefix(bob, "juno"), Uint128::new(100))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(Some(bob), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, bob.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(100));
+        });
+}
@@ -0,0 +1,164 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+fn assert_invalid_input(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidInput { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+    }
+}
+
+#[test]
+fn replacements_require_a_request_when_enabled() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_replacements_require_request(
+            bob,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .set_replacements_require_request(
+            owner,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the owner can't redirect the allocation on its own anymore
+        .replace_address(owner, bob, carol, assert_invalid_input)
+        .replace_addresses(
+            owner,
+            vec![(bob.to_string(), carol.to_string())],
+            assert_invalid_input,
+        )
+        .request_address_replacement(bob, Some(bob), assert_invalid_input)
+        .request_address_replacement(
+            carol,
+            Some(dan),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        )
+        .request_address_replacement(
+            bob,
+            Some(carol),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_address_replacement_request(bob, |result| {
+            assert_eq!(result.unwrap().new_address, Some(carol.to_string()));
+        })
+        // the replacement must match the request
+        .replace_address(owner, bob, dan, assert_invalid_input)
+        .replace_address(
+            owner,
+            bob,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_address_replacement_request(bob, |result| {
+            assert_eq!(result.unwrap().new_address, None);
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].1.amount, Uint128::new(10_000));
+        });
+}
+
+#[test]
+fn requests_can_be_withdrawn() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_replacements_require_request(
+            owner,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .request_address_replacement(bob, None, assert_invalid_input)
+        .request_address_replacement(
+            bob,
+            Some(carol),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .request_address_replacement(bob, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_address_replacement_request(bob, |result| {
+            assert_eq!(result.unwrap().new_address, None);
+        })
+        .replace_address(owner, bob, carol, assert_invalid_input);
+}
+
+#[test]
+fn replacements_dont_require_a_request_by_default() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .replace_address(
+            owner,
+            bob,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
@@ -0,0 +1,179 @@
+use cosmwasm_std::{coin, Addr, Int128, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+fn setup(suite: &mut TestingSuite) {
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(200)),
+                (carol.to_string(), Uint128::new(300)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+fn assert_allocation(suite: &mut TestingSuite, address: &Addr, expected: Option<u128>) {
+    suite.query_allocations(Some(address), None, None, |result| {
+        let allocations = result.unwrap().allocations;
+        assert_eq!(
+            allocations.first().map(|(_, amount)| amount.amount.u128()),
+            expected
+        );
+    });
+}
+
+#[test]
+fn apply_a_delta_to_the_allocations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let dan = &suite.senders[4].clone();
+
+    suite.apply_allocation_delta(
+        owner,
+        &[(dan.to_string(), Uint128::new(400))],
+        &[bob.to_string()],
+        &[
+            (alice.to_string(), Int128::new(50)),
+            (carol.to_string(), Int128::new(-100)),
+        ],
+        |result: Result<AppResponse, anyhow::Error>| {
+            let response = result.unwrap();
+            let event = response
+                .events
+                .iter()
+                .find(|event| event.ty == "wasm-claimdrop/allocation_delta")
+                .unwrap();
+            let attribute = |key: &str| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attribute| attribute.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            assert_eq!(attribute("applied_by"), owner.to_string());
+            assert_eq!(attribute("added"), "1");
+            assert_eq!(attribute("removed"), "1");
+            assert_eq!(attribute("adjusted"), "2");
+            // +400 - 200 + 50 - 100
+            assert_eq!(attribute("net_change"), "150");
+        },
+    );
+
+    assert_allocation(&mut suite, alice, Some(150));
+    assert_allocation(&mut suite, bob, None);
+    assert_allocation(&mut suite, carol, Some(200));
+    assert_allocation(&mut suite, dan, Some(400));
+
+    suite.query_counts(|result| {
+        assert_eq!(result.unwrap().allocations, 3);
+    });
+}
+
+#[test]
+fn invalid_deltas_are_rejected_as_a_whole() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let dan = &suite.senders[4].clone();
+
+    let assert_invalid_input = |result: Result<AppResponse, anyhow::Error>| {
+        let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+        match err {
+            ContractError::InvalidInput { .. } => {}
+            _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+        }
+    };
+
+    suite
+        .apply_allocation_delta(owner, &[], &[], &[], assert_invalid_input)
+        // the same address can't be both removed and adjusted
+        .apply_allocation_delta(
+            owner,
+            &[],
+            &[alice.to_string()],
+            &[(alice.to_string(), Int128::new(10))],
+            assert_invalid_input,
+        )
+        // adjusting an allocation down to zero must be a removal
+        .apply_allocation_delta(
+            owner,
+            &[],
+            &[],
+            &[(alice.to_string(), Int128::new(-100))],
+            assert_invalid_input,
+        )
+        // and can't take more than the allocation
+        .apply_allocation_delta(
+            owner,
+            &[],
+            &[],
+            &[(alice.to_string(), Int128::new(-101))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { reason } => {
+                        assert!(reason.contains(alice.as_str()))
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .apply_allocation_delta(
+            owner,
+            &[(bob.to_string(), Uint128::new(10))],
+            &[],
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { address } => {
+                        assert_eq!(address, bob.to_string())
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        )
+        // the valid removal isn't applied when another part of the delta fails
+        .apply_allocation_delta(
+            owner,
+            &[],
+            &[bob.to_string(), dan.to_string()],
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { address } => {
+                        assert_eq!(address, dan.to_string())
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        );
+
+    assert_allocation(&mut suite, alice, Some(100));
+    assert_allocation(&mut suite, bob, Some(200));
+}
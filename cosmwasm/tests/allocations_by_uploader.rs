@@ -0,0 +1,106 @@
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::Role;
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn query_allocations_added_by_each_wallet() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let eve = &suite.senders[4].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .grant_roles(
+            owner,
+            bob,
+            vec![Role::AllocationManager],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![
+                (dan.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            bob,
+            &vec![(eve.to_string(), Uint128::new(3_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations_by_uploader(owner, None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 2);
+            assert!(allocations.contains(&(carol.to_string(), coin(2_000, ""))));
+            assert!(allocations.contains(&(dan.to_string(), coin(1_000, ""))));
+        })
+        .query_allocations_by_uploader(bob, None, None, |result| {
+            assert_eq!(
+                result.unwrap().allocations,
+                vec![(eve.to_string(), coin(3_000, ""))]
+            );
+        })
+        .query_allocations_by_uploader(carol, None, None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        });
+
+    // paginate over the owner uploads
+    let mut uploaded = [carol.to_string(), dan.to_string()];
+    uploaded.sort();
+    let first = Addr::unchecked(uploaded[0].clone());
+
+    suite
+        .query_allocations_by_uploader(owner, None, Some(1), |result| {
+            assert_eq!(result.unwrap().allocations[0].0, uploaded[0]);
+        })
+        .query_allocations_by_uploader(owner, Some(&first), None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, uploaded[1]);
+        });
+}
+
+#[test]
+fn replaced_address_keeps_its_uploader() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .replace_address(
+            owner,
+            bob,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations_by_uploader(owner, None, None, |result| {
+            assert_eq!(
+                result.unwrap().allocations,
+                vec![(carol.to_string(), coin(1_000, ""))]
+            );
+        });
+}
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use cw_multi_test::{AppResponse, IntoBech32};
+use mantra_claimdrop_std::msg::{AllocationsResponse, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn addresses(response: &AllocationsResponse) -> Vec<String> {
+    response
+        .allocations
+        .iter()
+        .map(|(address, _)| address.clone())
+        .collect()
+}
+
+fn sorted(addresses: &[&Addr]) -> Vec<String> {
+    let mut addresses: Vec<String> = addresses.iter().map(|addr| addr.to_string()).collect();
+    addresses.sort();
+    addresses
+}
+
+#[test]
+fn filter_allocations_by_amount_and_claimed_status() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(500)),
+                (carol.to_string(), Uint128::new(1_000)),
+            ],
+            CampaignParams {
+                name: "Filtered Campaign".to_string(),
+                description: "Campaign with filtered allocations".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(1_600, "uom"),
+                distribution_type: vec![DistributionType::LumpSum {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    cliff_duration: None,
+                }],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(7),
+                ..Default::default()
+            },
+        )
+        .query_filtered_allocations(None, None, Some(Uint128::new(500)), None, |result| {
+            let response = result.unwrap();
+            assert_eq!(addresses(&response), sorted(&[bob, carol]));
+            assert_eq!(response.total_count, Some(3));
+            assert_eq!(response.next_start_after, None);
+        })
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_filtered_allocations(None, None, None, Some(true), |result| {
+            let response = result.unwrap();
+            assert_eq!(addresses(&response), sorted(&[bob]));
+            assert_eq!(response.total_count, Some(3));
+        })
+        .query_filtered_allocations(None, None, Some(Uint128::new(500)), Some(false), |result| {
+            assert_eq!(addresses(&result.unwrap()), sorted(&[carol]));
+        });
+}
+
+#[test]
+fn paginate_filtered_allocations_with_the_cursor() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(500)),
+                (carol.to_string(), Uint128::new(1_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    let collected = RefCell::new(vec![]);
+    let cursor = RefCell::new(None::<String>);
+    loop {
+        let start_after = cursor.borrow().clone();
+        suite.query_filtered_allocations(
+            start_after.as_deref(),
+            Some(1),
+            Some(Uint128::new(500)),
+            None,
+            |result| {
+                let response = result.unwrap();
+                assert!(response.allocations.len() <= 1);
+                collected.borrow_mut().extend(addresses(&response));
+                *cursor.borrow_mut() = response.next_start_after;
+            },
+        );
+        if cursor.borrow().is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(collected.into_inner(), sorted(&[bob, carol]));
+}
+
+#[test]
+fn page_scan_is_bounded() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    let allocations: Vec<(String, Uint128)> = (0..1_001)
+        .map(|i| {
+            (
+                format!("wallet{i}")
+                    .into_bech32_with_prefix("mantra")
+                    .to_string(),
+                Uint128::new(100),
+            )
+        })
+        .collect();
+    let mut expected: Vec<String> = allocations.iter().map(|(a, _)| a.clone()).collect();
+    expected.sort();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &allocations,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_contract_info(|result| {
+            assert_eq!(result.unwrap().limits.max_allocations_page_size, 1_000);
+        })
+        // no allocation matches, the scan stops after a full page and points to the rest
+        .query_filtered_allocations(None, None, Some(Uint128::new(1_000)), None, |result| {
+            let response = result.unwrap();
+            assert!(response.allocations.is_empty());
+            assert_eq!(response.total_count, Some(1_001));
+            assert_eq!(response.next_start_after, Some(expected[999].clone()));
+        })
+        .query_filtered_allocations(
+            Some(&expected[999]),
+            None,
+            Some(Uint128::new(1_000)),
+            None,
+            |result| {
+                let response = result.unwrap();
+                assert!(response.allocations.is_empty());
+                assert_eq!(response.next_start_after, None);
+            },
+        );
+}
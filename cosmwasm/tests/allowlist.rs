@@ -0,0 +1,221 @@
+use claimdrop_contract::commands::MAX_ALLOWLIST_BATCH_SIZE;
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, ClaimMode};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, claim_mode: ClaimMode) {
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+            ],
+            CampaignParams {
+                claim_mode,
+                ..campaign_params(current_time, 3_000)
+            },
+        )
+        .add_day();
+}
+
+#[test]
+fn only_allowlisted_addresses_can_claim_in_allowlist_mode() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, ClaimMode::AllowlistOnly);
+
+    suite
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claim_mode, ClaimMode::AllowlistOnly);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressNotAllowlisted => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AddressNotAllowlisted"
+                    ),
+                }
+            },
+        )
+        .manage_allowlist(
+            owner,
+            vec![bob.to_string(), carol.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_allowlisted(bob, |result| {
+            assert!(result.unwrap().is_allowlisted);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        .manage_allowlist(
+            owner,
+            vec![carol.to_string()],
+            false,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_allowlisted(carol, |result| {
+            assert!(!result.unwrap().is_allowlisted);
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressNotAllowlisted => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AddressNotAllowlisted"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn allowlist_is_ignored_in_open_mode() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite, ClaimMode::Open);
+
+    suite
+        .query_is_allowlisted(bob, |result| {
+            assert!(!result.unwrap().is_allowlisted);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn manage_allowlist_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite, ClaimMode::AllowlistOnly);
+
+    suite
+        .manage_allowlist(
+            bob,
+            vec![bob.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .manage_allowlist(
+            owner,
+            vec![],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_allowlist(
+            owner,
+            vec![bob.to_string(); MAX_ALLOWLIST_BATCH_SIZE + 1],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::BatchSizeLimitExceeded { actual, max } => {
+                        assert_eq!(actual, MAX_ALLOWLIST_BATCH_SIZE + 1);
+                        assert_eq!(max, MAX_ALLOWLIST_BATCH_SIZE);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::BatchSizeLimitExceeded"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn replace_address_moves_allowlist_entry() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let dan = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite, ClaimMode::AllowlistOnly);
+
+    suite
+        .manage_allowlist(
+            owner,
+            vec![bob.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .replace_address(
+            owner,
+            bob,
+            dan,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_allowlisted(bob, |result| {
+            assert!(!result.unwrap().is_allowlisted);
+        })
+        .query_is_allowlisted(dan, |result| {
+            assert!(result.unwrap().is_allowlisted);
+        })
+        .claim(
+            dan,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_allowlisted(&Addr::unchecked("placeholder.eth"), |result| {
+            assert!(!result.unwrap().is_allowlisted);
+        });
+}
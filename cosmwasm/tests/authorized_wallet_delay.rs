@@ -0,0 +1,136 @@
+use cosmwasm_std::coin;
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn authorized_wallets_take_effect_after_the_delay() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dave = &suite.senders[3].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    suite
+        .set_authorized_wallet_delay(
+            owner,
+            86_400,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_authorized_wallets(
+            owner,
+            vec![bob.to_string(), carol.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_authorized(bob.to_string(), |result| {
+            assert!(!result.unwrap().is_authorized);
+        })
+        .query_pending_authorized_wallets(|result| {
+            let wallets = result.unwrap().wallets;
+            assert_eq!(wallets.len(), 2);
+        })
+        .activate_authorized_wallets(
+            dave,
+            vec![bob.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        // the removals are immediate, and cancel the pending additions
+        .manage_authorized_wallets(
+            owner,
+            vec![carol.to_string()],
+            false,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .activate_authorized_wallets(
+            dave,
+            vec![carol.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .activate_authorized_wallets(
+            dave,
+            vec![bob.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_authorized(bob.to_string(), |result| {
+            assert!(result.unwrap().is_authorized);
+        })
+        .query_pending_authorized_wallets(|result| {
+            assert!(result.unwrap().wallets.is_empty());
+        });
+}
+
+#[test]
+fn lowering_the_delay_takes_the_current_delay() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    suite
+        .set_authorized_wallet_delay(
+            owner,
+            86_400,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_authorized_wallet_delay(owner, 0, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        // the current delay still applies
+        .manage_authorized_wallets(
+            owner,
+            vec![bob.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_authorized(bob.to_string(), |result| {
+            assert!(!result.unwrap().is_authorized);
+        })
+        .add_day()
+        .manage_authorized_wallets(
+            owner,
+            vec![carol.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_authorized(carol.to_string(), |result| {
+            assert!(result.unwrap().is_authorized);
+        });
+}
@@ -8,6 +8,7 @@ use cosmwasm_std::{
 };
 
 use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::Role;
 
 /// Test that the owner can add authorized wallets
 #[test]
@@ -26,8 +27,13 @@ fn owner_can_add_authorized_wallet() {
     };
 
     // Test adding authorized wallet
-    let result =
-        manage_authorized_wallets(deps.as_mut(), info, vec![authorized_addr.to_string()], true);
+    let result = manage_authorized_wallets(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        vec![authorized_addr.to_string()],
+        true,
+    );
 
     assert!(result.is_ok());
     let response = result.unwrap();
@@ -66,6 +72,7 @@ fn owner_can_remove_authorized_wallet() {
     // First add the wallet
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         info.clone(),
         vec![authorized_addr.to_string()],
         true,
@@ -78,6 +85,7 @@ fn owner_can_remove_authorized_wallet() {
     // Now remove it
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         info,
         vec![authorized_addr.to_string()],
         false,
@@ -110,8 +118,13 @@ fn unauthorized_cannot_manage_wallets() {
     };
 
     // Try to add authorized wallet as non-owner
-    let result =
-        manage_authorized_wallets(deps.as_mut(), info, vec![target_addr.to_string()], true);
+    let result = manage_authorized_wallets(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        vec![target_addr.to_string()],
+        true,
+    );
 
     // Should fail with owner check error
     assert!(result.is_err());
@@ -136,6 +149,7 @@ fn authorized_wallet_can_perform_admin_actions() {
     };
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![authorized_addr.to_string()],
         true,
@@ -149,15 +163,25 @@ fn authorized_wallet_can_perform_admin_actions() {
     };
     let allocations = vec![("mantra1test123".to_string(), Uint128::new(1000))];
 
-    let result = add_allocations(deps.as_mut(), env, authorized_info.clone(), allocations);
+    let result = add_allocations(
+        deps.as_mut(),
+        env,
+        authorized_info.clone(),
+        allocations,
+        false,
+        None,
+        None,
+    );
     assert!(result.is_ok());
 
     // Test that authorized wallet can blacklist addresses
     let result = blacklist_address(
         deps.as_mut(),
+        mock_env(),
         authorized_info,
         "mantra1test456".to_string(),
         true,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -181,7 +205,15 @@ fn unauthorized_wallet_cannot_perform_admin_actions() {
     };
     let allocations = vec![("mantra1test123".to_string(), Uint128::new(1000))];
 
-    let result = add_allocations(deps.as_mut(), env, unauthorized_info.clone(), allocations);
+    let result = add_allocations(
+        deps.as_mut(),
+        env,
+        unauthorized_info.clone(),
+        allocations,
+        false,
+        None,
+        None,
+    );
     assert!(result.is_err());
     // Should fail with ownership error (since assert_authorized uses cw_ownable)
     assert!(result.is_err());
@@ -189,9 +221,11 @@ fn unauthorized_wallet_cannot_perform_admin_actions() {
     // Test that unauthorized wallet cannot blacklist addresses
     let result = blacklist_address(
         deps.as_mut(),
+        mock_env(),
         unauthorized_info,
         "mantra1test456".to_string(),
         true,
+        None,
     );
     assert!(result.is_err());
     // Should fail with ownership error (since assert_authorized uses cw_ownable)
@@ -217,6 +251,7 @@ fn test_assert_authorized_function() {
     };
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![authorized_addr.to_string()],
         true,
@@ -224,15 +259,15 @@ fn test_assert_authorized_function() {
     .unwrap();
 
     // Owner should pass
-    let result = assert_authorized(deps.as_ref(), &owner);
+    let result = assert_authorized(deps.as_ref(), &owner, Role::CampaignManager);
     assert!(result.is_ok());
 
     // Authorized wallet should pass
-    let result = assert_authorized(deps.as_ref(), &authorized_addr);
+    let result = assert_authorized(deps.as_ref(), &authorized_addr, Role::CampaignManager);
     assert!(result.is_ok());
 
     // Unauthorized wallet should fail
-    let result = assert_authorized(deps.as_ref(), &unauthorized_addr);
+    let result = assert_authorized(deps.as_ref(), &unauthorized_addr, Role::CampaignManager);
     assert!(result.is_err());
     // Should fail with ownership error (since assert_authorized uses cw_ownable)
     assert!(result.is_err());
@@ -259,6 +294,7 @@ fn test_query_authorized_functions() {
     // Add two authorized wallets
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info.clone(),
         vec![authorized_addr1.to_string()],
         true,
@@ -267,6 +303,7 @@ fn test_query_authorized_functions() {
 
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![authorized_addr2.to_string()],
         true,
@@ -315,7 +352,7 @@ fn owner_always_admin() {
     assert!(is_authorized(deps.as_ref(), &owner).unwrap());
 
     // Owner should pass assert_authorized
-    assert!(assert_authorized(deps.as_ref(), &owner).is_ok());
+    assert!(assert_authorized(deps.as_ref(), &owner, Role::CampaignManager).is_ok());
 
     // Owner should appear as authorized in query
     let result = query_is_authorized(deps.as_ref(), owner.to_string());
@@ -341,6 +378,7 @@ fn test_edge_cases() {
     // Test adding the same wallet twice
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info.clone(),
         vec![target_addr.to_string()],
         true,
@@ -349,6 +387,7 @@ fn test_edge_cases() {
 
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info.clone(),
         vec![target_addr.to_string()],
         true,
@@ -359,6 +398,7 @@ fn test_edge_cases() {
     let nonexistent_addr = deps.api.addr_make("nonexistent");
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![nonexistent_addr.to_string()],
         false,
@@ -422,6 +462,7 @@ fn test_batch_authorize_multiple_wallets() {
     // Authorize multiple wallets in one batch
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![addr1.to_string(), addr2.to_string(), addr3.to_string()],
         true,
@@ -459,6 +500,7 @@ fn test_batch_unauthorize_multiple_wallets() {
     // First authorize all wallets
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info.clone(),
         vec![addr1.to_string(), addr2.to_string(), addr3.to_string()],
         true,
@@ -468,6 +510,7 @@ fn test_batch_unauthorize_multiple_wallets() {
     // Then remove them all in one batch
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![addr1.to_string(), addr2.to_string(), addr3.to_string()],
         false,
@@ -508,6 +551,7 @@ fn test_batch_fails_with_invalid_address() {
 
     let result = manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![
             valid_addr1.to_string(),
@@ -536,7 +580,7 @@ fn test_empty_address_list_fails() {
     };
 
     // Try to authorize empty list
-    let result = manage_authorized_wallets(deps.as_mut(), owner_info, vec![], true);
+    let result = manage_authorized_wallets(deps.as_mut(), mock_env(), owner_info, vec![], true);
 
     // Should fail with InvalidInput error
     assert!(result.is_err());
@@ -566,7 +610,8 @@ fn test_batch_size_limit() {
         .map(|i| deps.api.addr_make(&format!("addr{i:04}")).to_string())
         .collect();
 
-    let result = manage_authorized_wallets(deps.as_mut(), owner_info, large_batch, true);
+    let result =
+        manage_authorized_wallets(deps.as_mut(), mock_env(), owner_info, large_batch, true);
 
     // Should fail with BatchSizeLimitExceeded error
     assert!(result.is_err());
@@ -599,7 +644,14 @@ fn test_authorized_wallets_pagination() {
         .map(|i| deps.api.addr_make(&format!("addr{i:03}")).to_string())
         .collect();
 
-    manage_authorized_wallets(deps.as_mut(), owner_info, addresses.clone(), true).unwrap();
+    manage_authorized_wallets(
+        deps.as_mut(),
+        mock_env(),
+        owner_info,
+        addresses.clone(),
+        true,
+    )
+    .unwrap();
 
     // Test query without pagination (should return all)
     let result = query_authorized_wallets(deps.as_ref(), None, None);
@@ -649,7 +701,7 @@ fn test_authorized_wallets_pagination_large_limit() {
         .map(|i| deps.api.addr_make(&format!("addr{i:03}")).to_string())
         .collect();
 
-    manage_authorized_wallets(deps.as_mut(), owner_info, addresses, true).unwrap();
+    manage_authorized_wallets(deps.as_mut(), mock_env(), owner_info, addresses, true).unwrap();
 
     // Test query with limit larger than MAX_LIMIT (should be capped)
     let result = query_authorized_wallets(deps.as_ref(), None, Some(10000));
@@ -678,6 +730,7 @@ fn test_query_is_authorized_comprehensive() {
     // Authorize one wallet
     manage_authorized_wallets(
         deps.as_mut(),
+        mock_env(),
         owner_info,
         vec![authorized_addr.to_string()],
         true,
@@ -0,0 +1,104 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+fn attribute(response: &AppResponse, key: &str) -> Option<String> {
+    response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+}
+
+#[test]
+fn resubmitted_batches_are_a_no_op() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    let batch = [
+        (alice.to_string(), Uint128::new(100)),
+        (bob.to_string(), Uint128::new(200)),
+    ];
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations_batch(
+            owner,
+            &batch,
+            "batch-1",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(attribute(&response, "count"), Some("2".to_string()));
+                assert_eq!(
+                    attribute(&response, "batch_id"),
+                    Some("batch-1".to_string())
+                );
+            },
+        )
+        // the re-broadcast batch doesn't fail on the duplicates, nor adds anything
+        .add_allocations_batch(
+            owner,
+            &batch,
+            "batch-1",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(
+                    attribute(&response, "already_processed"),
+                    Some("true".to_string())
+                );
+                assert_eq!(attribute(&response, "count"), None);
+            },
+        )
+        // a failed batch isn't recorded, so it can be fixed and retried with the same id
+        .add_allocations_batch(
+            owner,
+            &[
+                (bob.to_string(), Uint128::new(200)),
+                (carol.to_string(), Uint128::new(300)),
+            ],
+            "batch-2",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        )
+        .add_allocations_batch(
+            owner,
+            &[(carol.to_string(), Uint128::new(300))],
+            "batch-2",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(attribute(&response, "count"), Some("1".to_string()));
+            },
+        )
+        .add_allocations_batch(
+            owner,
+            &[(carol.to_string(), Uint128::new(300))],
+            "",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().allocations, 3);
+        })
+        .query_allocations(Some(bob), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1.amount, Uint128::new(200));
+        });
+}
@@ -0,0 +1,113 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn query_rewards_of_a_batch_of_addresses() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(1_000)),
+                (bob.to_string(), Uint128::new(2_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Batch Campaign".to_string(),
+                    description: "Campaign queried in batches".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(3_000, "uom"),
+                    distribution_type: vec![DistributionType::LinearVesting {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        end_time: current_time.plus_seconds(86_400),
+                        cliff_duration: None,
+                        cliff_behavior: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(3_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_batch_rewards(
+            vec![alice.to_string(), bob.to_string(), carol.to_string()],
+            |result| {
+                let rewards = result.unwrap().rewards;
+                assert_eq!(rewards.len(), 3);
+
+                assert_eq!(rewards[0].address, alice.to_string());
+                assert_eq!(
+                    rewards[0].rewards.total_allocation,
+                    vec![coin(1_000, "uom")]
+                );
+                assert_eq!(rewards[0].rewards.claimed, vec![coin(1_000, "uom")]);
+                assert!(rewards[0].rewards.available_to_claim.is_empty());
+
+                assert_eq!(rewards[1].address, bob.to_string());
+                assert_eq!(
+                    rewards[1].rewards.total_allocation,
+                    vec![coin(2_000, "uom")]
+                );
+                assert!(rewards[1].rewards.claimed.is_empty());
+                assert_eq!(
+                    rewards[1].rewards.available_to_claim,
+                    vec![coin(2_000, "uom")]
+                );
+
+                // carol has no allocation
+                assert_eq!(rewards[2].address, carol.to_string());
+                assert!(rewards[2].rewards.total_allocation.is_empty());
+                assert!(rewards[2].rewards.pending.is_empty());
+            },
+        )
+        .query_batch_rewards(vec![alice.to_string(); 101], |result| {
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains(
+                &ContractError::BatchSizeLimitExceeded {
+                    actual: 101,
+                    max: 100,
+                }
+                .to_string()
+            ));
+        });
+}
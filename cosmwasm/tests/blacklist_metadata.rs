@@ -0,0 +1,112 @@
+use claimdrop_contract::commands::MAX_BLACKLIST_REASON_LENGTH;
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn blacklisting_records_who_when_and_why() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let blacklisted_at = suite.get_time().seconds();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .blacklist_address_with_reason(
+            owner,
+            bob,
+            "sybil cluster #42",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_blacklist_entry(bob, |result| {
+            let response = result.unwrap();
+            assert_eq!(response.address, bob.to_string());
+
+            let entry = response.entry.unwrap();
+            assert_eq!(entry.blacklisted_by, owner.to_string());
+            assert_eq!(entry.blacklisted_at, blacklisted_at);
+            assert_eq!(entry.reason, Some("sybil cluster #42".to_string()));
+        })
+        .blacklist_address(
+            owner,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_blacklist_entry(carol, |result| {
+            assert_eq!(result.unwrap().entry.unwrap().reason, None);
+        })
+        .blacklist_address(
+            owner,
+            bob,
+            false,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_blacklist_entry(bob, |result| {
+            assert!(result.unwrap().entry.is_none());
+        })
+        .blacklist_address_with_reason(
+            owner,
+            bob,
+            &"a".repeat(MAX_BLACKLIST_REASON_LENGTH + 1),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
+
+#[test]
+fn replacing_address_keeps_blacklist_metadata() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let dan = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address_with_reason(
+            owner,
+            bob,
+            "compromised key",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .replace_address(
+            owner,
+            bob,
+            dan,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_blacklist_entry(bob, |result| {
+            assert!(result.unwrap().entry.is_none());
+        })
+        .query_blacklist_entry(dan, |result| {
+            let entry = result.unwrap().entry.unwrap();
+            assert_eq!(entry.blacklisted_by, owner.to_string());
+            assert_eq!(entry.reason, Some("compromised key".to_string()));
+        });
+}
@@ -0,0 +1,138 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::CampaignAction;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+#[test]
+fn the_campaign_only_spends_and_refunds_its_budget() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+    let contract = &suite.claimdrop_contract_addr.clone();
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(300)),
+                (bob.to_string(), Uint128::new(200)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, 1_000)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the treasury funds the contract beyond the needs of the campaign
+        .top_up_campaign(
+            owner,
+            &[coin(3_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_budget(
+            alice,
+            Uint128::new(400),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .set_budget(
+            owner,
+            Uint128::new(400),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InsufficientFunds {
+                        required,
+                        available,
+                    } => {
+                        assert_eq!(required, Uint128::new(200));
+                        assert_eq!(available, Uint128::new(100));
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InsufficientFunds"),
+                }
+            },
+        )
+        .query_budget(|result| {
+            let budget = result.unwrap();
+            assert_eq!(budget.amount, Some(Uint128::new(400)));
+            assert_eq!(budget.used, Uint128::new(300));
+            assert_eq!(budget.remaining, Some(Uint128::new(100)));
+        })
+        .set_budget(
+            owner,
+            Uint128::new(250),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .set_budget(
+            owner,
+            Uint128::new(600),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // closing refunds what's left of the budget, the rest of the balance stays
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", contract, |balance| {
+            assert_eq!(balance, Uint128::new(2_400));
+        });
+}
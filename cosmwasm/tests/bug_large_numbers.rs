@@ -49,10 +49,12 @@ fn bug_large_numbers() {
                     total_reward: coin(amount, denom),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::percent(100),
-                        start_time: current_time.seconds(),
+                        start_time: *current_time,
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds(),
-                    end_time: current_time.plus_days(14).seconds(),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(14),
+                    ..Default::default()
                 }),
             },
             &[], // No funds during campaign creation
@@ -70,12 +72,17 @@ fn bug_large_numbers() {
 
     suite
         .query_rewards(&alice, |result| {
+            let rewards = result.unwrap();
             assert_eq!(
-                result.unwrap(),
+                rewards,
                 RewardsResponse {
+                    total_allocation: coins(alice_amount, denom),
                     claimed: vec![],
                     pending: coins(alice_amount, denom),
                     available_to_claim: coins(alice_amount, denom),
+                    locked: vec![],
+                    accrual_rate: rewards.accrual_rate,
+                    next_unlocks: rewards.next_unlocks.clone(),
                 }
             );
         })
@@ -130,12 +137,14 @@ fn bug_large_numbers_2() {
                     total_reward: coin(amount, denom),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::percent(100),
-                        start_time: current_time.seconds(),
-                        end_time: current_time.plus_days(7).seconds(),
+                        start_time: *current_time,
+                        end_time: current_time.plus_days(7),
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds(),
-                    end_time: current_time.plus_days(7).seconds(),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
                 }),
             },
             &[], // No funds during campaign creation
@@ -155,12 +164,17 @@ fn bug_large_numbers_2() {
 
     suite
         .query_rewards(&alice, |result| {
+            let rewards = result.unwrap();
             assert_eq!(
-                result.unwrap(),
+                rewards,
                 RewardsResponse {
+                    total_allocation: coins(alice_amount, denom),
                     claimed: vec![],
                     pending: coins(alice_amount, denom),
                     available_to_claim: coins(9_999_999_999_999_999990000000000000, denom),
+                    locked: coins(alice_amount - 9_999_999_999_999_999990000000000000, denom),
+                    accrual_rate: rewards.accrual_rate,
+                    next_unlocks: rewards.next_unlocks.clone(),
                 }
             );
         })
@@ -231,10 +245,12 @@ fn bug_large_numbers_3() {
                     total_reward: coin(amount, denom),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::percent(100),
-                        start_time: current_time.seconds(),
+                        start_time: *current_time,
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds(),
-                    end_time: current_time.plus_days(7).seconds(),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
                 }),
             },
             &[], // No funds during campaign creation
@@ -254,12 +270,17 @@ fn bug_large_numbers_3() {
 
     suite
         .query_rewards(&alice, |result| {
+            let rewards = result.unwrap();
             assert_eq!(
-                result.unwrap(),
+                rewards,
                 RewardsResponse {
+                    total_allocation: coins(alice_amount, denom),
                     claimed: vec![],
                     pending: coins(alice_amount, denom),
                     available_to_claim: coins(alice_amount, denom),
+                    locked: vec![],
+                    accrual_rate: rewards.accrual_rate,
+                    next_unlocks: rewards.next_unlocks.clone(),
                 }
             );
         })
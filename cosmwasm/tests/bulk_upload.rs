@@ -0,0 +1,151 @@
+use std::cell::Cell;
+
+use claimdrop_contract::commands::add_allocations;
+use claimdrop_contract::state::ALLOCATIONS_COUNT;
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Empty, MessageInfo, OwnedDeps, Record, Storage, Uint128};
+use mantra_claimdrop_std::error::ContractError;
+
+/// Storage counting the reads and writes, the bulk of the gas spent uploading allocations.
+#[derive(Default)]
+struct CountingStorage {
+    inner: MockStorage,
+    reads: Cell<usize>,
+    writes: usize,
+}
+
+impl CountingStorage {
+    fn reset(&mut self) {
+        self.reads.set(0);
+        self.writes = 0;
+    }
+}
+
+impl Storage for CountingStorage {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.reads.set(self.reads.get() + 1);
+        self.inner.get(key)
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: cosmwasm_std::Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        self.inner.range(start, end, order)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes += 1;
+        self.inner.set(key, value)
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes += 1;
+        self.inner.remove(key)
+    }
+}
+
+fn setup() -> (
+    OwnedDeps<CountingStorage, MockApi, MockQuerier, Empty>,
+    MessageInfo,
+) {
+    let mut deps = OwnedDeps {
+        storage: CountingStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::default(),
+        custom_query_type: Default::default(),
+    };
+    let owner = deps.api.addr_make("owner");
+    let deps_api = deps.api;
+    cw_ownable::initialize_owner(deps.as_mut().storage, &deps_api, Some(owner.as_str())).unwrap();
+
+    (
+        deps,
+        MessageInfo {
+            sender: owner,
+            funds: vec![],
+        },
+    )
+}
+
+fn allocations(deps_api: &MockApi, range: std::ops::Range<usize>) -> Vec<(String, Uint128)> {
+    range
+        .map(|i| {
+            (
+                deps_api.addr_make(&format!("user{i}")).to_string(),
+                Uint128::new(100),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn bulk_uploads_cost_one_read_per_allocation() {
+    const BATCH: usize = 500;
+    let (mut deps, info) = setup();
+    let batch = allocations(&deps.api, 0..BATCH);
+
+    deps.storage.reset();
+    add_allocations(deps.as_mut(), mock_env(), info, batch, false, None, None).unwrap();
+
+    // one read to detect duplicates, one write for the allocation and one per index, plus the
+    // fixed reads and writes of the batch
+    let reads = deps.storage.reads.get();
+    let writes = deps.storage.writes;
+    println!("{BATCH} allocations: {reads} reads, {writes} writes");
+    assert!(reads <= BATCH + 10, "{reads} reads");
+    assert!(writes <= BATCH * 5 + 10, "{writes} writes");
+    assert_eq!(ALLOCATIONS_COUNT.load(&deps.storage).unwrap(), BATCH as u64);
+}
+
+#[test]
+fn duplicates_are_skipped_when_requested() {
+    let (mut deps, info) = setup();
+    let api = deps.api;
+
+    add_allocations(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        allocations(&api, 0..10),
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // resuming the upload from the middle fails on the duplicates by default
+    let err = add_allocations(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        allocations(&api, 5..20),
+        false,
+        None,
+        None,
+    )
+    .unwrap_err();
+    match err {
+        ContractError::AllocationAlreadyExists { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::AllocationAlreadyExists"),
+    }
+
+    let mut batch = allocations(&api, 5..20);
+    batch.push(batch[14].clone());
+    let response =
+        add_allocations(deps.as_mut(), mock_env(), info, batch, true, None, None).unwrap();
+
+    let attribute = |key: &str| {
+        response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+            .unwrap()
+    };
+    assert_eq!(attribute("count"), "10");
+    assert_eq!(attribute("skipped"), "6");
+    assert_eq!(ALLOCATIONS_COUNT.load(&deps.storage).unwrap(), 20);
+}
@@ -0,0 +1,282 @@
+use cosmwasm_std::{coin, Decimal, HexBinary};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(suite: &mut TestingSuite) -> CampaignParams {
+    let current_time = suite.get_time();
+
+    CampaignParams {
+        name: "Metadata Campaign".to_string(),
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_days(1),
+            cliff_duration: None,
+        }],
+        start_time: current_time.plus_days(1),
+        metadata_uri: Some(
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+        ),
+        allocations_hash: Some(HexBinary::from([0xab; 32])),
+        ..suite::campaign_params(&current_time, 10_000)
+    }
+}
+
+#[test]
+fn metadata_uri_can_be_updated_before_the_campaign_starts() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let params = campaign_params(&mut suite);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params.clone()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            assert_eq!(campaign.metadata_uri, params.metadata_uri);
+            assert_eq!(campaign.allocations_hash, params.allocations_hash);
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadataUri {
+                metadata_uri: Some("https://mantra.zone/allocations.csv".to_string()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            assert_eq!(
+                campaign.metadata_uri,
+                Some("https://mantra.zone/allocations.csv".to_string())
+            );
+            // the hash is untouched
+            assert_eq!(campaign.allocations_hash, params.allocations_hash);
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadataUri {
+                metadata_uri: Some(String::new()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "metadata_uri");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadataUri { metadata_uri: None },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().metadata_uri, None);
+        })
+        .add_day()
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadataUri {
+                metadata_uri: Some("https://mantra.zone/allocations.csv".to_string()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignAlreadyStarted { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignAlreadyStarted"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn cannot_create_campaign_with_invalid_metadata() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let params = campaign_params(&mut suite);
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for (params, expected_param) in [
+        (
+            CampaignParams {
+                metadata_uri: Some("a".repeat(501)),
+                ..params.clone()
+            },
+            "metadata_uri",
+        ),
+        (
+            CampaignParams {
+                allocations_hash: Some(HexBinary::default()),
+                ..params.clone()
+            },
+            "allocations_hash",
+        ),
+        (
+            CampaignParams {
+                allocations_hash: Some(HexBinary::from([0xab; 65])),
+                ..params
+            },
+            "allocations_hash",
+        ),
+    ] {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, expected_param);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+}
+
+#[test]
+fn name_description_and_type_can_be_updated_until_the_campaign_closes() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let params = campaign_params(&mut suite);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params.clone()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .add_day()
+        // the typo is fixed after the campaign started, leaving the description as is
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadata {
+                name: Some("Metadata Campaign 2025".to_string()),
+                description: None,
+                ty: Some("retrodrop".to_string()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let event = response
+                    .events
+                    .iter()
+                    .find(|event| event.ty == "wasm-claimdrop/update_metadata")
+                    .unwrap();
+                assert!(event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "old_name" && attr.value == "Metadata Campaign"));
+                assert!(!event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "new_description"));
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            assert_eq!(campaign.name, "Metadata Campaign 2025");
+            assert_eq!(campaign.description, params.description);
+            assert_eq!(campaign.ty, "retrodrop");
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadata {
+                name: None,
+                description: Some("".to_string()),
+                ty: None,
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "description")
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadata {
+                name: None,
+                description: None,
+                ty: None,
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateMetadata {
+                name: Some("Closed Campaign".to_string()),
+                description: None,
+                ty: None,
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignClosed => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignClosed"),
+                }
+            },
+        );
+}
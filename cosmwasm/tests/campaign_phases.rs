@@ -0,0 +1,236 @@
+use cosmwasm_std::{coin, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CampaignPhase};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, manual_phases: bool) -> CampaignParams {
+    CampaignParams {
+        end_time: current_time.plus_days(10),
+        manual_phases,
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+fn assert_campaign_error(result: Result<AppResponse, anyhow::Error>, expected: &str) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::CampaignError { reason } => assert_eq!(reason, expected),
+        _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+    }
+}
+
+#[test]
+fn phases_follow_the_campaign_times_by_default() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, false)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::AllocationUpload);
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_campaign_error(result, "the campaign phases follow the campaign times");
+            },
+        )
+        .add_day()
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Active);
+        })
+        .add_week()
+        .add_week()
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Ended);
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Closed);
+        });
+}
+
+#[test]
+fn manual_phases_gate_allocations_and_claims() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, true)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(10_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Upcoming);
+        })
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_campaign_error(result, "the campaign doesn't accept allocations yet");
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.events.iter().any(|event| {
+                    event
+                        .attributes
+                        .iter()
+                        .any(|attr| attr.key == "to_phase" && attr.value == "allocation_upload")
+                }));
+            },
+        )
+        // the campaign start time has passed, but it's still in the register phase
+        .add_day()
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignNotStarted { .. } => {}
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::CampaignNotStarted")
+                    }
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Active);
+        })
+        .add_allocations(
+            owner,
+            &vec![(owner.to_string(), Uint128::new(1))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignAlreadyStarted { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignAlreadyStarted"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_campaign_error(
+                    result,
+                    "the campaign can't be advanced from the active phase",
+                );
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_010_000));
+        })
+        .add_week()
+        .add_week()
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Ended);
+        });
+}
+
+#[test]
+fn only_campaign_managers_can_advance_the_phase() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, true)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            bob,
+            CampaignAction::AdvancePhase {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .query_campaign_phase(|result| {
+            assert_eq!(result.unwrap().phase, CampaignPhase::Upcoming);
+        });
+}
@@ -0,0 +1,107 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CampaignStats, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn claims_are_tracked_in_the_stats() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .query_campaign_stats(|result| {
+            assert_eq!(result.unwrap(), CampaignStats::default());
+        })
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(10_000)),
+                (carol.to_string(), Uint128::new(10_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Tracked Campaign".to_string(),
+                    description: "Campaign tracking the claim statistics".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(20_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(10),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(20_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    let first_claim_time = suite.get_time();
+
+    suite
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(1_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            carol,
+            None,
+            Some(Uint128::new(4_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    let last_claim_time = suite.get_time();
+
+    suite
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(2_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_stats(|result| {
+            assert_eq!(
+                result.unwrap(),
+                CampaignStats {
+                    claims: 3,
+                    claimants: 2,
+                    largest_claim: Uint128::new(4_000),
+                    first_claim_at: Some(first_claim_time),
+                    last_claim_at: Some(last_claim_time),
+                }
+            );
+        });
+}
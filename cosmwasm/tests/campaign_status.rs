@@ -0,0 +1,174 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CampaignStatus, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn query_campaign_status() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    let current_time = &suite.get_time();
+
+    suite
+        .query_campaign_status(|result| {
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains("There's not an active campaign"));
+        })
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(3_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Status Campaign".to_string(),
+                    description: "Campaign to check the status".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(10_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(3_600),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(3_600),
+                    end_time: current_time.plus_seconds(86_400),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(5_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.campaign.name, "Status Campaign");
+            assert_eq!(status.status, CampaignStatus::NotStarted);
+            assert_eq!(status.total_allocated, coin(4_000, "uom"));
+            assert_eq!(status.total_claimed, coin(0, "uom"));
+            assert_eq!(status.allocations_count, 2);
+            assert_eq!(status.claimants_count, 0);
+            assert_eq!(status.balance, coin(5_000, "uom"));
+        })
+        .add_week()
+        .query_campaign_status(|result| {
+            assert_eq!(result.unwrap().status, CampaignStatus::Ended);
+        });
+}
+
+#[test]
+fn campaign_status_tracks_claims_and_closing() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    let current_time = &suite.get_time();
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(3_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Status Campaign".to_string(),
+                    description: "Campaign to check the status".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(4_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(86_400 * 7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(4_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.status, CampaignStatus::Active);
+            assert_eq!(status.total_claimed, coin(1_000, "uom"));
+            assert_eq!(status.claimants_count, 1);
+            assert_eq!(status.balance, coin(3_000, "uom"));
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.status, CampaignStatus::Closed);
+            assert_eq!(status.balance, coin(0, "uom"));
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignClosed => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignClosed"),
+                }
+            },
+        );
+}
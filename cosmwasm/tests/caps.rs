@@ -0,0 +1,247 @@
+use cosmwasm_std::{coin, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(
+    current_time: &Timestamp,
+    max_allocation_per_address: Option<Uint128>,
+    max_single_claim: Option<Uint128>,
+) -> CampaignParams {
+    CampaignParams {
+        max_allocation_per_address,
+        max_single_claim,
+        ..suite::campaign_params(current_time, 100_000)
+    }
+}
+
+fn assert_invalid_input(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidInput { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+    }
+}
+
+fn assert_invalid_campaign_param(result: Result<AppResponse, anyhow::Error>, expected: &str) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidCampaignParam { param, .. } => assert_eq!(param, expected),
+        _ => panic!("Wrong error type, should return ContractError::InvalidCampaignParam"),
+    }
+}
+
+#[test]
+fn allocations_above_the_cap_are_rejected() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    Some(Uint128::new(10_000)),
+                    None,
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // a fat-fingered allocation rejects the whole batch
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(10_000)),
+                (carol.to_string(), Uint128::new(1_000_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_input(result);
+            },
+        )
+        .query_allocations(Some(bob), None, None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        })
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(10_000)),
+                (carol.to_string(), Uint128::new(1_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the cap can't be lowered below the uploaded allocations
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    Some(Uint128::new(5_000)),
+                    None,
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, "max_allocation_per_address");
+            },
+        );
+}
+
+#[test]
+fn existing_allocations_above_the_cap_reject_the_campaign() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(50_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    Some(Uint128::new(10_000)),
+                    None,
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, "max_allocation_per_address");
+            },
+        );
+}
+
+#[test]
+fn claims_above_the_cap_are_rejected() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    None,
+                    Some(Uint128::new(6_000)),
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(100_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidClaimAmount { reason } => {
+                        assert_eq!(
+                            reason,
+                            "claim amount 10000 exceeds the maximum single claim 6000"
+                        );
+                    }
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::InvalidClaimAmount")
+                    }
+                }
+            },
+        )
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(6_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_010_000));
+        });
+}
+
+#[test]
+fn cap_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    let mut below_min_claim = campaign_params(current_time, None, Some(Uint128::new(100)));
+    below_min_claim.min_claim_amount = Some(Uint128::new(500));
+
+    let invalid_params = [
+        (
+            campaign_params(current_time, Some(Uint128::zero()), None),
+            "max_allocation_per_address",
+        ),
+        (
+            campaign_params(current_time, None, Some(Uint128::new(100_001))),
+            "max_single_claim",
+        ),
+        (below_min_claim, "max_single_claim"),
+    ];
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for (params, expected_param) in invalid_params {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, expected_param);
+            },
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CircuitBreaker, CircuitBreakerWindow};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite) {
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(1_000)),
+                (dan.to_string(), Uint128::new(1_000)),
+            ],
+            campaign_params(current_time, 3_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn circuit_breaker_pauses_the_claims() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .update_config(
+            owner,
+            Some(CircuitBreaker {
+                max_claim_amount: Uint128::new(1_500),
+                window: CircuitBreakerWindow::Hour,
+            }),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // carol's claim would exceed the threshold, so the claims are paused instead
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response
+                    .events
+                    .iter()
+                    .any(|event| event.ty == "wasm-circuit_breaker_tripped"));
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_config(|result| {
+            assert!(result.unwrap().paused);
+        })
+        .claim(
+            dan,
+            None,
+            Some(Uint128::new(1)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::ClaimsPaused => {}
+                    _ => panic!("Wrong error type, should return ContractError::ClaimsPaused"),
+                }
+            },
+        )
+        .resume_claims(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::OwnershipError(_) => {}
+                _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+            }
+        })
+        .resume_claims(owner, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_config(|result| {
+            assert!(!result.unwrap().paused);
+        })
+        // the window was reset when resuming the claims
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
+
+#[test]
+fn circuit_breaker_window_rolls_over() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .update_config(
+            owner,
+            Some(CircuitBreaker {
+                max_claim_amount: Uint128::new(1_000),
+                window: CircuitBreakerWindow::Hour,
+            }),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        .query_config(|result| {
+            assert!(!result.unwrap().paused);
+        });
+}
+
+#[test]
+fn update_config_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    let circuit_breaker = CircuitBreaker {
+        max_claim_amount: Uint128::zero(),
+        window: CircuitBreakerWindow::Block,
+    };
+
+    suite
+        .update_config(bob, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::OwnershipError(_) => {}
+                _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+            }
+        })
+        .update_config(
+            owner,
+            Some(circuit_breaker),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .resume_claims(owner, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => {
+                    assert_eq!(reason, "claims are not paused");
+                }
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        })
+        .query_config(|result| {
+            let config = result.unwrap();
+            assert_eq!(config.circuit_breaker, None);
+            assert!(!config.paused);
+        });
+}
@@ -0,0 +1,181 @@
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{
+    campaign_params, contract_execution_authorization, generic_authorization, TestingSuite,
+};
+
+fn setup_campaign(suite: &mut TestingSuite, receiver: &Addr) {
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(receiver.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+fn assert_unauthorized(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::Unauthorized => {}
+        _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn custodian_claims_with_an_authz_grant() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let custodian = &suite.senders[4].clone();
+
+    setup_campaign(&mut suite, bob);
+
+    suite
+        .claim_authz(
+            custodian,
+            bob,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        .set_authz_grant(bob, custodian, None)
+        .claim_authz(
+            custodian,
+            bob,
+            Some(Uint128::new(400)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response
+                    .events
+                    .iter()
+                    .any(|event| event.attributes.iter().any(
+                        |attr| attr.key == "authz_grantee" && attr.value == custodian.as_str()
+                    )));
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_400));
+        })
+        .query_balance("uom", custodian, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        });
+}
+
+#[test]
+fn cannot_claim_with_an_expired_or_foreign_authz_grant() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let custodian = &suite.senders[4].clone();
+    let current_time = suite.get_time();
+
+    setup_campaign(&mut suite, bob);
+
+    suite
+        // the grant expired
+        .set_authz_grant(bob, custodian, Some(current_time))
+        .claim_authz(
+            custodian,
+            bob,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        // carol granted the custodian, but it's bob who has the allocation
+        .set_authz_grant(carol, custodian, None)
+        .claim_authz(
+            custodian,
+            bob,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        .claim_authz(
+            custodian,
+            carol,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        });
+}
+
+#[test]
+fn cannot_claim_with_an_authz_grant_the_contract_cannot_enforce() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let custodian = &suite.senders[4].clone();
+    let other_contract = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite, bob);
+    let contract = &suite.claimdrop_contract_addr.clone();
+
+    suite
+        // the grant authorizes another message
+        .set_authz_grant_with_authorization(
+            bob,
+            custodian,
+            None,
+            generic_authorization("/cosmos.bank.v1beta1.MsgSend"),
+        )
+        // the grant authorizes another contract
+        .set_authz_grant_with_authorization(
+            bob,
+            custodian,
+            None,
+            contract_execution_authorization(other_contract, None, None),
+        )
+        // the grant doesn't allow claiming
+        .set_authz_grant_with_authorization(
+            bob,
+            custodian,
+            None,
+            contract_execution_authorization(contract, Some(&["accept_terms"]), None),
+        )
+        // the grant limits the calls, which the contract can't consume
+        .set_authz_grant_with_authorization(
+            bob,
+            custodian,
+            None,
+            contract_execution_authorization(contract, None, Some(1)),
+        )
+        .claim_authz(
+            custodian,
+            bob,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        .set_authz_grant_with_authorization(
+            bob,
+            custodian,
+            None,
+            contract_execution_authorization(contract, Some(&["claim_authz"]), None),
+        )
+        .claim_authz(
+            custodian,
+            bob,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
@@ -0,0 +1,212 @@
+use cosmwasm_std::{coin, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::CampaignParams;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite) {
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+            ],
+            CampaignParams {
+                end_time: current_time.plus_days(10),
+                ..campaign_params(current_time, 3_000)
+            },
+        )
+        .manage_authorized_wallets(
+            owner,
+            vec![alice.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+}
+
+#[test]
+fn authorized_wallet_claims_for_a_batch_of_receivers() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .claim_batch(
+            alice,
+            vec![bob.to_string(), carol.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(
+                    &Event::new("wasm-claimdrop/claim_batch")
+                        .add_attribute("receiver", bob.to_string())
+                        .add_attribute("amount", "1000")
+                        .add_attribute("receiver", carol.to_string())
+                        .add_attribute("amount", "2000")
+                ));
+                assert!(response.has_event(
+                    &Event::new("wasm")
+                        .add_attribute("action", "claim_batch")
+                        .add_attribute("count", "2")
+                        .add_attribute("total_claimed", "3000")
+                ));
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_002_000));
+        })
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claimed, coin(3_000, "uom"));
+        });
+}
+
+#[test]
+fn batch_skips_the_receivers_with_nothing_to_claim() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // bob already claimed everything
+        .claim_batch(
+            alice,
+            vec![bob.to_string(), carol.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(
+                    &Event::new("wasm-claimdrop/claim_batch")
+                        .add_attribute("skipped", bob.to_string())
+                        .add_attribute("receiver", carol.to_string())
+                        .add_attribute("amount", "2000")
+                ));
+                assert!(response.has_event(
+                    &Event::new("wasm")
+                        .add_attribute("action", "claim_batch")
+                        .add_attribute("count", "1")
+                        .add_attribute("skipped", "1")
+                        .add_attribute("total_claimed", "2000")
+                ));
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_002_000));
+        });
+}
+
+#[test]
+fn batch_fails_if_any_claim_fails() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let dan = &suite.senders[4].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .claim_batch(
+            alice,
+            vec![bob.to_string(), dan.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        });
+}
+
+#[test]
+fn claim_batch_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .claim_batch(
+            bob,
+            vec![bob.to_string(), carol.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        )
+        .claim_batch(
+            alice,
+            vec![],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .claim_batch(
+            alice,
+            vec![bob.to_string(), bob.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .claim_batch(
+            alice,
+            (0..51).map(|i| format!("receiver{i}")).collect(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::BatchSizeLimitExceeded { actual, max } => {
+                        assert_eq!(actual, 51);
+                        assert_eq!(max, 50);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::BatchSizeLimitExceeded"
+                    ),
+                }
+            },
+        );
+}
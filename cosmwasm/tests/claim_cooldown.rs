@@ -0,0 +1,135 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+const COOLDOWN: u64 = 86_400 * 2;
+
+fn campaign_params(
+    suite: &mut TestingSuite,
+    claim_cooldown_seconds: Option<u64>,
+) -> CampaignParams {
+    let current_time = &suite.get_time();
+
+    CampaignParams {
+        distribution_type: vec![DistributionType::LinearVesting {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            end_time: current_time.plus_seconds(86_400 * 10),
+            cliff_duration: None,
+            cliff_behavior: None,
+        }],
+        end_time: current_time.plus_seconds(86_400 * 10),
+        claim_cooldown_seconds,
+        ..suite::campaign_params(current_time, 20_000)
+    }
+}
+
+#[test]
+fn claims_are_rate_limited_per_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    let params = campaign_params(&mut suite, Some(COOLDOWN));
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(10_000)),
+                (carol.to_string(), Uint128::new(10_000)),
+            ],
+            params,
+        )
+        .add_day()
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claim_cooldown_seconds, Some(COOLDOWN));
+        });
+
+    let first_claim_time = suite.get_time().seconds();
+
+    suite
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::ClaimCooldownActive { next_claim_at } => {
+                        assert_eq!(next_claim_at, first_claim_time + COOLDOWN);
+                    }
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::ClaimCooldownActive")
+                    }
+                }
+            },
+        )
+        // the cooldown is tracked per address, carol hasn't claimed yet
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            // roughly 3 out of 10 days of vesting
+            assert!(
+                balance > Uint128::new(1_000_002_900) && balance <= Uint128::new(1_000_003_000)
+            );
+        });
+}
+
+#[test]
+fn cannot_create_campaign_with_zero_cooldown() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    let params = campaign_params(&mut suite, Some(0));
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, reason } => {
+                        assert_eq!(param, "claim_cooldown_seconds");
+                        assert_eq!(reason, "cannot be zero");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+}
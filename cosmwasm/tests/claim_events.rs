@@ -0,0 +1,110 @@
+use cosmwasm_std::{coin, Decimal, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn slot_event(
+    receiver: &str,
+    slot: &str,
+    distribution_type: &str,
+    amount: &str,
+    slot_claimed: &str,
+    remaining_allocation: &str,
+) -> Event {
+    Event::new("wasm-claimdrop/claim")
+        .add_attribute("receiver", receiver)
+        .add_attribute("slot", slot)
+        .add_attribute("distribution_type", distribution_type)
+        .add_attribute("amount", amount)
+        .add_attribute("slot_claimed", slot_claimed)
+        .add_attribute("remaining_allocation", remaining_allocation)
+}
+
+#[test]
+fn claim_emits_an_event_per_slot() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(10_000))],
+            CampaignParams {
+                name: "Events Campaign".to_string(),
+                description: "Campaign emitting claim events".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(10_000, "uom"),
+                distribution_type: vec![
+                    DistributionType::LumpSum {
+                        percentage: Decimal::percent(50),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    },
+                    DistributionType::LinearVesting {
+                        percentage: Decimal::percent(50),
+                        start_time: current_time.plus_seconds(1),
+                        end_time: current_time.plus_seconds(1 + 86_400 * 10),
+                        cliff_duration: None,
+                        cliff_behavior: None,
+                    },
+                ],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_seconds(1 + 86_400 * 10),
+                ..Default::default()
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(&slot_event(
+                    bob.as_str(),
+                    "0",
+                    "lump_sum",
+                    "5000",
+                    "5000",
+                    "4501"
+                )));
+                assert!(response.has_event(&slot_event(
+                    bob.as_str(),
+                    "1",
+                    "linear_vesting",
+                    "499",
+                    "499",
+                    "4501"
+                )));
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                // the lump sum is fully claimed, so only the linear vesting is claimed from
+                assert_eq!(
+                    response
+                        .events
+                        .iter()
+                        .filter(|event| event.ty == "wasm-claimdrop/claim")
+                        .count(),
+                    1
+                );
+                assert!(response.has_event(&slot_event(
+                    bob.as_str(),
+                    "1",
+                    "linear_vesting",
+                    "500",
+                    "999",
+                    "4001"
+                )));
+            },
+        );
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::{coin, Decimal, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, ClaimFee, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(suite: &mut TestingSuite, claim_fee: Option<ClaimFee>) -> CampaignParams {
+    let current_time = &suite.get_time();
+
+    CampaignParams {
+        distribution_type: vec![DistributionType::LinearVesting {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            end_time: current_time.plus_days(10),
+            cliff_duration: None,
+            cliff_behavior: None,
+        }],
+        end_time: current_time.plus_days(10),
+        claim_fee,
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+#[test]
+fn claim_fee_is_sent_to_the_treasury() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let treasury = &suite.senders[3].clone();
+
+    let params = campaign_params(
+        &mut suite,
+        Some(ClaimFee {
+            percentage: Decimal::percent(2),
+            recipient: treasury.to_string(),
+        }),
+    );
+
+    suite
+        .setup_campaign(&[(bob.to_string(), Uint128::new(10_000))], params)
+        .add_day()
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(500)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(&Event::new("wasm").add_attribute("claim_fee", "10")));
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_490));
+        })
+        .query_balance("uom", treasury, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_010));
+        })
+        // the fee is part of the claimed amount
+        .query_claimed(Some(bob), None, None, |result| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(500, "uom"));
+        })
+        .add_day()
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(49)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            assert_eq!(campaign.claimed, coin(549, "uom"));
+            // 2% of 49 rounds down to 0
+            assert_eq!(campaign.fees_collected, Uint128::new(10));
+        });
+}
+
+#[test]
+fn cannot_create_campaign_with_invalid_claim_fee() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let treasury = &suite.senders[3].clone();
+
+    let zero_fee = campaign_params(
+        &mut suite,
+        Some(ClaimFee {
+            percentage: Decimal::zero(),
+            recipient: treasury.to_string(),
+        }),
+    );
+    let full_fee = campaign_params(
+        &mut suite,
+        Some(ClaimFee {
+            percentage: Decimal::one(),
+            recipient: treasury.to_string(),
+        }),
+    );
+    let invalid_recipient = campaign_params(
+        &mut suite,
+        Some(ClaimFee {
+            percentage: Decimal::percent(1),
+            recipient: "treasury".to_string(),
+        }),
+    );
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for params in [zero_fee, full_fee] {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "claim_fee");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+
+    suite.manage_campaign(
+        owner,
+        CampaignAction::CreateCampaign {
+            params: Box::new(invalid_recipient),
+        },
+        &[],
+        |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Std(_) => {}
+                _ => panic!("Wrong error type, should return ContractError::Std"),
+            }
+        },
+    );
+}
@@ -0,0 +1,230 @@
+use claimdrop_contract::commands::MAX_CLAIM_HOOKS;
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn registered_hooks_are_notified_on_claim() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+    let hook = suite.instantiate_claim_hook_contract();
+
+    suite
+        .manage_claim_hooks(
+            owner,
+            vec![hook.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claim_hooks(|result| {
+            assert_eq!(result.unwrap().hooks, vec![hook.to_string()]);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let hook_event = response
+                    .events
+                    .iter()
+                    .find(|event| {
+                        event.ty == "wasm"
+                            && event
+                                .attributes
+                                .iter()
+                                .any(|attr| attr.key == "action" && attr.value == "claim_hook")
+                    })
+                    .expect("claim hook was not called");
+
+                let attribute = |key: &str| {
+                    hook_event
+                        .attributes
+                        .iter()
+                        .find(|attr| attr.key == key)
+                        .unwrap()
+                        .value
+                        .clone()
+                };
+
+                assert_eq!(attribute("_contract_address"), hook.to_string());
+                assert_eq!(attribute("receiver"), bob.to_string());
+                assert_eq!(attribute("amount"), "1000uom");
+                assert_eq!(attribute("campaign"), "Test Campaign");
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
+
+#[test]
+fn unregistered_hooks_are_not_notified() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+    let hook = suite.instantiate_claim_hook_contract();
+
+    suite
+        .manage_claim_hooks(
+            owner,
+            vec![hook.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_claim_hooks(
+            owner,
+            vec![hook.to_string()],
+            false,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claim_hooks(|result| {
+            assert!(result.unwrap().hooks.is_empty());
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(!response.events.iter().any(|event| event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "action" && attr.value == "claim_hook")));
+            },
+        );
+}
+
+#[test]
+fn failing_hook_reverts_the_claim() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    // the claimdrop contract itself doesn't understand the hook message
+    let claimdrop = suite.claimdrop_contract_addr.clone();
+
+    suite
+        .manage_claim_hooks(
+            owner,
+            vec![claimdrop.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        });
+}
+
+#[test]
+fn manage_claim_hooks_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    let hooks = (0..=MAX_CLAIM_HOOKS)
+        .map(|_| suite.instantiate_claim_hook_contract().to_string())
+        .collect::<Vec<String>>();
+
+    suite
+        .manage_claim_hooks(
+            bob,
+            vec![hooks[0].clone()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .manage_claim_hooks(
+            owner,
+            vec![],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_claim_hooks(
+            owner,
+            vec!["invalid".to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        )
+        .manage_claim_hooks(
+            owner,
+            hooks.clone(),
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::BatchSizeLimitExceeded { actual, max } => {
+                        assert_eq!(actual, MAX_CLAIM_HOOKS + 1);
+                        assert_eq!(max, MAX_CLAIM_HOOKS);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::BatchSizeLimitExceeded"
+                    ),
+                }
+            },
+        )
+        .manage_claim_hooks(
+            owner,
+            hooks[..MAX_CLAIM_HOOKS].to_vec(),
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claim_hooks(|result| {
+            assert_eq!(result.unwrap().hooks.len(), MAX_CLAIM_HOOKS);
+        });
+}
@@ -0,0 +1,110 @@
+use cosmwasm_std::{coin, Decimal, StdResult, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::ClaimedResponse;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup(suite: &mut TestingSuite) {
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn percentages_are_converted_against_the_claimable_amount() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    setup(&mut suite);
+
+    let assert_claimed = |expected: u128| {
+        move |result: StdResult<ClaimedResponse>| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(expected, "uom"));
+        }
+    };
+
+    suite
+        .claim_percentage(
+            bob,
+            None,
+            Decimal::percent(25),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claimed(Some(bob), None, None, assert_claimed(250))
+        // half of the remaining 750, rounded down
+        .claim_percentage(
+            bob,
+            None,
+            Decimal::percent(50),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claimed(Some(bob), None, None, assert_claimed(625))
+        .claim_percentage(
+            bob,
+            None,
+            Decimal::one(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claimed(Some(bob), None, None, assert_claimed(1_000))
+        .claim_percentage(
+            bob,
+            None,
+            Decimal::one(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        );
+}
+
+#[test]
+fn claim_percentage_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    setup(&mut suite);
+
+    let assert_out_of_range = |result: Result<AppResponse, anyhow::Error>| {
+        let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+        match err {
+            ContractError::InvalidClaimAmount { reason } => {
+                assert_eq!(reason, "percentage must be within (0, 1]");
+            }
+            _ => panic!("Wrong error type, should return ContractError::InvalidClaimAmount"),
+        }
+    };
+
+    suite
+        .claim_percentage(bob, None, Decimal::zero(), assert_out_of_range)
+        .claim_percentage(bob, None, Decimal::percent(101), assert_out_of_range)
+        .claim_percentage(
+            bob,
+            Some(Uint128::new(100)),
+            Decimal::percent(10),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { reason } => {
+                        assert_eq!(reason, "amount and percentage cannot be set together");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
@@ -0,0 +1,145 @@
+use std::cell::RefCell;
+
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{
+    CampaignAction, CampaignParams, ClaimPriority, DistributionType, Limits,
+};
+
+mod suite;
+use suite::TestingSuite;
+
+/// Returns the (slot, amount) pairs claimed, from the slot claim events.
+fn slot_claims(response: &AppResponse) -> Vec<(String, String)> {
+    response
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm-claimdrop/claim")
+        .map(|event| {
+            let attribute = |key: &str| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attribute| attribute.key == key)
+                    .map(|attribute| attribute.value.clone())
+                    .unwrap()
+            };
+            (attribute("slot"), attribute("amount"))
+        })
+        .collect()
+}
+
+/// Claims 300 out of a fully claimable allocation of 1000, split into a lump sum of 250, a vesting
+/// of 500 and another lump sum of 250, returning the amounts taken from each slot.
+fn partial_claim(claim_priority: ClaimPriority) -> Vec<(String, String)> {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract_with_limits(
+            Some(owner.to_string()),
+            Some(Limits {
+                max_distribution_slots: Some(3),
+                ..Default::default()
+            }),
+        )
+        .add_allocations(
+            owner,
+            &vec![(alice.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Prioritized Campaign".to_string(),
+                    description: "Campaign draining the slots in a set order".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(1_000, "uom"),
+                    distribution_type: vec![
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(25),
+                            start_time: current_time.plus_seconds(1),
+                            cliff_duration: None,
+                        },
+                        DistributionType::LinearVesting {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_seconds(1),
+                            end_time: current_time.plus_days(1),
+                            cliff_duration: None,
+                            cliff_behavior: None,
+                        },
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(25),
+                            start_time: current_time.plus_seconds(1),
+                            cliff_duration: None,
+                        },
+                    ],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    claim_priority,
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .add_day();
+
+    let claims = RefCell::new(vec![]);
+    suite.claim(
+        alice,
+        None,
+        Some(Uint128::new(300)),
+        |result: Result<AppResponse, anyhow::Error>| {
+            *claims.borrow_mut() = slot_claims(&result.unwrap());
+        },
+    );
+
+    claims.into_inner()
+}
+
+fn pairs(claims: &[(&str, &str)]) -> Vec<(String, String)> {
+    claims
+        .iter()
+        .map(|(slot, amount)| (slot.to_string(), amount.to_string()))
+        .collect()
+}
+
+#[test]
+fn partial_claims_drain_the_lump_sums_first_by_default() {
+    assert_eq!(
+        partial_claim(ClaimPriority::default()),
+        pairs(&[("0", "250"), ("2", "50")])
+    );
+}
+
+#[test]
+fn partial_claims_drain_the_vesting_first_if_set() {
+    assert_eq!(
+        partial_claim(ClaimPriority::VestingFirst),
+        pairs(&[("1", "300")])
+    );
+}
+
+#[test]
+fn partial_claims_drain_the_slots_in_order_if_set() {
+    assert_eq!(
+        partial_claim(ClaimPriority::SlotOrder),
+        pairs(&[("0", "250"), ("1", "50")])
+    );
+}
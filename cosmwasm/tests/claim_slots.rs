@@ -0,0 +1,151 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+/// Sets up a campaign with a 30% lump sum on slot 0 and a 70% linear vesting over 10 days on slot 1.
+fn setup_campaign(suite: &mut TestingSuite) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.setup_campaign(
+        &[(bob.to_string(), Uint128::new(1_000))],
+        CampaignParams {
+            distribution_type: vec![
+                DistributionType::LumpSum {
+                    percentage: Decimal::percent(30),
+                    start_time: *current_time,
+                    cliff_duration: None,
+                },
+                DistributionType::LinearVesting {
+                    percentage: Decimal::percent(70),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(10),
+                    cliff_duration: None,
+                    cliff_behavior: None,
+                },
+            ],
+            start_time: *current_time,
+            end_time: current_time.plus_days(10),
+            ..campaign_params(current_time, 1_000)
+        },
+    );
+}
+
+#[test]
+fn can_claim_from_selected_slots() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    for _ in 0..5 {
+        suite.add_day();
+    }
+
+    suite
+        // take part of the vested tokens, keeping the lump sum
+        .claim_from_slots(
+            bob,
+            vec![1],
+            Some(Uint128::new(100)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claimed_by_slot(Some(bob), None, None, |result| {
+            let claims = result.unwrap().claims;
+            assert_eq!(claims.len(), 1);
+            assert_eq!(claims[0].slots.len(), 1);
+            assert_eq!(claims[0].slots[0].slot, 1);
+            assert_eq!(claims[0].slots[0].claimed, coin(100, "uom"));
+        })
+        // half of the vesting has passed, 350 vested in total
+        .claim_from_slots(
+            bob,
+            vec![1],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_350));
+        })
+        .claim_from_slots(
+            bob,
+            vec![0],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_650));
+        });
+}
+
+#[test]
+fn cannot_claim_from_invalid_slots() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+    suite.add_day();
+
+    let assert_invalid_input = |expected: &'static str| {
+        move |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => assert_eq!(reason, expected),
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        }
+    };
+
+    suite
+        .claim_from_slots(
+            bob,
+            vec![],
+            None,
+            assert_invalid_input("slots cannot be empty"),
+        )
+        .claim_from_slots(
+            bob,
+            vec![2],
+            None,
+            assert_invalid_input("distribution slot 2 doesn't exist"),
+        )
+        .claim_from_slots(
+            bob,
+            vec![0],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_from_slots(
+            bob,
+            vec![0, 1],
+            None,
+            assert_invalid_input("nothing to claim from distribution slot 0"),
+        )
+        // the requested amount can't exceed what's claimable from the selected slots
+        .claim_from_slots(
+            bob,
+            vec![1],
+            Some(Uint128::new(71)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidClaimAmount { .. } => {}
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::InvalidClaimAmount")
+                    }
+                }
+            },
+        );
+}
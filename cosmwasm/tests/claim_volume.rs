@@ -0,0 +1,113 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+#[test]
+fn claims_are_aggregated_per_day() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(10_000)),
+                (carol.to_string(), Uint128::new(10_000)),
+            ],
+            CampaignParams {
+                name: "Tracked Campaign".to_string(),
+                description: "Campaign tracking the daily claim volume".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(20_000, "uom"),
+                distribution_type: vec![DistributionType::LumpSum {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    cliff_duration: None,
+                }],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(10),
+                ..Default::default()
+            },
+        )
+        .add_day();
+
+    let first_day = suite.get_time().seconds() / SECONDS_PER_DAY;
+
+    suite
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(1_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            carol,
+            None,
+            Some(Uint128::new(2_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claim_volume(first_day, first_day + 2, |result| {
+            let response = result.unwrap();
+            assert_eq!(
+                response.volumes,
+                vec![
+                    (first_day, coin(3_000, "uom")),
+                    (first_day + 2, coin(9_000, "uom")),
+                ]
+            );
+            assert_eq!(response.total, coin(12_000, "uom"));
+        })
+        .query_claim_volume(first_day + 1, first_day + 1, |result| {
+            let response = result.unwrap();
+            assert!(response.volumes.is_empty());
+            assert_eq!(response.total, coin(0, "uom"));
+        })
+        .query_claim_volume(first_day + 1, first_day, |result| {
+            assert!(result.is_err());
+        })
+        .query_claim_volume(first_day, first_day + 366, |result| {
+            assert!(result.is_err());
+        });
+}
+
+#[test]
+fn claim_volume_range_validation() {
+    let mut deps = cosmwasm_std::testing::mock_dependencies();
+
+    let err = claimdrop_contract::contract::query(
+        deps.as_mut().as_ref(),
+        cosmwasm_std::testing::mock_env(),
+        mantra_claimdrop_std::msg::QueryMsg::ClaimVolume {
+            from_day: 10,
+            to_day: 9,
+        },
+    )
+    .unwrap_err();
+
+    match err {
+        ContractError::InvalidInput { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+    }
+}
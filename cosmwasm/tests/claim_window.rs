@@ -0,0 +1,150 @@
+use cosmwasm_std::{coin, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams};
+
+mod suite;
+use suite::TestingSuite;
+
+const CLAIM_WINDOW_SECONDS: u64 = 2 * 86_400;
+
+fn campaign_params(current_time: &Timestamp, community_pool: Option<String>) -> CampaignParams {
+    CampaignParams {
+        claim_window_seconds: Some(CLAIM_WINDOW_SECONDS),
+        community_pool,
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+#[test]
+fn unclaimed_allocations_are_forfeited_once_the_window_expires() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let community_pool = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(400)),
+                (carol.to_string(), Uint128::new(600)),
+            ],
+            campaign_params(current_time, Some(community_pool.to_string())),
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_claim_window(carol, |result| {
+            let response = result.unwrap();
+            assert_eq!(
+                response.expires_at,
+                Some(current_time.plus_seconds(1 + CLAIM_WINDOW_SECONDS))
+            );
+            assert_eq!(response.forfeited, None);
+        })
+        .forfeit_expired_allocations(
+            bob,
+            vec![carol],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .add_day()
+        .add_day()
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::ClaimWindowExpired { expired_at } => {
+                        assert_eq!(
+                            expired_at,
+                            current_time.plus_seconds(1 + CLAIM_WINDOW_SECONDS)
+                        )
+                    }
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::ClaimWindowExpired")
+                    }
+                }
+            },
+        )
+        // bob claimed everything, so there's nothing to forfeit
+        .forfeit_expired_allocations(
+            carol,
+            vec![bob],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        )
+        .forfeit_expired_allocations(
+            bob,
+            vec![bob, carol],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", community_pool, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_600));
+        })
+        .query_claim_window(carol, |result| {
+            assert_eq!(result.unwrap().forfeited, Some(Uint128::new(600)));
+        })
+        // allocations are only forfeited once
+        .forfeit_expired_allocations(
+            bob,
+            vec![carol],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        );
+}
+
+#[test]
+fn claim_windows_require_a_community_pool() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, None)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "claim_window_seconds")
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+}
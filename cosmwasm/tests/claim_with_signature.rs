@@ -0,0 +1,195 @@
+use bech32::{Bech32, Hrp};
+use claimdrop_contract::helpers::pubkey_to_canonical_address;
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use k256::ecdsa::SigningKey;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CircuitBreaker, CircuitBreakerWindow};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+/// Returns a signing key and the mantra address derived from its public key
+fn signer(seed: u8) -> (SigningKey, Addr) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32].into()).unwrap();
+    let pubkey = signing_key.verifying_key().to_encoded_point(true);
+    let address = bech32::encode::<Bech32>(
+        Hrp::parse("mantra").unwrap(),
+        &pubkey_to_canonical_address(pubkey.as_bytes()),
+    )
+    .unwrap();
+
+    (signing_key, Addr::unchecked(address))
+}
+
+fn setup_campaign(suite: &mut TestingSuite, receivers: &[&Addr]) {
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &receivers
+                .iter()
+                .map(|receiver| (receiver.to_string(), Uint128::new(1_000)))
+                .collect::<Vec<_>>(),
+            campaign_params(current_time, 2_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn relayer_claims_on_behalf_of_the_signer() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let relayer = &suite.senders[4].clone();
+    let (signing_key, receiver) = signer(1);
+
+    setup_campaign(&mut suite, &[&receiver]);
+
+    suite
+        .query_claim_nonce(&receiver, |result| {
+            assert_eq!(result.unwrap().nonce, 0);
+        })
+        .claim_with_signature(
+            relayer,
+            &receiver,
+            &signing_key,
+            Some(Uint128::new(400)),
+            0,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", &receiver, |balance| {
+            assert_eq!(balance, Uint128::new(400));
+        })
+        .query_balance("uom", relayer, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_claim_nonce(&receiver, |result| {
+            assert_eq!(result.unwrap().nonce, 1);
+        })
+        // the same signed claim can't be replayed
+        .claim_with_signature(
+            relayer,
+            &receiver,
+            &signing_key,
+            Some(Uint128::new(400)),
+            0,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidNonce { expected, actual } => {
+                        assert_eq!(expected, 1);
+                        assert_eq!(actual, 0);
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidNonce"),
+                }
+            },
+        )
+        .claim_with_signature(
+            relayer,
+            &receiver,
+            &signing_key,
+            None,
+            1,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", &receiver, |balance| {
+            assert_eq!(balance, Uint128::new(1_000));
+        });
+}
+
+#[test]
+fn cannot_claim_with_someone_else_signature() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let relayer = &suite.senders[4].clone();
+    let (_, alice) = signer(1);
+    let (bob_signing_key, bob) = signer(2);
+
+    setup_campaign(&mut suite, &[&alice, &bob]);
+
+    suite
+        .claim_with_signature(
+            relayer,
+            &alice,
+            &bob_signing_key,
+            None,
+            0,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidSignature => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidSignature"),
+                }
+            },
+        )
+        .query_balance("uom", &alice, |balance| {
+            assert_eq!(balance, Uint128::zero());
+        })
+        .query_claim_nonce(&alice, |result| {
+            assert_eq!(result.unwrap().nonce, 0);
+        });
+}
+
+#[test]
+fn tripping_the_circuit_breaker_keeps_the_nonce() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let relayer = &suite.senders[4].clone();
+    let (signing_key, receiver) = signer(1);
+
+    setup_campaign(&mut suite, &[&receiver]);
+
+    suite
+        .update_config(
+            owner,
+            Some(CircuitBreaker {
+                max_claim_amount: Uint128::new(500),
+                window: CircuitBreakerWindow::Hour,
+            }),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the claim trips the circuit breaker, so nothing is paid out
+        .claim_with_signature(
+            relayer,
+            &receiver,
+            &signing_key,
+            None,
+            0,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", &receiver, |balance| {
+            assert_eq!(balance, Uint128::zero());
+        })
+        .query_claim_nonce(&receiver, |result| {
+            assert_eq!(result.unwrap().nonce, 0);
+        })
+        .update_config(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .resume_claims(owner, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        // the same signed claim is relayed again once the claims are resumed
+        .claim_with_signature(
+            relayer,
+            &receiver,
+            &signing_key,
+            None,
+            0,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", &receiver, |balance| {
+            assert_eq!(balance, Uint128::new(1_000));
+        })
+        .query_claim_nonce(&receiver, |result| {
+            assert_eq!(result.unwrap().nonce, 1);
+        });
+}
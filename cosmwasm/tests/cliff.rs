@@ -0,0 +1,341 @@
+use std::cell::Cell;
+
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CliffBehavior, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+const DAY: u64 = 86_400;
+
+fn setup_campaign(suite: &mut TestingSuite, params: CampaignParams) {
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    suite.setup_campaign(&[(bob.to_string(), Uint128::new(10_000))], params);
+}
+
+fn assert_nothing_to_claim(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::NothingToClaim => {}
+        _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+    }
+}
+
+#[test]
+fn lump_sum_is_unlocked_after_cliff() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let current_time = suite.get_time();
+
+    let params = CampaignParams {
+        name: "Cliff Campaign".to_string(),
+        description: "Lump sum with cliff".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: coin(10_000, "uom"),
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            cliff_duration: Some(DAY * 3),
+        }],
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_seconds(DAY * 7),
+        ..Default::default()
+    };
+
+    setup_campaign(&mut suite, params);
+
+    suite
+        .add_day()
+        .query_rewards(bob, |result| {
+            let rewards = result.unwrap();
+            assert!(rewards.available_to_claim.is_empty());
+            assert_eq!(rewards.pending, vec![coin(10_000, "uom")]);
+        })
+        .claim(bob, None, None, assert_nothing_to_claim)
+        .add_day()
+        .add_day()
+        // the cliff ends one second after three days have passed
+        .claim(bob, None, None, assert_nothing_to_claim)
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_010_000));
+        });
+}
+
+#[test]
+fn campaign_cliff_gates_all_distributions() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let current_time = suite.get_time();
+
+    let params = CampaignParams {
+        name: "Cliff Campaign".to_string(),
+        description: "Campaign with a global cliff".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: coin(10_000, "uom"),
+        distribution_type: vec![
+            DistributionType::LumpSum {
+                percentage: Decimal::percent(30),
+                start_time: current_time.plus_seconds(1),
+                cliff_duration: None,
+            },
+            DistributionType::LinearVesting {
+                percentage: Decimal::percent(70),
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_seconds(1 + DAY * 10),
+                cliff_duration: None,
+                cliff_behavior: None,
+            },
+        ],
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_seconds(1 + DAY * 10),
+        cliff_duration: Some(DAY * 5),
+        ..Default::default()
+    };
+
+    setup_campaign(&mut suite, params);
+
+    suite
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().cliff_duration, Some(DAY * 5));
+        })
+        .add_day()
+        .claim(bob, None, None, assert_nothing_to_claim)
+        .add_day()
+        .add_day()
+        .add_day()
+        .add_day()
+        // the cliff ends one second after five days have passed
+        .claim(bob, None, None, assert_nothing_to_claim)
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // lump sum + 60% of the linear vesting, accrued since the distribution start
+        .query_balance("uom", bob, |balance| {
+            let claimed = balance - Uint128::new(1_000_000_000);
+            assert!(claimed > Uint128::new(7_190) && claimed <= Uint128::new(7_200));
+        });
+}
+
+/// Sets up a linear vesting of 10_000 over 10 days with a cliff of 4 days, and returns what bob
+/// claimed one day after the cliff, and by the end of the vesting.
+fn claim_after_linear_cliff(cliff_behavior: Option<CliffBehavior>) -> (Uint128, Uint128) {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let current_time = suite.get_time();
+
+    let params = CampaignParams {
+        name: "Cliff Campaign".to_string(),
+        description: "Linear vesting with cliff".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: coin(10_000, "uom"),
+        distribution_type: vec![DistributionType::LinearVesting {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            end_time: current_time.plus_seconds(1 + DAY * 10),
+            cliff_duration: Some(DAY * 4),
+            cliff_behavior,
+        }],
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_seconds(1 + DAY * 10),
+        ..Default::default()
+    };
+
+    setup_campaign(&mut suite, params);
+
+    let claimed = |suite: &mut TestingSuite| {
+        let balance = Cell::new(Uint128::zero());
+        suite.query_balance("uom", bob, |b| balance.set(b));
+        balance.get() - Uint128::new(1_000_000_000)
+    };
+
+    suite
+        .add_day()
+        .add_day()
+        .add_day()
+        .add_day()
+        // the cliff ends one second after four days have passed
+        .query_rewards(bob, |result| {
+            assert!(result.unwrap().available_to_claim.is_empty());
+        })
+        .claim(bob, None, None, assert_nothing_to_claim)
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+    let after_cliff = claimed(&mut suite);
+
+    suite
+        .add_day()
+        .add_day()
+        .add_day()
+        .add_day()
+        .add_day()
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    (after_cliff, claimed(&mut suite))
+}
+
+#[test]
+fn linear_vesting_releases_the_accrued_amount_at_the_cliff() {
+    // 5 days minus a second of the 10 days accrued since the start unlock at once
+    let expected = (Uint128::new(4_999), Uint128::new(10_000));
+
+    assert_eq!(claim_after_linear_cliff(None), expected);
+    assert_eq!(
+        claim_after_linear_cliff(Some(CliffBehavior::ReleaseAccrued)),
+        expected
+    );
+}
+
+#[test]
+fn linear_vesting_only_accrues_after_a_delaying_cliff() {
+    // a day minus a second of the 6 days left after the cliff, yet fully vested at the end
+    assert_eq!(
+        claim_after_linear_cliff(Some(CliffBehavior::DelayOnly)),
+        (Uint128::new(1_666), Uint128::new(10_000))
+    );
+}
+
+#[test]
+fn validate_cliff_params() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = suite.get_time();
+
+    let params = CampaignParams {
+        name: "Cliff Campaign".to_string(),
+        description: "Invalid cliffs".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: coin(10_000, "uom"),
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            cliff_duration: None,
+        }],
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_seconds(1 + DAY * 7),
+        ..Default::default()
+    };
+
+    let invalid_params = vec![
+        (
+            CampaignParams {
+                cliff_duration: Some(0),
+                ..params.clone()
+            },
+            "cannot be zero",
+        ),
+        (
+            CampaignParams {
+                cliff_duration: Some(DAY * 7),
+                ..params.clone()
+            },
+            "cannot be greater or equal than the campaign duration",
+        ),
+        (
+            CampaignParams {
+                distribution_type: vec![DistributionType::LumpSum {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    cliff_duration: Some(0),
+                }],
+                ..params.clone()
+            },
+            "cannot be zero",
+        ),
+        (
+            CampaignParams {
+                distribution_type: vec![DistributionType::LumpSum {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    cliff_duration: Some(DAY * 7 + 1),
+                }],
+                ..params.clone()
+            },
+            "cannot end after the campaign end time",
+        ),
+        (
+            CampaignParams {
+                distribution_type: vec![DistributionType::LinearVesting {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(1 + DAY * 7),
+                    cliff_duration: None,
+                    cliff_behavior: Some(CliffBehavior::DelayOnly),
+                }],
+                ..params.clone()
+            },
+            "cannot be set without a cliff duration",
+        ),
+    ];
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for (params, expected_reason) in invalid_params {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, reason } => {
+                        assert!(param == "cliff_duration" || param == "cliff_behavior");
+                        assert_eq!(reason, expected_reason);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+
+    suite.manage_campaign(
+        owner,
+        CampaignAction::CreateCampaign {
+            params: Box::new(CampaignParams {
+                cliff_duration: Some(DAY),
+                ..params
+            }),
+        },
+        &[],
+        |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        },
+    );
+}
@@ -0,0 +1,159 @@
+use cosmwasm_std::{coin, Addr, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CloneOverrides, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+/// Sets up a monthly campaign where bob claimed, returning the source contract address and the
+/// start time of the campaign.
+fn setup_source_contract(suite: &mut TestingSuite) -> (Addr, Timestamp) {
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let start_time = suite.get_time().plus_seconds(1);
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+            ],
+            CampaignParams {
+                name: "January Drop".to_string(),
+                description: "Monthly reward drop".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(3_000, "uom"),
+                distribution_type: vec![
+                    DistributionType::LumpSum {
+                        percentage: Decimal::percent(50),
+                        start_time,
+                        cliff_duration: None,
+                    },
+                    DistributionType::LinearVesting {
+                        percentage: Decimal::percent(50),
+                        start_time,
+                        end_time: start_time.plus_days(3),
+                        cliff_duration: None,
+                        cliff_behavior: None,
+                    },
+                ],
+                start_time,
+                end_time: start_time.plus_days(3),
+                ..Default::default()
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    (suite.claimdrop_contract_addr.clone(), start_time)
+}
+
+#[test]
+fn finished_campaign_is_cloned_with_its_schedule_shifted() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    let (source_contract, source_start_time) = setup_source_contract(&mut suite);
+    suite.add_week();
+    let start_time = suite.get_time().plus_days(1);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CloneCampaign {
+                source_contract: source_contract.to_string(),
+                start_time,
+                overrides: Box::new(CloneOverrides {
+                    name: Some("February Drop".to_string()),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            let offset = start_time.seconds() - source_start_time.seconds();
+
+            assert_eq!(campaign.name, "February Drop");
+            assert_eq!(campaign.description, "Monthly reward drop");
+            assert_eq!(campaign.total_reward, coin(3_000, "uom"));
+            assert_eq!(campaign.claimed, coin(0, "uom"));
+            assert_eq!(campaign.start_time, start_time);
+            assert_eq!(campaign.end_time, start_time.plus_days(3));
+            assert_eq!(
+                campaign.distribution_type[1],
+                DistributionType::LinearVesting {
+                    percentage: Decimal::percent(50),
+                    start_time: source_start_time.plus_seconds(offset),
+                    end_time: source_start_time.plus_days(3).plus_seconds(offset),
+                    cliff_duration: None,
+                    cliff_behavior: None,
+                }
+            );
+        })
+        // the recurring drop keeps the allocations of the addresses that claimed
+        .import_all_allocations(
+            owner,
+            &source_contract,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(None, None, None, |result| {
+            let mut addresses = result
+                .unwrap()
+                .allocations
+                .into_iter()
+                .map(|(address, _)| address)
+                .collect::<Vec<_>>();
+            addresses.sort();
+            let mut expected = vec![bob.to_string(), carol.to_string()];
+            expected.sort();
+            assert_eq!(addresses, expected);
+        });
+}
+
+#[test]
+fn cant_clone_a_campaign_that_has_not_ended() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    let (source_contract, _) = setup_source_contract(&mut suite);
+    let start_time = suite.get_time().plus_days(1);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CloneCampaign {
+                source_contract: source_contract.to_string(),
+                start_time,
+                overrides: Box::default(),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "only finished campaigns can be cloned");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        );
+}
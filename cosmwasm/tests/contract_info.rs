@@ -0,0 +1,44 @@
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{Limits, DEFAULT_MAX_DISTRIBUTION_SLOTS};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn contract_info_reports_the_version_features_and_limits() {
+    let mut suite = TestingSuite::default_with_balances(vec![]);
+    let owner = &suite.senders[0].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .query_contract_info(|result| {
+            let info = result.unwrap();
+            assert_eq!(info.contract, "mantra_claimdrop-contract");
+            assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+            assert!(info.features.contains(&"mint_on_claim".to_string()));
+            assert!(!info.features.contains(&"cw20".to_string()));
+            assert_eq!(info.limits.max_allocation_batch_size, 6000);
+            assert_eq!(
+                info.limits.max_distribution_slots,
+                DEFAULT_MAX_DISTRIBUTION_SLOTS
+            );
+        })
+        .update_limits(
+            owner,
+            Limits {
+                max_allocation_batch_size: Some(100),
+                max_distribution_slots: Some(12),
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the configured limits override the defaults
+        .query_contract_info(|result| {
+            let limits = result.unwrap().limits;
+            assert_eq!(limits.max_allocation_batch_size, 100);
+            assert_eq!(limits.max_distribution_slots, 12);
+            assert_eq!(limits.max_allowlist_batch_size, 3000);
+        });
+}
@@ -0,0 +1,138 @@
+use cosmwasm_std::{coin, Decimal, StdResult, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, CountsResponse, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn counts_are_kept_in_sync() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let eve = &suite.senders[4].clone();
+    let current_time = &suite.get_time();
+
+    let assert_counts = |allocations: u64, claimants: u64, blacklisted: u64| {
+        move |result: StdResult<CountsResponse>| {
+            assert_eq!(
+                result.unwrap(),
+                CountsResponse {
+                    allocations,
+                    claimants,
+                    blacklisted,
+                }
+            );
+        }
+    };
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .query_counts(assert_counts(0, 0, 0))
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(1_000)),
+                (dan.to_string(), Uint128::new(1_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            owner,
+            dan,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // blacklisting twice doesn't double count
+        .blacklist_address(
+            owner,
+            dan,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_counts(assert_counts(3, 0, 1))
+        .remove_address(owner, dan, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        // removing a missing address doesn't change the counts
+        .remove_address(owner, dan, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_counts(assert_counts(2, 0, 0))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Counted Campaign".to_string(),
+                    description: "Campaign with counters".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(2_000, "uom"),
+                    distribution_type: vec![DistributionType::LinearVesting {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        end_time: current_time.plus_days(7),
+                        cliff_duration: None,
+                        cliff_behavior: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(2_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        // a second claim from the same address doesn't double count
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_counts(assert_counts(2, 1, 0))
+        // replacing an address moves its entries without changing the counts
+        .replace_address(
+            owner,
+            bob,
+            eve,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_counts(assert_counts(2, 1, 0))
+        .query_campaign_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.allocations_count, 2);
+            assert_eq!(status.claimants_count, 1);
+        });
+}
@@ -0,0 +1,195 @@
+use claimdrop_contract::helpers::curve_vesting_progress;
+use cosmwasm_std::{coin, Decimal, Decimal256, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, Curve, DistributionType};
+use proptest::prelude::*;
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, curve: Curve) -> CampaignParams {
+    CampaignParams {
+        distribution_type: vec![DistributionType::CurveVesting {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            end_time: current_time.plus_seconds(1).plus_days(4),
+            curve,
+        }],
+        end_time: current_time.plus_seconds(1).plus_days(4),
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+#[test]
+fn piecewise_linear_curve_interpolates_between_points() {
+    let curve = Curve::PiecewiseLinear {
+        points: vec![(100, Decimal::percent(10)), (200, Decimal::percent(50))],
+    };
+
+    for (elapsed, expected) in [
+        (0, Decimal256::zero()),
+        (50, Decimal256::percent(5)),
+        (100, Decimal256::percent(10)),
+        (150, Decimal256::percent(30)),
+        (300, Decimal256::percent(75)),
+        (400, Decimal256::one()),
+    ] {
+        assert_eq!(
+            curve_vesting_progress(&curve, elapsed, 400).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn exponential_curve_is_back_loaded() {
+    let curve = Curve::Exponential {
+        rate: Decimal::one(),
+    };
+
+    assert_eq!(
+        curve_vesting_progress(&curve, 0, 1_000).unwrap(),
+        Decimal256::zero()
+    );
+    assert_eq!(
+        curve_vesting_progress(&curve, 1_000, 1_000).unwrap(),
+        Decimal256::one()
+    );
+
+    // (e^0.5 - 1) / (e - 1) ~ 0.3775
+    let halfway = curve_vesting_progress(&curve, 500, 1_000).unwrap();
+    assert!(halfway > Decimal256::permille(377) && halfway < Decimal256::permille(378));
+}
+
+proptest! {
+    #[test]
+    fn exponential_curve_is_monotonic(
+        rate in 1u64..=10_000,
+        duration in 1u64..10_000_000,
+        elapsed in (0u64..10_000_000, 0u64..10_000_000),
+    ) {
+        let curve = Curve::Exponential { rate: Decimal::permille(rate) };
+        let earlier = elapsed.0.min(elapsed.1).min(duration);
+        let later = elapsed.0.max(elapsed.1).min(duration);
+
+        let earlier_progress = curve_vesting_progress(&curve, earlier, duration).unwrap();
+        let later_progress = curve_vesting_progress(&curve, later, duration).unwrap();
+
+        prop_assert!(earlier_progress <= later_progress);
+        prop_assert!(later_progress <= Decimal256::one());
+    }
+}
+
+#[test]
+fn claim_from_curve_vesting() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(10_000))],
+            campaign_params(
+                current_time,
+                Curve::PiecewiseLinear {
+                    points: vec![
+                        (86_400, Decimal::percent(10)),
+                        (86_400 * 3, Decimal::percent(40)),
+                    ],
+                },
+            ),
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // one second short of the first point
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_999));
+        })
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // halfway between the points, at 25%
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_002_499));
+        })
+        // past the end time, the slot is fully vested
+        .add_day()
+        .add_day()
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_010_000));
+        });
+}
+
+#[test]
+fn invalid_curves_are_rejected() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for curve in [
+        Curve::Exponential {
+            rate: Decimal::zero(),
+        },
+        Curve::Exponential {
+            rate: Decimal::percent(1_001),
+        },
+        Curve::PiecewiseLinear { points: vec![] },
+        // not increasing in time
+        Curve::PiecewiseLinear {
+            points: vec![(200, Decimal::percent(10)), (100, Decimal::percent(50))],
+        },
+        // decreasing vested share
+        Curve::PiecewiseLinear {
+            points: vec![(100, Decimal::percent(50)), (200, Decimal::percent(10))],
+        },
+        // beyond the vesting duration
+        Curve::PiecewiseLinear {
+            points: vec![(86_400 * 4, Decimal::percent(50))],
+        },
+    ] {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, curve)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "curve");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+}
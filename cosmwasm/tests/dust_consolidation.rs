@@ -0,0 +1,94 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn rounding_dust_is_paid_on_the_final_claim_of_its_slot() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(alice.to_string(), Uint128::new(101))],
+            CampaignParams {
+                name: "Dusty Campaign".to_string(),
+                description: "Campaign with rounding dust".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(101, "uom"),
+                // 50.5% and 49.5% of 101 are 51.005 and 49.995, leaving 1 token of dust
+                distribution_type: vec![
+                    DistributionType::LumpSum {
+                        percentage: Decimal::permille(505),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    },
+                    DistributionType::LinearVesting {
+                        percentage: Decimal::permille(495),
+                        start_time: current_time.plus_seconds(1),
+                        end_time: current_time.plus_days(10),
+                        cliff_duration: None,
+                        cliff_behavior: None,
+                    },
+                ],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(30),
+                ..Default::default()
+            },
+        )
+        .add_day()
+        // the dust goes to the linear vesting, which has the largest rounding remainder
+        .query_simulate_claim(alice, |result| {
+            let simulation = result.unwrap();
+            assert_eq!(simulation.slots.len(), 2);
+
+            assert_eq!(simulation.slots[0].allocation, coin(51, "uom"));
+            assert_eq!(simulation.slots[0].rounding_remainder, Uint128::zero());
+            assert_eq!(simulation.slots[0].claimable, coin(51, "uom"));
+
+            assert_eq!(simulation.slots[1].allocation, coin(50, "uom"));
+            assert_eq!(simulation.slots[1].rounding_remainder, Uint128::one());
+            assert_eq!(simulation.slots[1].claimable, coin(4, "uom"));
+
+            assert_eq!(simulation.claimable, coin(55, "uom"));
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    for _ in 0..9 {
+        suite.add_day();
+    }
+
+    // the linear vesting has ended, so its final claim pays the dust even though the campaign is
+    // still running
+    suite
+        .query_simulate_claim(alice, |result| {
+            let simulation = result.unwrap();
+            assert_eq!(simulation.slots[0].claimed, coin(51, "uom"));
+            assert_eq!(simulation.slots[0].claimable, coin(0, "uom"));
+            assert_eq!(simulation.slots[1].claimed, coin(4, "uom"));
+            assert_eq!(simulation.slots[1].claimable, coin(46, "uom"));
+            assert_eq!(simulation.claimable, coin(46, "uom"));
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_101));
+        });
+}
@@ -37,12 +37,14 @@ fn can_claim_dust_after_vesting_ends() {
                     total_reward: coin(23, "uom"),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::percent(100),
-                        start_time: current_time.seconds(),
-                        end_time: current_time.plus_days(60).seconds(),
+                        start_time: *current_time,
+                        end_time: current_time.plus_days(60),
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds(),
-                    end_time: current_time.plus_days(90).seconds(),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(90),
+                    ..Default::default()
                 }),
             },
             &[], // No funds during campaign creation
@@ -88,16 +90,20 @@ fn can_claim_dust_after_vesting_ends() {
     // in 30 days.
     suite.add_day();
 
-    // executing the claiming here, will result on the compute_claimable_amount::new_claims being empty,
-    // as the claim_amount will be zero, while the rounding_error_compensation_amount will be 1.
+    // executing the claiming here, the final claim of the vesting slot pays the rounding dust.
     suite
         .query_rewards(alice, |result| {
+            let rewards = result.unwrap();
             assert_eq!(
-                result.unwrap(),
+                rewards,
                 RewardsResponse {
+                    total_allocation: coins(17u128, "uom"),
                     claimed: coins(16u128, "uom"),
                     pending: coins(17u128 - 16u128, "uom"),
                     available_to_claim: coins(17u128 - 16u128, "uom"),
+                    locked: vec![],
+                    accrual_rate: rewards.accrual_rate,
+                    next_unlocks: rewards.next_unlocks.clone(),
                 }
             );
         })
@@ -151,17 +157,20 @@ fn can_claim_dust_after_vesting_ends_2() {
                     distribution_type: vec![
                         DistributionType::LumpSum {
                             percentage: Decimal::percent(25),
-                            start_time: current_time.seconds(),
+                            start_time: *current_time,
+                            cliff_duration: None,
                         },
                         DistributionType::LinearVesting {
                             percentage: Decimal::percent(75),
-                            start_time: current_time.seconds(),
-                            end_time: current_time.plus_days(60).seconds(),
+                            start_time: *current_time,
+                            end_time: current_time.plus_days(60),
                             cliff_duration: None,
+                            cliff_behavior: None,
                         },
                     ],
-                    start_time: current_time.seconds(),
-                    end_time: current_time.plus_days(90).seconds(),
+                    start_time: *current_time,
+                    end_time: current_time.plus_days(90),
+                    ..Default::default()
                 }),
             },
             &[], // No funds during campaign creation
@@ -209,16 +218,20 @@ fn can_claim_dust_after_vesting_ends_2() {
         suite.add_day();
     }
 
-    // executing the claiming here, will result on the compute_claimable_amount::new_claims being empty,
-    // as the claim_amount will be zero, while the rounding_error_compensation_amount will be 1.
+    // executing the claiming here, the final claim of the vesting slot pays the rounding dust.
     suite
         .query_rewards(alice, |result| {
+            let rewards = result.unwrap();
             assert_eq!(
-                result.unwrap(),
+                rewards,
                 RewardsResponse {
+                    total_allocation: coins(17u128, "uom"),
                     claimed: coins(10u128, "uom"),
                     pending: coins(17u128 - 10u128, "uom"),
                     available_to_claim: coins(17u128 - 10u128, "uom"),
+                    locked: vec![],
+                    accrual_rate: rewards.accrual_rate,
+                    next_unlocks: rewards.next_unlocks.clone(),
                 }
             );
         })
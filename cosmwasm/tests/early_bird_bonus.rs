@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use claimdrop_contract::helpers::compute_early_bird_bonus;
+use cosmwasm_std::{coin, Decimal, Event, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{Campaign, CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(
+    current_time: &Timestamp,
+    distribution_type: Vec<DistributionType>,
+) -> CampaignParams {
+    CampaignParams {
+        distribution_type,
+        end_time: current_time.plus_days(30),
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+fn early_bird_bonus(base_percentage: Decimal, bonus_deadline: Timestamp) -> DistributionType {
+    DistributionType::EarlyBirdBonus {
+        base_percentage,
+        bonus_percentage: Decimal::percent(10),
+        bonus_deadline,
+    }
+}
+
+#[test]
+fn early_claimers_get_a_bonus_until_the_deadline() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(600)),
+                (carol.to_string(), Uint128::new(600)),
+                (dan.to_string(), Uint128::new(100)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    total_reward: coin(1_300, "uom"),
+                    ..campaign_params(
+                        current_time,
+                        vec![early_bird_bonus(Decimal::one(), current_time.plus_days(7))],
+                    )
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_500, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(
+                    response.has_event(&Event::new("wasm").add_attribute("early_bird_bonus", "60"))
+                );
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_660));
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_660));
+        })
+        // the bonus isn't counted towards the allocation
+        .query_claimed(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(600, "uom"));
+        })
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claimed, coin(1_320, "uom"));
+        })
+        .add_week()
+        .claim(
+            dan,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", dan, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_100));
+        });
+}
+
+#[test]
+fn bonus_is_capped_by_the_remaining_pool() {
+    let current_time = Timestamp::from_seconds(1_000);
+    let campaign = Campaign::from_params(campaign_params(
+        &current_time,
+        vec![early_bird_bonus(Decimal::one(), current_time.plus_days(7))],
+    ));
+    let claims_to_record = HashMap::from([(0, (Uint128::new(600), current_time.seconds()))]);
+
+    // the pool is 10% of the total reward, so only 40 are left after 60 were paid out
+    for (bonus_claimed, expected_bonus) in [(0, 60), (60, 40), (100, 0)] {
+        let bonus = compute_early_bird_bonus(
+            &campaign,
+            &current_time.plus_days(1),
+            Uint128::new(600),
+            &HashMap::new(),
+            &claims_to_record,
+            Uint128::new(bonus_claimed),
+        )
+        .unwrap();
+        assert_eq!(bonus, Uint128::new(expected_bonus));
+    }
+}
+
+#[test]
+fn bonus_is_paid_once_on_the_first_claim_from_the_bonus_slot() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    vec![
+                        early_bird_bonus(Decimal::percent(50), current_time.plus_days(7)),
+                        DistributionType::LinearVesting {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_seconds(1),
+                            end_time: current_time.plus_days(10),
+                            cliff_duration: None,
+                            cliff_behavior: None,
+                        },
+                    ],
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_100, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        // claims 200 out of the 500 of the early bird slot, plus the 100 bonus
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(200)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_300));
+        })
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(200)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_500));
+        });
+}
+
+#[test]
+fn cannot_create_invalid_early_bird_bonus() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    vec![
+                        early_bird_bonus(Decimal::percent(50), current_time.plus_days(7)),
+                        early_bird_bonus(Decimal::percent(50), current_time.plus_days(7)),
+                    ],
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "distribution_type");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    vec![early_bird_bonus(Decimal::one(), current_time.plus_days(31))],
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "bonus_deadline");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    vec![DistributionType::EarlyBirdBonus {
+                        base_percentage: Decimal::one(),
+                        bonus_percentage: Decimal::zero(),
+                        bonus_deadline: current_time.plus_days(7),
+                    }],
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "bonus_percentage");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+}
@@ -0,0 +1,139 @@
+use cosmwasm_std::{coin, Addr, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, Eligibility};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, eligibility: Option<Eligibility>) -> CampaignParams {
+    CampaignParams {
+        eligibility,
+        ..suite::campaign_params(current_time, 2_000)
+    }
+}
+
+fn oracle_check(oracle: &Addr, query_template: &str) -> Option<Eligibility> {
+    Some(Eligibility::OracleCheck {
+        contract: oracle.to_string(),
+        query_template: query_template.to_string(),
+    })
+}
+
+#[test]
+fn only_the_addresses_passing_the_oracle_check_can_claim() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+    let oracle = &suite.instantiate_eligibility_oracle_contract(&[alice]);
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(1_000)),
+                (bob.to_string(), Uint128::new(1_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    oracle_check(oracle, r#"{"is_eligible":{"address":"{address}"}}"#),
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(2_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressNotEligible { address } => {
+                        assert_eq!(address, bob.to_string())
+                    }
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::AddressNotEligible")
+                    }
+                }
+            },
+        )
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000 + 1_000));
+        });
+}
+
+#[test]
+fn the_query_template_must_contain_the_address_placeholder() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+    let oracle = &suite.instantiate_eligibility_oracle_contract(&[]);
+
+    suite
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    oracle_check(oracle, r#"{"is_eligible":{}}"#),
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "eligibility")
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    oracle_check(&Addr::unchecked("not an address"), "{address}"),
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        );
+}
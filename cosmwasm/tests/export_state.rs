@@ -0,0 +1,128 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::export::{RawClaim, StateEntry, StateSection};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+#[test]
+fn the_state_is_exported_page_by_page() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(200)),
+                (bob.to_string(), Uint128::new(300)),
+                (carol.to_string(), Uint128::new(500)),
+            ],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            owner,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_authorized_wallets(
+            owner,
+            vec![alice.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    // the allocations are exported sorted by address
+    let mut expected = [
+        (alice.to_string(), Uint128::new(200), false),
+        (bob.to_string(), Uint128::new(300), true),
+        (carol.to_string(), Uint128::new(500), false),
+    ];
+    expected.sort();
+    let allocations = |entries: Vec<StateEntry>| -> Vec<(String, Uint128, bool)> {
+        entries
+            .into_iter()
+            .map(|entry| match entry {
+                StateEntry::Allocation {
+                    address,
+                    amount,
+                    claimed,
+                    ..
+                } => (address, amount, claimed),
+                _ => panic!("Wrong entry type, should be StateEntry::Allocation"),
+            })
+            .collect()
+    };
+
+    suite
+        .query_export_state(StateSection::Allocations, None, Some(2), |result| {
+            let response = result.unwrap();
+            assert_eq!(allocations(response.entries), expected[..2]);
+            assert_eq!(response.next_start_after, Some(expected[1].0.clone()));
+        })
+        .query_export_state(
+            StateSection::Allocations,
+            Some(expected[1].0.clone()),
+            Some(2),
+            |result| {
+                let response = result.unwrap();
+                assert_eq!(allocations(response.entries), expected[2..]);
+                assert_eq!(response.next_start_after, None);
+            },
+        );
+
+    suite
+        .query_export_state(StateSection::Claims, None, None, |result| {
+            let response = result.unwrap();
+            assert_eq!(response.next_start_after, None);
+            match &response.entries[..] {
+                [StateEntry::Claims { address, claims }] => {
+                    assert_eq!(address, &bob.to_string());
+                    assert_eq!(
+                        claims,
+                        &vec![RawClaim {
+                            slot: 0,
+                            amount: Uint128::new(300),
+                            claimed_at: current_time.plus_days(1).seconds(),
+                        }]
+                    );
+                }
+                _ => panic!("Wrong entries, should be bob's claims"),
+            }
+        })
+        .query_export_state(StateSection::Blacklist, None, None, |result| {
+            let entries = result.unwrap().entries;
+            match &entries[..] {
+                [StateEntry::Blacklisted { address, .. }] => {
+                    assert_eq!(address, &carol.to_string())
+                }
+                _ => panic!("Wrong entries, should be carol's blacklisting"),
+            }
+        })
+        .query_export_state(StateSection::AuthorizedWallets, None, None, |result| {
+            assert_eq!(
+                result.unwrap().entries,
+                vec![StateEntry::AuthorizedWallet {
+                    address: alice.to_string()
+                }]
+            );
+        });
+}
@@ -0,0 +1,184 @@
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType, FeeGrant};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, fee_grant: Option<FeeGrant>) -> CampaignParams {
+    CampaignParams {
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_days(1),
+            cliff_duration: None,
+        }],
+        start_time: current_time.plus_days(1),
+        end_time: current_time.plus_days(10),
+        fee_grant,
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+fn granted_allowances(response: &AppResponse) -> Vec<(String, String, String)> {
+    response
+        .events
+        .iter()
+        .filter(|event| event.ty == "grant_allowance")
+        .map(|event| {
+            let attribute = |key: &str| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (
+                attribute("grantee"),
+                attribute("spend_limit"),
+                attribute("expiration"),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn adding_allocations_grants_fee_allowances() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+    let expiration = current_time.plus_days(30);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    Some(FeeGrant {
+                        spend_limit: coin(50_000, "uom"),
+                        expiration: Some(expiration),
+                    }),
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(5_000)),
+                (carol.to_string(), Uint128::new(5_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(
+                    granted_allowances(&response),
+                    vec![
+                        (
+                            bob.to_string(),
+                            "50000uom".to_string(),
+                            expiration.seconds().to_string()
+                        ),
+                        (
+                            carol.to_string(),
+                            "50000uom".to_string(),
+                            expiration.seconds().to_string()
+                        ),
+                    ]
+                );
+            },
+        );
+}
+
+#[test]
+fn no_fee_allowances_are_granted_by_default() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        // there's no campaign yet, so there's no fee grant to issue
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(5_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert!(granted_allowances(&result.unwrap()).is_empty());
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, None)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(carol.to_string(), Uint128::new(5_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert!(granted_allowances(&result.unwrap()).is_empty());
+            },
+        );
+}
+
+#[test]
+fn fee_grant_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    let invalid_fee_grants = [
+        (
+            FeeGrant {
+                spend_limit: coin(0, "uom"),
+                expiration: None,
+            },
+            "the spend limit must be greater than zero",
+        ),
+        (
+            FeeGrant {
+                spend_limit: coin(50_000, "uom"),
+                expiration: Some(current_time.minus_seconds(1)),
+            },
+            "the expiration must be in the future",
+        ),
+    ];
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for (fee_grant, expected_reason) in invalid_fee_grants {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, Some(fee_grant))),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, reason } => {
+                        assert_eq!(param, "fee_grant");
+                        assert_eq!(reason, expected_reason);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+}
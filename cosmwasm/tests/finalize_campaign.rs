@@ -0,0 +1,130 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::CampaignParams;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, grace_period: Option<u64>) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.setup_campaign(
+        &[(bob.to_string(), Uint128::new(4_000))],
+        CampaignParams {
+            end_time: current_time.plus_days(2),
+            grace_period,
+            ..campaign_params(current_time, 10_000)
+        },
+    );
+}
+
+#[test]
+fn anyone_can_finalize_campaign_after_grace_period() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, Some(86_400 * 7));
+
+    suite
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .add_day()
+        // the campaign has ended, but the grace period hasn't passed yet
+        .finalize_campaign(carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert!(reason.starts_with("the campaign can't be finalized until"));
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .add_week()
+        .finalize_campaign(carol, |result: Result<AppResponse, anyhow::Error>| {
+            let response = result.unwrap();
+            assert!(response.events.iter().any(|event| event
+                .attributes
+                .iter()
+                .any(|attribute| attribute.key == "finalized_by"
+                    && attribute.value == carol.to_string())));
+        })
+        // the remaining funds are refunded to the owner
+        .query_balance("uom", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000 - 4_000));
+        })
+        .query_campaign(|result| {
+            assert!(result.unwrap().closed.is_some());
+        })
+        .finalize_campaign(carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignClosed => {}
+                _ => panic!("Wrong error type, should return ContractError::CampaignClosed"),
+            }
+        });
+}
+
+#[test]
+fn cannot_finalize_campaign_without_grace_period() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, None);
+
+    suite
+        .add_week()
+        .add_week()
+        .finalize_campaign(carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(
+                        reason,
+                        "the campaign has no grace period, only the owner can close it"
+                    );
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .query_campaign(|result| {
+            assert!(result.unwrap().closed.is_none());
+        });
+}
+
+#[test]
+fn cannot_finalize_campaign_refunding_the_owner_once_renounced() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, Some(86_400));
+
+    suite
+        .update_ownership(
+            owner,
+            cw_ownable::Action::RenounceOwnership,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_week()
+        .finalize_campaign(carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        });
+}
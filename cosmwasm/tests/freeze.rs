@@ -0,0 +1,229 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::FrozenResolution;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup(suite: &mut TestingSuite) {
+    let _owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(200)),
+                (bob.to_string(), Uint128::new(300)),
+                (carol.to_string(), Uint128::new(500)),
+            ],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn frozen_addresses_cannot_claim_until_released() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .freeze_address(
+            alice,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .freeze_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .freeze_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressFrozen => {}
+                    _ => panic!("Wrong error type, should return ContractError::AddressFrozen"),
+                }
+            },
+        )
+        .query_frozen_entry(carol, |result| {
+            let entry = result.unwrap().entry.unwrap();
+            assert_eq!(entry.frozen_by, owner.to_string());
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressFrozen => {}
+                    _ => panic!("Wrong error type, should return ContractError::AddressFrozen"),
+                }
+            },
+        )
+        .resolve_frozen(
+            owner,
+            carol,
+            FrozenResolution::Release,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_frozen_entry(carol, |result| {
+            assert!(result.unwrap().entry.is_none());
+        })
+        .resolve_frozen(
+            owner,
+            carol,
+            FrozenResolution::Release,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_500));
+        });
+}
+
+#[test]
+fn frozen_allocations_are_redistributed_once_forfeited() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .blacklist_address(
+            owner,
+            bob,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .freeze_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // carol's allocation is neither reclaimed nor topped up while frozen
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_allocations(Some(alice), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(500, "uom"));
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(500, "uom"));
+        })
+        .resolve_frozen(
+            owner,
+            carol,
+            FrozenResolution::Forfeit,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_blacklist_entry(carol, |result| {
+            assert!(result.unwrap().entry.is_some());
+        })
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_allocations(Some(alice), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(1_000, "uom"));
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(0, "uom"));
+        });
+}
+
+#[test]
+fn forfeiting_a_blacklisted_frozen_address_keeps_its_blacklist_entry() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .freeze_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address_with_reason(
+            owner,
+            carol,
+            "sybil",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    let blacklisted_at = suite.get_time().seconds();
+
+    suite
+        .add_day()
+        .resolve_frozen(
+            owner,
+            carol,
+            FrozenResolution::Forfeit,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_frozen_entry(carol, |result| {
+            assert!(result.unwrap().entry.is_none());
+        })
+        // the original blacklist entry is kept, and the address is counted once
+        .query_blacklist_entry(carol, |result| {
+            let entry = result.unwrap().entry.unwrap();
+            assert_eq!(entry.blacklisted_at, blacklisted_at);
+            assert_eq!(entry.reason, Some("sybil".to_string()));
+        })
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().blacklisted, 1);
+        });
+}
@@ -0,0 +1,143 @@
+use cosmwasm_std::{coin, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, require_full_funding: bool) -> CampaignParams {
+    CampaignParams {
+        require_full_funding,
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+fn setup(suite: &mut TestingSuite, require_full_funding: bool) {
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(400)),
+                (carol.to_string(), Uint128::new(600)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, require_full_funding)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(500, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn campaigns_requiring_full_funding_go_live_once_funded() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup(&mut suite, true);
+
+    suite
+        .query_is_live(|result| {
+            assert!(!result.unwrap().is_live);
+        })
+        .add_day()
+        // started, but underfunded
+        .query_is_live(|result| {
+            assert!(!result.unwrap().is_live);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignNotFullyFunded {
+                        required,
+                        available,
+                    } => {
+                        assert_eq!(required, Uint128::new(1_000));
+                        assert_eq!(available, Uint128::new(500));
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignNotFullyFunded"
+                    ),
+                }
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(500, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_live(|result| {
+            assert!(result.unwrap().is_live);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the balance covers what's left to claim
+        .query_is_live(|result| {
+            assert!(result.unwrap().is_live);
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn underfunded_campaigns_are_live_unless_they_require_full_funding() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup(&mut suite, false);
+
+    suite
+        .add_day()
+        .query_is_live(|result| {
+            assert!(result.unwrap().is_live);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
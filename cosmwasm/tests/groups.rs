@@ -0,0 +1,275 @@
+use cosmwasm_std::{coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+use sha2::{Digest, Sha256};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn team_schedule(current_time: &Timestamp) -> Vec<DistributionType> {
+    vec![DistributionType::LinearVesting {
+        percentage: Decimal::one(),
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_seconds(172_801),
+        cliff_duration: None,
+        cliff_behavior: None,
+    }]
+}
+
+#[test]
+fn group_members_claim_the_pool_pro_rata_to_their_weights() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(&[], campaign_params(current_time, 10_000))
+        .create_group(
+            alice,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(6_000),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .create_group(
+            owner,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(10_001),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .create_group(
+            owner,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(6_000),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_group_members(
+            owner,
+            "team",
+            vec![
+                (bob.to_string(), Uint128::new(2)),
+                (carol.to_string(), Uint128::new(1)),
+                (alice.to_string(), Uint128::new(1)),
+            ],
+            vec![],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_group_members(
+            owner,
+            "team",
+            vec![],
+            vec![alice.to_string()],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_group("team", |result| {
+            let group = result.unwrap();
+            assert_eq!(group.total, coin(6_000, "uom"));
+            assert_eq!(group.total_weight, Uint128::new(3));
+            assert_eq!(group.members, 2);
+        })
+        .add_day()
+        .update_group_members(
+            owner,
+            "team",
+            vec![(alice.to_string(), Uint128::new(1))],
+            vec![],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignAlreadyStarted { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignAlreadyStarted"
+                    ),
+                }
+            },
+        )
+        .claim_group(
+            alice,
+            "team",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NotGroupMember { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NotGroupMember"),
+                }
+            },
+        )
+        // half of the pool has vested, minus the first second of the schedule
+        .query_group_member("team", bob, |result| {
+            let member = result.unwrap();
+            assert_eq!(member.share, coin(4_000, "uom"));
+            assert_eq!(member.claimable, coin(1_999, "uom"));
+        })
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::NothingToClaim => {}
+                _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+            }
+        })
+        .add_day()
+        .add_day()
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .claim_group(
+            carol,
+            "team",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_group_member("team", bob, |result| {
+            let member = result.unwrap();
+            assert_eq!(member.claimed, coin(4_000, "uom"));
+            assert_eq!(member.claimable, coin(0, "uom"));
+        })
+        .query_group("team", |result| {
+            assert_eq!(result.unwrap().claimed, coin(6_000, "uom"));
+        })
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claimed, coin(6_000, "uom"));
+        });
+}
+
+#[test]
+fn group_pools_and_allocations_share_the_total_reward() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(alice.to_string(), Uint128::new(4_000))],
+            campaign_params(current_time, 10_000),
+        )
+        .create_group(
+            owner,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(6_001),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .create_group(
+            owner,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(6_000),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
+
+#[test]
+fn group_claims_go_through_the_terms_and_the_cooldown() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+    let terms_hash = HexBinary::from(Sha256::digest(b"terms").to_vec());
+
+    suite
+        .setup_campaign(
+            &[],
+            CampaignParams {
+                terms_hash: Some(terms_hash.clone()),
+                claim_cooldown_seconds: Some(172_800),
+                ..campaign_params(current_time, 10_000)
+            },
+        )
+        .create_group(
+            owner,
+            "team",
+            team_schedule(current_time),
+            Uint128::new(6_000),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_group_members(
+            owner,
+            "team",
+            vec![(bob.to_string(), Uint128::new(1))],
+            vec![],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::TermsNotAccepted { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::TermsNotAccepted"),
+            }
+        })
+        .accept_terms(
+            bob,
+            terms_hash,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .add_day()
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::ClaimCooldownActive { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::ClaimCooldownActive"),
+            }
+        })
+        .add_day()
+        .claim_group(bob, "team", |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        });
+}
@@ -0,0 +1,178 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup(suite: &mut TestingSuite) {
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(400)),
+                (bob.to_string(), Uint128::new(600)),
+            ],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn held_addresses_can_claim_once_the_hold_expires() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let now = suite.get_time().seconds();
+    let until = now + 86_401;
+
+    suite
+        .hold_address(
+            bob,
+            alice,
+            Some(until),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .hold_address(
+            owner,
+            alice,
+            Some(now),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .hold_address(
+            owner,
+            alice,
+            Some(until),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response
+                    .events
+                    .iter()
+                    .any(|event| event.ty == "wasm-claimdrop/hold"));
+            },
+        )
+        .query_hold(alice, |result| {
+            let hold = result.unwrap().hold.unwrap();
+            assert_eq!(hold.held_by, owner.to_string());
+            assert_eq!(hold.until, until);
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressOnHold { until: held_until } => {
+                        assert_eq!(held_until, until)
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::AddressOnHold"),
+                }
+            },
+        )
+        // the held address can't escape the hold by moving its allocation
+        .transfer_allocation(
+            alice,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressOnHold { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::AddressOnHold"),
+                }
+            },
+        )
+        // unlike the blacklist, the hold doesn't affect the other addresses
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .add_day()
+        .query_hold(alice, |result| {
+            assert!(result.unwrap().hold.is_none());
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_400));
+        });
+}
+
+#[test]
+fn holds_can_be_lifted_early() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let until = suite.get_time().plus_days(30).seconds();
+
+    suite
+        .hold_address(
+            owner,
+            owner,
+            Some(until),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        .hold_address(
+            owner,
+            alice,
+            Some(until),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .hold_address(
+            owner,
+            alice,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_hold(alice, |result| {
+            assert!(result.unwrap().hold.is_none());
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
@@ -0,0 +1,232 @@
+use claimdrop_contract::contract::{execute, instantiate, query};
+use claimdrop_contract::ibc::{
+    ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_receive,
+};
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, mock_ibc_channel_close_init,
+    mock_ibc_channel_connect_ack, mock_ibc_channel_open_try, mock_ibc_packet_recv, MockApi,
+    MockQuerier, MockStorage,
+};
+use cosmwasm_std::{from_json, Addr, IbcOrder, OwnedDeps, Uint128};
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{
+    AllocationsResponse, BlacklistResponse, ExecuteMsg, IbcAdmin, IbcAdminPacket, InstantiateMsg,
+    QueryMsg, IBC_ADMIN_VERSION,
+};
+
+const CHANNEL_ID: &str = "channel-0";
+
+fn setup() -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, Addr) {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        InstantiateMsg {
+            owner: None,
+            action: None,
+            limits: None,
+        },
+    )
+    .unwrap();
+
+    (deps, owner)
+}
+
+fn set_ibc_admin(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, owner: &Addr) {
+    // the mock channel is opened by their_port on connection-2
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(owner, &[]),
+        ExecuteMsg::UpdateIbcAdmin {
+            ibc_admin: Some(IbcAdmin {
+                connection_id: "connection-2".to_string(),
+                counterparty_port_id: "their_port".to_string(),
+            }),
+        },
+    )
+    .unwrap();
+}
+
+fn assert_invalid_channel(err: ContractError) {
+    match err {
+        ContractError::InvalidIbcAdminChannel { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidIbcAdminChannel"),
+    }
+}
+
+#[test]
+fn admin_channel_can_only_be_opened_by_the_ibc_admin() {
+    let (mut deps, owner) = setup();
+
+    // the IBC administration is disabled by default
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL_ID, IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap_err();
+    assert_invalid_channel(err);
+
+    let alice = deps.api.addr_make("alice");
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&alice, &[]),
+        ExecuteMsg::UpdateIbcAdmin { ibc_admin: None },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::OwnershipError(_) => {}
+        _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+    }
+
+    set_ibc_admin(&mut deps, &owner);
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL_ID, IbcOrder::Ordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap_err();
+    assert_invalid_channel(err);
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL_ID, IbcOrder::Unordered, "ics20-1"),
+    )
+    .unwrap_err();
+    assert_invalid_channel(err);
+
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL_ID, IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(CHANNEL_ID, IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap();
+
+    // only one admin channel can be open at a time
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap_err();
+    assert_invalid_channel(err);
+
+    ibc_channel_close(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_close_init(CHANNEL_ID, IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap();
+
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap();
+}
+
+#[test]
+fn admin_packets_are_executed_with_the_owner_authority() {
+    let (mut deps, owner) = setup();
+    let alice = deps.api.addr_make("alice");
+    let bob = deps.api.addr_make("bob");
+
+    set_ibc_admin(&mut deps, &owner);
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(CHANNEL_ID, IbcOrder::Unordered, IBC_ADMIN_VERSION),
+    )
+    .unwrap();
+
+    let add_allocations = IbcAdminPacket::AddAllocations {
+        allocations: vec![(alice.to_string(), Uint128::new(1_000))],
+    };
+
+    // packets from other channels are rejected
+    let err = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv("channel-1", &add_allocations).unwrap(),
+    )
+    .unwrap_err();
+    assert_invalid_channel(err);
+
+    ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(CHANNEL_ID, &add_allocations).unwrap(),
+    )
+    .unwrap();
+
+    let allocations: AllocationsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Allocations {
+                address: Some(alice.to_string()),
+                start_after: None,
+                limit: None,
+                min_amount: None,
+                has_claimed: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(allocations.allocations.len(), 1);
+
+    ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(
+            CHANNEL_ID,
+            &IbcAdminPacket::BlacklistAddress {
+                address: bob.to_string(),
+                blacklist: true,
+                reason: Some("blacklisted by the partner DAO".to_string()),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let blacklist: BlacklistResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::IsBlacklisted {
+                address: bob.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(blacklist.is_blacklisted);
+
+    // there's no campaign to close
+    let err = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(CHANNEL_ID, &IbcAdminPacket::CloseCampaign {}).unwrap(),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::NoActiveCampaign => {}
+        _ => panic!("Wrong error type, should return ContractError::NoActiveCampaign"),
+    }
+}
@@ -0,0 +1,408 @@
+use claimdrop_contract::contract::{execute, instantiate, query};
+use claimdrop_contract::ibc::ibc_source_callback;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, mock_ibc_packet_ack, mock_ibc_packet_timeout,
+    MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coin, from_json, BankMsg, CosmosMsg, Decimal, IbcAckCallbackMsg, IbcAcknowledgement, IbcMsg,
+    IbcSourceCallbackMsg, IbcTimeoutCallbackMsg, OwnedDeps, StdAck, Uint128,
+};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{
+    CampaignAction, CampaignParams, DistributionType, ExecuteMsg, IbcReceiver, InstantiateMsg,
+    LocksResponse, QueryMsg,
+};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+fn ibc_receiver(channel_id: &str) -> IbcReceiver {
+    IbcReceiver {
+        channel_id: channel_id.to_string(),
+        to_address: "osmo1remoteaddress".to_string(),
+        timeout_seconds: 600,
+    }
+}
+
+#[test]
+fn claim_over_allowed_ibc_channel() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .claim_over_ibc(
+            bob,
+            None,
+            ibc_receiver("channel-0"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::IbcChannelNotAllowed { channel_id } => {
+                        assert_eq!(channel_id, "channel-0");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::IbcChannelNotAllowed"
+                    ),
+                }
+            },
+        )
+        .manage_ibc_channels(
+            owner,
+            vec!["channel-0".to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_config(|result| {
+            assert_eq!(result.unwrap().allowed_ibc_channels, vec!["channel-0"]);
+        })
+        .claim_over_ibc(
+            bob,
+            None,
+            ibc_receiver("channel-0"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let event = response
+                    .events
+                    .iter()
+                    .find(|event| event.ty == "wasm")
+                    .unwrap();
+                assert!(event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "ibc_channel" && attr.value == "channel-0"));
+                assert!(event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "ibc_receiver" && attr.value == "osmo1remoteaddress"));
+            },
+        )
+        // the rewards were not sent to bob on the local chain
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_claimed(Some(bob), None, None, |result| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(1_000, "uom"));
+        });
+}
+
+#[test]
+fn claim_over_ibc_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .manage_ibc_channels(
+            bob,
+            vec!["channel-0".to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .manage_ibc_channels(
+            owner,
+            vec!["transfer".to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_ibc_channels(
+            owner,
+            vec!["channel-0".to_string(), "channel-1".to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_ibc_channels(
+            owner,
+            vec!["channel-1".to_string()],
+            false,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_over_ibc(
+            bob,
+            None,
+            ibc_receiver("channel-1"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::IbcChannelNotAllowed { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::IbcChannelNotAllowed"
+                    ),
+                }
+            },
+        )
+        // the owner can't redirect bob's rewards to a remote chain
+        .claim_over_ibc(
+            owner,
+            Some(bob.to_string()),
+            ibc_receiver("channel-0"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        )
+        .claim_over_ibc(
+            bob,
+            None,
+            IbcReceiver {
+                timeout_seconds: 0,
+                ..ibc_receiver("channel-0")
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
+
+/// The ICS-20 packet data of a transfer, as relayed back to the source callback.
+#[cw_serde]
+struct FungibleTokenPacketData {
+    denom: String,
+    amount: String,
+    sender: String,
+    receiver: String,
+    memo: String,
+}
+
+/// Claims bob's rewards over IBC on a mock chain, returning the source callback of the transfer,
+/// acknowledged with the given ack or timed out if there's none.
+fn claim_over_ibc_packet(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ack: Option<StdAck>,
+) -> IbcSourceCallbackMsg {
+    let mut env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let bob = deps.api.addr_make("bob");
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        InstantiateMsg {
+            owner: None,
+            action: None,
+            limits: None,
+        },
+    )
+    .unwrap();
+    for msg in [
+        ExecuteMsg::AddAllocations {
+            allocations: vec![(bob.to_string(), Uint128::new(1_000))],
+            skip_duplicates: None,
+            tag: None,
+            batch_id: None,
+        },
+        ExecuteMsg::ManageCampaign {
+            action: CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "IBC Campaign".to_string(),
+                    description: "Campaign paying out over IBC".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(1_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: env.block.time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: env.block.time.plus_seconds(1),
+                    end_time: env.block.time.plus_seconds(86400 * 7),
+                    ..Default::default()
+                }),
+            },
+        },
+        ExecuteMsg::ManageIbcChannels {
+            channel_ids: vec!["channel-0".to_string()],
+            allowed: true,
+        },
+    ] {
+        execute(deps.as_mut(), env.clone(), message_info(&owner, &[]), msg).unwrap();
+    }
+    deps.querier
+        .bank
+        .update_balance(&env.contract.address, vec![coin(1_000, "uom")]);
+
+    env.block.time = env.block.time.plus_days(1);
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&bob, &[]),
+        ExecuteMsg::Claim {
+            receiver: None,
+            amount: None,
+            percentage: None,
+            slots: None,
+            into_vesting_account: None,
+            ibc_receiver: Some(ibc_receiver("channel-0")),
+            lock_option: None,
+        },
+    )
+    .unwrap();
+
+    let transfer = response
+        .messages
+        .iter()
+        .find_map(|msg| match &msg.msg {
+            CosmosMsg::Ibc(IbcMsg::Transfer {
+                to_address,
+                amount,
+                memo,
+                ..
+            }) => Some(FungibleTokenPacketData {
+                denom: amount.denom.clone(),
+                amount: amount.amount.to_string(),
+                sender: env.contract.address.to_string(),
+                receiver: to_address.clone(),
+                memo: memo.clone().unwrap(),
+            }),
+            _ => None,
+        })
+        .unwrap();
+    // the transfer is sent by the contract, the funds leave it
+    deps.querier
+        .bank
+        .update_balance(&env.contract.address, vec![]);
+
+    match ack {
+        Some(ack) => {
+            let msg = mock_ibc_packet_ack(
+                "channel-0",
+                &transfer,
+                IbcAcknowledgement::new(ack.to_binary()),
+            )
+            .unwrap();
+            IbcSourceCallbackMsg::Acknowledgement(IbcAckCallbackMsg::new(
+                msg.acknowledgement,
+                msg.original_packet,
+                msg.relayer,
+            ))
+        }
+        None => {
+            let msg = mock_ibc_packet_timeout("channel-0", &transfer).unwrap();
+            IbcSourceCallbackMsg::Timeout(IbcTimeoutCallbackMsg::new(msg.packet, msg.relayer))
+        }
+    }
+}
+
+fn query_locks(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> LocksResponse {
+    let bob = deps.api.addr_make("bob");
+    from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Locks {
+                address: bob.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn failed_ibc_payout_is_credited_back_to_the_receiver() {
+    let mut deps = mock_dependencies();
+    let bob = deps.api.addr_make("bob");
+    let callback = claim_over_ibc_packet(&mut deps, None);
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_days(2);
+
+    // the transfer timed out, so the escrowed tokens were refunded to the contract
+    deps.querier
+        .bank
+        .update_balance(&env.contract.address, vec![coin(1_000, "uom")]);
+    ibc_source_callback(deps.as_mut(), env.clone(), callback.clone()).unwrap();
+
+    let locks = query_locks(&deps);
+    assert_eq!(locks.locks.len(), 1);
+    assert_eq!(locks.locks[0].amount, coin(1_000, "uom"));
+    assert_eq!(
+        locks.locks[0].unlocks_at.seconds(),
+        env.block.time.seconds()
+    );
+
+    // the payout is settled once, a replayed callback doesn't credit it twice
+    ibc_source_callback(deps.as_mut(), env.clone(), callback).unwrap();
+    assert_eq!(query_locks(&deps).locks.len(), 1);
+
+    let response = execute(
+        deps.as_mut(),
+        env,
+        message_info(&bob, &[]),
+        ExecuteMsg::WithdrawUnlocked {},
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: bob.to_string(),
+            amount: vec![coin(1_000, "uom")],
+        })
+    );
+}
+
+#[test]
+fn error_ack_credits_the_ibc_payout_back_to_the_receiver() {
+    let mut deps = mock_dependencies();
+    let callback = claim_over_ibc_packet(&mut deps, Some(StdAck::error("receiver rejected")));
+
+    ibc_source_callback(deps.as_mut(), mock_env(), callback).unwrap();
+
+    let locks = query_locks(&deps);
+    assert_eq!(locks.locks.len(), 1);
+    assert_eq!(locks.locks[0].amount, coin(1_000, "uom"));
+}
+
+#[test]
+fn successful_ibc_payout_is_not_credited_back() {
+    let mut deps = mock_dependencies();
+    let callback = claim_over_ibc_packet(&mut deps, Some(StdAck::success(b"\x01")));
+
+    ibc_source_callback(deps.as_mut(), mock_env(), callback).unwrap();
+
+    assert!(query_locks(&deps).locks.is_empty());
+}
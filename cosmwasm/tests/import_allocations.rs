@@ -0,0 +1,151 @@
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn attribute(response: &AppResponse, key: &str) -> String {
+    response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+        .unwrap()
+}
+
+/// Sets up a source campaign where bob already claimed, returning the source contract address.
+fn setup_source_contract(suite: &mut TestingSuite) -> Addr {
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+                (dan.to_string(), Uint128::new(3_000)),
+                ("vitalik.eth".to_string(), Uint128::new(4_000)),
+            ],
+            CampaignParams {
+                name: "V1 Campaign".to_string(),
+                description: "Campaign to migrate".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(10_000, "uom"),
+                distribution_type: vec![DistributionType::LumpSum {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    cliff_duration: None,
+                }],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(7),
+                ..Default::default()
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(100)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    suite.claimdrop_contract_addr.clone()
+}
+
+#[test]
+fn import_allocations_skips_claimed_addresses() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    let source_contract = &setup_source_contract(&mut suite);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        // the allocations are sorted by address: carol, bob, dan, vitalik.eth
+        .import_allocations(
+            owner,
+            source_contract,
+            None,
+            Some(2),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(attribute(&response, "imported"), "1");
+                assert_eq!(attribute(&response, "skipped"), "1");
+                assert_eq!(attribute(&response, "last_address"), bob.to_string());
+            },
+        )
+        .import_allocations(
+            owner,
+            source_contract,
+            Some(bob.as_str()),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(attribute(&response, "imported"), "2");
+                assert_eq!(attribute(&response, "skipped"), "0");
+                assert_eq!(attribute(&response, "last_address"), "vitalik.eth");
+            },
+        )
+        .query_allocations(None, None, None, |result| {
+            assert_eq!(
+                result.unwrap().allocations,
+                vec![
+                    (carol.to_string(), coin(2_000, "")),
+                    (dan.to_string(), coin(3_000, "")),
+                    ("vitalik.eth".to_string(), coin(4_000, "")),
+                ]
+            );
+        })
+        // importing the same page twice fails, as the allocations already exist
+        .import_allocations(
+            owner,
+            source_contract,
+            Some(bob.as_str()),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn only_authorized_wallets_can_import_allocations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    let source_contract = &setup_source_contract(&mut suite);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .import_allocations(
+            bob,
+            source_contract,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        );
+}
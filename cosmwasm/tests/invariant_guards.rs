@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use claimdrop_contract::contract::execute;
+use claimdrop_contract::state::{Allocation, ALLOCATIONS, CAMPAIGN, CLAIMS};
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{coin, Uint128};
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{Campaign, ExecuteMsg};
+
+#[test]
+fn claimed_amount_exceeding_total_reward_is_an_invariant_violation() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+
+    cw_ownable::initialize_owner(&mut deps.storage, &deps.api, Some(owner.as_str())).unwrap();
+    CAMPAIGN
+        .save(
+            &mut deps.storage,
+            &Campaign {
+                total_reward: coin(1_000, "uom"),
+                claimed: coin(1_001, "uom"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        message_info(&owner, &[]),
+        ExecuteMsg::UpdateConfig {
+            circuit_breaker: None,
+            limits: None,
+            replacements_require_request: None,
+            voucher_pubkey: None,
+            allowed_prefixes: None,
+            authorized_wallet_delay: None,
+            sweep_threshold: None,
+            registry: None,
+        },
+    )
+    .unwrap_err();
+
+    match err {
+        ContractError::InvariantViolation { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvariantViolation"),
+    }
+}
+
+#[test]
+fn claims_exceeding_the_allocation_are_an_invariant_violation() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let bob = deps.api.addr_make("bob");
+    let carol = deps.api.addr_make("carol");
+
+    CAMPAIGN
+        .save(
+            &mut deps.storage,
+            &Campaign {
+                total_reward: coin(1_000, "uom"),
+                claimed: coin(0, "uom"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    ALLOCATIONS
+        .save(
+            &mut deps.storage,
+            bob.as_str(),
+            &Allocation::new(Uint128::new(100), bob.clone(), env.block.time),
+        )
+        .unwrap();
+    CLAIMS
+        .save(
+            &mut deps.storage,
+            bob.to_string(),
+            &HashMap::from([(0, (Uint128::new(101), env.block.time.seconds()))]),
+        )
+        .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        message_info(&bob, &[]),
+        ExecuteMsg::RequestAddressReplacement {
+            new_address: Some(carol.to_string()),
+        },
+    )
+    .unwrap_err();
+
+    match err {
+        ContractError::InvariantViolation { invariant } => {
+            assert!(invariant.contains(bob.as_str()));
+        }
+        _ => panic!("Wrong error type, should return ContractError::InvariantViolation"),
+    }
+}
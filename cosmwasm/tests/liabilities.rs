@@ -0,0 +1,118 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn liabilities_are_reconciled_against_the_balance() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+    let last = alice.max(bob);
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(400)),
+                (bob.to_string(), Uint128::new(600)),
+                (carol.to_string(), Uint128::new(100)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .remove_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Audited Campaign".to_string(),
+                    description: "Campaign reconciled by the treasury".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(1_000, "uom"),
+                    distribution_type: vec![
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_seconds(1),
+                            cliff_duration: None,
+                        },
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_days(3),
+                            cliff_duration: None,
+                        },
+                    ],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(800, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .query_liabilities(None, None, |result| {
+            let liabilities = result.unwrap();
+            assert_eq!(liabilities.total_allocated, Uint128::new(1_000));
+            assert_eq!(liabilities.total_claimed, Uint128::zero());
+            assert_eq!(liabilities.outstanding, Uint128::new(1_000));
+            assert_eq!(liabilities.balance, coin(800, "uom"));
+            assert_eq!(liabilities.surplus, Uint128::zero());
+            assert_eq!(liabilities.shortfall, Uint128::new(200));
+            assert_eq!(liabilities.claimable, Uint128::new(500));
+            assert_eq!(liabilities.next_start_after, None);
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(300, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // alice already claimed what she could, so only bob has something claimable
+        .query_liabilities(None, Some(2), |result| {
+            let liabilities = result.unwrap();
+            assert_eq!(liabilities.total_claimed, Uint128::new(200));
+            assert_eq!(liabilities.outstanding, Uint128::new(800));
+            assert_eq!(liabilities.balance, coin(900, "uom"));
+            assert_eq!(liabilities.surplus, Uint128::new(100));
+            assert_eq!(liabilities.shortfall, Uint128::zero());
+            assert_eq!(liabilities.claimable, Uint128::new(300));
+            assert_eq!(liabilities.next_start_after, Some(last.to_string()));
+        })
+        .query_liabilities(Some(last), Some(2), |result| {
+            let liabilities = result.unwrap();
+            assert_eq!(liabilities.claimable, Uint128::zero());
+            assert_eq!(liabilities.next_start_after, None);
+        });
+}
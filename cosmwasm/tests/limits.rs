@@ -0,0 +1,190 @@
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType, Limits};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, denom: &str, duration: u64) -> CampaignParams {
+    CampaignParams {
+        total_reward: coin(10_000, denom),
+        end_time: current_time.plus_seconds(1 + duration),
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+fn assert_invalid_campaign_param(result: Result<AppResponse, anyhow::Error>, expected: &str) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidCampaignParam { param, .. } => {
+            assert_eq!(param, expected);
+        }
+        _ => panic!("Wrong error type, should return ContractError::InvalidCampaignParam"),
+    }
+}
+
+#[test]
+fn limits_set_at_instantiation_are_enforced() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract_with_limits(
+            Some(owner.to_string()),
+            Some(Limits {
+                max_allocation_batch_size: Some(1),
+                min_campaign_duration: Some(86_400),
+                allowed_reward_denoms: vec!["uom".to_string()],
+                ..Default::default()
+            }),
+        )
+        .query_config(|result| {
+            let limits = result.unwrap().limits;
+            assert_eq!(limits.max_allocation_batch_size, Some(1));
+            assert_eq!(limits.allowed_reward_denoms, vec!["uom"]);
+        })
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(5_000)),
+                (carol.to_string(), Uint128::new(5_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::BatchSizeLimitExceeded { actual, max } => {
+                        assert_eq!(actual, 2);
+                        assert_eq!(max, 1);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::BatchSizeLimitExceeded"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "uusdc", 86_400)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, "total_reward");
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "uom", 3_600)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, "end_time");
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "uom", 86_400)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn owner_can_update_limits() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    let mut params = campaign_params(current_time, "uom", 86_400);
+    params.distribution_type = vec![
+        DistributionType::LumpSum {
+            percentage: Decimal::percent(25),
+            start_time: current_time.plus_seconds(1),
+            cliff_duration: None,
+        },
+        DistributionType::LumpSum {
+            percentage: Decimal::percent(25),
+            start_time: current_time.plus_seconds(10),
+            cliff_duration: None,
+        },
+        DistributionType::LumpSum {
+            percentage: Decimal::percent(50),
+            start_time: current_time.plus_seconds(100),
+            cliff_duration: None,
+        },
+    ];
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        // the default limit is 2 distribution types
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params.clone()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_invalid_campaign_param(result, "distribution_type");
+            },
+        )
+        .update_limits(
+            bob,
+            Limits {
+                max_distribution_slots: Some(3),
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .update_limits(
+            owner,
+            Limits {
+                max_distribution_slots: Some(0),
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { reason } => {
+                        assert_eq!(reason, "max_distribution_slots cannot be zero");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .update_limits(
+            owner,
+            Limits {
+                max_distribution_slots: Some(3),
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
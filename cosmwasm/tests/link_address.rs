@@ -0,0 +1,181 @@
+use claimdrop_contract::helpers::pubkey_to_ethereum_address;
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use k256::ecdsa::SigningKey;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+/// Returns a signing key and the ethereum address derived from its public key
+fn ethereum_signer(seed: u8) -> (SigningKey, String) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32].into()).unwrap();
+    let address = pubkey_to_ethereum_address(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes(),
+    );
+
+    (signing_key, address)
+}
+
+fn setup_campaign(suite: &mut TestingSuite, allocations: Vec<(String, Uint128)>) {
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(&allocations, campaign_params(current_time, 2_000))
+        .add_day();
+}
+
+#[test]
+fn linked_address_claims_the_foreign_allocation() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let (signing_key, foreign_address) = ethereum_signer(1);
+
+    // the allocation is uploaded with the checksummed address
+    setup_campaign(
+        &mut suite,
+        vec![(
+            foreign_address.to_uppercase().replace("0X", "0x"),
+            Uint128::new(2_000),
+        )],
+    );
+
+    suite
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        )
+        .link_address(
+            alice,
+            &signing_key,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response
+                    .events
+                    .iter()
+                    .any(|event| event.attributes.iter().any(|attr| attr.key
+                        == "foreign_address"
+                        && attr.value == foreign_address)));
+            },
+        )
+        .query_allocations(
+            Some(&Addr::unchecked(&foreign_address)),
+            None,
+            None,
+            |result| {
+                assert!(result.unwrap().allocations.is_empty());
+            },
+        )
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_002_000));
+        })
+        // the foreign address has been linked already
+        .link_address(
+            alice,
+            &signing_key,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        );
+}
+
+#[test]
+fn cant_link_with_a_signature_for_another_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let (signing_key, foreign_address) = ethereum_signer(1);
+    let (other_signing_key, _) = ethereum_signer(2);
+
+    setup_campaign(&mut suite, vec![(foreign_address, Uint128::new(2_000))]);
+
+    suite
+        // bob replays the signature alice got from the foreign key
+        .link_address(
+            bob,
+            &signing_key,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidSignature => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidSignature"),
+                }
+            },
+        )
+        // the other key has no allocation
+        .link_address(
+            alice,
+            &other_signing_key,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        );
+}
+
+#[test]
+fn blacklisted_foreign_address_cant_be_linked() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let (signing_key, foreign_address) = ethereum_signer(1);
+
+    setup_campaign(
+        &mut suite,
+        vec![(foreign_address.clone(), Uint128::new(2_000))],
+    );
+
+    suite
+        .blacklist_address(
+            owner,
+            &Addr::unchecked(&foreign_address),
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .link_address(
+            alice,
+            &signing_key,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AddressBlacklisted => {}
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::AddressBlacklisted")
+                    }
+                }
+            },
+        );
+}
@@ -0,0 +1,217 @@
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, LockBoost, LockTier};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, lock_boost: LockBoost) -> CampaignParams {
+    CampaignParams {
+        lock_boost: Some(lock_boost),
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+fn lock_boost() -> LockBoost {
+    LockBoost {
+        pool_percentage: Decimal::percent(10),
+        tiers: vec![
+            (LockTier::ThreeMonths, Decimal::percent(110)),
+            (LockTier::TwelveMonths, Decimal::percent(150)),
+        ],
+    }
+}
+
+#[test]
+fn locked_claims_are_boosted_and_withdrawn_once_unlocked() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(300)),
+                (bob.to_string(), Uint128::new(400)),
+                (carol.to_string(), Uint128::new(300)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, lock_boost())),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_100, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    let claimed_at = suite.get_time();
+    let contract = &suite.claimdrop_contract_addr.clone();
+
+    // alice is boosted by 10% for 3 months, bob's 50% boost is capped by what's left in the pool
+    suite
+        .claim_with_lock(
+            alice,
+            LockTier::ThreeMonths,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_with_lock(
+            bob,
+            LockTier::TwelveMonths,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_locks(bob.as_str(), |result| {
+            let locks = result.unwrap().locks;
+            assert_eq!(locks.len(), 1);
+            assert_eq!(locks[0].amount, coin(470, "uom"));
+            assert_eq!(
+                locks[0].unlocks_at.seconds(),
+                claimed_at.seconds() + LockTier::TwelveMonths.duration()
+            );
+        })
+        .withdraw_unlocked(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::NothingToClaim => {}
+                _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+            }
+        })
+        // the locked tokens don't fund the claims of the others
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", contract, |balance| {
+            assert_eq!(balance, Uint128::new(800));
+        });
+
+    for _ in 0..365 {
+        suite.add_day();
+    }
+
+    suite
+        .withdraw_unlocked(bob, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .withdraw_unlocked(alice, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_330));
+        })
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_470));
+        })
+        .query_locks(bob.as_str(), |result| {
+            assert!(result.unwrap().locks.is_empty());
+        });
+}
+
+#[test]
+fn claims_can_only_be_locked_for_the_offered_tiers() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(
+                    current_time,
+                    LockBoost {
+                        pool_percentage: Decimal::percent(10),
+                        tiers: vec![(LockTier::SixMonths, Decimal::percent(90))],
+                    },
+                )),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "lock_boost")
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, lock_boost())),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_100, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim_with_lock(
+            bob,
+            LockTier::SixMonths,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
@@ -0,0 +1,172 @@
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, receivers: &[&Addr]) {
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &receivers
+                .iter()
+                .map(|receiver| (receiver.to_string(), Uint128::new(1_000)))
+                .collect::<Vec<_>>(),
+            campaign_params(current_time, 2_000),
+        )
+        .add_day();
+}
+
+#[test]
+fn locked_allocations_only_pay_out_to_their_receiver() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let exchange = &suite.senders[3].clone();
+    let exchange_deposit = &suite.senders[4].clone();
+
+    setup_campaign(&mut suite, &[bob, carol]);
+
+    suite
+        .manage_authorized_wallets(
+            owner,
+            vec![exchange.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_locked_receiver(
+            exchange,
+            bob.as_str(),
+            Some(exchange_deposit.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .set_locked_receiver(
+            owner,
+            bob.as_str(),
+            Some(exchange_deposit.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_locked_receiver(bob.as_str(), |result| {
+            assert_eq!(
+                result.unwrap().locked_receiver,
+                Some(exchange_deposit.to_string())
+            );
+        })
+        .query_locked_receiver(carol.as_str(), |result| {
+            assert_eq!(result.unwrap().locked_receiver, None);
+        })
+        // the authorized wallet claiming for bob pays out to the locked receiver
+        .claim(
+            exchange,
+            Some(bob.to_string()),
+            Some(Uint128::new(400)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // and so does bob claiming himself
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_balance("uom", exchange_deposit, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        // unlocked allocations pay out to their receiver as usual
+        .claim(
+            exchange,
+            Some(carol.to_string()),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
+
+#[test]
+fn locked_allocations_cant_be_redirected() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let exchange_deposit = &suite.senders[4].clone();
+
+    setup_campaign(&mut suite, &[bob]);
+
+    suite
+        .set_locked_receiver(
+            owner,
+            exchange_deposit.as_str(),
+            Some(bob.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        )
+        .set_locked_receiver(
+            owner,
+            bob.as_str(),
+            Some(exchange_deposit.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_into_vesting_account(bob, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => {
+                    assert_eq!(
+                        reason,
+                        "the payouts of the allocation are locked to a receiver"
+                    );
+                }
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        })
+        // once the lock is removed, bob gets the payouts again
+        .set_locked_receiver(
+            owner,
+            bob.as_str(),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
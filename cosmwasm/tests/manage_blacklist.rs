@@ -0,0 +1,106 @@
+use claimdrop_contract::commands::MAX_BLACKLIST_BATCH_SIZE;
+use cosmwasm_std::coin;
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+fn attribute(response: &AppResponse, key: &str) -> Option<String> {
+    response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+}
+
+#[test]
+fn addresses_are_blacklisted_in_batches_skipping_the_owner() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_blacklist(
+            alice,
+            vec![bob.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .manage_blacklist(
+            owner,
+            vec![],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_blacklist(
+            owner,
+            vec![alice.to_string(); MAX_BLACKLIST_BATCH_SIZE + 1],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::BatchSizeLimitExceeded { actual, max } => {
+                        assert_eq!(actual, MAX_BLACKLIST_BATCH_SIZE + 1);
+                        assert_eq!(max, MAX_BLACKLIST_BATCH_SIZE);
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::BatchSizeLimitExceeded"
+                    ),
+                }
+            },
+        )
+        .manage_blacklist(
+            owner,
+            vec![alice.to_string(), owner.to_string(), bob.to_string()],
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert_eq!(attribute(&response, "count"), Some("2".to_string()));
+                assert_eq!(attribute(&response, "skipped"), Some(owner.to_string()));
+            },
+        )
+        .query_is_blacklisted(alice, |result| {
+            assert!(result.unwrap().is_blacklisted);
+        })
+        .query_is_blacklisted(owner, |result| {
+            assert!(!result.unwrap().is_blacklisted);
+        })
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().blacklisted, 2);
+        })
+        // the addresses already in the requested state are left untouched
+        .manage_blacklist(
+            owner,
+            vec![alice.to_string(), carol.to_string()],
+            false,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_blacklisted(alice, |result| {
+            assert!(!result.unwrap().is_blacklisted);
+        })
+        .query_is_blacklisted(bob, |result| {
+            assert!(result.unwrap().is_blacklisted);
+        })
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().blacklisted, 1);
+        });
+}
@@ -0,0 +1,178 @@
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{
+    CampaignAction, CampaignParams, DistributionType, Limits, MAX_DISTRIBUTION_SLOTS,
+};
+
+mod suite;
+use suite::TestingSuite;
+
+const UNLOCK_PERIOD: u64 = 28 * 86_400;
+
+fn campaign_params(current_time: &Timestamp, unlocks: u64) -> CampaignParams {
+    CampaignParams {
+        distribution_type: (0..unlocks)
+            .map(|unlock| DistributionType::LumpSum {
+                percentage: Decimal::from_ratio(1u128, unlocks),
+                start_time: current_time.plus_seconds(1 + unlock * UNLOCK_PERIOD),
+                cliff_duration: None,
+            })
+            .collect(),
+        end_time: current_time.plus_seconds(1 + unlocks * UNLOCK_PERIOD),
+        ..suite::campaign_params(current_time, 12_000)
+    }
+}
+
+fn add_period(suite: &mut TestingSuite) {
+    for _ in 0..4 {
+        suite.add_week();
+    }
+}
+
+#[test]
+fn the_slots_limit_is_capped() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .update_limits(
+            owner,
+            Limits {
+                max_distribution_slots: Some(MAX_DISTRIBUTION_SLOTS + 1),
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { reason } => {
+                        assert_eq!(reason, "max_distribution_slots cannot exceed 64");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
+
+#[test]
+fn fully_claimed_slots_are_skipped_until_the_allocation_changes() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract_with_limits(
+            Some(owner.to_string()),
+            Some(Limits {
+                max_distribution_slots: Some(40),
+                ..Default::default()
+            }),
+        )
+        .add_allocations(
+            owner,
+            &vec![
+                (alice.to_string(), Uint128::new(4_000)),
+                (bob.to_string(), Uint128::new(4_000)),
+                (carol.to_string(), Uint128::new(4_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, 40)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(12_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    // alice claims each unlock as it comes, exhausting its slot
+    for unlock in 1..=3u128 {
+        suite
+            .claim(
+                alice,
+                None,
+                None,
+                |result: Result<AppResponse, anyhow::Error>| {
+                    result.unwrap();
+                },
+            )
+            .query_balance("uom", alice, |balance| {
+                assert_eq!(balance, Uint128::new(1_000_000_000 + unlock * 100));
+            });
+        add_period(&mut suite);
+    }
+
+    // carol's allocation is redistributed, raising the slots alice already exhausted
+    suite
+        .blacklist_address(
+            owner,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        // 4 unlocks of 150 each, minus the 300 already claimed
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_600));
+        });
+
+    for _ in 0..36 {
+        add_period(&mut suite);
+    }
+
+    suite
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_006_000));
+        })
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_006_000));
+        });
+}
@@ -0,0 +1,530 @@
+use std::collections::HashMap;
+
+use claimdrop_contract::contract::{execute, instantiate, migrate};
+use claimdrop_contract::queries::{
+    query_allocations_by_uploader, query_campaign_stats, query_counts, query_top_allocations,
+    query_unclaimed_allocations,
+};
+use claimdrop_contract::state::{
+    get_slots_claimed, ALLOCATIONS, BLACKLIST, CAMPAIGN, CLAIMS, CLAIM_HISTORY, TOTAL_ALLOCATED,
+};
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{coin, to_json_vec, Decimal, Order, StdResult, Timestamp, Uint128};
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{
+    CampaignAction, CampaignParams, ClaimMode, DistributionType, ExecuteMsg, InstantiateMsg,
+    MigrateMsg,
+};
+
+const CONTRACT_NAME: &str = "mantra_claimdrop-contract";
+
+#[test]
+fn migrate_v1_campaign_layout() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.0").unwrap();
+
+    let campaign_v1 = r#"{
+        "name": "Campaign V1",
+        "description": "A v1 campaign",
+        "type": "airdrop",
+        "reward_denom": "uom",
+        "total_reward": {"denom": "uom", "amount": "100000"},
+        "claimed": {"denom": "uom", "amount": "2500"},
+        "distribution_type": [{"lump_sum": {"percentage": "1", "start_time": 1000}}],
+        "start_time": 1000,
+        "end_time": 2000,
+        "closed": null
+    }"#;
+    deps.as_mut()
+        .storage
+        .set(CAMPAIGN.as_slice(), campaign_v1.as_bytes());
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(response.events.len(), 8);
+    assert_eq!(response.events[0].ty, "migrate_state");
+    assert_eq!(response.events[0].attributes[0].value, "1.0.0");
+    assert_eq!(response.events[0].attributes[1].value, "2.0.0");
+    assert_eq!(response.events[1].ty, "migrate_state");
+    assert_eq!(response.events[1].attributes[0].value, "2.0.0");
+    assert_eq!(response.events[1].attributes[1].value, "3.0.0");
+    assert_eq!(response.events[2].attributes[0].value, "3.0.0");
+    assert_eq!(response.events[2].attributes[1].value, "3.1.0");
+    assert_eq!(response.events[3].attributes[0].value, "3.1.0");
+    assert_eq!(response.events[3].attributes[1].value, "3.2.0");
+    assert_eq!(response.events[4].attributes[0].value, "3.2.0");
+    assert_eq!(response.events[4].attributes[1].value, "3.3.0");
+    assert_eq!(response.events[5].attributes[0].value, "3.3.0");
+    assert_eq!(response.events[5].attributes[1].value, "3.4.0");
+    assert_eq!(response.events[6].attributes[0].value, "3.4.0");
+    assert_eq!(response.events[6].attributes[1].value, "3.5.0");
+    assert_eq!(response.events[7].attributes[0].value, "3.5.0");
+    assert_eq!(response.events[7].attributes[1].value, "3.6.0");
+
+    // the stale reward_denom field is dropped from the stored campaign
+    let raw_campaign = deps.as_ref().storage.get(CAMPAIGN.as_slice()).unwrap();
+    assert!(!String::from_utf8(raw_campaign)
+        .unwrap()
+        .contains("reward_denom"));
+
+    let campaign = CAMPAIGN.load(deps.as_ref().storage).unwrap();
+    assert_eq!(campaign.name, "Campaign V1");
+    assert_eq!(campaign.total_reward, coin(100_000, "uom"));
+    assert_eq!(campaign.claimed, coin(2_500, "uom"));
+    assert_eq!(
+        campaign.distribution_type,
+        vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: Timestamp::from_seconds(1000),
+            cliff_duration: None,
+        }]
+    );
+
+    let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn migrate_v2_campaign_times() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "2.0.0").unwrap();
+
+    let campaign_v2 = r#"{
+        "name": "Campaign V2",
+        "description": "A v2 campaign",
+        "type": "airdrop",
+        "total_reward": {"denom": "uom", "amount": "100000"},
+        "claimed": {"denom": "uom", "amount": "0"},
+        "distribution_type": [
+            {"lump_sum": {"percentage": "0.25", "start_time": 1700000000}},
+            {"linear_vesting": {"percentage": "0.75", "start_time": 1700000000, "end_time": 1700086400, "cliff_duration": 3600}}
+        ],
+        "start_time": 1700000000,
+        "end_time": 1700086400,
+        "closed": null
+    }"#;
+    deps.as_mut()
+        .storage
+        .set(CAMPAIGN.as_slice(), campaign_v2.as_bytes());
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(response.events.len(), 7);
+    assert_eq!(response.events[0].attributes[0].value, "2.0.0");
+    assert_eq!(response.events[0].attributes[1].value, "3.0.0");
+
+    // the campaign is written with the v3.0.0 layout, the later fields take their defaults
+    let raw_campaign = deps.as_ref().storage.get(CAMPAIGN.as_slice()).unwrap();
+    assert!(!String::from_utf8(raw_campaign)
+        .unwrap()
+        .contains("reward_source"));
+
+    let campaign = CAMPAIGN.load(deps.as_ref().storage).unwrap();
+    assert_eq!(campaign.name, "Campaign V2");
+    assert_eq!(campaign.start_time, Timestamp::from_seconds(1_700_000_000));
+    assert_eq!(campaign.end_time, Timestamp::from_seconds(1_700_086_400));
+    assert_eq!(campaign.claim_mode, ClaimMode::Open);
+    assert_eq!(campaign.claim_cooldown_seconds, None);
+    assert_eq!(
+        campaign.distribution_type,
+        vec![
+            DistributionType::LumpSum {
+                percentage: Decimal::percent(25),
+                start_time: Timestamp::from_seconds(1_700_000_000),
+                cliff_duration: None,
+            },
+            DistributionType::LinearVesting {
+                percentage: Decimal::percent(75),
+                start_time: Timestamp::from_seconds(1_700_000_000),
+                end_time: Timestamp::from_seconds(1_700_086_400),
+                cliff_duration: Some(3_600),
+                cliff_behavior: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn migrate_v3_initializes_counters() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.0.0").unwrap();
+
+    for (address, amount) in [("alice", 100u128), ("bob", 200), ("carol", 300)] {
+        deps.as_mut().storage.set(
+            &ALLOCATIONS.key(address),
+            to_json_vec(&Uint128::new(amount)).unwrap().as_slice(),
+        );
+    }
+    CLAIMS
+        .save(
+            deps.as_mut().storage,
+            "alice".to_string(),
+            &HashMap::from([(0, (Uint128::new(50), 1_700_000_000))]),
+        )
+        .unwrap();
+    // the blacklist entries had no metadata before v3.2.0
+    deps.as_mut()
+        .storage
+        .set(&BLACKLIST.key("bob"), to_json_vec(&()).unwrap().as_slice());
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 6);
+    assert_eq!(response.events[0].attributes[1].value, "3.1.0");
+    assert_eq!(response.events[1].attributes[1].value, "3.2.0");
+    assert_eq!(response.events[2].attributes[1].value, "3.3.0");
+    assert_eq!(response.events[3].attributes[1].value, "3.4.0");
+    assert_eq!(response.events[4].attributes[1].value, "3.5.0");
+    assert_eq!(response.events[5].attributes[1].value, "3.6.0");
+
+    let counts = query_counts(deps.as_ref()).unwrap();
+    assert_eq!(counts.allocations, 3);
+    assert_eq!(counts.claimants, 1);
+    assert_eq!(counts.blacklisted, 1);
+
+    let entry = BLACKLIST.load(deps.as_ref().storage, "bob").unwrap();
+    assert_eq!(entry.blacklisted_by, "");
+    assert_eq!(entry.blacklisted_at, 0);
+    assert_eq!(entry.reason, None);
+}
+
+#[test]
+fn migrate_v3_2_indexes_allocations_by_amount() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.2.0").unwrap();
+
+    // the allocations weren't indexed before v3.3.0
+    for (address, amount) in [("alice", 100u128), ("bob", 300), ("carol", 200)] {
+        deps.as_mut().storage.set(
+            &ALLOCATIONS.key(address),
+            to_json_vec(&Uint128::new(amount)).unwrap().as_slice(),
+        );
+    }
+    assert!(query_top_allocations(deps.as_ref(), None)
+        .unwrap()
+        .allocations
+        .is_empty());
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 4);
+    assert_eq!(response.events[0].attributes[1].value, "3.3.0");
+
+    let top_allocations = query_top_allocations(deps.as_ref(), Some(2))
+        .unwrap()
+        .allocations
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect::<Vec<String>>();
+    assert_eq!(top_allocations, vec!["bob", "carol"]);
+}
+
+#[test]
+fn migrate_v3_3_indexes_allocations_by_claimed_status() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.3.0").unwrap();
+
+    // the allocations only stored the allocated amount before v3.4.0
+    for (address, amount) in [("alice", 100u128), ("bob", 300), ("carol", 200)] {
+        deps.as_mut().storage.set(
+            &ALLOCATIONS.key(address),
+            to_json_vec(&Uint128::new(amount)).unwrap().as_slice(),
+        );
+    }
+    CLAIMS
+        .save(
+            deps.as_mut().storage,
+            "bob".to_string(),
+            &HashMap::from([(0, (Uint128::new(50), 1_700_000_000))]),
+        )
+        .unwrap();
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 3);
+    assert_eq!(response.events[0].attributes[1].value, "3.4.0");
+
+    let unclaimed = query_unclaimed_allocations(deps.as_ref(), None, None)
+        .unwrap()
+        .allocations
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect::<Vec<String>>();
+    assert_eq!(unclaimed, vec!["alice", "carol"]);
+
+    let allocation = ALLOCATIONS.load(deps.as_ref().storage, "bob").unwrap();
+    assert_eq!(allocation.amount, Uint128::new(300));
+    assert!(allocation.claimed);
+}
+
+#[test]
+fn migrate_v3_4_records_allocation_uploader() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.4.0").unwrap();
+    let owner = deps.api.addr_make("owner");
+    cw_ownable::initialize_owner(&mut deps.storage, &deps.api, Some(owner.as_str())).unwrap();
+
+    // the allocations didn't record who added them before v3.5.0
+    for (address, amount, claimed) in [("alice", 100u128, false), ("bob", 300, true)] {
+        deps.as_mut().storage.set(
+            &ALLOCATIONS.key(address),
+            format!(r#"{{"amount":"{amount}","claimed":{claimed}}}"#).as_bytes(),
+        );
+    }
+
+    let response = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 2);
+    assert_eq!(response.events[0].attributes[1].value, "3.5.0");
+
+    let allocation = ALLOCATIONS.load(deps.as_ref().storage, "bob").unwrap();
+    assert_eq!(allocation.amount, Uint128::new(300));
+    assert!(allocation.claimed);
+    assert_eq!(allocation.added_by, owner);
+    assert_eq!(allocation.added_at, env.block.time);
+
+    let uploaded = query_allocations_by_uploader(deps.as_ref(), owner.to_string(), None, None)
+        .unwrap()
+        .allocations
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect::<Vec<String>>();
+    assert_eq!(uploaded, vec!["alice", "bob"]);
+}
+
+#[test]
+fn migrate_v3_5_indexes_and_totals_the_allocations() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.5.0").unwrap();
+    let owner = deps.api.addr_make("owner");
+
+    // the allocations couldn't be tagged before v3.6.0
+    for (address, amount) in [("alice", 100u128), ("bob", 300)] {
+        deps.as_mut().storage.set(
+            &ALLOCATIONS.key(address),
+            format!(
+                r#"{{"amount":"{amount}","claimed":false,"added_by":"{owner}","added_at":"1700000000000000000"}}"#
+            )
+            .as_bytes(),
+        );
+    }
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 1);
+    assert_eq!(response.events[0].attributes[0].value, "3.5.0");
+    assert_eq!(response.events[0].attributes[1].value, "3.6.0");
+
+    let untagged = ALLOCATIONS
+        .idx
+        .tag
+        .prefix(String::new())
+        .keys(deps.as_ref().storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()
+        .unwrap();
+    assert_eq!(untagged, vec!["alice", "bob"]);
+
+    let allocation = ALLOCATIONS.load(deps.as_ref().storage, "bob").unwrap();
+    assert_eq!(allocation.amount, Uint128::new(300));
+    assert_eq!(allocation.tag, None);
+
+    assert_eq!(
+        TOTAL_ALLOCATED.load(deps.as_ref().storage).unwrap(),
+        Uint128::new(400)
+    );
+}
+
+#[test]
+fn migrate_v3_5_builds_the_campaign_stats() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.5.0").unwrap();
+
+    // alice claimed from two slots at once, bob claimed twice from the first slot
+    let storage = deps.as_mut().storage;
+    CLAIMS
+        .save(
+            storage,
+            "alice".to_string(),
+            &HashMap::from([
+                (0, (Uint128::new(100), 1_000)),
+                (1, (Uint128::new(50), 1_000)),
+            ]),
+        )
+        .unwrap();
+    CLAIMS
+        .save(
+            storage,
+            "bob".to_string(),
+            &HashMap::from([(0, (Uint128::new(300), 2_000))]),
+        )
+        .unwrap();
+    for (receiver, sequence, entry) in [
+        ("alice", 0, (Uint128::new(100), 1_000, 0)),
+        ("alice", 1, (Uint128::new(50), 1_000, 1)),
+        ("bob", 0, (Uint128::new(200), 1_500, 0)),
+        ("bob", 1, (Uint128::new(100), 2_000, 0)),
+    ] {
+        CLAIM_HISTORY
+            .save(storage, (receiver, sequence), &entry)
+            .unwrap();
+    }
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let stats = query_campaign_stats(deps.as_ref()).unwrap();
+    assert_eq!(stats.claims, 3);
+    assert_eq!(stats.claimants, 2);
+    assert_eq!(stats.largest_claim, Uint128::new(200));
+    assert_eq!(stats.first_claim_at, Some(Timestamp::from_seconds(1_000)));
+    assert_eq!(stats.last_claim_at, Some(Timestamp::from_seconds(2_000)));
+}
+
+#[test]
+fn migrate_v3_5_builds_the_amounts_claimed_per_slot() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.5.0").unwrap();
+
+    for (address, claims) in [
+        (
+            "alice",
+            HashMap::from([
+                (0, (Uint128::new(100), 1_000)),
+                (1, (Uint128::new(50), 1_000)),
+            ]),
+        ),
+        ("bob", HashMap::from([(0, (Uint128::new(300), 2_000))])),
+    ] {
+        CLAIMS
+            .save(deps.as_mut().storage, address.to_string(), &claims)
+            .unwrap();
+    }
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let slots_claimed = get_slots_claimed(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        slots_claimed,
+        HashMap::from([(0, Uint128::new(400)), (1, Uint128::new(50))])
+    );
+}
+
+#[test]
+fn migrate_v3_5_checks_the_group_pools_against_the_allocations() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let alice = deps.api.addr_make("alice");
+    let schedule = vec![DistributionType::LumpSum {
+        percentage: Decimal::one(),
+        start_time: env.block.time.plus_seconds(1),
+        cliff_duration: None,
+    }];
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        InstantiateMsg {
+            owner: None,
+            action: None,
+            limits: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        ExecuteMsg::AddAllocations {
+            allocations: vec![(alice.to_string(), Uint128::new(600))],
+            skip_duplicates: None,
+            tag: None,
+            batch_id: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        ExecuteMsg::ManageCampaign {
+            action: CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Test Campaign".to_string(),
+                    description: "Test campaign".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(1_000, "uom"),
+                    distribution_type: schedule.clone(),
+                    start_time: env.block.time.plus_seconds(1),
+                    end_time: env.block.time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+        },
+    )
+    .unwrap();
+
+    // the allocations total wasn't tracked before v3.6.0
+    deps.as_mut().storage.remove(TOTAL_ALLOCATED.as_slice());
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "3.5.0").unwrap();
+    migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+
+    let create_group = |total: u128| ExecuteMsg::CreateGroup {
+        name: "team".to_string(),
+        schedule: schedule.clone(),
+        total: Uint128::new(total),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        create_group(500),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::InvalidInput { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+    }
+    execute(
+        deps.as_mut(),
+        env,
+        message_info(&owner, &[]),
+        create_group(400),
+    )
+    .unwrap();
+}
+
+#[test]
+fn migrate_without_campaign() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.2.0").unwrap();
+
+    let response = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(response.events.len(), 8);
+    assert!(CAMPAIGN.may_load(deps.as_ref().storage).unwrap().is_none());
+}
+
+#[test]
+fn cannot_migrate_to_same_or_older_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(
+        deps.as_mut().storage,
+        CONTRACT_NAME,
+        env!("CARGO_PKG_VERSION"),
+    )
+    .unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::MigrateInvalidVersion"),
+    }
+}
+
+#[test]
+fn cannot_migrate_different_contract() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(deps.as_mut().storage, "other-contract", "1.0.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    match err {
+        ContractError::Std(_) => {}
+        _ => panic!("Wrong error type, should return ContractError::Std"),
+    }
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::{coin, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, min_claim_amount: Option<Uint128>) -> CampaignParams {
+    CampaignParams {
+        min_claim_amount,
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+fn assert_below_minimum(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidClaimAmount { reason } => {
+            assert!(reason.contains("below the minimum claim amount"));
+        }
+        _ => panic!("Wrong error type, should return ContractError::InvalidClaimAmount"),
+    }
+}
+
+#[test]
+fn claims_below_the_minimum_are_rejected_unless_final() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, Some(Uint128::new(300))),
+        )
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().min_claim_amount, Some(Uint128::new(300)));
+        })
+        .add_day()
+        .claim(bob, None, Some(Uint128::new(250)), assert_below_minimum)
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(800)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the remaining 200 are below the minimum, but the claim exhausts the allocation
+        .claim(bob, None, Some(Uint128::new(100)), assert_below_minimum)
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        });
+}
+
+#[test]
+fn min_claim_amount_must_be_valid() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for min_claim_amount in [Uint128::zero(), Uint128::new(1_001)] {
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, Some(min_claim_amount))),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "min_claim_amount");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+}
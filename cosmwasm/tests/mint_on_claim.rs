@@ -0,0 +1,128 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, RewardSource};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(suite: &mut TestingSuite, denom: &str) -> CampaignParams {
+    let current_time = suite.get_time();
+
+    CampaignParams {
+        total_reward: coin(3_000, denom),
+        reward_source: RewardSource::MintOnClaim,
+        ..suite::campaign_params(&current_time, 3_000)
+    }
+}
+
+#[test]
+fn claim_mints_the_rewards() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    let contract = suite.claimdrop_contract_addr.clone();
+    let denom = format!("factory/{contract}/reward");
+    let params = campaign_params(&mut suite, &denom);
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(2_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().reward_source, RewardSource::MintOnClaim);
+        })
+        // the contract was never topped up
+        .query_balance(&denom, &contract, |balance| {
+            assert_eq!(balance, Uint128::zero());
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            carol,
+            None,
+            Some(Uint128::new(500)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance(&denom, bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000));
+        })
+        .query_balance(&denom, carol, |balance| {
+            assert_eq!(balance, Uint128::new(500));
+        })
+        .query_balance(&denom, &contract, |balance| {
+            assert_eq!(balance, Uint128::zero());
+        })
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claimed.amount, Uint128::new(1_500));
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn cannot_mint_on_claim_denoms_not_administered_by_the_contract() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    for denom in ["uom".to_string(), format!("factory/{owner}/reward")] {
+        let params = campaign_params(&mut suite, &denom);
+
+        suite.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "reward_source");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
 use mantra_claimdrop_std::error::ContractError;
-use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType, Role, SudoMsg};
 
 mod suite;
 use suite::TestingSuite;
@@ -27,10 +28,12 @@ fn test_cannot_blacklist_owner() {
                     total_reward: coin(10_000_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 86400,
+                        start_time: current_time.plus_seconds(86400),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 86400,
-                    end_time: current_time.seconds() + 86400 * 7,
+                    start_time: current_time.plus_seconds(86400),
+                    end_time: current_time.plus_seconds(86400 * 7),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -133,10 +136,12 @@ fn test_owner_can_have_allocations_but_cannot_be_blacklisted() {
                     total_reward: coin(10_000_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 86400,
+                        start_time: current_time.plus_seconds(86400),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 86400,
-                    end_time: current_time.seconds() + 86400 * 7,
+                    start_time: current_time.plus_seconds(86400),
+                    end_time: current_time.plus_seconds(86400 * 7),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -245,10 +250,12 @@ fn test_owner_protection_ensures_owner_can_claim() {
                     total_reward: coin(10_000_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 86400,
+                        start_time: current_time.plus_seconds(86400),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 86400,
-                    end_time: current_time.seconds() + 86400 * 7,
+                    start_time: current_time.plus_seconds(86400),
+                    end_time: current_time.plus_seconds(86400 * 7),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -358,10 +365,12 @@ fn test_owner_protection_with_multiple_authorized_wallets() {
                     total_reward: coin(10_000_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 86400,
+                        start_time: current_time.plus_seconds(86400),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 86400,
-                    end_time: current_time.seconds() + 86400 * 7,
+                    start_time: current_time.plus_seconds(86400),
+                    end_time: current_time.plus_seconds(86400 * 7),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -472,3 +481,293 @@ fn test_owner_protection_with_multiple_authorized_wallets() {
         assert_eq!(allocations.allocations[0].1.amount.u128(), 3000);
     });
 }
+
+#[test]
+fn test_pending_owner_cannot_be_blacklisted() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+
+    let owner = &suite.senders[0].clone();
+    let authorized_wallet = &suite.senders[1].clone();
+    let pending_owner = &suite.senders[2].clone();
+    let blacklisted_user = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_authorized_wallets(
+            owner,
+            vec![authorized_wallet.to_string()],
+            true,
+            &[],
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_ownership(
+            owner,
+            cw_ownable::Action::TransferOwnership {
+                new_owner: pending_owner.to_string(),
+                expiry: None,
+            },
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_ownership(|result| {
+            assert_eq!(
+                result.unwrap().pending_owner,
+                Some(pending_owner.to_string())
+            );
+        })
+        .blacklist_address(
+            authorized_wallet,
+            pending_owner,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "Cannot blacklist the pending owner");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        // the ownership can't be transferred to a blacklisted address either
+        .blacklist_address(
+            authorized_wallet,
+            blacklisted_user,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_ownership(
+            owner,
+            cw_ownable::Action::TransferOwnership {
+                new_owner: blacklisted_user.to_string(),
+                expiry: None,
+            },
+            |result: Result<_, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(
+                            reason,
+                            "Cannot transfer the ownership to a blacklisted address"
+                        );
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        );
+}
+
+#[test]
+fn test_new_owner_permissions_are_revoked_on_acceptance() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+
+    let owner = &suite.senders[0].clone();
+    let new_owner = &suite.senders[1].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_authorized_wallets(
+            owner,
+            vec![new_owner.to_string()],
+            true,
+            &[],
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .grant_roles(
+            owner,
+            new_owner,
+            vec![Role::BlacklistManager],
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_ownership(
+            owner,
+            cw_ownable::Action::TransferOwnership {
+                new_owner: new_owner.to_string(),
+                expiry: None,
+            },
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the permissions are kept while the transfer is pending
+        .query_authorized_wallets(None, None, |result| {
+            assert_eq!(result.unwrap().wallets, vec![new_owner.to_string()]);
+        })
+        .update_ownership(
+            new_owner,
+            cw_ownable::Action::AcceptOwnership,
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_ownership(|result| {
+            let ownership = result.unwrap();
+            assert_eq!(ownership.owner, Some(new_owner.to_string()));
+            assert_eq!(ownership.pending_owner, None);
+        })
+        .query_authorized_wallets(None, None, |result| {
+            assert!(result.unwrap().wallets.is_empty());
+        })
+        .query_role_holders(Role::BlacklistManager, None, None, |result| {
+            assert!(result.unwrap().holders.is_empty());
+        })
+        // the previous owner lost its permissions with the ownership
+        .blacklist_address(
+            owner,
+            new_owner,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        );
+}
+
+#[test]
+fn test_owner_protections_are_handed_off_on_acceptance() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+
+    let owner = &suite.senders[0].clone();
+    let new_owner = &suite.senders[1].clone();
+    let approver = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_authorized_wallets(
+            owner,
+            vec![approver.to_string()],
+            true,
+            &[],
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_sweep_threshold(
+            owner,
+            Uint128::new(100),
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_000, "uusdc")],
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            None,
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_ownership(
+            owner,
+            cw_ownable::Action::TransferOwnership {
+                new_owner: new_owner.to_string(),
+                expiry: None,
+            },
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .freeze_address(owner, new_owner, |result: Result<_, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .update_ownership(
+            new_owner,
+            cw_ownable::Action::AcceptOwnership,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let event = response
+                    .events
+                    .iter()
+                    .find(|event| event.ty == "wasm-claimdrop/ownership_handoff")
+                    .unwrap();
+                assert!(event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "pending_sweep_discarded" && attr.value == "true"));
+            },
+        )
+        // the sweep requested by the previous owner would have paid out to the new owner
+        .query_pending_sweep(|result| {
+            assert!(result.unwrap().pending_sweep.is_none());
+        })
+        // the previous owner lost the protection against being blacklisted
+        .blacklist_address(
+            new_owner,
+            owner,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            approver,
+            new_owner,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        );
+}
+
+#[test]
+fn test_governance_transfer_lifts_the_new_owner_blacklisting() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+
+    let owner = &suite.senders[0].clone();
+    let new_owner = &suite.senders[1].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .blacklist_address(
+            owner,
+            new_owner,
+            true,
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .sudo(
+            SudoMsg::TransferOwnership {
+                new_owner: new_owner.to_string(),
+            },
+            |result: Result<_, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_blacklisted(new_owner, |result| {
+            assert!(!result.unwrap().is_blacklisted);
+        })
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().blacklisted, 0);
+        });
+}
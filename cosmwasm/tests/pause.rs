@@ -0,0 +1,275 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CircuitBreaker, CircuitBreakerWindow, PauseScope};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup(suite: &mut TestingSuite) {
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(alice.to_string(), Uint128::new(1_000))],
+            campaign_params(current_time, 1_000),
+        )
+        .add_day();
+}
+
+fn assert_paused(result: Result<AppResponse, anyhow::Error>, expected_scope: &str) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::ContractPaused { scope } => assert_eq!(scope, expected_scope),
+        _ => panic!("Wrong error type, should return ContractError::ContractPaused"),
+    }
+}
+
+#[test]
+fn pausing_the_claims_keeps_the_allocations_manageable() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+
+    suite
+        .set_pause(
+            alice,
+            Some(PauseScope::Claims),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .set_pause(
+            owner,
+            Some(PauseScope::Claims),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.scope, Some(PauseScope::Claims));
+            assert!(status.claims_paused);
+            assert!(!status.allocation_management_paused);
+            assert!(!status.circuit_breaker_tripped);
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "claims");
+            },
+        )
+        .replace_address(
+            owner,
+            alice,
+            bob,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_pause(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert_eq!(status.scope, None);
+            assert!(!status.claims_paused);
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn pausing_the_allocation_management_keeps_the_claims_open() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+
+    suite
+        .set_pause(
+            owner,
+            Some(PauseScope::AllocationManagement),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(100))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "allocation_management");
+            },
+        )
+        .remove_address(
+            owner,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "allocation_management");
+            },
+        )
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn pausing_everything_only_allows_lifting_the_pause() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+
+    suite
+        .set_pause(
+            owner,
+            Some(PauseScope::All),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert!(status.claims_paused);
+            assert!(status.allocation_management_paused);
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "all");
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "all");
+            },
+        )
+        .set_pause(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn the_pause_and_the_circuit_breaker_are_lifted_separately() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+
+    suite
+        .update_config(
+            owner,
+            Some(CircuitBreaker {
+                max_claim_amount: Uint128::new(500),
+                window: CircuitBreakerWindow::Hour,
+            }),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // alice's claim trips the circuit breaker
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_pause(
+            owner,
+            Some(PauseScope::Claims),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert!(status.claims_paused);
+            assert!(status.circuit_breaker_tripped);
+            assert_eq!(status.scope, Some(PauseScope::Claims));
+        })
+        // lifting the pause doesn't resume the claims paused by the circuit breaker
+        .set_pause(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert!(status.claims_paused);
+            assert!(status.circuit_breaker_tripped);
+            assert_eq!(status.scope, None);
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::ClaimsPaused => {}
+                    _ => panic!("Wrong error type, should return ContractError::ClaimsPaused"),
+                }
+            },
+        )
+        // and resuming the claims doesn't lift the pause
+        .set_pause(
+            owner,
+            Some(PauseScope::Claims),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .resume_claims(owner, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_paused(result, "claims");
+            },
+        )
+        .set_pause(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_pause_status(|result| {
+            let status = result.unwrap();
+            assert!(!status.claims_paused);
+            assert!(!status.circuit_breaker_tripped);
+        });
+}
@@ -0,0 +1,134 @@
+use cosmwasm_std::{coin, Addr, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, IbcReceiver, PayoutTarget};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, locker: &Addr) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[(bob.to_string(), Uint128::new(1_000))],
+            CampaignParams {
+                payout_target: PayoutTarget::Contract {
+                    addr: locker.to_string(),
+                    msg_template: r#"{"lock":{"owner":"{receiver}"}}"#.to_string(),
+                },
+                ..campaign_params(current_time, 1_000)
+            },
+        )
+        .add_day();
+}
+
+#[test]
+fn claims_are_locked_in_the_payout_contract() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let locker = suite.instantiate_locker_contract();
+
+    setup_campaign(&mut suite, &locker);
+
+    suite
+        .query_campaign(|result| {
+            assert_eq!(
+                result.unwrap().payout_target,
+                PayoutTarget::Contract {
+                    addr: locker.to_string(),
+                    msg_template: r#"{"lock":{"owner":"{receiver}"}}"#.to_string(),
+                }
+            );
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(
+                    &Event::new("wasm")
+                        .add_attribute("action", "lock")
+                        .add_attribute("owner", bob.as_str())
+                        .add_attribute("amount", "1000uom")
+                ));
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_balance("uom", &locker, |balance| {
+            assert_eq!(balance, Uint128::new(1_000));
+        })
+        .query_claimed(Some(bob), None, None, |result| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(1_000, "uom"));
+        });
+}
+
+#[test]
+fn cannot_claim_over_ibc_when_paying_out_to_a_contract() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let locker = suite.instantiate_locker_contract();
+
+    setup_campaign(&mut suite, &locker);
+
+    suite.claim_over_ibc(
+        bob,
+        None,
+        IbcReceiver {
+            channel_id: "channel-0".to_string(),
+            to_address: "cosmos1receiver".to_string(),
+            timeout_seconds: 600,
+        },
+        |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => {
+                    assert_eq!(
+                        reason,
+                        "cannot claim over IBC when the payout target is a contract"
+                    );
+                }
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        },
+    );
+}
+
+#[test]
+fn payout_message_template_must_include_the_receiver() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let locker = suite.instantiate_locker_contract();
+    let params = CampaignParams {
+        payout_target: PayoutTarget::Contract {
+            addr: locker.to_string(),
+            msg_template: r#"{"lock":{}}"#.to_string(),
+        },
+        ..campaign_params(&suite.get_time(), 1_000)
+    };
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "payout_target");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        );
+}
@@ -0,0 +1,158 @@
+use cosmwasm_std::{coin, coins, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, ProposalAction};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+/// Sets up a campaign and authorizes bob to perform admin actions.
+fn setup_campaign(suite: &mut TestingSuite) {
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(&[], campaign_params(current_time, 10_000))
+        .manage_authorized_wallets(
+            owner,
+            vec![bob.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn authorized_wallet_can_only_propose_closing_the_campaign() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .manage_campaign(
+            bob,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .propose(
+            bob,
+            ProposalAction::CloseCampaign {},
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_proposals(None, None, |result| {
+            let proposals = result.unwrap().proposals;
+            assert_eq!(proposals.len(), 1);
+            assert_eq!(proposals[0].id, 1);
+            assert_eq!(proposals[0].action, ProposalAction::CloseCampaign {});
+            assert_eq!(proposals[0].proposer, bob.to_string());
+            assert!(proposals[0].approved_at.is_none());
+        })
+        // proposing doesn't close the campaign
+        .query_campaign(|result| {
+            assert!(result.unwrap().closed.is_none());
+        })
+        .approve(bob, 1, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::OwnershipError(_) => {}
+                _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+            }
+        })
+        .approve(owner, 1, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_campaign(|result| {
+            assert!(result.unwrap().closed.is_some());
+        })
+        .query_balance("uom", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_proposals(None, None, |result| {
+            assert!(result.unwrap().proposals[0].approved_at.is_some());
+        })
+        .approve(owner, 1, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => {
+                    assert_eq!(reason, "proposal 1 has already been approved");
+                }
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        })
+        .approve(owner, 2, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { reason } => {
+                    assert_eq!(reason, "proposal 2 doesn't exist");
+                }
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        });
+}
+
+#[test]
+fn owner_approves_sweep_proposal() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .top_up_campaign(
+            carol,
+            &coins(5_000, "uusdc"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // carol is not authorized to make proposals
+        .propose(
+            carol,
+            ProposalAction::Sweep {
+                denom: "uusdc".to_string(),
+                amount: None,
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .propose(
+            bob,
+            ProposalAction::Sweep {
+                denom: "uusdc".to_string(),
+                amount: Some(Uint128::new(2_000)),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .approve(owner, 1, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_002_000));
+        });
+}
@@ -0,0 +1,142 @@
+use cosmwasm_std::{coin, Decimal, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn prune_event(allocations: &str, claims: &str, blacklist: &str, done: &str) -> Event {
+    Event::new("wasm-claimdrop/prune_state")
+        .add_attribute("allocations", allocations)
+        .add_attribute("claims", claims)
+        .add_attribute("blacklist", blacklist)
+        .add_attribute("done", done)
+}
+
+#[test]
+fn owner_prunes_the_state_of_a_closed_campaign() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(1_000)),
+                (dan.to_string(), Uint128::new(1_000)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            owner,
+            dan,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Pruned Campaign".to_string(),
+                    description: "Campaign pruned after closing".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(3_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(3_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .prune_state(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "the campaign must be closed to prune its state");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .prune_state(bob, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::OwnershipError(_) => {}
+                _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+            }
+        })
+        .prune_state(
+            owner,
+            Some(2),
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert!(result
+                    .unwrap()
+                    .has_event(&prune_event("2", "0", "0", "false")));
+            },
+        )
+        .query_counts(|result| {
+            let counts = result.unwrap();
+            assert_eq!(counts.allocations, 1);
+            assert_eq!(counts.claimants, 1);
+            assert_eq!(counts.blacklisted, 1);
+        })
+        .prune_state(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            assert!(result
+                .unwrap()
+                .has_event(&prune_event("1", "1", "1", "true")));
+        })
+        .query_counts(|result| {
+            let counts = result.unwrap();
+            assert_eq!(counts.allocations, 0);
+            assert_eq!(counts.claimants, 0);
+            assert_eq!(counts.blacklisted, 0);
+        })
+        .query_allocations(None, None, None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        })
+        .query_is_blacklisted(dan, |result| {
+            assert!(!result.unwrap().is_blacklisted);
+        });
+}
@@ -0,0 +1,114 @@
+use bech32::{Bech32, Hrp};
+use claimdrop_contract::helpers::{pubkey_to_canonical_address, PUBKEY_ADDRESS_PREFIX};
+use cosmwasm_std::{coin, Addr, Binary, Uint128};
+use cw_multi_test::AppResponse;
+use k256::ecdsa::SigningKey;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::TestingSuite;
+
+/// Returns the prefixed base64 compressed public key of a signing key and the mantra address it
+/// derives
+fn pubkey_and_address(secret: u8) -> (String, Addr) {
+    let signing_key = SigningKey::from_bytes(&[secret; 32].into()).unwrap();
+    let pubkey = signing_key.verifying_key().to_encoded_point(true);
+    let address = bech32::encode::<Bech32>(
+        Hrp::parse("mantra").unwrap(),
+        &pubkey_to_canonical_address(pubkey.as_bytes()),
+    )
+    .unwrap();
+
+    (
+        format!(
+            "{PUBKEY_ADDRESS_PREFIX}{}",
+            Binary::from(pubkey.as_bytes()).to_base64()
+        ),
+        Addr::unchecked(address),
+    )
+}
+
+#[test]
+fn pubkeys_are_converted_to_the_address_they_derive() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let (alice_pubkey, alice) = pubkey_and_address(7);
+    let (carol_pubkey, carol) = pubkey_and_address(8);
+
+    // an unprefixed public key is kept as a placeholder
+    let placeholder = carol_pubkey
+        .strip_prefix(PUBKEY_ADDRESS_PREFIX)
+        .unwrap()
+        .to_string();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![
+                (alice_pubkey.clone(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(200)),
+                (carol_pubkey, Uint128::new(300)),
+                (placeholder.clone(), Uint128::new(400)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(Some(&alice), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, alice.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(100));
+        })
+        .query_allocations(Some(&carol), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations[0].0, carol.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(300));
+        })
+        // the public key resolves to the same allocation as the address
+        .query_allocations(Some(&Addr::unchecked(alice_pubkey)), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations[0].1.amount, Uint128::new(100));
+        })
+        .query_allocations(
+            Some(&Addr::unchecked(placeholder.to_lowercase())),
+            None,
+            None,
+            |result| {
+                let allocations = result.unwrap().allocations;
+                assert_eq!(allocations[0].1.amount, Uint128::new(400));
+            },
+        );
+}
+
+#[test]
+fn prefixed_strings_must_be_public_keys() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    // 33 bytes that aren't a compressed public key
+    let mut not_a_pubkey = [1u8; 33];
+    not_a_pubkey[0] = 0x04;
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(
+                format!(
+                    "{PUBKEY_ADDRESS_PREFIX}{}",
+                    Binary::from(not_a_pubkey).to_base64()
+                ),
+                Uint128::new(100),
+            )],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        );
+}
@@ -0,0 +1,242 @@
+use claimdrop_contract::helpers::draw_raffle_winners;
+use cosmwasm_std::{coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+const SEED: &str = "8ee2f3bb4fb5ca9e1aa3f3b0e1b2b6e8c0c5c8e0d1a4b3c2f9e8d7c6b5a4f3e2";
+
+/// Sets up a campaign with a raffle of 10% of the total reward among 3 winners, drawn 2 days after
+/// the campaign starts. Returns the draw time.
+fn setup_campaign(suite: &mut TestingSuite) -> Timestamp {
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+    let draw_time = current_time.plus_days(2);
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(500)),
+                (carol.to_string(), Uint128::new(300)),
+                (dan.to_string(), Uint128::new(200)),
+            ],
+            CampaignParams {
+                distribution_type: vec![
+                    DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    },
+                    DistributionType::Raffle {
+                        prize_pool_percentage: Decimal::percent(10),
+                        draw_time,
+                        winners: 3,
+                    },
+                ],
+                end_time: current_time.plus_days(30),
+                ..campaign_params(current_time, 1_000)
+            },
+        )
+        // the prize pool comes on top of the total reward
+        .top_up_campaign(
+            owner,
+            &[coin(100, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    draw_time
+}
+
+#[test]
+fn raffle_winners_get_their_allocations_credited() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite);
+
+    // the entrants are the allocation holders, sorted by address as in storage
+    let seed = HexBinary::from_hex(SEED).unwrap();
+    let mut entrants = vec![
+        (bob.to_string(), Uint128::new(500)),
+        (carol.to_string(), Uint128::new(300)),
+        (dan.to_string(), Uint128::new(200)),
+    ];
+    entrants.sort();
+    let expected_winners = draw_raffle_winners(seed.as_slice(), &entrants, 3);
+
+    suite
+        .add_day()
+        .submit_raffle_seed(
+            owner,
+            seed.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "the raffle draw time has not passed yet");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        .add_day()
+        .submit_raffle_seed(
+            bob,
+            seed.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .submit_raffle_seed(
+            owner,
+            seed.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // anyone can draw the raffle
+        .draw_raffle(dan, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_raffle(|result| {
+            let raffle = result.unwrap();
+            assert!(raffle.drawn);
+            assert_eq!(raffle.seed, Some(seed.clone()));
+            assert_eq!(
+                raffle
+                    .winners
+                    .iter()
+                    .map(|(winner, _)| winner.clone())
+                    .collect::<Vec<_>>(),
+                expected_winners
+            );
+            assert!(raffle
+                .winners
+                .iter()
+                .all(|(_, prize)| *prize == coin(33, "uom")));
+        })
+        .query_allocations(None, None, None, |result| {
+            let total = result
+                .unwrap()
+                .allocations
+                .iter()
+                .fold(Uint128::zero(), |acc, (_, allocation)| {
+                    acc + allocation.amount
+                });
+            assert_eq!(total, Uint128::new(1_099));
+        })
+        .draw_raffle(dan, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "the raffle has already been drawn");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .submit_raffle_seed(
+            owner,
+            seed.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "the raffle seed has already been submitted");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        );
+
+    // the winners can claim their prizes along with their allocations
+    let bob_prizes = expected_winners
+        .iter()
+        .filter(|winner| *winner == bob.as_str())
+        .count() as u128;
+    suite
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_500 + 33 * bob_prizes));
+        });
+}
+
+#[test]
+fn cannot_draw_raffle_without_seed() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .draw_raffle(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "the raffle draw time has not passed yet");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .add_week()
+        .draw_raffle(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "the raffle seed has not been submitted");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .query_raffle(|result| {
+            let raffle = result.unwrap();
+            assert!(!raffle.drawn);
+            assert!(raffle.seed.is_none());
+            assert!(raffle.winners.is_empty());
+        });
+}
+
+#[test]
+fn raffle_draw_is_weighted_by_allocation() {
+    let seed = HexBinary::from_hex(SEED).unwrap();
+    let entrants = vec![
+        ("alice".to_string(), Uint128::zero()),
+        ("bob".to_string(), Uint128::new(100)),
+    ];
+
+    let winners = draw_raffle_winners(seed.as_slice(), &entrants, 10);
+    assert_eq!(winners, vec!["bob".to_string(); 10]);
+
+    // the draw is deterministic given the seed
+    let entrants = vec![
+        ("alice".to_string(), Uint128::new(1_000)),
+        ("bob".to_string(), Uint128::new(1_000)),
+        ("carol".to_string(), Uint128::new(1_000)),
+    ];
+    assert_eq!(
+        draw_raffle_winners(seed.as_slice(), &entrants, 50),
+        draw_raffle_winners(seed.as_slice(), &entrants, 50)
+    );
+    assert!(draw_raffle_winners(&[], &[], 3).is_empty());
+}
@@ -0,0 +1,97 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn redistribute_attribute(response: &AppResponse, key: &str) -> String {
+    response
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-claimdrop/redistribute")
+        .and_then(|event| event.attributes.iter().find(|attr| attr.key == key))
+        .map(|attr| attr.value.clone())
+        .unwrap()
+}
+
+#[test]
+fn blacklisted_allocations_are_redistributed_proportionally() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(200)),
+                (bob.to_string(), Uint128::new(300)),
+                (carol.to_string(), Uint128::new(500)),
+            ],
+            campaign_params(current_time, 1_000),
+        )
+        // the allocations can still change before the campaign starts
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignNotStarted { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::CampaignNotStarted"),
+            }
+        })
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            owner,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .redistribute_forfeited(alice, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::OwnershipError { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+            }
+        })
+        // the first call reclaims carol's allocation, the second spreads it over alice and bob
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            let response = result.unwrap();
+            assert_eq!(redistribute_attribute(&response, "pool"), "500");
+            assert_eq!(redistribute_attribute(&response, "done"), "false");
+        })
+        .redistribute_forfeited(owner, None, |result: Result<AppResponse, anyhow::Error>| {
+            let response = result.unwrap();
+            assert_eq!(redistribute_attribute(&response, "distributed"), "500");
+            assert_eq!(redistribute_attribute(&response, "done"), "true");
+        })
+        .query_allocations(Some(alice), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(400, "uom"));
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(0, "uom"));
+        })
+        // bob claims the share added on top of what he already claimed
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_600));
+        });
+}
@@ -0,0 +1,255 @@
+use bech32::{Bech32, Hrp};
+use claimdrop_contract::helpers::pubkey_to_canonical_address;
+use cosmwasm_std::{coin, Addr, Binary, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use k256::ecdsa::SigningKey;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{
+    AllocationVoucher, CampaignAction, CampaignParams, DistributionType,
+};
+
+mod suite;
+use suite::TestingSuite;
+
+/// Returns the signing key of the owner, its compressed public key and its mantra address
+fn owner_signer() -> (SigningKey, Binary, Addr) {
+    let signing_key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+    let pubkey = signing_key.verifying_key().to_encoded_point(true);
+    let address = bech32::encode::<Bech32>(
+        Hrp::parse("mantra").unwrap(),
+        &pubkey_to_canonical_address(pubkey.as_bytes()),
+    )
+    .unwrap();
+
+    (
+        signing_key,
+        pubkey.as_bytes().into(),
+        Addr::unchecked(address),
+    )
+}
+
+#[test]
+fn users_register_their_allocations_with_owner_vouchers() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let (signing_key, pubkey, owner) = owner_signer();
+    let current_time = suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+    let voucher = AllocationVoucher {
+        address: alice.to_string(),
+        amount: Uint128::new(1_000),
+        campaign_id: suite.claimdrop_contract_addr.to_string(),
+        expiry: current_time.plus_days(1),
+    };
+
+    suite
+        // the vouchers are disabled until the owner sets its key
+        .register_allocation(
+            alice,
+            &owner,
+            &signing_key,
+            voucher.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        .set_voucher_pubkey(
+            &owner,
+            pubkey.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // bob relays alice's voucher, the allocation goes to alice
+        .register_allocation(
+            bob,
+            &owner,
+            &signing_key,
+            voucher.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(Some(alice), None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 1);
+            assert_eq!(allocations[0].0, alice.to_string());
+            assert_eq!(allocations[0].1.amount, Uint128::new(1_000));
+        })
+        // the voucher can't be registered twice
+        .register_allocation(
+            bob,
+            &owner,
+            &signing_key,
+            voucher.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::VoucherAlreadyRegistered => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::VoucherAlreadyRegistered"
+                    ),
+                }
+            },
+        )
+        // nor once the owner removed the allocation it added
+        .remove_address(
+            &owner,
+            alice,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .register_allocation(
+            bob,
+            &owner,
+            &signing_key,
+            voucher.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::VoucherAlreadyRegistered => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::VoucherAlreadyRegistered"
+                    ),
+                }
+            },
+        )
+        .query_allocations(Some(alice), None, None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        })
+        // vouchers signed by another key are rejected
+        .register_allocation(
+            bob,
+            &owner,
+            &SigningKey::from_bytes(&[8; 32].into()).unwrap(),
+            AllocationVoucher {
+                address: bob.to_string(),
+                ..voucher.clone()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidSignature => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidSignature"),
+                }
+            },
+        )
+        .add_day()
+        .add_day()
+        .register_allocation(
+            bob,
+            &owner,
+            &signing_key,
+            AllocationVoucher {
+                address: bob.to_string(),
+                ..voucher
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::VoucherExpired { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::VoucherExpired"),
+                }
+            },
+        );
+}
+
+#[test]
+fn voucher_pubkey_must_belong_to_the_owner() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let (_, pubkey, owner) = owner_signer();
+
+    suite
+        .instantiate_claimdrop_contract(Some(alice.to_string()))
+        .set_voucher_pubkey(
+            alice,
+            pubkey.clone(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .set_voucher_pubkey(
+            &owner,
+            pubkey,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert!(result.is_err());
+            },
+        );
+}
+
+#[test]
+fn vouchers_cant_be_registered_once_the_campaign_started() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let alice = &suite.senders[1].clone();
+    let (signing_key, pubkey, owner) = owner_signer();
+    let current_time = suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_voucher_pubkey(
+            &owner,
+            pubkey,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            &owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Voucher Campaign".to_string(),
+                    description: "Campaign with allocations registered by the users".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(10_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(10),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    let voucher = AllocationVoucher {
+        address: alice.to_string(),
+        amount: Uint128::new(1_000),
+        campaign_id: suite.claimdrop_contract_addr.to_string(),
+        expiry: current_time.plus_days(5),
+    };
+
+    suite.register_allocation(
+        alice,
+        &owner,
+        &signing_key,
+        voucher,
+        |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignAlreadyStarted { .. } => {}
+                _ => {
+                    panic!("Wrong error type, should return ContractError::CampaignAlreadyStarted")
+                }
+            }
+        },
+    );
+}
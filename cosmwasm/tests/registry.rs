@@ -0,0 +1,195 @@
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams};
+
+mod suite;
+use suite::TestingSuite;
+
+const DAY: u64 = 86_400;
+
+/// Returns the attributes of the registry update received by the registry, if any.
+fn registry_update(response: &AppResponse, registry: &Addr) -> Option<Vec<(String, String)>> {
+    response
+        .events
+        .iter()
+        .find(|event| {
+            event.ty == "wasm"
+                && event.attributes.iter().any(|attribute| {
+                    attribute.key == "_contract_address" && attribute.value == registry.as_str()
+                })
+        })
+        .map(|event| {
+            event
+                .attributes
+                .iter()
+                .filter(|attribute| {
+                    !["_contract_address", "action"].contains(&attribute.key.as_str())
+                })
+                .map(|attribute| (attribute.key.clone(), attribute.value.clone()))
+                .collect()
+        })
+}
+
+fn pairs(attributes: &[(&str, &str)]) -> Option<Vec<(String, String)>> {
+    Some(
+        attributes
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
+fn campaign_params(suite: &mut TestingSuite, grace_period: Option<u64>) -> CampaignParams {
+    let current_time = suite.get_time();
+
+    CampaignParams {
+        name: "Registered Campaign".to_string(),
+        end_time: current_time.plus_seconds(DAY * 7),
+        grace_period,
+        ..suite::campaign_params(&current_time, 1_000)
+    }
+}
+
+fn setup(suite: &mut TestingSuite) -> Addr {
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let registry = suite.instantiate_registry_contract();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_registry(
+            alice,
+            registry.as_str(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        )
+        .set_registry(
+            owner,
+            "invalid",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        )
+        .set_registry(
+            owner,
+            registry.as_str(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(alice.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    registry
+}
+
+#[test]
+fn the_registry_is_notified_when_the_campaign_is_created_and_closed() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let registry = setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let params = campaign_params(&mut suite, None);
+
+    suite
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_eq!(
+                    registry_update(&result.unwrap(), &registry),
+                    pairs(&[
+                        ("update", "campaign_created"),
+                        ("name", "Registered Campaign"),
+                        ("total_reward", "1000uom"),
+                    ])
+                );
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(1_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_eq!(
+                    registry_update(&result.unwrap(), &registry),
+                    pairs(&[
+                        ("update", "campaign_closed"),
+                        ("name", "Registered Campaign"),
+                        ("refunded", "1000uom"),
+                    ])
+                );
+            },
+        );
+}
+
+#[test]
+fn the_registry_is_notified_when_the_campaign_is_finalized() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let registry = setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[2].clone();
+    let params = campaign_params(&mut suite, Some(DAY));
+
+    suite
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_week()
+        .add_day()
+        .finalize_campaign(bob, |result: Result<AppResponse, anyhow::Error>| {
+            assert_eq!(
+                registry_update(&result.unwrap(), &registry),
+                pairs(&[
+                    ("update", "campaign_finalized"),
+                    ("name", "Registered Campaign"),
+                    ("finalized_by", bob.as_str()),
+                ])
+            );
+        });
+}
+
+#[test]
+fn removing_the_registry_stops_the_notifications() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let registry = setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let params = campaign_params(&mut suite, None);
+
+    suite
+        .set_registry(owner, "", |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_eq!(registry_update(&result.unwrap(), &registry), None);
+            },
+        );
+}
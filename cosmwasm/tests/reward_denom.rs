@@ -0,0 +1,126 @@
+use cosmwasm_std::{coin, Timestamp};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{validate_denom, CampaignAction, CampaignParams, Limits};
+
+mod suite;
+use suite::TestingSuite;
+
+const IBC_HASH: &str = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+fn campaign_params(current_time: &Timestamp, denom: &str) -> CampaignParams {
+    CampaignParams {
+        total_reward: coin(10_000, denom),
+        end_time: current_time.plus_days(10),
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+fn assert_invalid_denom(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::InvalidDenom { .. } => {}
+        _ => panic!("Wrong error type, should return ContractError::InvalidDenom"),
+    }
+}
+
+#[test]
+fn denom_format_is_validated() {
+    for denom in [
+        "uom".to_string(),
+        "uusdc".to_string(),
+        format!("ibc/{IBC_HASH}"),
+        "factory/mantra1c758pr6v2zpgdl2rg2enmjedfglxjkac8m7syw/ausdy".to_string(),
+        "factory/mantra1c758pr6v2zpgdl2rg2enmjedfglxjkac8m7syw/nested/denom".to_string(),
+    ] {
+        assert!(validate_denom(&denom).is_ok(), "{denom} should be valid");
+    }
+
+    for denom in [
+        "ab".to_string(),
+        "a".repeat(129),
+        "1uom".to_string(),
+        "u om".to_string(),
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB".to_string(),
+        format!("ibc/{}", IBC_HASH.to_lowercase()),
+        "ibc/".to_string(),
+        "factory/mantra1c758pr6v2zpgdl2rg2enmjedfglxjkac8m7syw".to_string(),
+        "factory//ausdy".to_string(),
+        "factory/mantra1c758pr6v2zpgdl2rg2enmjedfglxjkac8m7syw/".to_string(),
+    ] {
+        assert!(
+            matches!(
+                validate_denom(&denom),
+                Err(ContractError::InvalidDenom { .. })
+            ),
+            "{denom} should be invalid"
+        );
+    }
+}
+
+#[test]
+fn cannot_create_campaign_with_a_malformed_denom() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "ibc/27394FB092D2")),
+            },
+            &[],
+            assert_invalid_denom,
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, &format!("ibc/{IBC_HASH}"))),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn reward_denom_must_exist_when_required() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .update_limits(
+            owner,
+            Limits {
+                require_existing_reward_denom: true,
+                ..Default::default()
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // a typo'd denom has no supply
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "uomm")),
+            },
+            &[],
+            assert_invalid_denom,
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, "uom")),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
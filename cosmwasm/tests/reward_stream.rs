@@ -0,0 +1,126 @@
+use cosmwasm_std::{coin, Decimal, Decimal256, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp) -> CampaignParams {
+    CampaignParams {
+        distribution_type: vec![
+            DistributionType::LinearVesting {
+                percentage: Decimal::percent(80),
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_seconds(100_001),
+                cliff_duration: None,
+                cliff_behavior: None,
+            },
+            DistributionType::LumpSum {
+                percentage: Decimal::percent(20),
+                start_time: current_time.plus_seconds(1),
+                cliff_duration: Some(172_800),
+            },
+        ],
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+#[test]
+fn rewards_stream_and_claims_up_to_a_time() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![(alice.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(10_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .query_rewards(alice, |result| {
+            let rewards = result.unwrap();
+            assert_eq!(
+                rewards.accrual_rate,
+                Decimal256::from_ratio(8_000u128, 100_000u128)
+            );
+            assert_eq!(
+                rewards.next_unlocks,
+                vec![current_time.plus_seconds(172_801)]
+            );
+        })
+        .claim_up_to(
+            alice,
+            current_time.plus_days(2),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        // half of the linear vesting had vested at that time
+        .claim_up_to(
+            alice,
+            current_time.plus_seconds(50_001),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000 + 4_000));
+        })
+        .claim_up_to(
+            alice,
+            current_time.plus_seconds(50_001),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        )
+        .claim_up_to(
+            alice,
+            current_time.plus_seconds(75_001),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000 + 6_000));
+        })
+        .add_day()
+        .add_day()
+        // the linear vesting is over and the lump sum unlocked, nothing streams anymore
+        .query_rewards(alice, |result| {
+            let rewards = result.unwrap();
+            assert_eq!(rewards.accrual_rate, Decimal256::zero());
+            assert!(rewards.next_unlocks.is_empty());
+        });
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::Role;
+
+mod suite;
+use suite::TestingSuite;
+
+fn assert_unauthorized(result: Result<AppResponse, anyhow::Error>) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::OwnershipError(_) => {}
+        _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+    }
+}
+
+#[test]
+fn roles_restrict_wallets_to_their_admin_actions() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .grant_roles(
+            alice,
+            bob,
+            vec![Role::AllocationManager],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        .grant_roles(
+            owner,
+            bob,
+            vec![],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { reason } => {
+                        assert_eq!(reason, "roles cannot be empty");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .grant_roles(
+            owner,
+            bob,
+            vec![Role::AllocationManager],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_roles(bob, |result| {
+            assert_eq!(result.unwrap().roles, vec![Role::AllocationManager]);
+        })
+        .add_allocations(
+            bob,
+            &vec![(carol.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the allocation manager can't blacklist
+        .blacklist_address(
+            bob,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        )
+        .grant_roles(
+            owner,
+            bob,
+            vec![Role::BlacklistManager],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .blacklist_address(
+            bob,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_role_holders(Role::BlacklistManager, None, None, |result| {
+            assert_eq!(result.unwrap().holders, vec![bob.to_string()]);
+        })
+        .revoke_roles(
+            owner,
+            bob,
+            vec![Role::AllocationManager],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_roles(bob, |result| {
+            assert_eq!(result.unwrap().roles, vec![Role::BlacklistManager]);
+        })
+        .query_role_holders(Role::AllocationManager, None, None, |result| {
+            assert!(result.unwrap().holders.is_empty());
+        })
+        .add_allocations(
+            bob,
+            &vec![(alice.to_string(), Uint128::new(1_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_unauthorized(result);
+            },
+        );
+}
+
+#[test]
+fn owner_and_authorized_wallets_have_all_roles() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_authorized_wallets(
+            owner,
+            vec![alice.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_roles(owner, |result| {
+            assert_eq!(result.unwrap().roles, Role::ALL.to_vec());
+        })
+        .query_roles(alice, |result| {
+            assert_eq!(result.unwrap().roles, Role::ALL.to_vec());
+        })
+        .query_roles(bob, |result| {
+            assert!(result.unwrap().roles.is_empty());
+        });
+}
@@ -29,12 +29,14 @@ fn test_invalid_distribution_duration_fails() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 100,
-                        end_time: current_time.seconds() + 50, // end_time < start_time
+                        start_time: current_time.plus_seconds(100),
+                        end_time: current_time.plus_seconds(50), // end_time < start_time
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -75,12 +77,14 @@ fn test_claim_before_distribution_start_optimized_early_return() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 1000, // Far in the future
-                        end_time: current_time.seconds() + 2000,
+                        start_time: current_time.plus_seconds(1000), // Far in the future
+                        end_time: current_time.plus_seconds(2000),
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -147,12 +151,14 @@ fn test_rounding_error_compensation_invariant() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 10,
-                        end_time: current_time.seconds() + 100,
+                        start_time: current_time.plus_seconds(10),
+                        end_time: current_time.plus_seconds(100),
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -218,17 +224,20 @@ fn test_distribution_validation_and_early_return() {
                     distribution_type: vec![
                         DistributionType::LumpSum {
                             percentage: Decimal::from_str("0.5").unwrap(), // 50%
-                            start_time: current_time.seconds() + 10,
+                            start_time: current_time.plus_seconds(10),
+                            cliff_duration: None,
                         },
                         DistributionType::LinearVesting {
                             percentage: Decimal::from_str("0.5").unwrap(), // 50%
-                            start_time: current_time.seconds() + 10,
-                            end_time: current_time.seconds() + 100,
+                            start_time: current_time.plus_seconds(10),
+                            end_time: current_time.plus_seconds(100),
                             cliff_duration: None,
+                            cliff_behavior: None,
                         },
                     ],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -309,12 +318,14 @@ fn test_zero_distribution_duration_fails() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LinearVesting {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 100,
-                        end_time: current_time.seconds() + 100, // Same as start_time
+                        start_time: current_time.plus_seconds(100),
+                        end_time: current_time.plus_seconds(100), // Same as start_time
                         cliff_duration: None,
+                        cliff_behavior: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -0,0 +1,118 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{AllocationMode, CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn allocations_are_set_per_mode_before_the_campaign_starts() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_allocations(
+            owner,
+            &[
+                (alice.to_string(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(200)),
+            ],
+            AllocationMode::CreateOnly,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_allocations(
+            owner,
+            &[(alice.to_string(), Uint128::new(150))],
+            AllocationMode::CreateOnly,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        )
+        // fixes alice's amount in place and adds carol
+        .set_allocations(
+            owner,
+            &[
+                (alice.to_string(), Uint128::new(150)),
+                (carol.to_string(), Uint128::new(50)),
+            ],
+            AllocationMode::UpsertBeforeStart,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_allocations(
+            owner,
+            &[
+                (bob.to_string(), Uint128::new(25)),
+                (bob.to_string(), Uint128::new(25)),
+            ],
+            AllocationMode::IncrementBeforeStart,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations(Some(alice), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1.amount, Uint128::new(150));
+        })
+        .query_allocations(Some(bob), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1.amount, Uint128::new(250));
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1.amount, Uint128::new(50));
+        })
+        .query_counts(|result| {
+            assert_eq!(result.unwrap().allocations, 3);
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Set Campaign".to_string(),
+                    description: "Campaign with allocations set per mode".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(450, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .set_allocations(
+            owner,
+            &[(alice.to_string(), Uint128::new(1_000))],
+            AllocationMode::UpsertBeforeStart,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignAlreadyStarted { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignAlreadyStarted"
+                    ),
+                }
+            },
+        );
+}
@@ -0,0 +1,185 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType, SlotSolvency};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn partially_funded_slots_only_pay_out_their_share() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Partially Funded Campaign".to_string(),
+                    description: "Campaign funded half way".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(10_000, "uom"),
+                    distribution_type: vec![
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_seconds(1),
+                            cliff_duration: None,
+                        },
+                        DistributionType::LumpSum {
+                            percentage: Decimal::percent(50),
+                            start_time: current_time.plus_seconds(1),
+                            cliff_duration: None,
+                        },
+                    ],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(10),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(5_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .query_slot_solvency(|result| {
+            let response = result.unwrap();
+            assert_eq!(
+                response.slots,
+                vec![
+                    SlotSolvency {
+                        slot: 0,
+                        obligation: Uint128::new(5_000),
+                        funded: Uint128::new(2_500),
+                        claimed: Uint128::zero(),
+                    },
+                    SlotSolvency {
+                        slot: 1,
+                        obligation: Uint128::new(5_000),
+                        funded: Uint128::new(2_500),
+                        claimed: Uint128::zero(),
+                    },
+                ]
+            );
+            assert_eq!(response.balance, coin(5_000, "uom"));
+            assert_eq!(response.funds_received, coin(5_000, "uom"));
+        })
+        // the balance covers the claim, but not the funding of the slots
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(5_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::SlotNotFunded { slot, .. } => assert_eq!(slot, 0),
+                    _ => panic!("Wrong error type, should return ContractError::SlotNotFunded"),
+                }
+            },
+        )
+        .claim(
+            bob,
+            None,
+            Some(Uint128::new(2_500)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_from_slots(
+            bob,
+            vec![0],
+            Some(Uint128::new(1)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::SlotNotFunded {
+                        slot,
+                        requested,
+                        available,
+                    } => {
+                        assert_eq!(slot, 0);
+                        assert_eq!(requested, Uint128::new(1));
+                        assert_eq!(available, Uint128::zero());
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::SlotNotFunded"),
+                }
+            },
+        )
+        .claim_from_slots(
+            bob,
+            vec![1],
+            Some(Uint128::new(2_500)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_slot_solvency(|result| {
+            let response = result.unwrap();
+            assert_eq!(
+                response
+                    .slots
+                    .iter()
+                    .map(|slot| (slot.funded, slot.claimed))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (Uint128::new(2_500), Uint128::new(2_500)),
+                    (Uint128::new(2_500), Uint128::new(2_500)),
+                ]
+            );
+            assert_eq!(response.balance, coin(0, "uom"));
+            assert_eq!(response.funds_received, coin(5_000, "uom"));
+        })
+        // once the campaign is fully funded, the rest can be claimed
+        .top_up_campaign(
+            owner,
+            &[coin(5_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", bob, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_010_000));
+        })
+        .query_slot_solvency(|result| {
+            let response = result.unwrap();
+            assert!(response
+                .slots
+                .iter()
+                .all(|slot| slot.funded == slot.obligation && slot.claimed == slot.obligation));
+        });
+}
+
+#[test]
+fn slot_solvency_requires_a_campaign() {
+    TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")])
+        .instantiate_claimdrop_contract(None)
+        .query_slot_solvency(|result| {
+            assert!(result.is_err());
+        });
+}
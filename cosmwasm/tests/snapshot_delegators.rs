@@ -0,0 +1,169 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+const VALIDATOR_A: &str = "mantravaloper1validatora";
+const VALIDATOR_B: &str = "mantravaloper1validatorb";
+
+#[test]
+fn snapshot_delegators_adds_allocations_proportional_to_stake() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let dan = &suite.senders[4].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        // more delegations than a page of the staking query
+        .set_validator_delegations(
+            VALIDATOR_A,
+            vec![
+                (alice, Uint128::new(1_000)),
+                (bob, Uint128::new(300)),
+                (carol, Uint128::new(50)),
+            ],
+        )
+        .set_validator_delegations(
+            VALIDATOR_B,
+            vec![(bob, Uint128::new(700)), (dan, Uint128::new(99))],
+        )
+        .snapshot_delegators(
+            alice,
+            vec![VALIDATOR_A, VALIDATOR_B],
+            Uint128::new(100),
+            Decimal::percent(50),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .snapshot_delegators(
+            owner,
+            vec![VALIDATOR_A, VALIDATOR_B],
+            Uint128::new(100),
+            Decimal::percent(50),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // carol and dan are below the minimum stake, bob's stake is summed across validators
+        .query_allocations(None, None, None, |result| {
+            let mut allocations = result
+                .unwrap()
+                .allocations
+                .into_iter()
+                .map(|(address, allocation)| (address, allocation.amount))
+                .collect::<Vec<_>>();
+            allocations.sort();
+
+            let mut expected = vec![
+                (alice.to_string(), Uint128::new(500)),
+                (bob.to_string(), Uint128::new(500)),
+            ];
+            expected.sort();
+
+            assert_eq!(allocations, expected);
+        })
+        // snapshotting the same delegators twice fails
+        .snapshot_delegators(
+            owner,
+            vec![VALIDATOR_A],
+            Uint128::new(100),
+            Decimal::percent(50),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AllocationAlreadyExists { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::AllocationAlreadyExists"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn cannot_snapshot_delegators_after_campaign_starts() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .set_validator_delegations(VALIDATOR_A, vec![(alice, Uint128::new(1_000))])
+        .snapshot_delegators(
+            owner,
+            vec![],
+            Uint128::zero(),
+            Decimal::one(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .snapshot_delegators(
+            owner,
+            vec![VALIDATOR_A],
+            Uint128::zero(),
+            Decimal::zero(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Stakers Campaign".to_string(),
+                    description: "Airdrop to the stakers".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(10_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .snapshot_delegators(
+            owner,
+            vec![VALIDATOR_A],
+            Uint128::zero(),
+            Decimal::one(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignAlreadyStarted { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::CampaignAlreadyStarted"
+                    ),
+                }
+            },
+        );
+}
@@ -0,0 +1,169 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, SudoMsg};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite) {
+    let current_time = &suite.get_time();
+
+    suite.setup_campaign(&[], campaign_params(current_time, 10_000));
+}
+
+#[test]
+fn sudo_force_close_campaign_refunds_owner() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .sudo(
+            SudoMsg::ForceCloseCampaign {},
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_campaign(|result| {
+            assert!(result.unwrap().closed.is_some());
+        })
+        .query_balance("uom", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .sudo(
+            SudoMsg::ForceCloseCampaign {},
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignClosed => {}
+                    _ => panic!("Wrong error type, should return ContractError::CampaignClosed"),
+                }
+            },
+        );
+}
+
+#[test]
+fn sudo_force_close_campaign_fails_once_the_ownership_is_renounced() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .update_ownership(
+            owner,
+            cw_ownable::Action::RenounceOwnership,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // there's no owner to refund
+        .sudo(
+            SudoMsg::ForceCloseCampaign {},
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        );
+}
+
+#[test]
+fn sudo_force_blacklist_can_blacklist_owner() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .sudo(
+            SudoMsg::ForceBlacklist {
+                address: bob.to_string(),
+                reason: Some("sanctioned".to_string()),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .sudo(
+            SudoMsg::ForceBlacklist {
+                address: owner.to_string(),
+                reason: None,
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_is_blacklisted(bob, |result| {
+            assert!(result.unwrap().is_blacklisted);
+        })
+        .query_is_blacklisted(owner, |result| {
+            assert!(result.unwrap().is_blacklisted);
+        })
+        .query_blacklist_entry(bob, |result| {
+            let entry = result.unwrap().entry.unwrap();
+            assert_eq!(entry.blacklisted_by, "governance");
+            assert_eq!(entry.reason, Some("sanctioned".to_string()));
+        });
+}
+
+#[test]
+fn sudo_transfer_ownership_overrides_pending_transfer() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite);
+
+    suite
+        .update_ownership(
+            owner,
+            cw_ownable::Action::TransferOwnership {
+                new_owner: bob.to_string(),
+                expiry: None,
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .sudo(
+            SudoMsg::TransferOwnership {
+                new_owner: carol.to_string(),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_ownership(|result| {
+            let ownership = result.unwrap();
+            assert_eq!(ownership.owner, Some(carol.to_string()));
+            assert_eq!(ownership.pending_owner, None);
+        })
+        // the previous owner lost its admin rights
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .sudo(
+            SudoMsg::TransferOwnership {
+                new_owner: "invalid".to_string(),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap_err();
+            },
+        );
+}
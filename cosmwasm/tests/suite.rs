@@ -1,15 +1,426 @@
-use cosmwasm_std::{coin, Addr, Coin, Empty, StdResult, Timestamp, Uint128};
+#![allow(dead_code)]
+
+use anyhow::{anyhow, bail, ensure, Result as AnyResult};
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, to_json_vec, Addr, BalanceResponse, BankMsg, BankQuery,
+    Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, Event, GrpcQuery, HexBinary, Int128,
+    MessageInfo, Querier, QuerierWrapper, Response, StdResult, Timestamp, Uint128,
+};
+use cosmwasm_std::{AnyMsg, Api, BlockInfo, CustomMsg, CustomQuery, Storage};
 use cw_multi_test::{
-    App, AppBuilder, AppResponse, BankKeeper, Contract, ContractWrapper, Executor, MockApiBech32,
-    WasmKeeper,
+    App, AppBuilder, AppResponse, BankKeeper, BankSudo, Contract, ContractWrapper, CosmosRouter,
+    DistributionKeeper, Executor, FailingModule, GovFailingModule, IbcAcceptingModule,
+    MockApiBech32, StakeKeeper, Stargate, WasmKeeper,
 };
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey};
+use mantra_claimdrop_std::export::{ExportStateResponse, StateSection};
 use mantra_claimdrop_std::msg::{
-    AllocationsResponse, AuthorizedResponse, AuthorizedWalletsResponse, BlacklistResponse,
-    CampaignAction, CampaignResponse, ClaimedResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
-    RewardsResponse,
+    AddressReplacementRequestResponse, AllocationMode, AllocationVoucher, AllocationsResponse,
+    AllowlistResponse, AuthorizedResponse, AuthorizedWalletsResponse, BatchRewardsResponse,
+    BlacklistEntryResponse, BlacklistResponse, BudgetResponse, CampaignAction, CampaignParams,
+    CampaignPhaseResponse, CampaignResponse, CampaignStats, CampaignStatusResponse, CircuitBreaker,
+    ClaimHistoryResponse, ClaimHookExecuteMsg, ClaimHooksResponse, ClaimNonceResponse,
+    ClaimSignaturePayload, ClaimVolumeResponse, ClaimWindowResponse, ClaimedBySlotResponse,
+    ClaimedResponse, ConfigResponse, ContractInfoResponse, CountsResponse, DistributionType,
+    ExecuteMsg, FrozenEntryResponse, FrozenResolution, FundersResponse, FundingHistoryResponse,
+    GroupMemberResponse, GroupResponse, HoldResponse, IbcReceiver, InstantiateMsg, IsLiveResponse,
+    LiabilitiesResponse, Limits, LinkAddressPayload, LockTier, LockedReceiverResponse,
+    LocksResponse, PauseScope, PauseStatusResponse, PendingAuthorizedWalletsResponse,
+    PendingSweepResponse, ProposalAction, ProposalsResponse, QueryMsg, RaffleResponse,
+    RegistryExecuteMsg, RegistryUpdateMsg, RewardsResponse, Role, RoleHoldersResponse,
+    RolesResponse, SimulateClaimResponse, SlotSolvencyResponse, SudoMsg, TagStatsResponse,
+    TermsAcceptanceResponse, VestingActivationResponse,
 };
+use serde::de::DeserializeOwned;
+
+type MantraApp = App<
+    BankKeeper,
+    MockApiBech32,
+    MockStorage,
+    FailingModule<Empty, Empty, Empty>,
+    WasmKeeper<Empty, Empty>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcAcceptingModule,
+    GovFailingModule,
+    MockStargate,
+>;
+
+/// A minimal stargate module, handling the tokenfactory `MsgMint` messages by minting the requested
+/// coin to the sender, the `MsgCreateVestingAccount` messages by sending the coins to the
+/// receiver, emitting a `create_vesting_account` event, failing if the receiver account exists,
+/// i.e. it holds any uom, and the `MsgGrantAllowance` messages by emitting a `grant_allowance`
+/// event, failing if the allowance was already granted. It answers the auth `Account` grpc queries
+/// for the accounts holding any uom, the authz `Grants` grpc queries with the grants set with
+/// [TestingSuite::set_authz_grant], and the `ValidatorDelegations` grpc queries with the
+/// delegations set with [TestingSuite::set_validator_delegations], in pages of
+/// [MOCK_DELEGATIONS_PAGE_SIZE].
+pub struct MockStargate;
+
+/// The page size of the mocked `ValidatorDelegations` query, small to exercise the pagination
+pub const MOCK_DELEGATIONS_PAGE_SIZE: usize = 2;
+
+fn fee_grant_key(granter: &str, grantee: &str) -> Vec<u8> {
+    format!("mock_fee_grants/{granter}/{grantee}").into_bytes()
+}
+
+fn authz_grant_key(granter: &str, grantee: &str) -> Vec<u8> {
+    format!("mock_authz_grants/{granter}/{grantee}").into_bytes()
+}
+
+fn validator_delegations_key(validator: &str) -> Vec<u8> {
+    format!("mock_validator_delegations/{validator}").into_bytes()
+}
+
+/// Encodes a `google.protobuf.Any`
+fn encode_any(type_url: &str, value: &[u8]) -> Binary {
+    let mut any = vec![];
+    MockStargate::encode_bytes(1, type_url.as_bytes(), &mut any);
+    MockStargate::encode_bytes(2, value, &mut any);
+    any.into()
+}
+
+/// Encodes an authz `GenericAuthorization` of the given message type
+pub fn generic_authorization(msg_type_url: &str) -> Binary {
+    let mut authorization = vec![];
+    MockStargate::encode_bytes(1, msg_type_url.as_bytes(), &mut authorization);
+    encode_any("/cosmos.authz.v1beta1.GenericAuthorization", &authorization)
+}
+
+/// Encodes a wasm `ContractExecutionAuthorization` of the given contract, allowing the given message
+/// keys or all the messages if not set, limited to the given number of calls or to no funds if not
+/// set
+pub fn contract_execution_authorization(
+    contract: &Addr,
+    keys: Option<&[&str]>,
+    max_calls: Option<u64>,
+) -> Binary {
+    let limit = match max_calls {
+        Some(max_calls) => {
+            let mut limit = vec![];
+            MockStargate::encode_varint(1 << 3, &mut limit);
+            MockStargate::encode_varint(max_calls, &mut limit);
+            encode_any("/cosmwasm.wasm.v1.MaxCallsLimit", &limit)
+        }
+        None => encode_any("/cosmwasm.wasm.v1.MaxFundsLimit", &[]),
+    };
+    let filter = match keys {
+        Some(keys) => {
+            let mut filter = vec![];
+            for key in keys {
+                MockStargate::encode_bytes(1, key.as_bytes(), &mut filter);
+            }
+            encode_any("/cosmwasm.wasm.v1.AcceptedMessageKeysFilter", &filter)
+        }
+        None => encode_any("/cosmwasm.wasm.v1.AllowAllMessagesFilter", &[]),
+    };
+
+    let mut grant = vec![];
+    MockStargate::encode_bytes(1, contract.as_bytes(), &mut grant);
+    MockStargate::encode_bytes(2, limit.as_slice(), &mut grant);
+    MockStargate::encode_bytes(3, filter.as_slice(), &mut grant);
+
+    let mut authorization = vec![];
+    MockStargate::encode_bytes(1, &grant, &mut authorization);
+    encode_any(
+        "/cosmwasm.wasm.v1.ContractExecutionAuthorization",
+        &authorization,
+    )
+}
+
+impl MockStargate {
+    /// Decodes the fields of a protobuf message, by field number. Varints are returned as their
+    /// little endian bytes.
+    fn decode_fields(mut bytes: &[u8]) -> AnyResult<Vec<(u64, Vec<u8>)>> {
+        fn read_varint(bytes: &mut &[u8]) -> AnyResult<u64> {
+            let mut value = 0u64;
+            for shift in (0..64).step_by(7) {
+                let (byte, rest) = bytes.split_first().ok_or_else(|| anyhow!("truncated"))?;
+                *bytes = rest;
+                value |= u64::from(byte & 0x7f) << shift;
+                if byte & 0x80 == 0 {
+                    return Ok(value);
+                }
+            }
+            bail!("invalid varint")
+        }
+
+        let mut fields = vec![];
+        while !bytes.is_empty() {
+            let tag = read_varint(&mut bytes)?;
+            match tag & 0x7 {
+                0 => {
+                    let value = read_varint(&mut bytes)?;
+                    fields.push((tag >> 3, value.to_le_bytes().to_vec()));
+                }
+                2 => {
+                    let len = read_varint(&mut bytes)? as usize;
+                    ensure!(bytes.len() >= len, "truncated");
+                    let (field, rest) = bytes.split_at(len);
+                    fields.push((tag >> 3, field.to_vec()));
+                    bytes = rest;
+                }
+                _ => bail!("unexpected wire type"),
+            }
+        }
+
+        Ok(fields)
+    }
+
+    /// Decodes a `cosmos.base.v1beta1.Coin` into its denom and amount
+    fn decode_coin(bytes: &[u8]) -> AnyResult<(String, Uint128)> {
+        let fields = Self::decode_fields(bytes)?;
+        let denom = String::from_utf8(Self::field(&fields, 1)?)?;
+        let amount = String::from_utf8(Self::field(&fields, 2)?)?.parse()?;
+
+        Ok((denom, amount))
+    }
+
+    /// Encodes a length-delimited protobuf field
+    fn encode_bytes(field_number: u64, bytes: &[u8], buf: &mut Vec<u8>) {
+        Self::encode_varint(field_number << 3 | 2, buf);
+        Self::encode_varint(bytes.len() as u64, buf);
+        buf.extend_from_slice(bytes);
+    }
+
+    fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+        while value >= 0x80 {
+            buf.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    fn field(fields: &[(u64, Vec<u8>)], number: u64) -> AnyResult<Vec<u8>> {
+        fields
+            .iter()
+            .find(|(n, _)| *n == number)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| anyhow!("missing field {number}"))
+    }
+}
+
+impl Stargate for MockStargate {
+    fn execute_any<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: AnyMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let fields = Self::decode_fields(msg.value.as_slice())?;
+
+        match msg.type_url.as_str() {
+            "/osmosis.tokenfactory.v1beta1.MsgMint" => {
+                let minter = String::from_utf8(Self::field(&fields, 1)?)?;
+                let (denom, amount) = Self::decode_coin(&Self::field(&fields, 2)?)?;
+
+                ensure!(minter == sender.as_str(), "the minter must be the sender");
+                ensure!(
+                    denom.starts_with(&format!("factory/{sender}/")),
+                    "the sender is not the admin of {denom}"
+                );
+
+                router.sudo(
+                    api,
+                    storage,
+                    block,
+                    BankSudo::Mint {
+                        to_address: sender.to_string(),
+                        amount: vec![coin(amount.u128(), denom)],
+                    }
+                    .into(),
+                )
+            }
+            "/cosmos.vesting.v1beta1.MsgCreateVestingAccount" => {
+                let from_address = String::from_utf8(Self::field(&fields, 1)?)?;
+                let to_address = String::from_utf8(Self::field(&fields, 2)?)?;
+                let (denom, amount) = Self::decode_coin(&Self::field(&fields, 3)?)?;
+                let end_time = u64::from_le_bytes(Self::field(&fields, 4)?.try_into().unwrap());
+
+                ensure!(
+                    from_address == sender.as_str(),
+                    "the funder must be the sender"
+                );
+                let balance: BalanceResponse = from_json(
+                    router.query(
+                        api,
+                        storage,
+                        block,
+                        BankQuery::Balance {
+                            address: to_address.clone(),
+                            denom: "uom".to_string(),
+                        }
+                        .into(),
+                    )?,
+                )?;
+                ensure!(
+                    balance.amount.amount.is_zero(),
+                    "account {to_address} already exists"
+                );
+
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    sender,
+                    BankMsg::Send {
+                        to_address: to_address.clone(),
+                        amount: vec![coin(amount.u128(), &denom)],
+                    }
+                    .into(),
+                )?;
+
+                Ok(AppResponse {
+                    events: vec![Event::new("create_vesting_account")
+                        .add_attribute("to_address", to_address)
+                        .add_attribute("amount", coin(amount.u128(), denom).to_string())
+                        .add_attribute("end_time", end_time.to_string())],
+                    data: None,
+                })
+            }
+            "/cosmos.feegrant.v1beta1.MsgGrantAllowance" => {
+                let granter = String::from_utf8(Self::field(&fields, 1)?)?;
+                let grantee = String::from_utf8(Self::field(&fields, 2)?)?;
+                let allowance = Self::decode_fields(&Self::field(&fields, 3)?)?;
+                let allowance_type_url = String::from_utf8(Self::field(&allowance, 1)?)?;
+                let basic_allowance = Self::decode_fields(&Self::field(&allowance, 2)?)?;
+                let (denom, amount) = Self::decode_coin(&Self::field(&basic_allowance, 1)?)?;
+                let expiration = Self::field(&basic_allowance, 2)
+                    .ok()
+                    .map(|timestamp| -> AnyResult<u64> {
+                        let seconds = Self::field(&Self::decode_fields(&timestamp)?, 1)?;
+                        Ok(u64::from_le_bytes(seconds.try_into().unwrap()))
+                    })
+                    .transpose()?;
+
+                ensure!(granter == sender.as_str(), "the granter must be the sender");
+                ensure!(
+                    allowance_type_url == "/cosmos.feegrant.v1beta1.BasicAllowance",
+                    "unsupported allowance {allowance_type_url}"
+                );
+                ensure!(
+                    storage.get(&fee_grant_key(&granter, &grantee)).is_none(),
+                    "fee allowance already exists"
+                );
+                storage.set(&fee_grant_key(&granter, &grantee), &[1]);
+
+                Ok(AppResponse {
+                    events: vec![Event::new("grant_allowance")
+                        .add_attribute("grantee", grantee)
+                        .add_attribute("spend_limit", coin(amount.u128(), denom).to_string())
+                        .add_attribute(
+                            "expiration",
+                            expiration.map_or("none".to_string(), |seconds| seconds.to_string()),
+                        )],
+                    data: None,
+                })
+            }
+            type_url => bail!("unsupported message {type_url}"),
+        }
+    }
+
+    fn query_grpc(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: GrpcQuery,
+    ) -> AnyResult<Binary> {
+        if request.path == "/cosmos.auth.v1beta1.Query/Account" {
+            let fields = Self::decode_fields(request.data.as_slice())?;
+            let address = String::from_utf8(Self::field(&fields, 1)?)?;
+            let balance = QuerierWrapper::<Empty>::new(querier).query_balance(&address, "uom")?;
+            ensure!(!balance.amount.is_zero(), "account {address} not found");
+
+            return Ok(Binary::default());
+        }
+
+        if request.path == "/cosmos.authz.v1beta1.Query/Grants" {
+            let fields = Self::decode_fields(request.data.as_slice())?;
+            let granter = String::from_utf8(Self::field(&fields, 1)?)?;
+            let grantee = String::from_utf8(Self::field(&fields, 2)?)?;
+
+            let grants: Vec<(Option<u64>, Binary)> = storage
+                .get(&authz_grant_key(&granter, &grantee))
+                .map(|value| from_json(value).unwrap())
+                .unwrap_or_default();
+
+            let mut response = vec![];
+            for (expiration, authorization) in grants {
+                let mut grant = vec![];
+                Self::encode_bytes(1, authorization.as_slice(), &mut grant);
+                if let Some(seconds) = expiration {
+                    let mut timestamp = vec![];
+                    Self::encode_varint(1 << 3, &mut timestamp);
+                    Self::encode_varint(seconds, &mut timestamp);
+                    Self::encode_bytes(2, &timestamp, &mut grant);
+                }
+                Self::encode_bytes(1, &grant, &mut response);
+            }
+
+            return Ok(response.into());
+        }
+
+        ensure!(
+            request.path == "/cosmos.staking.v1beta1.Query/ValidatorDelegations",
+            "unsupported grpc query {}",
+            request.path
+        );
+
+        let fields = Self::decode_fields(request.data.as_slice())?;
+        let validator = String::from_utf8(Self::field(&fields, 1)?)?;
+        // the pagination key is the index of the first delegation of the page
+        let start = Self::field(&fields, 2)
+            .and_then(|pagination| Self::field(&Self::decode_fields(&pagination)?, 1))
+            .map(|key| String::from_utf8(key).unwrap().parse::<usize>().unwrap())
+            .unwrap_or_default();
+
+        let delegations: Vec<(String, Uint128)> = storage
+            .get(&validator_delegations_key(&validator))
+            .map(|value| from_json(value).unwrap())
+            .unwrap_or_default();
+
+        let mut response = vec![];
+        for (delegator, amount) in delegations
+            .iter()
+            .skip(start)
+            .take(MOCK_DELEGATIONS_PAGE_SIZE)
+        {
+            let mut delegation = vec![];
+            Self::encode_bytes(1, delegator.as_bytes(), &mut delegation);
+            Self::encode_bytes(2, validator.as_bytes(), &mut delegation);
 
-type MantraApp = App<BankKeeper, MockApiBech32>;
+            let mut balance = vec![];
+            Self::encode_bytes(1, b"uom", &mut balance);
+            Self::encode_bytes(2, amount.to_string().as_bytes(), &mut balance);
+
+            let mut delegation_response = vec![];
+            Self::encode_bytes(1, &delegation, &mut delegation_response);
+            Self::encode_bytes(2, &balance, &mut delegation_response);
+
+            Self::encode_bytes(1, &delegation_response, &mut response);
+        }
+
+        let next = start + MOCK_DELEGATIONS_PAGE_SIZE;
+        if next < delegations.len() {
+            let mut pagination = vec![];
+            Self::encode_bytes(1, next.to_string().as_bytes(), &mut pagination);
+            Self::encode_bytes(2, &pagination, &mut response);
+        }
+
+        Ok(response.into())
+    }
+}
 
 pub fn claimdrop_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -17,11 +428,159 @@ pub fn claimdrop_contract() -> Box<dyn Contract<Empty>> {
         claimdrop_contract::contract::instantiate,
         claimdrop_contract::contract::query,
     )
-    .with_migrate(claimdrop_contract::contract::migrate);
+    .with_migrate(claimdrop_contract::contract::migrate)
+    .with_sudo(claimdrop_contract::contract::sudo);
+
+    Box::new(contract)
+}
+
+/// A contract that accepts the claim hook messages, emitting the received data as attributes.
+pub fn claim_hook_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, msg: ClaimHookExecuteMsg| {
+            let ClaimHookExecuteMsg::ClaimHook(hook) = msg;
+            StdResult::Ok(
+                Response::default()
+                    .add_attribute("action", "claim_hook")
+                    .add_attribute("receiver", hook.receiver)
+                    .add_attribute("amount", hook.amount.to_string())
+                    .add_attribute("campaign", hook.campaign),
+            )
+        },
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty| {
+            StdResult::Ok(Response::default())
+        },
+        |_deps: Deps, _env: Env, _msg: Empty| StdResult::Ok(Binary::default()),
+    );
+
+    Box::new(contract)
+}
+
+/// A contract that accepts the campaign registry updates, emitting the received data as
+/// attributes.
+pub fn registry_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, msg: RegistryExecuteMsg| {
+            let RegistryExecuteMsg::RegistryUpdate(update) = msg;
+            let response = Response::default().add_attribute("action", "registry_update");
+            StdResult::Ok(match update {
+                RegistryUpdateMsg::CampaignCreated {
+                    name, total_reward, ..
+                } => response
+                    .add_attribute("update", "campaign_created")
+                    .add_attribute("name", name)
+                    .add_attribute("total_reward", total_reward.to_string()),
+                RegistryUpdateMsg::CampaignClosed { name, refunded, .. } => response
+                    .add_attribute("update", "campaign_closed")
+                    .add_attribute("name", name)
+                    .add_attribute("refunded", refunded.to_string()),
+                RegistryUpdateMsg::CampaignFinalized {
+                    name, finalized_by, ..
+                } => response
+                    .add_attribute("update", "campaign_finalized")
+                    .add_attribute("name", name)
+                    .add_attribute("finalized_by", finalized_by),
+            })
+        },
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty| {
+            StdResult::Ok(Response::default())
+        },
+        |_deps: Deps, _env: Env, _msg: Empty| StdResult::Ok(Binary::default()),
+    );
+
+    Box::new(contract)
+}
+
+/// The message of the mock locker contract, locking the received funds on behalf of the owner.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockerExecuteMsg {
+    Lock { owner: String },
+}
+
+/// A contract that keeps the funds it receives, emitting the owner and the locked amount as
+/// attributes.
+pub fn locker_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |_deps: DepsMut, _env: Env, info: MessageInfo, msg: LockerExecuteMsg| {
+            let LockerExecuteMsg::Lock { owner } = msg;
+            StdResult::Ok(
+                Response::default()
+                    .add_attribute("action", "lock")
+                    .add_attribute("owner", owner)
+                    .add_attribute(
+                        "amount",
+                        info.funds
+                            .iter()
+                            .map(|coin| coin.to_string())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    ),
+            )
+        },
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty| {
+            StdResult::Ok(Response::default())
+        },
+        |_deps: Deps, _env: Env, _msg: Empty| StdResult::Ok(Binary::default()),
+    );
+
+    Box::new(contract)
+}
+
+/// The instantiate message of the mock eligibility oracle, listing the eligible addresses.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct OracleInstantiateMsg {
+    pub eligible: Vec<String>,
+}
+
+/// The query of the mock eligibility oracle.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OracleQueryMsg {
+    IsEligible { address: String },
+}
+
+/// A contract answering whether an address is among the eligible addresses it was instantiated
+/// with.
+pub fn eligibility_oracle_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty| {
+            StdResult::Ok(Response::default())
+        },
+        |deps: DepsMut, _env: Env, _info: MessageInfo, msg: OracleInstantiateMsg| {
+            for address in msg.eligible {
+                deps.storage.set(address.as_bytes(), &[1]);
+            }
+            StdResult::Ok(Response::default())
+        },
+        |deps: Deps, _env: Env, msg: OracleQueryMsg| {
+            let OracleQueryMsg::IsEligible { address } = msg;
+            to_json_binary(&deps.storage.get(address.as_bytes()).is_some())
+        },
+    );
 
     Box::new(contract)
 }
 
+/// Returns the parameters of a campaign paying out `total_reward` uom in a lump sum, starting a
+/// second after `current_time` and ending a week later. Tests override the fields they exercise.
+pub fn campaign_params(current_time: &Timestamp, total_reward: u128) -> CampaignParams {
+    CampaignParams {
+        name: "Test Campaign".to_string(),
+        description: "Test campaign".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: coin(total_reward, "uom"),
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(1),
+            cliff_duration: None,
+        }],
+        start_time: current_time.plus_seconds(1),
+        end_time: current_time.plus_days(7),
+        ..Default::default()
+    }
+}
+
 pub struct TestingSuite {
     app: MantraApp,
     pub senders: Vec<Addr>,
@@ -49,6 +608,64 @@ impl TestingSuite {
         self
     }
 
+    /// Sets the delegations returned by the staking module for the given validator
+    #[track_caller]
+    pub fn set_authz_grant(
+        &mut self,
+        granter: &Addr,
+        grantee: &Addr,
+        expiration: Option<Timestamp>,
+    ) -> &mut Self {
+        self.set_authz_grant_with_authorization(
+            granter,
+            grantee,
+            expiration,
+            generic_authorization("/cosmwasm.wasm.v1.MsgExecuteContract"),
+        )
+    }
+
+    pub fn set_authz_grant_with_authorization(
+        &mut self,
+        granter: &Addr,
+        grantee: &Addr,
+        expiration: Option<Timestamp>,
+        authorization: Binary,
+    ) -> &mut Self {
+        let expiration = expiration.map(|expiration| expiration.seconds());
+
+        self.app.init_modules(|_, _, storage| {
+            let key = authz_grant_key(granter.as_str(), grantee.as_str());
+            let mut grants: Vec<(Option<u64>, Binary)> = storage
+                .get(&key)
+                .map(|value| from_json(value).unwrap())
+                .unwrap_or_default();
+            grants.push((expiration, authorization));
+            storage.set(&key, &to_json_vec(&grants).unwrap())
+        });
+
+        self
+    }
+
+    pub fn set_validator_delegations(
+        &mut self,
+        validator: &str,
+        delegations: Vec<(&Addr, Uint128)>,
+    ) -> &mut Self {
+        let delegations: Vec<(String, Uint128)> = delegations
+            .into_iter()
+            .map(|(delegator, amount)| (delegator.to_string(), amount))
+            .collect();
+
+        self.app.init_modules(|_, _, storage| {
+            storage.set(
+                &validator_delegations_key(validator),
+                &to_json_vec(&delegations).unwrap(),
+            )
+        });
+
+        self
+    }
+
     #[track_caller]
     pub fn add_week(&mut self) -> &mut Self {
         let mut block_info = self.app.block_info();
@@ -89,6 +706,8 @@ impl TestingSuite {
             .with_wasm(WasmKeeper::default())
             .with_bank(BankKeeper::new())
             .with_api(MockApiBech32::new("mantra"))
+            .with_ibc(IbcAcceptingModule::new())
+            .with_stargate(MockStargate)
             .build(|router, _api, storage| {
                 balances.into_iter().for_each(|(account, amount)| {
                     router.bank.init_balance(storage, &account, amount).unwrap()
@@ -104,9 +723,19 @@ impl TestingSuite {
 
     #[track_caller]
     pub fn instantiate_claimdrop_contract(&mut self, owner: Option<String>) -> &mut Self {
+        self.instantiate_claimdrop_contract_with_limits(owner, None)
+    }
+
+    #[track_caller]
+    pub fn instantiate_claimdrop_contract_with_limits(
+        &mut self,
+        owner: Option<String>,
+        limits: Option<Limits>,
+    ) -> &mut Self {
         let msg = InstantiateMsg {
             owner,
             action: None,
+            limits,
         };
 
         let claimdrop_contract_code_id = self.app.store_code(claimdrop_contract());
@@ -126,99 +755,113 @@ impl TestingSuite {
 
         self
     }
-}
 
-pub trait ResultHandler {
-    fn handle_result(&self, result: Result<AppResponse, anyhow::Error>);
-}
+    /// Instantiates the contract owned by the first sender, uploads the given allocations and
+    /// creates the campaign with the given parameters, funded with its total reward.
+    #[track_caller]
+    pub fn setup_campaign(
+        &mut self,
+        allocations: &[(String, Uint128)],
+        params: CampaignParams,
+    ) -> &mut Self {
+        let owner = &self.admin();
+        let total_reward = params.total_reward.clone();
 
-impl<F> ResultHandler for F
-where
-    F: Fn(Result<AppResponse, anyhow::Error>),
-{
-    fn handle_result(&self, result: Result<AppResponse, anyhow::Error>) {
-        self(result);
+        self.instantiate_claimdrop_contract(Some(owner.to_string()));
+        if !allocations.is_empty() {
+            self.add_allocations(
+                owner,
+                &allocations.to_vec(),
+                |result: Result<AppResponse, anyhow::Error>| {
+                    result.unwrap();
+                },
+            );
+        }
+
+        self.manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(params),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[total_reward],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
     }
 }
 
-// execute msg
+// claim hooks
 impl TestingSuite {
-    fn execute_contract(
-        &mut self,
-        sender: &Addr,
-        msg: ExecuteMsg,
-        funds: &[Coin],
-        result: impl ResultHandler,
-    ) -> &mut Self {
-        result.handle_result(self.app.execute_contract(
-            sender.clone(),
-            self.claimdrop_contract_addr.clone(),
-            &msg,
-            funds,
-        ));
+    #[track_caller]
+    pub fn instantiate_claim_hook_contract(&mut self) -> Addr {
+        let code_id = self.app.store_code(claim_hook_contract());
+        let admin = self.admin();
 
-        self
+        self.app
+            .instantiate_contract(code_id, admin, &Empty {}, &[], "claim-hook", None)
+            .unwrap()
     }
 
     #[track_caller]
-    pub fn top_up_campaign(
-        &mut self,
-        sender: &Addr,
-        funds: &[Coin],
-        result: impl ResultHandler,
-    ) -> &mut Self {
-        result.handle_result(self.app.send_tokens(
-            sender.clone(),
-            self.claimdrop_contract_addr.clone(),
-            funds,
-        ));
+    pub fn instantiate_registry_contract(&mut self) -> Addr {
+        let code_id = self.app.store_code(registry_contract());
+        let admin = self.admin();
 
-        self
+        self.app
+            .instantiate_contract(code_id, admin, &Empty {}, &[], "registry", None)
+            .unwrap()
     }
 
     #[track_caller]
-    pub fn manage_campaign(
-        &mut self,
-        sender: &Addr,
-        action: CampaignAction,
-        funds: &[Coin],
-        result: impl ResultHandler,
-    ) -> &mut Self {
-        self.execute_contract(sender, ExecuteMsg::ManageCampaign { action }, funds, result)
-    }
+    pub fn instantiate_eligibility_oracle_contract(&mut self, eligible: &[&Addr]) -> Addr {
+        let code_id = self.app.store_code(eligibility_oracle_contract());
+        let admin = self.admin();
 
-    #[track_caller]
-    pub fn claim(
-        &mut self,
-        sender: &Addr,
-        receiver: Option<String>,
-        amount: Option<Uint128>,
-        result: impl ResultHandler,
-    ) -> &mut Self {
-        self.execute_contract(sender, ExecuteMsg::Claim { receiver, amount }, &[], result)
+        self.app
+            .instantiate_contract(
+                code_id,
+                admin,
+                &OracleInstantiateMsg {
+                    eligible: eligible.iter().map(|addr| addr.to_string()).collect(),
+                },
+                &[],
+                "eligibility oracle",
+                None,
+            )
+            .unwrap()
     }
 
     #[track_caller]
-    pub fn update_ownership(
-        &mut self,
-        sender: &Addr,
-        action: cw_ownable::Action,
-        result: impl ResultHandler,
-    ) -> &mut Self {
-        self.execute_contract(sender, ExecuteMsg::UpdateOwnership(action), &[], result)
+    pub fn instantiate_locker_contract(&mut self) -> Addr {
+        let code_id = self.app.store_code(locker_contract());
+        let admin = self.admin();
+
+        self.app
+            .instantiate_contract(code_id, admin, &Empty {}, &[], "locker", None)
+            .unwrap()
     }
 
     #[track_caller]
-    pub fn add_allocations(
+    pub fn manage_claim_hooks(
         &mut self,
         sender: &Addr,
-        allocations: &Vec<(String, Uint128)>,
+        addresses: Vec<String>,
+        registered: bool,
         result: impl ResultHandler,
     ) -> &mut Self {
         self.execute_contract(
             sender,
-            ExecuteMsg::AddAllocations {
-                allocations: allocations.clone(),
+            ExecuteMsg::ManageClaimHooks {
+                addresses,
+                registered,
             },
             &[],
             result,
@@ -226,18 +869,18 @@ impl TestingSuite {
     }
 
     #[track_caller]
-    pub fn replace_address(
+    pub fn set_locked_receiver(
         &mut self,
         sender: &Addr,
-        old_address: &Addr,
-        new_address: &Addr,
+        address: &str,
+        locked_receiver: Option<String>,
         result: impl ResultHandler,
     ) -> &mut Self {
         self.execute_contract(
             sender,
-            ExecuteMsg::ReplaceAddress {
-                old_address: old_address.to_string(),
-                new_address: new_address.to_string(),
+            ExecuteMsg::SetLockedReceiver {
+                address: address.to_string(),
+                locked_receiver,
             },
             &[],
             result,
@@ -245,53 +888,1012 @@ impl TestingSuite {
     }
 
     #[track_caller]
-    pub fn remove_address(
+    pub fn query_locked_receiver(
         &mut self,
-        sender: &Addr,
-        address: &Addr,
-        result: impl ResultHandler,
+        address: &str,
+        result: impl Fn(StdResult<LockedReceiverResponse>),
     ) -> &mut Self {
-        self.execute_contract(
-            sender,
-            ExecuteMsg::RemoveAddress {
+        self.query_contract(
+            QueryMsg::LockedReceiver {
                 address: address.to_string(),
             },
-            &[],
             result,
         )
     }
 
     #[track_caller]
-    pub fn blacklist_address(
+    pub fn query_terms_acceptance(
         &mut self,
-        sender: &Addr,
         address: &Addr,
-        blacklist: bool,
-        result: impl ResultHandler,
+        result: impl Fn(StdResult<TermsAcceptanceResponse>),
     ) -> &mut Self {
-        self.execute_contract(
-            sender,
-            ExecuteMsg::BlacklistAddress {
+        self.query_contract(
+            QueryMsg::TermsAcceptance {
                 address: address.to_string(),
-                blacklist,
             },
-            &[],
             result,
         )
     }
 
     #[track_caller]
-    pub fn manage_authorized_wallets(
+    pub fn query_budget(&mut self, result: impl Fn(StdResult<BudgetResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::Budget {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_group(
         &mut self,
-        sender: &Addr,
-        addresses: Vec<String>,
-        authorized: bool,
-        funds: &[Coin],
-        result: impl ResultHandler,
+        name: &str,
+        result: impl Fn(StdResult<GroupResponse>),
     ) -> &mut Self {
-        self.execute_contract(
-            sender,
-            ExecuteMsg::ManageAuthorizedWallets {
+        self.query_contract(
+            QueryMsg::Group {
+                name: name.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_group_member(
+        &mut self,
+        name: &str,
+        address: &Addr,
+        result: impl Fn(StdResult<GroupMemberResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::GroupMember {
+                name: name.to_string(),
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_funding_history(
+        &mut self,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<FundingHistoryResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::FundingHistory { start_after, limit }, result)
+    }
+
+    #[track_caller]
+    pub fn query_funders(
+        &mut self,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<FundersResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Funders {
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_claim_window(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<ClaimWindowResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::ClaimWindow {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_locks(
+        &mut self,
+        address: &str,
+        result: impl Fn(StdResult<LocksResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Locks {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_export_state(
+        &mut self,
+        section: StateSection,
+        start_after: Option<String>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<ExportStateResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::ExportState {
+                section,
+                start_after,
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_is_live(&mut self, result: impl Fn(StdResult<IsLiveResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::IsLive {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_claim_hooks(
+        &mut self,
+        result: impl Fn(StdResult<ClaimHooksResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::ClaimHooks {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_counts(&mut self, result: impl Fn(StdResult<CountsResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::Counts {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_contract_info(
+        &mut self,
+        result: impl Fn(StdResult<ContractInfoResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::ContractInfo {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_config(&mut self, result: impl Fn(StdResult<ConfigResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::Config {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_proposals(
+        &mut self,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<ProposalsResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::Proposals { start_after, limit }, result)
+    }
+
+    #[track_caller]
+    pub fn query_raffle(&mut self, result: impl Fn(StdResult<RaffleResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::Raffle {}, result)
+    }
+}
+
+pub trait ResultHandler {
+    fn handle_result(&self, result: Result<AppResponse, anyhow::Error>);
+}
+
+impl<F> ResultHandler for F
+where
+    F: Fn(Result<AppResponse, anyhow::Error>),
+{
+    fn handle_result(&self, result: Result<AppResponse, anyhow::Error>) {
+        self(result);
+    }
+}
+
+// execute msg
+impl TestingSuite {
+    fn execute_contract(
+        &mut self,
+        sender: &Addr,
+        msg: ExecuteMsg,
+        funds: &[Coin],
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        result.handle_result(self.app.execute_contract(
+            sender.clone(),
+            self.claimdrop_contract_addr.clone(),
+            &msg,
+            funds,
+        ));
+
+        self
+    }
+
+    #[track_caller]
+    pub fn top_up_campaign(
+        &mut self,
+        sender: &Addr,
+        funds: &[Coin],
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        result.handle_result(self.app.send_tokens(
+            sender.clone(),
+            self.claimdrop_contract_addr.clone(),
+            funds,
+        ));
+
+        self
+    }
+
+    #[track_caller]
+    pub fn manage_campaign(
+        &mut self,
+        sender: &Addr,
+        action: CampaignAction,
+        funds: &[Coin],
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ManageCampaign { action }, funds, result)
+    }
+
+    #[track_caller]
+    pub fn claim(
+        &mut self,
+        sender: &Addr,
+        receiver: Option<String>,
+        amount: Option<Uint128>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver,
+                amount,
+                percentage: None,
+                slots: None,
+                into_vesting_account: None,
+                ibc_receiver: None,
+                lock_option: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_up_to(
+        &mut self,
+        sender: &Addr,
+        timestamp: Timestamp,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ClaimUpTo { timestamp }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn claim_percentage(
+        &mut self,
+        sender: &Addr,
+        amount: Option<Uint128>,
+        percentage: Decimal,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver: None,
+                amount,
+                percentage: Some(percentage),
+                slots: None,
+                into_vesting_account: None,
+                ibc_receiver: None,
+                lock_option: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_with_lock(
+        &mut self,
+        sender: &Addr,
+        lock_option: LockTier,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver: None,
+                amount: None,
+                percentage: None,
+                slots: None,
+                into_vesting_account: None,
+                ibc_receiver: None,
+                lock_option: Some(lock_option),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn withdraw_unlocked(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::WithdrawUnlocked {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn forfeit_expired_allocations(
+        &mut self,
+        sender: &Addr,
+        addresses: Vec<&Addr>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ForfeitExpiredAllocations {
+                addresses: addresses
+                    .into_iter()
+                    .map(|address| address.to_string())
+                    .collect(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_from_slots(
+        &mut self,
+        sender: &Addr,
+        slots: Vec<usize>,
+        amount: Option<Uint128>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver: None,
+                amount,
+                percentage: None,
+                slots: Some(slots),
+                into_vesting_account: None,
+                ibc_receiver: None,
+                lock_option: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_into_vesting_account(
+        &mut self,
+        sender: &Addr,
+        receiver: Option<String>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver,
+                amount: None,
+                percentage: None,
+                slots: None,
+                into_vesting_account: Some(true),
+                ibc_receiver: None,
+                lock_option: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_over_ibc(
+        &mut self,
+        sender: &Addr,
+        receiver: Option<String>,
+        ibc_receiver: IbcReceiver,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::Claim {
+                receiver,
+                amount: None,
+                percentage: None,
+                slots: None,
+                into_vesting_account: None,
+                ibc_receiver: Some(ibc_receiver),
+                lock_option: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    /// Relays a claim signed off-chain by the given key on behalf of the receiver
+    #[track_caller]
+    pub fn claim_authz(
+        &mut self,
+        sender: &Addr,
+        receiver: &Addr,
+        amount: Option<Uint128>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ClaimAuthz {
+                receiver: receiver.to_string(),
+                amount,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_with_signature(
+        &mut self,
+        sender: &Addr,
+        receiver: &Addr,
+        signing_key: &SigningKey,
+        amount: Option<Uint128>,
+        nonce: u64,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        let payload = to_json_vec(&ClaimSignaturePayload {
+            chain_id: self.app.block_info().chain_id,
+            contract: self.claimdrop_contract_addr.to_string(),
+            amount,
+            nonce,
+        })
+        .unwrap();
+        let signature: Signature = signing_key.sign(
+            &claimdrop_contract::helpers::adr36_sign_bytes(receiver.as_str(), &payload),
+        );
+
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ClaimWithSignature {
+                receiver: receiver.to_string(),
+                amount,
+                nonce,
+                pubkey: signing_key
+                    .verifying_key()
+                    .to_encoded_point(true)
+                    .as_bytes()
+                    .into(),
+                signature: signature.to_bytes().as_slice().into(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn update_ownership(
+        &mut self,
+        sender: &Addr,
+        action: cw_ownable::Action,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::UpdateOwnership(action), &[], result)
+    }
+
+    #[track_caller]
+    pub fn set_allocations(
+        &mut self,
+        sender: &Addr,
+        entries: &[(String, Uint128)],
+        mode: AllocationMode,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::SetAllocations {
+                entries: entries.to_vec(),
+                mode,
+                tag: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn apply_allocation_delta(
+        &mut self,
+        sender: &Addr,
+        additions: &[(String, Uint128)],
+        removals: &[String],
+        adjustments: &[(String, Int128)],
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ApplyAllocationDelta {
+                additions: additions.to_vec(),
+                removals: removals.to_vec(),
+                adjustments: adjustments.to_vec(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    #[allow(clippy::ptr_arg)]
+    pub fn add_allocations(
+        &mut self,
+        sender: &Addr,
+        allocations: &Vec<(String, Uint128)>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::AddAllocations {
+                allocations: allocations.clone(),
+                skip_duplicates: None,
+                tag: None,
+                batch_id: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn add_allocations_batch(
+        &mut self,
+        sender: &Addr,
+        allocations: &[(String, Uint128)],
+        batch_id: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::AddAllocations {
+                allocations: allocations.to_vec(),
+                skip_duplicates: None,
+                tag: None,
+                batch_id: Some(batch_id.to_string()),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn add_tagged_allocations(
+        &mut self,
+        sender: &Addr,
+        allocations: &[(String, Uint128)],
+        tag: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::AddAllocations {
+                allocations: allocations.to_vec(),
+                skip_duplicates: None,
+                tag: Some(tag.to_string()),
+                batch_id: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_tagged_allocations(
+        &mut self,
+        sender: &Addr,
+        entries: &[(String, Uint128)],
+        mode: AllocationMode,
+        tag: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::SetAllocations {
+                entries: entries.to_vec(),
+                mode,
+                tag: Some(tag.to_string()),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn replace_address(
+        &mut self,
+        sender: &Addr,
+        old_address: &Addr,
+        new_address: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ReplaceAddress {
+                old_address: old_address.to_string(),
+                new_address: new_address.to_string(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn request_address_replacement(
+        &mut self,
+        sender: &Addr,
+        new_address: Option<&Addr>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::RequestAddressReplacement {
+                new_address: new_address.map(|address| address.to_string()),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn replace_addresses(
+        &mut self,
+        sender: &Addr,
+        pairs: Vec<(String, String)>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ReplaceAddresses { pairs }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn snapshot_delegators(
+        &mut self,
+        sender: &Addr,
+        validator_addrs: Vec<&str>,
+        min_stake: Uint128,
+        ratio: Decimal,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::SnapshotDelegators {
+                validator_addrs: validator_addrs.into_iter().map(Into::into).collect(),
+                min_stake,
+                ratio,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_batch(
+        &mut self,
+        sender: &Addr,
+        receivers: Vec<String>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ClaimBatch { receivers }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn activate_vesting(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ActivateVesting {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn transfer_allocation(
+        &mut self,
+        sender: &Addr,
+        new_address: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::TransferAllocation {
+                new_address: new_address.to_string(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    /// Links the Ethereum address of the signing key to the sender, signing the payload for the
+    /// given address, which is the sender unless testing a mismatch.
+    #[track_caller]
+    pub fn link_address(
+        &mut self,
+        sender: &Addr,
+        signing_key: &SigningKey,
+        signed_for: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        let payload = to_json_vec(&LinkAddressPayload {
+            chain_id: self.app.block_info().chain_id,
+            contract: self.claimdrop_contract_addr.to_string(),
+            address: signed_for.to_string(),
+        })
+        .unwrap();
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(&claimdrop_contract::helpers::eip191_hash(&payload))
+            .unwrap();
+        let mut signature = signature.to_bytes().to_vec();
+        signature.push(recovery_id.to_byte() + 27);
+
+        self.execute_contract(
+            sender,
+            ExecuteMsg::LinkAddress {
+                foreign_address: claimdrop_contract::helpers::pubkey_to_ethereum_address(
+                    signing_key
+                        .verifying_key()
+                        .to_encoded_point(false)
+                        .as_bytes(),
+                ),
+                signature: signature.into(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn finalize_campaign(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::FinalizeCampaign {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn import_allocations(
+        &mut self,
+        sender: &Addr,
+        source_contract: &Addr,
+        start_after: Option<&str>,
+        limit: Option<u16>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ImportAllocations {
+                source_contract: source_contract.to_string(),
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+                include_claimed: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn import_all_allocations(
+        &mut self,
+        sender: &Addr,
+        source_contract: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ImportAllocations {
+                source_contract: source_contract.to_string(),
+                start_after: None,
+                limit: None,
+                include_claimed: Some(true),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn remove_address(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::RemoveAddress {
+                address: address.to_string(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn blacklist_address(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        blacklist: bool,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::BlacklistAddress {
+                address: address.to_string(),
+                blacklist,
+                reason: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn manage_blacklist(
+        &mut self,
+        sender: &Addr,
+        addresses: Vec<String>,
+        blacklist: bool,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ManageBlacklist {
+                addresses,
+                blacklist,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn blacklist_address_with_reason(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        reason: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::BlacklistAddress {
+                address: address.to_string(),
+                blacklist: true,
+                reason: Some(reason.to_string()),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn hold_address(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        until: Option<u64>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::HoldAddress {
+                address: address.to_string(),
+                until,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_pause(
+        &mut self,
+        sender: &Addr,
+        scope: Option<PauseScope>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::SetPause { scope }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn freeze_address(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::FreezeAddress {
+                address: address.to_string(),
+                reason: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn resolve_frozen(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        action: FrozenResolution,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ResolveFrozen {
+                address: address.to_string(),
+                action,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn manage_allowlist(
+        &mut self,
+        sender: &Addr,
+        addresses: Vec<String>,
+        allowlist: bool,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ManageAllowlist {
+                addresses,
+                allowlist,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn grant_roles(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        roles: Vec<Role>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::GrantRoles {
+                address: address.to_string(),
+                roles,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn revoke_roles(
+        &mut self,
+        sender: &Addr,
+        address: &Addr,
+        roles: Vec<Role>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::RevokeRoles {
+                address: address.to_string(),
+                roles,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn manage_authorized_wallets(
+        &mut self,
+        sender: &Addr,
+        addresses: Vec<String>,
+        authorized: bool,
+        funds: &[Coin],
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ManageAuthorizedWallets {
                 addresses,
                 authorized,
             },
@@ -301,14 +1903,464 @@ impl TestingSuite {
     }
 
     #[track_caller]
-    pub fn sweep(
+    pub fn sweep(
+        &mut self,
+        sender: &Addr,
+        denom: String,
+        amount: Option<Uint128>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::Sweep { denom, amount }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn approve_sweep(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ApproveSweep {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn set_sweep_threshold(
+        &mut self,
+        sender: &Addr,
+        sweep_threshold: Uint128,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: Some(sweep_threshold),
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn prune_state(
+        &mut self,
+        sender: &Addr,
+        limit: Option<u16>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::PruneState { limit }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn accept_terms(
+        &mut self,
+        sender: &Addr,
+        terms_hash: HexBinary,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::AcceptTerms { terms_hash }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn set_budget(
+        &mut self,
+        sender: &Addr,
+        amount: Uint128,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::SetBudget { amount }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn create_group(
+        &mut self,
+        sender: &Addr,
+        name: &str,
+        schedule: Vec<DistributionType>,
+        total: Uint128,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::CreateGroup {
+                name: name.to_string(),
+                schedule,
+                total,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn update_group_members(
+        &mut self,
+        sender: &Addr,
+        name: &str,
+        add: Vec<(String, Uint128)>,
+        remove: Vec<String>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateGroupMembers {
+                name: name.to_string(),
+                add,
+                remove,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn claim_group(
+        &mut self,
+        sender: &Addr,
+        name: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ClaimGroup {
+                name: name.to_string(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn top_up(
+        &mut self,
+        sender: &Addr,
+        funds: &[Coin],
+        allow_overfund: Option<bool>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::TopUpCampaign { allow_overfund },
+            funds,
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn redistribute_forfeited(
         &mut self,
         sender: &Addr,
-        denom: String,
-        amount: Option<Uint128>,
+        limit: Option<u16>,
         result: impl ResultHandler,
     ) -> &mut Self {
-        self.execute_contract(sender, ExecuteMsg::Sweep { denom, amount }, &[], result)
+        self.execute_contract(
+            sender,
+            ExecuteMsg::RedistributeForfeited { limit },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn propose(
+        &mut self,
+        sender: &Addr,
+        action: ProposalAction,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::Propose { action }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn approve(
+        &mut self,
+        sender: &Addr,
+        proposal_id: u64,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::Approve { proposal_id }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn update_config(
+        &mut self,
+        sender: &Addr,
+        circuit_breaker: Option<CircuitBreaker>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_registry(
+        &mut self,
+        sender: &Addr,
+        registry: &str,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: Some(registry.to_string()),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn update_limits(
+        &mut self,
+        sender: &Addr,
+        limits: Limits,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: Some(limits),
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_replacements_require_request(
+        &mut self,
+        sender: &Addr,
+        replacements_require_request: bool,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: Some(replacements_require_request),
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_voucher_pubkey(
+        &mut self,
+        sender: &Addr,
+        voucher_pubkey: Binary,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: Some(voucher_pubkey),
+                allowed_prefixes: None,
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_allowed_prefixes(
+        &mut self,
+        sender: &Addr,
+        allowed_prefixes: Vec<&str>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: Some(
+                    allowed_prefixes
+                        .into_iter()
+                        .map(|prefix| prefix.to_string())
+                        .collect(),
+                ),
+                authorized_wallet_delay: None,
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn set_authorized_wallet_delay(
+        &mut self,
+        sender: &Addr,
+        delay: u64,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::UpdateConfig {
+                circuit_breaker: None,
+                limits: None,
+                replacements_require_request: None,
+                voucher_pubkey: None,
+                allowed_prefixes: None,
+                authorized_wallet_delay: Some(delay),
+                sweep_threshold: None,
+                registry: None,
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn activate_authorized_wallets(
+        &mut self,
+        sender: &Addr,
+        addresses: Vec<String>,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ActivateAuthorizedWallets { addresses },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_pending_authorized_wallets(
+        &mut self,
+        result: impl Fn(StdResult<PendingAuthorizedWalletsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::PendingAuthorizedWallets {
+                start_after: None,
+                limit: None,
+            },
+            result,
+        )
+    }
+
+    /// Registers an allocation voucher, signed by the signing key of the given signer
+    #[track_caller]
+    pub fn register_allocation(
+        &mut self,
+        sender: &Addr,
+        signer: &Addr,
+        signing_key: &SigningKey,
+        voucher: AllocationVoucher,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        let signature: Signature =
+            signing_key.sign(&claimdrop_contract::helpers::adr36_sign_bytes(
+                signer.as_str(),
+                &to_json_vec(&voucher).unwrap(),
+            ));
+
+        self.execute_contract(
+            sender,
+            ExecuteMsg::RegisterAllocation {
+                voucher,
+                signature: signature.to_bytes().as_slice().into(),
+            },
+            &[],
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn resume_claims(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::ResumeClaims {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn submit_raffle_seed(
+        &mut self,
+        sender: &Addr,
+        seed: HexBinary,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::SubmitRaffleSeed { seed }, &[], result)
+    }
+
+    #[track_caller]
+    pub fn draw_raffle(&mut self, sender: &Addr, result: impl ResultHandler) -> &mut Self {
+        self.execute_contract(sender, ExecuteMsg::DrawRaffle {}, &[], result)
+    }
+
+    #[track_caller]
+    pub fn manage_ibc_channels(
+        &mut self,
+        sender: &Addr,
+        channel_ids: Vec<String>,
+        allowed: bool,
+        result: impl ResultHandler,
+    ) -> &mut Self {
+        self.execute_contract(
+            sender,
+            ExecuteMsg::ManageIbcChannels {
+                channel_ids,
+                allowed,
+            },
+            &[],
+            result,
+        )
+    }
+}
+
+// sudo msg
+impl TestingSuite {
+    #[track_caller]
+    pub fn sudo(&mut self, msg: SudoMsg, result: impl ResultHandler) -> &mut Self {
+        result.handle_result(
+            self.app
+                .wasm_sudo(self.claimdrop_contract_addr.clone(), &msg),
+        );
+
+        self
     }
 }
 
@@ -329,8 +2381,24 @@ impl TestingSuite {
     }
 
     #[track_caller]
-    pub fn query_campaign(&mut self, result: impl Fn(StdResult<CampaignResponse>)) -> &mut Self {
-        self.query_contract(QueryMsg::Campaign {}, result)
+    pub fn query_campaign(&mut self, result: impl Fn(StdResult<CampaignResponse>)) -> &mut Self {
+        self.query_contract(QueryMsg::Campaign {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_campaign_status(
+        &mut self,
+        result: impl Fn(StdResult<CampaignStatusResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::CampaignStatus {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_campaign_phase(
+        &mut self,
+        result: impl Fn(StdResult<CampaignPhaseResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::CampaignPhase {}, result)
     }
 
     #[track_caller]
@@ -347,6 +2415,29 @@ impl TestingSuite {
         )
     }
 
+    #[track_caller]
+    pub fn query_batch_rewards(
+        &mut self,
+        addresses: Vec<String>,
+        result: impl Fn(StdResult<BatchRewardsResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::BatchRewards { addresses }, result)
+    }
+
+    #[track_caller]
+    pub fn query_simulate_claim(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<SimulateClaimResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::SimulateClaim {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
     #[track_caller]
     pub fn query_claimed(
         &mut self,
@@ -368,6 +2459,42 @@ impl TestingSuite {
         )
     }
 
+    #[track_caller]
+    pub fn query_claimed_by_slot(
+        &mut self,
+        address: Option<&Addr>,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<ClaimedBySlotResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::ClaimedBySlot {
+                address: address.map(|addr| addr.to_string()),
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_claim_history(
+        &mut self,
+        address: &Addr,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<ClaimHistoryResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::ClaimHistory {
+                address: address.to_string(),
+                start_after,
+                limit,
+            },
+            result,
+        )
+    }
+
     #[track_caller]
     pub fn query_allocations(
         &mut self,
@@ -381,6 +2508,243 @@ impl TestingSuite {
                 address: address.map(|addr| addr.to_string()),
                 start_after: start_after.map(|addr| addr.to_string()),
                 limit,
+                min_amount: None,
+                has_claimed: None,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_filtered_allocations(
+        &mut self,
+        start_after: Option<&str>,
+        limit: Option<u16>,
+        min_amount: Option<Uint128>,
+        has_claimed: Option<bool>,
+        result: impl Fn(StdResult<AllocationsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Allocations {
+                address: None,
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+                min_amount,
+                has_claimed,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_top_allocations(
+        &mut self,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<AllocationsResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::TopAllocations { limit }, result)
+    }
+
+    #[track_caller]
+    pub fn query_unclaimed_allocations(
+        &mut self,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<AllocationsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::UnclaimedAllocations {
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_address_replacement_request(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<AddressReplacementRequestResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::AddressReplacementRequest {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_vesting_activation(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<VestingActivationResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::VestingActivation {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_claim_volume(
+        &mut self,
+        from_day: u64,
+        to_day: u64,
+        result: impl Fn(StdResult<ClaimVolumeResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::ClaimVolume { from_day, to_day }, result)
+    }
+
+    #[track_caller]
+    pub fn query_campaign_stats(&mut self, result: impl Fn(StdResult<CampaignStats>)) -> &mut Self {
+        self.query_contract(QueryMsg::CampaignStats {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_slot_solvency(
+        &mut self,
+        result: impl Fn(StdResult<SlotSolvencyResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::SlotSolvency {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_allocations_by_uploader(
+        &mut self,
+        uploader: &Addr,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<AllocationsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::AllocationsByUploader {
+                uploader: uploader.to_string(),
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_allocations_by_tag(
+        &mut self,
+        tag: &str,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<AllocationsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::AllocationsByTag {
+                tag: tag.to_string(),
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_tag_stats(
+        &mut self,
+        tag: &str,
+        result: impl Fn(StdResult<TagStatsResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::TagStats {
+                tag: tag.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_pending_sweep(
+        &mut self,
+        result: impl Fn(StdResult<PendingSweepResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::PendingSweep {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_hold(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<HoldResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Hold {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_pause_status(
+        &mut self,
+        result: impl Fn(StdResult<PauseStatusResponse>),
+    ) -> &mut Self {
+        self.query_contract(QueryMsg::PauseStatus {}, result)
+    }
+
+    #[track_caller]
+    pub fn query_liabilities(
+        &mut self,
+        start_after: Option<&Addr>,
+        limit: Option<u16>,
+        result: impl Fn(StdResult<LiabilitiesResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Liabilities {
+                start_after: start_after.map(|address| address.to_string()),
+                limit,
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_frozen_entry(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<FrozenEntryResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::FrozenEntry {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_blacklist_entry(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<BlacklistEntryResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::BlacklistEntry {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_claim_nonce(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<ClaimNonceResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::ClaimNonce {
+                address: address.to_string(),
             },
             result,
         )
@@ -401,7 +2765,21 @@ impl TestingSuite {
     }
 
     #[track_caller]
-    pub fn _query_ownership(
+    pub fn query_is_allowlisted(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<AllowlistResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::IsAllowlisted {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_ownership(
         &mut self,
         result: impl Fn(StdResult<cw_ownable::Ownership<String>>),
     ) -> &mut Self {
@@ -438,4 +2816,36 @@ impl TestingSuite {
     ) -> &mut Self {
         self.query_contract(QueryMsg::AuthorizedWallets { start_after, limit }, result)
     }
+
+    #[track_caller]
+    pub fn query_roles(
+        &mut self,
+        address: &Addr,
+        result: impl Fn(StdResult<RolesResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::Roles {
+                address: address.to_string(),
+            },
+            result,
+        )
+    }
+
+    #[track_caller]
+    pub fn query_role_holders(
+        &mut self,
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        result: impl Fn(StdResult<RoleHoldersResponse>),
+    ) -> &mut Self {
+        self.query_contract(
+            QueryMsg::RoleHolders {
+                role,
+                start_after,
+                limit,
+            },
+            result,
+        )
+    }
 }
@@ -30,10 +30,12 @@ fn test_sweep_non_reward_tokens() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 1,
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -135,10 +137,12 @@ fn test_sweep_cannot_sweep_reward_denom() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 1,
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
@@ -290,10 +294,12 @@ fn test_sweep_after_campaign_closed() {
                     total_reward: coin(100_000, "uom"),
                     distribution_type: vec![DistributionType::LumpSum {
                         percentage: Decimal::one(),
-                        start_time: current_time.seconds() + 1,
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
                     }],
-                    start_time: current_time.seconds() + 1,
-                    end_time: current_time.seconds() + 172_800,
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_seconds(172_800),
+                    ..Default::default()
                 }),
             },
             &[],
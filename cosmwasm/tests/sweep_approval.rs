@@ -0,0 +1,322 @@
+use cosmwasm_std::{coin, coins, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::ProposalAction;
+
+mod suite;
+use suite::TestingSuite;
+
+fn setup(suite: &mut TestingSuite) {
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_authorized_wallets(
+            owner,
+            vec![alice.to_string()],
+            true,
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .set_sweep_threshold(
+            owner,
+            Uint128::new(10_000),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // tokens sent to the contract by mistake
+        .top_up_campaign(
+            owner,
+            &coins(50_000, "uusdc"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
+
+#[test]
+fn sweeps_above_the_threshold_require_a_second_approval() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+
+    suite
+        // sweeps within the threshold go through at once
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            Some(Uint128::new(10_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(999_960_000));
+        })
+        // the sweeps add up against the threshold until a sweep is approved
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            Some(Uint128::new(1)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_pending_sweep(|result| {
+            let pending_sweep = result.unwrap().pending_sweep.unwrap();
+            assert_eq!(pending_sweep.amount, coin(1, "uusdc"));
+        })
+        .approve_sweep(alice, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(999_960_001));
+        })
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            Some(Uint128::new(9_999)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(999_970_000));
+        })
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(999_970_000));
+        })
+        .query_pending_sweep(|result| {
+            let pending_sweep = result.unwrap().pending_sweep.unwrap();
+            assert_eq!(pending_sweep.amount, coin(30_000, "uusdc"));
+            assert_eq!(pending_sweep.requested_by, owner.to_string());
+        })
+        // the pending sweep can't be replaced while it awaits approval
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            Some(Uint128::new(20_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .query_pending_sweep(|result| {
+            let pending_sweep = result.unwrap().pending_sweep.unwrap();
+            assert_eq!(pending_sweep.amount, coin(30_000, "uusdc"));
+        })
+        // the owner can't approve its own sweep
+        .approve_sweep(owner, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .approve_sweep(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .approve_sweep(alice, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        })
+        .query_pending_sweep(|result| {
+            assert!(result.unwrap().pending_sweep.is_none());
+        })
+        .approve_sweep(alice, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        });
+}
+
+#[test]
+fn pending_sweeps_expire_after_the_approval_window() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+
+    suite
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the sweep approval window is a day
+        .add_day()
+        .add_day()
+        .approve_sweep(alice, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        })
+        // the expired sweep can be requested again
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            Some(Uint128::new(20_000)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_pending_sweep(|result| {
+            let pending_sweep = result.unwrap().pending_sweep.unwrap();
+            assert_eq!(pending_sweep.amount, coin(20_000, "uusdc"));
+        })
+        // the owner can't lift the threshold on its own
+        .set_sweep_threshold(
+            owner,
+            Uint128::zero(),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .propose(
+            alice,
+            ProposalAction::SetSweepThreshold {
+                threshold: Uint128::zero(),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .approve(owner, 1, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_000));
+        });
+}
+
+#[test]
+fn owner_cannot_approve_its_own_sweep_proposals() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+
+    suite
+        .propose(
+            owner,
+            ProposalAction::Sweep {
+                denom: "uusdc".to_string(),
+                amount: None,
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .approve(owner, 1, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .propose(
+            owner,
+            ProposalAction::SetSweepThreshold {
+                threshold: Uint128::zero(),
+            },
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .approve(owner, 2, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .query_balance("uusdc", owner, |balance| {
+            assert_eq!(balance, Uint128::new(999_950_000));
+        });
+}
+
+#[test]
+fn cannot_approve_a_sweep_once_the_ownership_is_renounced() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    setup(&mut suite);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+
+    suite
+        .sweep(
+            owner,
+            "uusdc".to_string(),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .update_ownership(
+            owner,
+            cw_ownable::Action::RenounceOwnership,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // there's no owner to send the sweep to
+        .approve_sweep(alice, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        });
+}
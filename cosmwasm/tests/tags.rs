@@ -0,0 +1,154 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{AllocationMode, CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+#[test]
+fn allocations_are_queried_and_tracked_per_tag() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let dave = &suite.senders[4].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_tagged_allocations(
+            owner,
+            &[
+                (alice.to_string(), Uint128::new(100)),
+                (bob.to_string(), Uint128::new(200)),
+            ],
+            "early_users",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_tagged_allocations(
+            owner,
+            &[(carol.to_string(), Uint128::new(50))],
+            "community",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_allocations(
+            owner,
+            &vec![(dave.to_string(), Uint128::new(150))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_tagged_allocations(
+            owner,
+            &[(dave.to_string(), Uint128::new(150))],
+            "",
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        // bob moves to the community cohort with a bigger allocation
+        .set_tagged_allocations(
+            owner,
+            &[(bob.to_string(), Uint128::new(300))],
+            AllocationMode::UpsertBeforeStart,
+            "community",
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_allocations_by_tag("early_users", None, None, |result| {
+            assert_eq!(
+                result.unwrap().allocations,
+                vec![(alice.to_string(), coin(100, ""))]
+            );
+        })
+        .query_allocations_by_tag("community", None, None, |result| {
+            let allocations = result.unwrap().allocations;
+            assert_eq!(allocations.len(), 2);
+            assert!(allocations.contains(&(bob.to_string(), coin(300, ""))));
+            assert!(allocations.contains(&(carol.to_string(), coin(50, ""))));
+        })
+        .query_allocations_by_tag("community", None, Some(1), |result| {
+            assert_eq!(result.unwrap().allocations.len(), 1);
+        })
+        .query_tag_stats("early_users", |result| {
+            let stats = result.unwrap().stats;
+            assert_eq!(stats.allocations, 1);
+            assert_eq!(stats.total_allocated, Uint128::new(100));
+        })
+        .query_tag_stats("community", |result| {
+            let stats = result.unwrap().stats;
+            assert_eq!(stats.allocations, 2);
+            assert_eq!(stats.total_allocated, Uint128::new(350));
+        })
+        .query_tag_stats("unknown", |result| {
+            assert_eq!(result.unwrap().stats, Default::default());
+        })
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Tagged Campaign".to_string(),
+                    description: "Campaign with tagged allocations".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(600, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(7),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(600, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            dave,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_tag_stats("early_users", |result| {
+            let stats = result.unwrap().stats;
+            assert_eq!(stats.claimants, 1);
+            assert_eq!(stats.total_claimed, Uint128::new(100));
+        })
+        .query_tag_stats("community", |result| {
+            let stats = result.unwrap().stats;
+            assert_eq!(stats.claimants, 0);
+            assert_eq!(stats.total_claimed, Uint128::zero());
+        });
+}
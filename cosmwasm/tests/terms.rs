@@ -0,0 +1,123 @@
+use cosmwasm_std::{coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+use sha2::{Digest, Sha256};
+
+mod suite;
+use suite::TestingSuite;
+
+fn terms_hash(terms: &str) -> HexBinary {
+    HexBinary::from(Sha256::digest(terms.as_bytes()).to_vec())
+}
+
+fn campaign_params(current_time: &Timestamp, terms: &str) -> CampaignParams {
+    CampaignParams {
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::one(),
+            start_time: current_time.plus_seconds(3_600),
+            cliff_duration: None,
+        }],
+        start_time: current_time.plus_seconds(3_600),
+        terms_hash: Some(terms_hash(terms)),
+        ..suite::campaign_params(current_time, 1_000)
+    }
+}
+
+fn assert_terms_not_accepted(result: Result<AppResponse, anyhow::Error>, terms: &str) {
+    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+    match err {
+        ContractError::TermsNotAccepted { terms_hash: hash } => {
+            assert_eq!(hash, terms_hash(terms));
+        }
+        _ => panic!("Wrong error type, should return ContractError::TermsNotAccepted"),
+    }
+}
+
+#[test]
+fn claims_require_accepting_the_current_terms() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (alice.to_string(), Uint128::new(600)),
+                (bob.to_string(), Uint128::new(400)),
+            ],
+            campaign_params(current_time, "terms v1"),
+        )
+        .accept_terms(
+            alice,
+            terms_hash("terms v0"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidInput { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+                }
+            },
+        )
+        .accept_terms(
+            alice,
+            terms_hash("terms v1"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the terms are updated before the campaign starts, so they must be accepted again
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateCampaign {
+                params: Box::new(campaign_params(current_time, "terms v2")),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_terms_acceptance(alice, |result| {
+            let acceptance = result.unwrap();
+            assert!(!acceptance.accepted);
+            assert_eq!(acceptance.terms_hash, Some(terms_hash("terms v2")));
+            assert_eq!(acceptance.accepted_hash, Some(terms_hash("terms v1")));
+        })
+        .add_day()
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                assert_terms_not_accepted(result, "terms v2");
+            },
+        )
+        .accept_terms(
+            alice,
+            terms_hash("terms v2"),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim(
+            alice,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_terms_acceptance(alice, |result| {
+            assert!(result.unwrap().accepted);
+        })
+        .query_terms_acceptance(bob, |result| {
+            let acceptance = result.unwrap();
+            assert!(!acceptance.accepted);
+            assert_eq!(acceptance.accepted_at, None);
+        })
+        .query_balance("uom", alice, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_000_600));
+        });
+}
@@ -0,0 +1,76 @@
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::AllocationsResponse;
+
+mod suite;
+use suite::TestingSuite;
+
+fn addresses(response: AllocationsResponse) -> Vec<String> {
+    response
+        .allocations
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect()
+}
+
+#[test]
+fn query_largest_allocations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let eve = &suite.senders[4].clone();
+
+    let ordered = |addresses: &[&Addr]| -> Vec<String> {
+        addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect()
+    };
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .query_top_allocations(None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        })
+        .add_allocations(
+            owner,
+            &vec![
+                (bob.to_string(), Uint128::new(100)),
+                (carol.to_string(), Uint128::new(300)),
+                (dan.to_string(), Uint128::new(200)),
+            ],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_top_allocations(None, |result| {
+            assert_eq!(addresses(result.unwrap()), ordered(&[carol, dan, bob]));
+        })
+        .query_top_allocations(Some(2), |result| {
+            let response = result.unwrap();
+            assert_eq!(response.allocations.len(), 2);
+            assert_eq!(response.allocations[0].1.amount, Uint128::new(300));
+            assert_eq!(response.allocations[1].1.amount, Uint128::new(200));
+        })
+        // the index is kept up to date when allocations are removed or replaced
+        .remove_address(
+            owner,
+            carol,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .replace_address(
+            owner,
+            dan,
+            eve,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_top_allocations(None, |result| {
+            assert_eq!(addresses(result.unwrap()), ordered(&[eve, bob]));
+        });
+}
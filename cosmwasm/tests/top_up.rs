@@ -0,0 +1,298 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::AppResponse;
+use cw_utils::PaymentError;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, RefundPolicy};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+#[test]
+fn top_ups_are_guarded_and_recorded() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000, "uom"),
+        coin(1_000_000_000, "uusdc"),
+    ]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+
+    suite
+        .top_up(
+            owner,
+            &[coin(600, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoActiveCampaign => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoActiveCampaign"),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, 1_000)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            owner,
+            &[coin(600, "uusdc")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidDenom { denom, .. } => assert_eq!(denom, "uusdc"),
+                    _ => panic!("Wrong error type, should return ContractError::InvalidDenom"),
+                }
+            },
+        )
+        .top_up(
+            owner,
+            &[coin(600, "uom"), coin(600, "uusdc")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::PaymentError(PaymentError::MultipleDenoms {}) => {}
+                    _ => panic!("Wrong error type, should return ContractError::PaymentError"),
+                }
+            },
+        )
+        .top_up(
+            owner,
+            &[coin(600, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(500, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignOverfunded { required, funded } => {
+                        assert_eq!(required, Uint128::new(1_000));
+                        assert_eq!(funded, Uint128::new(1_100));
+                    }
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::CampaignOverfunded")
+                    }
+                }
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(400, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(100, "uom")],
+            Some(true),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_funding_history(None, None, |result| {
+            let deposits = result.unwrap().deposits;
+            assert_eq!(deposits.len(), 3);
+            assert_eq!(deposits[0].sequence, 0);
+            assert_eq!(deposits[0].funder, owner.to_string());
+            assert_eq!(deposits[0].amount, coin(600, "uom"));
+            assert_eq!(deposits[1].funder, alice.to_string());
+            assert_eq!(deposits[1].amount, coin(400, "uom"));
+            assert_eq!(deposits[2].amount, coin(100, "uom"));
+        })
+        .query_funding_history(Some(0), Some(1), |result| {
+            let deposits = result.unwrap().deposits;
+            assert_eq!(deposits.len(), 1);
+            assert_eq!(deposits[0].sequence, 1);
+        });
+}
+
+#[test]
+fn close_refunds_the_funders_pro_rata() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let carol = &suite.senders[3].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    refund_policy: RefundPolicy::ProRata,
+                    ..campaign_params(current_time, 1_000)
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(400, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            bob,
+            &[coin(300, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(200, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // funds sent without the top up handler aren't attributed
+        .top_up_campaign(
+            carol,
+            &[coin(100, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_funders(None, None, |result| {
+            let mut funders = result.unwrap().funders;
+            funders.sort_by_key(|(_, contributed)| contributed.amount);
+            assert_eq!(
+                funders,
+                vec![
+                    (bob.to_string(), coin(300, "uom")),
+                    (alice.to_string(), coin(600, "uom")),
+                ]
+            );
+        });
+
+    let balance = |suite: &mut TestingSuite, address| {
+        let balance = std::cell::Cell::new(Uint128::zero());
+        suite.query_balance("uom", address, |amount| balance.set(amount));
+        balance.get()
+    };
+    let (owner_before, alice_before, bob_before, carol_before) = (
+        balance(&mut suite, owner),
+        balance(&mut suite, alice),
+        balance(&mut suite, bob),
+        balance(&mut suite, carol),
+    );
+
+    suite.manage_campaign(
+        owner,
+        CampaignAction::CloseCampaign {},
+        &[],
+        |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        },
+    );
+
+    // the 100 not attributed go to the owner, and the 900 left are split 600:300
+    assert_eq!(balance(&mut suite, alice) - alice_before, Uint128::new(600));
+    assert_eq!(balance(&mut suite, bob) - bob_before, Uint128::new(300));
+    assert_eq!(balance(&mut suite, owner) - owner_before, Uint128::new(100));
+    assert_eq!(balance(&mut suite, carol), carol_before);
+}
+
+#[test]
+fn close_refunds_the_owner_funding_sent_without_the_top_up_handler() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let alice = &suite.senders[1].clone();
+    let bob = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(200))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    refund_policy: RefundPolicy::ProRata,
+                    ..campaign_params(current_time, 1_000)
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        // the owner funds the campaign with a plain bank transfer
+        .top_up_campaign(
+            owner,
+            &[coin(700, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up(
+            alice,
+            &[coin(300, "uom")],
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+
+    let balance = |suite: &mut TestingSuite, address| {
+        let balance = std::cell::Cell::new(Uint128::zero());
+        suite.query_balance("uom", address, |amount| balance.set(amount));
+        balance.get()
+    };
+    let (owner_before, alice_before) = (balance(&mut suite, owner), balance(&mut suite, alice));
+
+    suite.manage_campaign(
+        owner,
+        CampaignAction::CloseCampaign {},
+        &[],
+        |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        },
+    );
+
+    // the claim is paid from the owner funding, and alice gets her whole contribution back
+    assert_eq!(balance(&mut suite, alice) - alice_before, Uint128::new(300));
+    assert_eq!(balance(&mut suite, owner) - owner_before, Uint128::new(500));
+}
@@ -0,0 +1,173 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, allocation_transfers_disabled: bool) {
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.setup_campaign(
+        &[(bob.to_string(), Uint128::new(10_000))],
+        CampaignParams {
+            distribution_type: vec![DistributionType::LinearVesting {
+                percentage: Decimal::one(),
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(10),
+                cliff_duration: None,
+                cliff_behavior: None,
+            }],
+            end_time: current_time.plus_days(10),
+            allocation_transfers_disabled,
+            ..campaign_params(current_time, 10_000)
+        },
+    );
+}
+
+#[test]
+fn user_transfers_remaining_allocation_to_new_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, false);
+
+    suite
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .transfer_allocation(bob, bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidInput { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::InvalidInput"),
+            }
+        })
+        .transfer_allocation(bob, carol, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_allocations(Some(bob), None, None, |result| {
+            assert!(result.unwrap().allocations.is_empty());
+        })
+        .query_allocations(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().allocations[0].1, coin(10_000, "uom"));
+        })
+        // the claims made by bob are attached to carol, so carol can only claim the remainder
+        .query_claimed(Some(carol), None, None, |result| {
+            assert_eq!(result.unwrap().claimed[0].1, coin(999, "uom"));
+        })
+        .add_day()
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_balance("uom", carol, |balance| {
+            assert_eq!(balance, Uint128::new(1_000_001_000));
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoAllocationFound { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+                }
+            },
+        );
+}
+
+#[test]
+fn cannot_transfer_allocation_to_or_from_blacklisted_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite, false);
+
+    suite
+        .blacklist_address(
+            owner,
+            carol,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .transfer_allocation(bob, carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::AddressBlacklisted => {}
+                _ => panic!("Wrong error type, should return ContractError::AddressBlacklisted"),
+            }
+        })
+        .blacklist_address(
+            owner,
+            bob,
+            true,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .transfer_allocation(bob, dan, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::AddressBlacklisted => {}
+                _ => panic!("Wrong error type, should return ContractError::AddressBlacklisted"),
+            }
+        })
+        .transfer_allocation(dan, carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::AddressBlacklisted => {}
+                _ => panic!("Wrong error type, should return ContractError::AddressBlacklisted"),
+            }
+        })
+        // dan has no allocation to transfer
+        .transfer_allocation(dan, owner, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::NoAllocationFound { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+            }
+        });
+}
+
+#[test]
+fn cannot_transfer_allocation_when_disabled() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+
+    setup_campaign(&mut suite, true);
+
+    suite
+        .transfer_allocation(bob, carol, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "allocation transfers are disabled");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .query_campaign(|result| {
+            assert!(result.unwrap().allocation_transfers_disabled);
+        });
+}
@@ -0,0 +1,110 @@
+use claimdrop_contract::events::{EVENTS_SCHEMA_VERSION, SCHEMA_VERSION_KEY};
+use cosmwasm_std::{coin, Decimal, Event, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn transfer_event(kind: &str, from: &str, to: &str, amount: &str) -> Event {
+    Event::new("wasm-claimdrop/transfer")
+        .add_attribute("kind", kind)
+        .add_attribute("from", from)
+        .add_attribute("to", to)
+        .add_attribute("amount", amount)
+        .add_attribute(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION)
+}
+
+#[test]
+fn payouts_emit_versioned_transfer_events() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite.instantiate_claimdrop_contract(Some(owner.to_string()));
+    let contract = suite.claimdrop_contract_addr.clone();
+
+    suite
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(4_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(
+                    &Event::new("wasm")
+                        .add_attribute("action", "add_allocations")
+                        .add_attribute(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION)
+                ));
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(CampaignParams {
+                    name: "Transfer Events Campaign".to_string(),
+                    description: "Campaign emitting transfer events".to_string(),
+                    ty: "airdrop".to_string(),
+                    total_reward: coin(10_000, "uom"),
+                    distribution_type: vec![DistributionType::LumpSum {
+                        percentage: Decimal::one(),
+                        start_time: current_time.plus_seconds(1),
+                        cliff_duration: None,
+                    }],
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(10),
+                    ..Default::default()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .top_up_campaign(
+            owner,
+            &[coin(10_000, "uom")],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(&transfer_event(
+                    "claim",
+                    contract.as_str(),
+                    bob.as_str(),
+                    "4000uom"
+                )));
+                assert!(response.has_event(
+                    &Event::new("wasm-claimdrop/claim")
+                        .add_attribute("slot", "0")
+                        .add_attribute(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION)
+                ));
+                assert!(response.has_event(
+                    &Event::new("wasm")
+                        .add_attribute("action", "claim")
+                        .add_attribute(SCHEMA_VERSION_KEY, EVENTS_SCHEMA_VERSION)
+                ));
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CloseCampaign {},
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                assert!(response.has_event(&transfer_event(
+                    "refund",
+                    contract.as_str(),
+                    owner.as_str(),
+                    "6000uom"
+                )));
+            },
+        );
+}
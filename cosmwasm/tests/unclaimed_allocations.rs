@@ -0,0 +1,90 @@
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::msg::{AllocationsResponse, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn addresses(response: AllocationsResponse) -> Vec<String> {
+    response
+        .allocations
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect()
+}
+
+fn sorted(addresses: &[&Addr]) -> Vec<String> {
+    let mut addresses: Vec<String> = addresses.iter().map(|addr| addr.to_string()).collect();
+    addresses.sort();
+    addresses
+}
+
+#[test]
+fn query_addresses_that_havent_claimed() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+    let eve = &suite.senders[4].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .setup_campaign(
+            &[
+                (bob.to_string(), Uint128::new(1_000)),
+                (carol.to_string(), Uint128::new(1_000)),
+                (dan.to_string(), Uint128::new(1_000)),
+            ],
+            CampaignParams {
+                name: "Reminder Campaign".to_string(),
+                description: "Campaign tracking unclaimed allocations".to_string(),
+                ty: "airdrop".to_string(),
+                total_reward: coin(3_000, "uom"),
+                distribution_type: vec![DistributionType::LinearVesting {
+                    percentage: Decimal::one(),
+                    start_time: current_time.plus_seconds(1),
+                    end_time: current_time.plus_days(10),
+                    cliff_duration: None,
+                    cliff_behavior: None,
+                }],
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(10),
+                ..Default::default()
+            },
+        )
+        .query_unclaimed_allocations(None, None, |result| {
+            assert_eq!(addresses(result.unwrap()), sorted(&[bob, carol, dan]));
+        })
+        .add_day()
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .query_unclaimed_allocations(None, None, |result| {
+            assert_eq!(addresses(result.unwrap()), sorted(&[carol, dan]));
+        })
+        // bob keeps its claimed status when transferring its allocation
+        .transfer_allocation(bob, eve, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .query_unclaimed_allocations(None, None, |result| {
+            assert_eq!(addresses(result.unwrap()), sorted(&[carol, dan]));
+        });
+
+    let unclaimed = sorted(&[carol, dan]);
+    suite
+        .query_unclaimed_allocations(None, Some(1), |result| {
+            let response = result.unwrap();
+            assert_eq!(response.allocations.len(), 1);
+            assert_eq!(response.allocations[0].0, unclaimed[0]);
+            assert_eq!(response.allocations[0].1, coin(1_000, "uom"));
+        })
+        .query_unclaimed_allocations(Some(&Addr::unchecked(&unclaimed[0])), None, |result| {
+            assert_eq!(addresses(result.unwrap()), vec![unclaimed[1].clone()]);
+        });
+}
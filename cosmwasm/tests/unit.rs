@@ -1,5 +1,8 @@
-use claimdrop_contract::helpers::{validate_raw_address, MAX_PLACEHOLDER_ADDRESS_LEN};
+use claimdrop_contract::helpers::{
+    pubkey_to_ethereum_address, validate_raw_address, MAX_PLACEHOLDER_ADDRESS_LEN,
+};
 use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{HexBinary, Timestamp, Uint128};
 use mantra_claimdrop_std::error::ContractError;
 
 #[test]
@@ -20,6 +23,15 @@ fn invalid_bech32_but_valid_placeholder() {
     assert_eq!(result.unwrap(), "example.eth");
 }
 
+#[test]
+fn unprefixed_pubkey_is_a_placeholder() {
+    let deps = mock_dependencies();
+    // the base64 compressed public key of the private key 0x...01, without the pubkey prefix
+    let address_raw = "Anm+Zn753LusVaBilc6HCwcCm/zbLc4o2VnygVsW+BeY";
+    let result = validate_raw_address(deps.as_ref(), address_raw);
+    assert_eq!(result.unwrap(), address_raw.to_lowercase());
+}
+
 #[test]
 fn invalid_bech32_and_invalid_placeholder() {
     let deps = mock_dependencies();
@@ -249,3 +261,54 @@ fn ethereum_address_mixed_case() {
         "0x742d35cc6361c4c93f09bb9eca5e90de2c0a5b8f"
     );
 }
+
+#[test]
+fn ethereum_address_with_invalid_hex() {
+    let deps = mock_dependencies();
+    let address_raw = "0x742d35cc6361c4c93f09bb9eca5e90de2c0a5bzz";
+    let result = validate_raw_address(deps.as_ref(), address_raw);
+    assert!(result.is_err());
+}
+
+#[test]
+fn ethereum_address_from_pubkey() {
+    // the public key of the private key 0x...01, i.e. the secp256k1 generator point
+    let pubkey = HexBinary::from_hex(
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    )
+    .unwrap();
+    assert_eq!(
+        pubkey_to_ethereum_address(&pubkey),
+        "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+    );
+}
+
+#[test]
+fn error_codes_are_stable() {
+    let errors = [
+        (ContractError::NoActiveCampaign, 201, "no_active_campaign"),
+        (ContractError::CampaignClosed, 203, "campaign_closed"),
+        (
+            ContractError::CampaignNotStarted {
+                starts_at: Timestamp::from_seconds(1_700_000_000),
+            },
+            204,
+            "campaign_not_started",
+        ),
+        (
+            ContractError::SlotNotFunded {
+                slot: 0,
+                requested: Uint128::new(10),
+                available: Uint128::zero(),
+            },
+            208,
+            "slot_not_funded",
+        ),
+        (ContractError::Unauthorized, 800, "unauthorized"),
+    ];
+
+    for (error, code, name) in errors {
+        assert_eq!(error.code(), code);
+        assert_eq!(error.name(), name);
+    }
+}
@@ -0,0 +1,171 @@
+use cosmwasm_std::{coin, Decimal, Event, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignAction, CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+fn campaign_params(current_time: &Timestamp, start_offset: u64, duration: u64) -> CampaignParams {
+    let start_time = current_time.plus_seconds(start_offset);
+
+    CampaignParams {
+        distribution_type: vec![DistributionType::LinearVesting {
+            percentage: Decimal::one(),
+            start_time,
+            end_time: start_time.plus_seconds(duration),
+            cliff_duration: None,
+            cliff_behavior: None,
+        }],
+        start_time,
+        end_time: start_time.plus_seconds(duration),
+        ..suite::campaign_params(current_time, 10_000)
+    }
+}
+
+#[test]
+fn campaign_can_be_updated_before_it_starts() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    let extended_params = campaign_params(current_time, 86_400, 86_400 * 20);
+    let new_start_time = extended_params.start_time;
+    let new_end_time = extended_params.end_time;
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, 3_600, 86_400 * 10)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            bob,
+            CampaignAction::UpdateCampaign {
+                params: Box::new(extended_params.clone()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::OwnershipError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::OwnershipError"),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateCampaign {
+                params: Box::new(CampaignParams {
+                    total_reward: coin(10_000, "uusdc"),
+                    ..extended_params.clone()
+                }),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCampaignParam { param, .. } => {
+                        assert_eq!(param, "total_reward");
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::InvalidCampaignParam"
+                    ),
+                }
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::UpdateCampaign {
+                params: Box::new(extended_params.clone()),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let event = response
+                    .events
+                    .iter()
+                    .find(|event| event.ty == "wasm-claimdrop/update_campaign")
+                    .unwrap();
+                let changed_fields = event
+                    .attributes
+                    .iter()
+                    .map(|attribute| attribute.key.as_str())
+                    .filter(|key| key.starts_with("new_"))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    changed_fields,
+                    vec!["new_distribution_type", "new_start_time", "new_end_time"]
+                );
+                assert!(response.has_event(
+                    &Event::new("wasm-claimdrop/update_campaign")
+                        .add_attribute(
+                            "old_start_time",
+                            current_time.plus_seconds(3_600).seconds().to_string()
+                        )
+                        .add_attribute("new_start_time", new_start_time.seconds().to_string())
+                ));
+            },
+        )
+        .query_campaign(|result| {
+            let campaign = result.unwrap();
+            assert_eq!(campaign.start_time, new_start_time);
+            assert_eq!(campaign.end_time, new_end_time);
+        });
+}
+
+#[test]
+fn cannot_update_campaign_after_it_starts() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let current_time = &suite.get_time();
+
+    suite
+        .instantiate_claimdrop_contract(Some(owner.to_string()))
+        .add_allocations(
+            owner,
+            &vec![(bob.to_string(), Uint128::new(10_000))],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .manage_campaign(
+            owner,
+            CampaignAction::CreateCampaign {
+                params: Box::new(campaign_params(current_time, 1, 86_400 * 10)),
+            },
+            &[],
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_day();
+
+    let current_time = &suite.get_time();
+
+    suite.manage_campaign(
+        owner,
+        CampaignAction::UpdateCampaign {
+            params: Box::new(campaign_params(current_time, 86_400, 86_400 * 10)),
+        },
+        &[],
+        |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignAlreadyStarted { .. } => {}
+                _ => {
+                    panic!("Wrong error type, should return ContractError::CampaignAlreadyStarted")
+                }
+            }
+        },
+    );
+}
@@ -0,0 +1,199 @@
+use cosmwasm_std::{coin, Addr, Decimal, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::TestingSuite;
+
+/// Sets up a campaign with a 30% lump sum and a 70% linear vesting over 10 days, the vesting
+/// starting `vesting_offset_days` after the campaign. The allocations go to bob, whose account
+/// exists, and to a fresh address. Returns the vesting start time and the fresh address.
+fn setup_campaign(suite: &mut TestingSuite, vesting_offset_days: u64) -> (Timestamp, Addr) {
+    let _owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+    let fresh = Addr::unchecked("mantra13qtg0gys4lfxccjeqed3vrdgmp7g5kzcmf7kjm");
+    let start_time = suite.get_time();
+    let vesting_start_time = start_time.plus_days(vesting_offset_days);
+
+    suite.setup_campaign(
+        &[
+            (bob.to_string(), Uint128::new(1_000)),
+            (fresh.to_string(), Uint128::new(1_000)),
+        ],
+        CampaignParams {
+            name: "Vesting Campaign".to_string(),
+            description: "Campaign with a linear vesting".to_string(),
+            ty: "airdrop".to_string(),
+            total_reward: coin(2_000, "uom"),
+            distribution_type: vec![
+                DistributionType::LumpSum {
+                    percentage: Decimal::percent(30),
+                    start_time,
+                    cliff_duration: None,
+                },
+                DistributionType::LinearVesting {
+                    percentage: Decimal::percent(70),
+                    start_time: vesting_start_time,
+                    end_time: vesting_start_time.plus_days(10),
+                    cliff_duration: None,
+                    cliff_behavior: None,
+                },
+            ],
+            start_time,
+            end_time: vesting_start_time.plus_days(10),
+            ..Default::default()
+        },
+    );
+
+    (vesting_start_time, fresh)
+}
+
+#[test]
+fn claim_into_vesting_account_locks_the_unvested_tokens() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    let (start_time, fresh) = setup_campaign(&mut suite, 0);
+
+    for _ in 0..5 {
+        suite.add_day();
+    }
+
+    suite
+        .claim_into_vesting_account(
+            owner,
+            Some(fresh.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let response = result.unwrap();
+                let event = response
+                    .events
+                    .iter()
+                    .find(|event| event.ty == "create_vesting_account")
+                    .unwrap();
+                let attribute = |key: &str| {
+                    event
+                        .attributes
+                        .iter()
+                        .find(|attribute| attribute.key == key)
+                        .unwrap()
+                        .value
+                        .clone()
+                };
+
+                // half of the linear vesting is locked until its end
+                assert_eq!(attribute("to_address"), fresh.to_string());
+                assert_eq!(attribute("amount"), "350uom");
+                assert_eq!(
+                    attribute("end_time"),
+                    start_time.plus_days(10).seconds().to_string()
+                );
+            },
+        )
+        // the lump sum and the vested tokens are sent, the rest goes to the vesting account
+        .query_balance("uom", &fresh, |balance| {
+            assert_eq!(balance, Uint128::new(1_000));
+        })
+        .query_campaign(|result| {
+            assert_eq!(result.unwrap().claimed, coin(1_000, "uom"));
+        })
+        .add_day()
+        .claim(
+            owner,
+            Some(fresh.to_string()),
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        );
+}
+
+#[test]
+fn claim_into_vesting_account_requires_a_fresh_receiver() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+    let bob = &suite.senders[1].clone();
+
+    let (_, fresh) = setup_campaign(&mut suite, 0);
+
+    suite
+        .add_day()
+        // bob's account exists, so the vesting module would refuse the vesting account
+        .claim_into_vesting_account(bob, None, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::ReceiverAccountExists { address } => {
+                    assert_eq!(address, bob.to_string());
+                }
+                _ => panic!("Wrong error type, should return ContractError::ReceiverAccountExists"),
+            }
+        })
+        // once the fresh address received tokens, its account exists as well
+        .claim(
+            owner,
+            Some(fresh.to_string()),
+            Some(Uint128::new(100)),
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .claim_into_vesting_account(
+            owner,
+            Some(fresh.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::ReceiverAccountExists { address } => {
+                        assert_eq!(address, fresh.to_string());
+                    }
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::ReceiverAccountExists"
+                    ),
+                }
+            },
+        );
+}
+
+#[test]
+fn claim_into_vesting_account_validations() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let owner = &suite.senders[0].clone();
+
+    let (_, fresh) = setup_campaign(&mut suite, 2);
+
+    suite
+        .add_day()
+        .claim_into_vesting_account(
+            owner,
+            Some(fresh.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "the linear vesting has not started yet");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        .add_week()
+        .add_week()
+        // the whole linear vesting has been vested
+        .claim_into_vesting_account(
+            owner,
+            Some(fresh.to_string()),
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "there are no unvested tokens left");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        );
+}
@@ -0,0 +1,159 @@
+use cosmwasm_std::{coin, Decimal, Uint128};
+use cw_multi_test::AppResponse;
+use mantra_claimdrop_std::error::ContractError;
+use mantra_claimdrop_std::msg::{CampaignParams, DistributionType};
+
+mod suite;
+use suite::{campaign_params, TestingSuite};
+
+fn setup_campaign(suite: &mut TestingSuite, vesting_from_activation: bool) {
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let current_time = &suite.get_time();
+
+    suite.setup_campaign(
+        &[
+            (bob.to_string(), Uint128::new(10_000)),
+            (carol.to_string(), Uint128::new(10_000)),
+        ],
+        CampaignParams {
+            distribution_type: vec![DistributionType::LinearVesting {
+                percentage: Decimal::one(),
+                start_time: current_time.plus_seconds(1),
+                end_time: current_time.plus_days(10),
+                cliff_duration: None,
+                cliff_behavior: None,
+            }],
+            end_time: current_time.plus_days(10),
+            vesting_from_activation,
+            ..campaign_params(current_time, 20_000)
+        },
+    );
+}
+
+#[test]
+fn vesting_starts_at_the_activation_of_each_address() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+    let carol = &suite.senders[2].clone();
+    let dan = &suite.senders[3].clone();
+
+    setup_campaign(&mut suite, true);
+
+    suite
+        .activate_vesting(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignNotStarted { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::CampaignNotStarted"),
+            }
+        })
+        .add_day()
+        .query_rewards(bob, |result| {
+            assert!(result.unwrap().available_to_claim.is_empty());
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::CampaignError { reason } => {
+                        assert_eq!(reason, "the vesting has not been activated");
+                    }
+                    _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+                }
+            },
+        )
+        .activate_vesting(dan, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::NoAllocationFound { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::NoAllocationFound"),
+            }
+        })
+        .activate_vesting(bob, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .activate_vesting(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { .. } => {}
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        });
+
+    let bob_activated_at = suite.get_time();
+
+    suite
+        .query_vesting_activation(bob, |result| {
+            assert_eq!(result.unwrap().activated_at, Some(bob_activated_at));
+        })
+        .query_vesting_activation(carol, |result| {
+            assert_eq!(result.unwrap().activated_at, None);
+        })
+        .add_day()
+        .add_day()
+        .activate_vesting(carol, |result: Result<AppResponse, anyhow::Error>| {
+            result.unwrap();
+        })
+        .add_day()
+        .add_day()
+        .add_day()
+        // bob vested for 5 days, carol for 3 days
+        .query_rewards(bob, |result| {
+            assert_eq!(result.unwrap().available_to_claim, vec![coin(5_000, "uom")]);
+        })
+        .query_rewards(carol, |result| {
+            assert_eq!(result.unwrap().available_to_claim, vec![coin(3_000, "uom")]);
+        })
+        .claim(
+            carol,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        )
+        .add_week()
+        .add_week()
+        // past the offset end of their schedules, both are fully vested
+        .query_rewards(bob, |result| {
+            assert_eq!(
+                result.unwrap().available_to_claim,
+                vec![coin(10_000, "uom")]
+            );
+        })
+        .query_rewards(carol, |result| {
+            assert_eq!(result.unwrap().available_to_claim, vec![coin(7_000, "uom")]);
+        });
+}
+
+#[test]
+fn activation_is_only_available_when_enabled() {
+    let mut suite = TestingSuite::default_with_balances(vec![coin(1_000_000_000, "uom")]);
+    let bob = &suite.senders[1].clone();
+
+    setup_campaign(&mut suite, false);
+
+    suite
+        .add_day()
+        .activate_vesting(bob, |result: Result<AppResponse, anyhow::Error>| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CampaignError { reason } => {
+                    assert_eq!(reason, "the campaign doesn't vest from the activation");
+                }
+                _ => panic!("Wrong error type, should return ContractError::CampaignError"),
+            }
+        })
+        .claim(
+            bob,
+            None,
+            None,
+            |result: Result<AppResponse, anyhow::Error>| {
+                result.unwrap();
+            },
+        );
+}
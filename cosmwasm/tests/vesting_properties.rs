@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use claimdrop_contract::helpers::{
+    aggregate_claims, calculate_claimable_amount, split_claim_across_slots, DistributionClaims,
+};
+use cosmwasm_std::{coin, Decimal, Timestamp, Uint128};
+use mantra_claimdrop_std::msg::{Campaign, CampaignParams, DistributionType};
+use proptest::prelude::*;
+
+const CAMPAIGN_START: u64 = 1_000;
+
+/// Builds a distribution with the given percentage (in permille), starting `offset` seconds after
+/// the campaign start.
+fn distribution(
+    permille: u64,
+    linear: bool,
+    offset: u64,
+    duration: u64,
+    cliff: Option<u64>,
+) -> DistributionType {
+    let percentage = Decimal::permille(permille);
+    let start_time = Timestamp::from_seconds(CAMPAIGN_START + offset);
+
+    if linear {
+        DistributionType::LinearVesting {
+            percentage,
+            start_time,
+            end_time: start_time.plus_seconds(duration),
+            cliff_duration: cliff
+                .map(|cliff| cliff % duration)
+                .filter(|cliff| *cliff > 0),
+            cliff_behavior: None,
+        }
+    } else {
+        DistributionType::LumpSum {
+            percentage,
+            start_time,
+            cliff_duration: cliff,
+        }
+    }
+}
+
+prop_compose! {
+    fn arb_campaign()(
+        split in 1u64..1_000,
+        two_slots in any::<bool>(),
+        first_linear in any::<bool>(),
+        second_linear in any::<bool>(),
+        offsets in (0u64..10_000, 0u64..10_000),
+        durations in (1u64..100_000, 1u64..100_000),
+        cliffs in (proptest::option::of(1u64..100_000), proptest::option::of(1u64..100_000)),
+    ) -> Campaign {
+        let distribution_type = if two_slots {
+            vec![
+                distribution(split, first_linear, offsets.0, durations.0, cliffs.0),
+                distribution(1_000 - split, second_linear, offsets.1, durations.1, cliffs.1),
+            ]
+        } else {
+            vec![distribution(1_000, first_linear, offsets.0, durations.0, cliffs.0)]
+        };
+
+        Campaign::from_params(CampaignParams {
+            name: "Property Campaign".to_string(),
+            description: "Campaign used for property testing".to_string(),
+            ty: "airdrop".to_string(),
+            total_reward: coin(u128::MAX, "uom"),
+            distribution_type,
+            start_time: Timestamp::from_seconds(CAMPAIGN_START),
+            end_time: Timestamp::from_seconds(CAMPAIGN_START + 200_000),
+            ..Default::default()
+        })
+    }
+}
+
+fn total(claims: &DistributionClaims) -> Uint128 {
+    claims
+        .values()
+        .fold(Uint128::zero(), |acc, (amount, _)| acc + *amount)
+}
+
+proptest! {
+    #[test]
+    fn vested_amount_is_monotonic_and_bounded(
+        campaign in arb_campaign(),
+        allocation in 1u128..1_000_000_000_000_000_000_000_000u128,
+        mut times in proptest::collection::vec(0u64..250_000, 1..20),
+    ) {
+        times.sort();
+        let allocation = Uint128::new(allocation);
+        let mut previously_vested = Uint128::zero();
+
+        for time in times {
+            let time = Timestamp::from_seconds(CAMPAIGN_START + time);
+            let (claimable, _, _) =
+                calculate_claimable_amount(&campaign, &time, allocation, HashMap::new()).unwrap();
+
+            prop_assert!(claimable.amount >= previously_vested);
+            prop_assert!(claimable.amount <= allocation);
+            previously_vested = claimable.amount;
+        }
+    }
+
+    #[test]
+    fn claims_never_exceed_allocation(
+        campaign in arb_campaign(),
+        allocation in 1u128..1_000_000_000_000_000_000_000_000u128,
+        mut claims in proptest::collection::vec((0u64..250_000, 1u64..=100), 1..20),
+    ) {
+        claims.sort();
+        let allocation = Uint128::new(allocation);
+        let mut previous_claims: DistributionClaims = HashMap::new();
+
+        for (time, claim_percent) in claims {
+            let time = Timestamp::from_seconds(CAMPAIGN_START + time);
+            let (claimable, new_claims, _) =
+                calculate_claimable_amount(&campaign, &time, allocation, previous_claims.clone())
+                    .unwrap();
+
+            let amount = claimable.amount.multiply_ratio(claim_percent, 100u64);
+            if amount.is_zero() {
+                continue;
+            }
+
+            let claims_to_record =
+                split_claim_across_slots(&campaign, &new_claims, amount, &time).unwrap();
+            prop_assert_eq!(total(&claims_to_record), amount);
+
+            let updated_claims = aggregate_claims(&previous_claims, &claims_to_record).unwrap();
+            prop_assert!(total(&updated_claims) >= total(&previous_claims));
+            prop_assert!(total(&updated_claims) <= allocation);
+            previous_claims = updated_claims;
+        }
+
+        // once all distributions have ended, the whole allocation can be claimed
+        let end = Timestamp::from_seconds(CAMPAIGN_START + 1_000_000);
+        let (claimable, _, _) =
+            calculate_claimable_amount(&campaign, &end, allocation, previous_claims.clone())
+                .unwrap();
+        prop_assert_eq!(total(&previous_claims) + claimable.amount, allocation);
+    }
+}